@@ -2,6 +2,8 @@
 
 use std::collections::{HashMap, HashSet};
 
+use super::types::MethodRoute;
+
 /// Error returned by [`generate`](super::generate).
 #[derive(Debug, thiserror::Error)]
 #[non_exhaustive]
@@ -21,14 +23,16 @@ pub enum GenerateError {
         param: String,
     },
 
-    /// Partial body selector (body is a field name, not `"*"`).
+    /// Partial body selector (body is a field name, not `"*"`) that cannot
+    /// be bound.
     ///
-    /// Currently only `body: "*"` (whole message) is supported.
-    /// Field-level body selectors like `body: "user"` require sub-message
-    /// deserialization that is not yet implemented.
+    /// Field-level selectors like `body: "user"` must name a singular message
+    /// or scalar field of the request message. Server-streaming methods only
+    /// support `body: "*"`.
     #[error(
         "partial body selector `{body}` in method `{method}` is not supported; \
-         use `body: \"*\"` instead"
+         it must name a singular message or scalar request field \
+         (streaming methods require `body: \"*\"`)"
     )]
     UnsupportedBodySelector {
         /// The RPC method name.
@@ -37,11 +41,51 @@ pub enum GenerateError {
         body: String,
     },
 
+    /// `response_body` selector that cannot be served.
+    ///
+    /// The selector must name a field of the response message, and
+    /// server-streaming methods always return whole messages.
+    #[error(
+        "response_body selector `{response_body}` in method `{method}` is not supported; \
+         it must name a response field (server-streaming methods return whole messages)"
+    )]
+    UnsupportedResponseBody {
+        /// The RPC method name.
+        method: String,
+        /// The unsupported `response_body` value.
+        response_body: String,
+    },
+
+    /// A streaming RPC shape that has no REST mapping.
+    ///
+    /// Client-streaming methods take an NDJSON body, so they require
+    /// `body: "*"`, no path parameters, and a unary response.
+    #[error("streaming method `{method}` cannot be exposed over REST: {reason}")]
+    UnsupportedStreaming {
+        /// The RPC method name.
+        method: String,
+        /// Why the method shape is unsupported.
+        reason: String,
+    },
+
     /// Generic configuration error.
     #[error("{0}")]
     Config(String),
 }
 
+/// Wire format for server-streaming REST responses.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StreamingFormat {
+    /// Server-Sent Events (`text/event-stream`) with named, numbered events
+    /// and keep-alive comments.
+    #[default]
+    Sse,
+    /// Newline-delimited JSON (`application/x-ndjson`): one message per line,
+    /// with a failed stream ending in an `{"error": {...}}` line.
+    Ndjson,
+}
+
 /// Configuration for REST route code generation.
 ///
 /// Decouples the generator from any specific service — all project-specific
@@ -77,6 +121,7 @@ pub enum GenerateError {
 /// let code = tonic_rest_build::generate(&descriptor_bytes, &config)?;
 /// ```
 #[derive(Clone, Debug)]
+#[expect(clippy::struct_excessive_bools)]
 pub struct RestCodegenConfig {
     /// Proto package → Rust module mapping.
     ///
@@ -90,6 +135,13 @@ pub struct RestCodegenConfig {
     /// - Value: Rust module path (e.g., `"auth"` or `"auth::v1"`)
     pub(crate) packages: HashMap<String, String>,
 
+    /// Rust module paths that replace the inferred ones during auto-discovery.
+    ///
+    /// For packages whose prost module diverges from dots → `::` (e.g. via
+    /// `prost_build::Config::include_file` layouts or re-exports). Unlike
+    /// [`packages`](Self::packages), overrides keep auto-discovery enabled.
+    pub(crate) module_overrides: HashMap<String, String>,
+
     /// Proto method names whose REST paths should bypass authentication.
     ///
     /// These are emitted as `PUBLIC_REST_PATHS` in the generated code.
@@ -118,6 +170,15 @@ pub struct RestCodegenConfig {
     /// SSE keep-alive interval in seconds (default: 15).
     pub(crate) sse_keep_alive_secs: u64,
 
+    /// Fixed `event:` name for streamed SSE messages.
+    ///
+    /// When `None`, messages are unnamed and delivered to
+    /// `EventSource.onmessage`.
+    pub(crate) sse_event_name: Option<String>,
+
+    /// Response format for server-streaming methods (default: SSE).
+    pub(crate) streaming_format: StreamingFormat,
+
     /// Concrete extension type extracted from Axum request extensions.
     ///
     /// When set, generated handlers use `Option<Extension<{extension_type}>>` to
@@ -132,19 +193,111 @@ pub struct RestCodegenConfig {
     /// and call `build_tonic_request_with_headers` instead of `build_tonic_request`.
     /// Use this for vendor-specific headers (e.g., `["cf-connecting-ip"]` for Cloudflare).
     pub(crate) extra_forwarded_headers: Vec<String>,
+
+    /// Cap on the forwarded headers' total metadata size, in bytes.
+    ///
+    /// When set, generated handlers call `build_tonic_request_checked` (or
+    /// `check_forwarded_headers` before resumable SSE requests) and return a
+    /// 400 `RestError` when the cap is exceeded.
+    pub(crate) max_forwarded_header_bytes: Option<usize>,
+
+    /// gRPC response metadata keys copied into HTTP response headers.
+    ///
+    /// When set, unary handlers return `(HeaderMap, ...)` and call
+    /// `apply_response_metadata` with the generated `RESPONSE_HEADERS` constant.
+    pub(crate) response_headers: Vec<String>,
+
+    /// Request fields read from HTTP request headers instead of the body or
+    /// query string (proto field names, e.g. `client_version`).
+    pub(crate) header_fields: Vec<String>,
+
+    /// Proto method names whose request body is bound with `Form<T>`
+    /// (`application/x-www-form-urlencoded`) instead of `Json<T>`.
+    pub(crate) form_urlencoded_methods: HashSet<String>,
+
+    /// Path prefix prepended to every generated route and public path
+    /// (e.g., `/api`); empty by default.
+    pub(crate) route_prefix: String,
+
+    /// Also emit `RestServices` and `all_rest_routes_with` (default: `false`).
+    pub(crate) unified_state: bool,
+
+    /// Wrap each service call in a `tracing::info_span!` (default: `false`).
+    pub(crate) tracing: bool,
+
+    /// Register an explicit `HEAD` route for every `GET` route (default: `false`).
+    pub(crate) auto_head: bool,
+
+    /// Register an `OPTIONS` route per path listing its methods (default: `false`).
+    pub(crate) cors_preflight: bool,
+
+    /// Make handlers `pub` and emit a `pub const` route descriptor for each
+    /// (default: `false`).
+    pub(crate) emit_route_descriptors: bool,
+
+    /// Add operation ID and description lines to handler doc comments
+    /// (default: `false`).
+    pub(crate) emit_doc_comments: bool,
+
+    /// Emit `REST_ROUTE_COUNT`, `rest_routes()` and `operation_id_for`
+    /// (default: `false`).
+    pub(crate) emit_route_manifest: bool,
+
+    /// Proto method name → description for [`Self::emit_doc_comments`].
+    pub(crate) method_descriptions: HashMap<String, String>,
+
+    /// Prepend a `#![allow(...)]` block to the generated file (default: `false`).
+    pub(crate) emit_lint_allows: bool,
+
+    /// Lints named in the `#![allow(...)]` block (default: [`DEFAULT_LINT_ALLOWS`]).
+    pub(crate) lint_allows: Vec<String>,
 }
 
+/// Lints allowed by [`RestCodegenConfig::emit_lint_allows`] unless overridden
+/// with [`RestCodegenConfig::lint_allows`].
+///
+/// `unfulfilled_lint_expectations` covers the `#[expect(...)]` attributes on
+/// generated handlers, which stay unfulfilled when a lint does not fire.
+pub const DEFAULT_LINT_ALLOWS: &[&str] = &[
+    "clippy::all",
+    "clippy::pedantic",
+    "unused",
+    "missing_docs",
+    "unfulfilled_lint_expectations",
+];
+
 impl Default for RestCodegenConfig {
     fn default() -> Self {
         Self {
             packages: HashMap::new(),
+            module_overrides: HashMap::new(),
             public_methods: HashSet::new(),
             proto_root: "crate".to_string(),
             runtime_crate: "tonic_rest".to_string(),
             wrapper_type: None,
             sse_keep_alive_secs: 15,
+            sse_event_name: None,
+            streaming_format: StreamingFormat::Sse,
             extension_type: None,
             extra_forwarded_headers: Vec::new(),
+            max_forwarded_header_bytes: None,
+            response_headers: Vec::new(),
+            header_fields: Vec::new(),
+            form_urlencoded_methods: HashSet::new(),
+            route_prefix: String::new(),
+            unified_state: false,
+            tracing: false,
+            auto_head: false,
+            cors_preflight: false,
+            emit_doc_comments: false,
+            emit_route_descriptors: false,
+            emit_route_manifest: false,
+            method_descriptions: HashMap::new(),
+            emit_lint_allows: false,
+            lint_allows: DEFAULT_LINT_ALLOWS
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
         }
     }
 }
@@ -173,6 +326,25 @@ impl RestCodegenConfig {
         self
     }
 
+    /// Override the Rust module inferred for a proto package during auto-discovery.
+    ///
+    /// Auto-discovery maps `auth.v1` → `auth::v1`. When prost places the
+    /// package elsewhere, point it at the real module without giving up
+    /// discovery of the remaining packages. Overrides also apply to packages
+    /// without HTTP services, so cross-package type references resolve too.
+    /// Ignored when packages are registered explicitly via [`package`](Self::package).
+    ///
+    /// # Example
+    /// ```ignore
+    /// config.module_override("store.v1", "store_api")
+    /// ```
+    #[must_use]
+    pub fn module_override(mut self, proto_package: &str, rust_module: &str) -> Self {
+        self.module_overrides
+            .insert(proto_package.to_string(), rust_module.to_string());
+        self
+    }
+
     /// Set proto method names whose REST paths bypass authentication.
     ///
     /// Method names should be in `PascalCase` as defined in proto (e.g., `"Authenticate"`).
@@ -221,6 +393,38 @@ impl RestCodegenConfig {
         self
     }
 
+    /// Use a fixed `event:` name for every streamed SSE message.
+    ///
+    /// By default messages carry no `event:` line, so browsers deliver them to
+    /// `EventSource.onmessage`; a named event is only seen by
+    /// `addEventListener(name, ...)`. Error events always use `error`.
+    ///
+    /// # Example
+    /// ```ignore
+    /// config.sse_event_name("message")
+    /// ```
+    #[must_use]
+    pub fn sse_event_name(mut self, name: &str) -> Self {
+        self.sse_event_name = Some(name.to_string());
+        self
+    }
+
+    /// Choose how server-streaming methods are served (default: SSE).
+    ///
+    /// With [`StreamingFormat::Ndjson`], handlers return an
+    /// `application/x-ndjson` body built by the runtime's `ndjson_response`;
+    /// the SSE keep-alive and event-name options then have no effect.
+    ///
+    /// # Example
+    /// ```ignore
+    /// config.streaming_format(StreamingFormat::Ndjson)
+    /// ```
+    #[must_use]
+    pub fn streaming_format(mut self, format: StreamingFormat) -> Self {
+        self.streaming_format = format;
+        self
+    }
+
     /// Set the extension type extracted from Axum request extensions.
     ///
     /// When set, generated handlers use `Option<Extension<T>>` to extract
@@ -254,6 +458,281 @@ impl RestCodegenConfig {
         self
     }
 
+    /// Reject requests whose forwarded headers exceed `max_bytes` of gRPC
+    /// metadata.
+    ///
+    /// Generated handlers then build requests with the runtime's
+    /// `build_tonic_request_checked` and answer oversized headers with a 400
+    /// `RestError` tagged with the handler's method and route. Unset by
+    /// default, which forwards headers of any size; the runtime's
+    /// `DEFAULT_MAX_FORWARDED_HEADER_BYTES` is a sensible value.
+    ///
+    /// # Example
+    /// ```ignore
+    /// config.max_forwarded_header_bytes(8 * 1024)
+    /// ```
+    #[must_use]
+    pub const fn max_forwarded_header_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_forwarded_header_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Copy these gRPC response metadata keys into HTTP response headers.
+    ///
+    /// Applies to unary and client-streaming handlers; SSE responses are
+    /// unaffected. Keys are matched case-insensitively.
+    ///
+    /// # Example
+    /// ```ignore
+    /// config.response_headers(&["x-request-id", "etag"])
+    /// ```
+    #[must_use]
+    pub fn response_headers(mut self, keys: &[&str]) -> Self {
+        self.response_headers = keys.iter().map(|k| k.to_ascii_lowercase()).collect();
+        self
+    }
+
+    /// Read these request fields from HTTP request headers.
+    ///
+    /// Each field is read from the header named after it with `_` replaced by
+    /// `-` (`client_version` → `client-version`) and overrides any value from
+    /// the JSON body; it is no longer bound from the query string. Only
+    /// singular `string` fields are supported, and only unary handlers read
+    /// them. Pair with `header_fields` in the `tonic-rest-openapi` config so
+    /// the spec documents the same headers.
+    ///
+    /// # Example
+    /// ```ignore
+    /// config.header_fields(&["client_version", "user_agent"])
+    /// ```
+    #[must_use]
+    pub fn header_fields(mut self, fields: &[&str]) -> Self {
+        self.header_fields = fields.iter().map(ToString::to_string).collect();
+        self
+    }
+
+    /// Bind the request body of these proto methods with `axum::extract::Form`.
+    ///
+    /// Use this for endpoints that accept `application/x-www-form-urlencoded`
+    /// bodies, such as OAuth token endpoints. Only unary methods with a
+    /// request body are affected; the generated code needs axum's `form`
+    /// feature. Pair with `form_urlencoded_endpoints` in the
+    /// `tonic-rest-openapi` config so the spec documents the same media type.
+    ///
+    /// # Example
+    /// ```ignore
+    /// config.form_urlencoded_methods(&["IssueToken"])
+    /// ```
+    #[must_use]
+    pub fn form_urlencoded_methods(mut self, methods: &[&str]) -> Self {
+        self.form_urlencoded_methods = methods.iter().map(ToString::to_string).collect();
+        self
+    }
+
+    /// Prefix every generated route with a base path.
+    ///
+    /// Applies to router registrations, `PUBLIC_REST_PATHS`, and the route
+    /// manifest, so auth middleware keeps matching the served paths. A
+    /// missing leading `/` is added and a trailing `/` dropped; an empty
+    /// prefix (the default) registers paths exactly as in the proto.
+    ///
+    /// # Example
+    /// ```ignore
+    /// // `/v1/users` is served at `/api/v1/users`
+    /// config.route_prefix("/api")
+    /// ```
+    #[must_use]
+    pub fn route_prefix(mut self, prefix: &str) -> Self {
+        let prefix = prefix.trim_end_matches('/');
+        self.route_prefix = if prefix.is_empty() || prefix.starts_with('/') {
+            prefix.to_string()
+        } else {
+            format!("/{prefix}")
+        };
+        self
+    }
+
+    /// Also generate a single-state entry point for the combined router.
+    ///
+    /// Emits a `RestServices` trait with one associated type and accessor per
+    /// service, and `all_rest_routes_with(state)` that builds the same router
+    /// as `all_rest_routes` from one application-state value. The
+    /// multi-generic `all_rest_routes` is still generated.
+    ///
+    /// # Example
+    /// ```ignore
+    /// config.unified_state(true)
+    /// ```
+    #[must_use]
+    pub const fn unified_state(mut self, enabled: bool) -> Self {
+        self.unified_state = enabled;
+        self
+    }
+
+    /// Instrument each generated handler with a `tracing` span.
+    ///
+    /// The service call runs inside `tracing::info_span!("{Service}.{Method}")`
+    /// with `http.method` and `http.route` fields. Requires `tracing` in the
+    /// consuming crate's `[dependencies]`; off by default.
+    ///
+    /// # Example
+    /// ```ignore
+    /// config.tracing(true)
+    /// ```
+    #[must_use]
+    pub const fn tracing(mut self, enabled: bool) -> Self {
+        self.tracing = enabled;
+        self
+    }
+
+    /// Register a `HEAD` route alongside every generated `GET` route.
+    ///
+    /// Each `GET` path also gets `axum::routing::head` with the same handler;
+    /// Axum strips the response body for `HEAD` requests, so clients can
+    /// probe existence and headers cheaply. Paths that already have a `HEAD`
+    /// binding in the proto keep it. The generated `HEAD` routes are listed in
+    /// the route manifest (`REST_ROUTE_COUNT`, `rest_routes()`,
+    /// `operation_id_for`); the `OpenAPI` output stays `GET`-only.
+    ///
+    /// # Example
+    /// ```ignore
+    /// config.auto_head(true)
+    /// ```
+    #[must_use]
+    pub const fn auto_head(mut self, enabled: bool) -> Self {
+        self.auto_head = enabled;
+        self
+    }
+
+    /// Answer CORS preflight requests on every generated path.
+    ///
+    /// Each distinct path in a service router gets an `OPTIONS` route that
+    /// returns `204 No Content` with `Allow` and
+    /// `Access-Control-Allow-Methods` headers listing the methods registered
+    /// on that path. Paths with an `OPTIONS` binding in the proto keep it.
+    /// Origin and header policy are left to a CORS layer such as
+    /// `tower_http::cors`.
+    ///
+    /// The preflight routes are counted in `REST_ROUTE_COUNT` and listed by
+    /// `rest_routes()`; `operation_id_for` returns `None` for them since they
+    /// have no proto operation.
+    ///
+    /// # Example
+    /// ```ignore
+    /// config.cors_preflight(true)
+    /// ```
+    #[must_use]
+    pub const fn cors_preflight(mut self, enabled: bool) -> Self {
+        self.cors_preflight = enabled;
+        self
+    }
+
+    /// Expand the `///` doc comment on each generated handler.
+    ///
+    /// Besides the proto method name and `HTTP_METHOD /path` line, the doc
+    /// comment then names the `OpenAPI` operation ID (`{Service}_{Method}`)
+    /// and, when registered with [`Self::method_description`], the method's
+    /// description — so `cargo doc` on the generated module is useful.
+    ///
+    /// # Example
+    /// ```ignore
+    /// config.emit_doc_comments(true)
+    /// ```
+    #[must_use]
+    pub const fn emit_doc_comments(mut self, enabled: bool) -> Self {
+        self.emit_doc_comments = enabled;
+        self
+    }
+
+    /// Expose each handler for manual routing.
+    ///
+    /// Generated handlers become `pub`, and each gets a
+    /// `pub const {HANDLER}_ROUTE: (axum::http::Method, &str)` with its HTTP
+    /// method and Axum path, so callers can assemble their own router
+    /// instead of using `*_rest_router`:
+    ///
+    /// ```ignore
+    /// let (method, path) = REST_USER_SERVICE_GET_USER_ROUTE;
+    /// let filter = axum::routing::MethodFilter::try_from(method)?;
+    /// let router = Router::new()
+    ///     .route(path, axum::routing::on(filter, rest_user_service_get_user::<MyService>))
+    ///     .with_state(service);
+    /// ```
+    ///
+    /// # Example
+    /// ```ignore
+    /// config.emit_route_descriptors(true)
+    /// ```
+    #[must_use]
+    pub const fn emit_route_descriptors(mut self, enabled: bool) -> Self {
+        self.emit_route_descriptors = enabled;
+        self
+    }
+
+    /// Emit a manifest of the generated routes.
+    ///
+    /// Adds `REST_ROUTE_COUNT`, `rest_routes()` listing every registered
+    /// `(method, path)` pair, and `operation_id_for(method, matched_path)`
+    /// mapping an `axum::extract::MatchedPath` back to the proto operation
+    /// ID. Downstream tests can assert on the count or set to catch routes
+    /// lost in proto refactors.
+    ///
+    /// # Example
+    /// ```ignore
+    /// config.emit_route_manifest(true)
+    /// ```
+    #[must_use]
+    pub const fn emit_route_manifest(mut self, enabled: bool) -> Self {
+        self.emit_route_manifest = enabled;
+        self
+    }
+
+    /// Describe a proto method in the doc comments enabled by
+    /// [`Self::emit_doc_comments`].
+    ///
+    /// The description may span several lines; each becomes a `///` line.
+    ///
+    /// # Example
+    /// ```ignore
+    /// config.method_description("CreateItem", "Create an item in the catalog.")
+    /// ```
+    #[must_use]
+    pub fn method_description(mut self, proto_method: &str, description: &str) -> Self {
+        self.method_descriptions
+            .insert(proto_method.to_string(), description.to_string());
+        self
+    }
+
+    /// Prepend a module-level `#![allow(...)]` block to the generated file.
+    ///
+    /// Lets the output compile in crates built with `#![deny(warnings)]` or
+    /// strict workspace lints. Inner attributes are only accepted at the top
+    /// of a module file, so compile the output as a module (e.g., copied into
+    /// `src/`) rather than through `include!`.
+    ///
+    /// # Example
+    /// ```ignore
+    /// config.emit_lint_allows(true)
+    /// ```
+    #[must_use]
+    pub const fn emit_lint_allows(mut self, enabled: bool) -> Self {
+        self.emit_lint_allows = enabled;
+        self
+    }
+
+    /// Replace the lints named by [`emit_lint_allows`](Self::emit_lint_allows).
+    ///
+    /// # Example
+    /// ```ignore
+    /// config.emit_lint_allows(true)
+    ///       .lint_allows(&["clippy::pedantic", "unused_imports"])
+    /// ```
+    #[must_use]
+    pub fn lint_allows(mut self, lints: &[&str]) -> Self {
+        self.lint_allows = lints.iter().map(ToString::to_string).collect();
+        self
+    }
+
     /// Resolve a proto package name to its Rust module name.
     pub(crate) fn rust_module(&self, proto_package: &str) -> Option<&str> {
         self.packages.get(proto_package).map(String::as_str)
@@ -275,31 +754,99 @@ impl RestCodegenConfig {
     /// When `extra_forwarded_headers` is empty, uses `build_tonic_request`
     /// (which forwards the default header set). When extra headers are
     /// configured, uses `build_tonic_request_with_headers` with the
-    /// generated `ALL_FORWARDED_HEADERS` constant.
-    pub(crate) fn extension_and_request_lines(&self, body_var: &str) -> String {
+    /// generated `ALL_FORWARDED_HEADERS` constant. With
+    /// `max_forwarded_header_bytes` set, uses `build_tonic_request_checked`
+    /// and tags its error with `method`'s HTTP method and route.
+    pub(crate) fn extension_and_request_lines(
+        &self,
+        body_var: &str,
+        method: &MethodRoute,
+    ) -> String {
+        self.request_lines(body_var, method, RequestKind::Unary)
+    }
+
+    /// Like [`Self::extension_and_request_lines`], for client-streaming
+    /// handlers where `body_var` is the raw NDJSON `axum::body::Body`.
+    pub(crate) fn extension_and_stream_request_lines(
+        &self,
+        body_var: &str,
+        method: &MethodRoute,
+    ) -> String {
+        self.request_lines(body_var, method, RequestKind::ClientStream)
+    }
+
+    /// Like [`Self::extension_and_request_lines`], for SSE handlers: also
+    /// forwards `Last-Event-ID` via `build_tonic_request_with_resume`.
+    pub(crate) fn extension_and_resume_request_lines(
+        &self,
+        body_var: &str,
+        method: &MethodRoute,
+    ) -> String {
+        self.request_lines(body_var, method, RequestKind::Resume)
+    }
+
+    fn request_lines(&self, body_var: &str, method: &MethodRoute, kind: RequestKind) -> String {
         let rt = &self.runtime_crate;
-        let build_fn = if self.extra_forwarded_headers.is_empty() {
-            match &self.extension_type {
-                Some(_) => format!("{rt}::build_tonic_request({body_var}, &headers, ext)",),
-                None => format!("{rt}::build_tonic_request::<_, ()>({body_var}, &headers, None)",),
-            }
+        let (type_args, ext_arg) = match &self.extension_type {
+            Some(_) => ("", "ext"),
+            None => ("::<_, ()>", "None"),
+        };
+        let stream = kind == RequestKind::ClientStream;
+        let forwarded = if self.extra_forwarded_headers.is_empty() {
+            format!("{rt}::FORWARDED_HEADERS")
         } else {
-            match &self.extension_type {
-                Some(_) => format!(
-                    "{rt}::build_tonic_request_with_headers({body_var}, &headers, ext, ALL_FORWARDED_HEADERS)",
-                ),
-                None => format!(
-                    "{rt}::build_tonic_request_with_headers::<_, ()>({body_var}, &headers, None, ALL_FORWARDED_HEADERS)",
-                ),
+            "ALL_FORWARDED_HEADERS".to_string()
+        };
+        let with_context = format!(
+            ".map_err(|err| err.with_context({:?}, {:?}))?",
+            method.http_method.to_uppercase(),
+            method.path,
+        );
+        let mut check = String::new();
+        let build_fn = if kind == RequestKind::Resume {
+            if let Some(max) = self.max_forwarded_header_bytes {
+                check = format!(
+                    "    {rt}::check_forwarded_headers(&headers, {forwarded}, {max})\n\
+                     \x20       {with_context};\n",
+                );
             }
+            format!(
+                "{rt}::build_tonic_request_with_resume{type_args}({body_var}, &headers, {ext_arg}, {forwarded})",
+            )
+        } else if let Some(max) = self.max_forwarded_header_bytes {
+            let body = if stream {
+                format!("{rt}::ndjson_streaming({body_var})")
+            } else {
+                body_var.to_string()
+            };
+            format!(
+                "{rt}::build_tonic_request_checked{type_args}({body}, &headers, {ext_arg}, {forwarded}, {max})\n\
+                 \x20       {with_context}",
+            )
+        } else if self.extra_forwarded_headers.is_empty() {
+            let func = if stream {
+                "build_tonic_request_stream"
+            } else {
+                "build_tonic_request"
+            };
+            format!("{rt}::{func}{type_args}({body_var}, &headers, {ext_arg})")
+        } else {
+            let body = if stream {
+                format!("{rt}::ndjson_streaming({body_var})")
+            } else {
+                body_var.to_string()
+            };
+            format!(
+                "{rt}::build_tonic_request_with_headers{type_args}({body}, &headers, {ext_arg}, ALL_FORWARDED_HEADERS)",
+            )
         };
 
         match &self.extension_type {
             Some(_) => format!(
                 "    let ext = ext.map(|Extension(v)| v);\n\
-                 \x20   let req = {build_fn};\n",
+                 {check}\x20   let req = {build_fn};\n",
             ),
-            None => format!("    let req = {build_fn};\n",),
+            None => format!("{check}    let req = {build_fn};\n"),
         }
     }
 
@@ -345,3 +892,14 @@ impl RestCodegenConfig {
         }
     }
 }
+
+/// Which `build_tonic_request*` helper a generated handler calls.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum RequestKind {
+    /// Unary JSON handler.
+    Unary,
+    /// Client-streaming handler reading an NDJSON body.
+    ClientStream,
+    /// SSE handler that forwards `Last-Event-ID`.
+    Resume,
+}
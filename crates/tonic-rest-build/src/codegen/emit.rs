@@ -5,12 +5,16 @@
 
 use std::fmt::Write as _;
 
-use super::config::RestCodegenConfig;
-use super::types::{MethodRoute, ParamAssignment, ServiceRoute};
+use super::config::{RestCodegenConfig, StreamingFormat};
+use super::types::{MethodRoute, ParamAssignment, QueryParamKind, ResponseField, ServiceRoute};
 
 pub fn generate_code(services: &[ServiceRoute], config: &RestCodegenConfig) -> String {
     let mut code = String::with_capacity(16_384);
 
+    if config.emit_lint_allows && !config.lint_allows.is_empty() {
+        let _ = writeln!(code, "#![allow({})]\n", config.lint_allows.join(", "));
+    }
+
     write_header(&mut code, services, config);
 
     // Per-service routers and handlers
@@ -24,9 +28,11 @@ pub fn generate_code(services: &[ServiceRoute], config: &RestCodegenConfig) -> S
     code
 }
 
+#[expect(clippy::too_many_lines)]
 fn write_header(code: &mut String, services: &[ServiceRoute], config: &RestCodegenConfig) {
     // Scan all methods to determine which imports are actually needed.
     let mut needs_json = false;
+    let mut needs_form = false;
     let mut needs_query = false;
     let mut needs_path = false;
     let mut needs_status_code = false;
@@ -35,12 +41,19 @@ fn write_header(code: &mut String, services: &[ServiceRoute], config: &RestCodeg
     for service in services {
         for method in &service.methods {
             if method.server_streaming {
-                needs_sse = true;
+                needs_sse |= config.streaming_format == StreamingFormat::Sse;
                 if method.http_method == "get" {
                     needs_query = true;
                 } else {
                     needs_json = true;
                 }
+            } else if method.client_streaming {
+                // NDJSON body in, unary response out
+                if method.returns_empty {
+                    needs_status_code = true;
+                } else {
+                    needs_json = true;
+                }
             } else {
                 // JSON handler
                 if method.returns_empty {
@@ -48,10 +61,19 @@ fn write_header(code: &mut String, services: &[ServiceRoute], config: &RestCodeg
                 } else {
                     needs_json = true; // Json<Response>
                 }
-                if method.has_body && method.http_method != "get" {
+                if method.form_body {
+                    needs_form = true; // Form(payload) or Form(body)
+                    needs_query |= method.body_field.is_some();
+                } else if method.body_field.is_some() {
+                    needs_json = true; // Json(payload)
+                    needs_query = true; // Query(body) for sibling fields
+                } else if method.has_body && method.http_method != "get" {
                     needs_json = true; // Json(body)
-                } else if method.http_method == "get" {
-                    needs_query = true; // Query(body)
+                } else if method.has_body
+                    || method.query_binds_request
+                    || !method.query_params.is_empty()
+                {
+                    needs_query = true; // Query(body) or Query(query)
                 }
             }
             if !method.path_params.is_empty() {
@@ -87,6 +109,9 @@ fn write_header(code: &mut String, services: &[ServiceRoute], config: &RestCodeg
     if config.extension_type.is_some() {
         extractors.push("Extension");
     }
+    if needs_form {
+        extractors.push("Form");
+    }
     if needs_json {
         extractors.push("Json");
     }
@@ -133,6 +158,15 @@ fn write_header(code: &mut String, services: &[ServiceRoute], config: &RestCodeg
         }
         code.push_str(");\n\n");
     }
+
+    if !config.response_headers.is_empty() {
+        code.push_str("/// gRPC response metadata keys copied to HTTP response headers.\n");
+        code.push_str("const RESPONSE_HEADERS: &[&str] = &[\n");
+        for h in &config.response_headers {
+            let _ = writeln!(code, "    \"{h}\",");
+        }
+        code.push_str("];\n\n");
+    }
 }
 
 fn generate_service(code: &mut String, service: &ServiceRoute, config: &RestCodegenConfig) {
@@ -164,7 +198,7 @@ where
     );
 
     for method in &service.methods {
-        let handler_name = format!("rest_{}_{}", svc_snake, method.rust_name);
+        let handler_name = method.handler_name(&svc_snake);
         let _ = writeln!(
             code,
             "        .route(\"{path}\", axum::routing::{http_method}({handler}::<S>))",
@@ -172,6 +206,26 @@ where
             http_method = method.http_method,
             handler = handler_name,
         );
+        if adds_auto_head(service, method, config) {
+            let _ = writeln!(
+                code,
+                "        .route(\"{path}\", axum::routing::head({handler_name}::<S>))",
+                path = method.axum_path,
+            );
+        }
+    }
+    if config.cors_preflight {
+        generate_preflight_routes(code, service, config);
+    }
+    for skipped in &service.skipped_bindings {
+        let _ = writeln!(
+            code,
+            "        // WARNING: `{proto_name}` binding `{kind} {path}` skipped: \
+             custom HTTP verb has no `axum::routing` method",
+            proto_name = skipped.proto_name,
+            kind = skipped.kind,
+            path = skipped.path,
+        );
     }
 
     code.push_str("        .with_state(service)\n}\n\n");
@@ -179,13 +233,92 @@ where
     // Handler functions
     for method in &service.methods {
         if method.server_streaming {
-            generate_sse_handler(code, service, method, config);
+            match config.streaming_format {
+                StreamingFormat::Sse => generate_sse_handler(code, service, method, config),
+                StreamingFormat::Ndjson => generate_ndjson_handler(code, service, method, config),
+            }
+        } else if method.client_streaming {
+            generate_client_stream_handler(code, service, method, config);
         } else {
             generate_json_handler(code, service, method, config);
         }
     }
 }
 
+/// Whether `auto_head` pairs this `GET` route with a generated `HEAD` route.
+///
+/// Paths that already carry a proto `HEAD` binding keep it instead.
+fn adds_auto_head(
+    service: &ServiceRoute,
+    method: &MethodRoute,
+    config: &RestCodegenConfig,
+) -> bool {
+    config.auto_head
+        && method.http_method == "get"
+        && !service
+            .methods
+            .iter()
+            .any(|m| m.http_method == "head" && m.axum_path == method.axum_path)
+}
+
+/// Emit one `OPTIONS` route per distinct path, listing the methods routed there.
+fn generate_preflight_routes(
+    code: &mut String,
+    service: &ServiceRoute,
+    config: &RestCodegenConfig,
+) {
+    for (path, allowed) in preflight_routes(service, config) {
+        let _ = writeln!(
+            code,
+            "        .route(\"{path}\", axum::routing::options(|| async {{
+            (
+                axum::http::StatusCode::NO_CONTENT,
+                [
+                    (axum::http::header::ALLOW, \"{allowed}\"),
+                    (axum::http::header::ACCESS_CONTROL_ALLOW_METHODS, \"{allowed}\"),
+                ],
+            )
+        }}))"
+        );
+    }
+}
+
+/// `(path, Allow header)` of every `OPTIONS` route `cors_preflight` adds.
+///
+/// Paths with a proto `OPTIONS` binding keep it and get no preflight route.
+fn preflight_routes<'a>(
+    service: &'a ServiceRoute,
+    config: &RestCodegenConfig,
+) -> Vec<(&'a str, String)> {
+    let mut paths: Vec<&str> = Vec::new();
+    for method in &service.methods {
+        if !paths.contains(&method.axum_path.as_str()) {
+            paths.push(&method.axum_path);
+        }
+    }
+
+    let mut routes = Vec::new();
+    for path in paths {
+        let mut allowed: Vec<String> = Vec::new();
+        for method in service.methods.iter().filter(|m| m.axum_path == path) {
+            let verb = method.http_method.to_uppercase();
+            if !allowed.contains(&verb) {
+                allowed.push(verb);
+            }
+        }
+        if allowed.iter().any(|m| m == "OPTIONS") {
+            continue;
+        }
+        let has_get = allowed.iter().any(|m| m == "GET");
+        if config.auto_head && has_get && !allowed.iter().any(|m| m == "HEAD") {
+            allowed.push("HEAD".to_string());
+        }
+        allowed.push("OPTIONS".to_string());
+        routes.push((path, allowed.join(", ")));
+    }
+    routes
+}
+
 fn generate_sse_handler(
     code: &mut String,
     service: &ServiceRoute,
@@ -193,14 +326,14 @@ fn generate_sse_handler(
     config: &RestCodegenConfig,
 ) {
     let svc_snake = super::to_snake_case(&service.service_name);
-    let handler_name = format!("rest_{}_{}", svc_snake, method.rust_name);
+    let handler_name = method.handler_name(&svc_snake);
     let trait_path = format!(
         "{}::{}::{}_server::{}",
         config.proto_root, service.package_mod, svc_snake, service.service_name
     );
     let rt = &config.runtime_crate;
     let ext_extractor = config.extension_extractor_line();
-    let ext_and_req = config.extension_and_request_lines("query");
+    let ext_and_req = config.extension_and_resume_request_lines("query", method);
 
     let extractor = if method.http_method == "get" {
         format!("    Query(query): Query<{}>,\n", method.input_type)
@@ -211,11 +344,11 @@ fn generate_sse_handler(
     let _ = write!(
         code,
         "\
-#[expect(clippy::too_many_arguments, clippy::needless_pass_by_value)]
+{descriptor}#[expect(clippy::too_many_arguments, clippy::needless_pass_by_value)]
 /// `{proto_name}` — SSE streaming endpoint.
 ///
 /// `{http_method} {path}` → `text/event-stream`
-async fn {handler_name}<S>(
+{doc_lines}{vis}async fn {handler_name}<S>(
     State(service): State<Arc<S>>,
     headers: HeaderMap,
 {ext_extractor}\
@@ -224,14 +357,17 @@ async fn {handler_name}<S>(
 where
     S: {trait_path} + Send + Sync + 'static,
 {{
-{ext_and_req}    let response = service.{rust_name}(req).await.map_err({rt}::RestError::from)?;
+{ext_and_req}{span}    let response = {call}.await{map_err}?;
     let stream = response.into_inner();
 
-    let sse_stream = stream.map(|result| {{
+    let mut next_id = {rt}::sse_first_event_id(&headers);
+    let sse_stream = stream.map(move |result| {{
         Ok::<_, Infallible>(match result {{
-            Ok(item) => Event::default()
-                .json_data(&item)
-                .unwrap_or_else(|_| Event::default().data(\"{{}}\")),
+            Ok(item) => {{
+                let id = next_id;
+                next_id += 1;
+                {rt}::sse_data_event({event_name}, id, &item)
+            }}
             Err(status) => {rt}::sse_error_event(&status),
         }})
     }});
@@ -247,11 +383,81 @@ where
         proto_name = method.proto_name,
         http_method = method.http_method.to_uppercase(),
         path = method.path,
-        rust_name = method.rust_name,
+        span = span_line(service, method, config),
+        doc_lines = doc_comment_lines(service, method, config),
+        descriptor = route_descriptor(method, &handler_name, config),
+        vis = handler_vis(config),
+        call = service_call(method, config),
         keep_alive = config.sse_keep_alive_secs,
+        event_name = sse_event_name(config),
+        map_err = map_err_with_context(method, rt),
+    );
+}
+
+fn generate_ndjson_handler(
+    code: &mut String,
+    service: &ServiceRoute,
+    method: &MethodRoute,
+    config: &RestCodegenConfig,
+) {
+    let svc_snake = super::to_snake_case(&service.service_name);
+    let handler_name = method.handler_name(&svc_snake);
+    let trait_path = format!(
+        "{}::{}::{}_server::{}",
+        config.proto_root, service.package_mod, svc_snake, service.service_name
+    );
+    let rt = &config.runtime_crate;
+    let ext_extractor = config.extension_extractor_line();
+    let ext_and_req = config.extension_and_resume_request_lines("query", method);
+
+    let extractor = if method.http_method == "get" {
+        format!("    Query(query): Query<{}>,\n", method.input_type)
+    } else {
+        format!("    Json(query): Json<{}>,\n", method.input_type)
+    };
+
+    let _ = write!(
+        code,
+        "\
+{descriptor}#[expect(clippy::too_many_arguments, clippy::needless_pass_by_value)]
+/// `{proto_name}` — NDJSON streaming endpoint.
+///
+/// `{http_method} {path}` → `application/x-ndjson`
+{doc_lines}{vis}async fn {handler_name}<S>(
+    State(service): State<Arc<S>>,
+    headers: HeaderMap,
+{ext_extractor}\
+{extractor}\
+) -> Result<axum::response::Response, {rt}::RestError>
+where
+    S: {trait_path} + Send + Sync + 'static,
+{{
+{ext_and_req}{span}    let response = {call}.await{map_err}?;
+    Ok({rt}::ndjson_response(response.into_inner()))
+}}
+
+",
+        proto_name = method.proto_name,
+        http_method = method.http_method.to_uppercase(),
+        path = method.path,
+        span = span_line(service, method, config),
+        doc_lines = doc_comment_lines(service, method, config),
+        descriptor = route_descriptor(method, &handler_name, config),
+        vis = handler_vis(config),
+        call = service_call(method, config),
+        map_err = map_err_with_context(method, rt),
     );
 }
 
+/// `sse_data_event` name argument: `Some("<name>")` when configured, else
+/// `None` so messages stay unnamed and reach `EventSource.onmessage`.
+fn sse_event_name(config: &RestCodegenConfig) -> String {
+    config
+        .sse_event_name
+        .as_ref()
+        .map_or_else(|| "None".to_string(), |name| format!("Some({name:?})"))
+}
+
 fn generate_json_handler(
     code: &mut String,
     service: &ServiceRoute,
@@ -259,53 +465,44 @@ fn generate_json_handler(
     config: &RestCodegenConfig,
 ) {
     let svc_snake = super::to_snake_case(&service.service_name);
-    let handler_name = format!("rest_{}_{}", svc_snake, method.rust_name);
+    let handler_name = method.handler_name(&svc_snake);
     let trait_path = format!(
         "{}::{}::{}_server::{}",
         config.proto_root, service.package_mod, svc_snake, service.service_name
     );
     let rt = &config.runtime_crate;
     let ext_extractor = config.extension_extractor_line();
-    let ext_and_req = config.extension_and_request_lines("body");
+    let ext_and_req = config.extension_and_request_lines("body", method);
 
     let has_path_params = !method.path_params.is_empty();
-    let needs_mut_body = has_path_params;
+    let needs_mut_body = has_path_params
+        || method.body_field.is_some()
+        || !method.query_params.is_empty()
+        || !method.header_params.is_empty();
+
+    let query_struct = query_struct_name(&handler_name);
+    generate_query_struct(code, method, &query_struct, config);
 
-    let extractors = build_extractors(method, needs_mut_body);
+    let extractors = build_extractors(method, &query_struct, needs_mut_body);
     let body_creation = build_body_creation(method, needs_mut_body);
+    let body_field_assign = build_body_field_assign(method);
     let path_assigns = build_path_assigns(method, config);
-
-    // --- Response shape ---
-    let return_type_owned;
-    let (return_type, call_line, ok_expr) = if method.returns_empty {
-        (
-            "StatusCode",
-            format!(
-                "    service.{rust_name}(req).await.map_err({rt}::RestError::from)?;",
-                rust_name = method.rust_name,
-            ),
-            "Ok(StatusCode::NO_CONTENT)".to_string(),
-        )
-    } else {
-        return_type_owned = format!("Json<{}>", method.output_type);
-        (
-            return_type_owned.as_str(),
-            format!(
-                "    let response = service.{rust_name}(req).await.map_err({rt}::RestError::from)?;",
-                rust_name = method.rust_name,
-            ),
-            "Ok(Json(response.into_inner()))".to_string(),
-        )
-    };
+    let query_assigns = build_query_assigns(method, rt);
+    let header_assigns = build_header_assigns(method);
+    let span = span_line(service, method, config);
+    let doc_lines = doc_comment_lines(service, method, config);
+    let descriptor = route_descriptor(method, &handler_name, config);
+    let vis = handler_vis(config);
+    let (return_type, call_line, ok_expr) = response_shape(method, config);
 
     let _ = write!(
         code,
         "\
-#[expect(clippy::too_many_arguments, clippy::needless_pass_by_value)]
+{descriptor}#[expect(clippy::too_many_arguments, clippy::needless_pass_by_value)]
 /// `{proto_name}` — JSON endpoint.
 ///
 /// `{http_method} {path}`
-async fn {handler_name}<S>(
+{doc_lines}{vis}async fn {handler_name}<S>(
     State(service): State<Arc<S>>,
     headers: HeaderMap,
 {ext_extractor}\
@@ -314,7 +511,55 @@ async fn {handler_name}<S>(
 where
     S: {trait_path} + Send + Sync + 'static,
 {{
-{body_creation}{path_assigns}{ext_and_req}{call_line}
+{body_creation}{body_field_assign}{path_assigns}{query_assigns}{header_assigns}{ext_and_req}{span}{call_line}
+    {ok_expr}
+}}
+
+",
+        proto_name = method.proto_name,
+        http_method = method.http_method.to_uppercase(),
+        path = method.path,
+    );
+}
+
+/// Client-streaming handler: NDJSON request body → `tonic::Streaming`.
+fn generate_client_stream_handler(
+    code: &mut String,
+    service: &ServiceRoute,
+    method: &MethodRoute,
+    config: &RestCodegenConfig,
+) {
+    let svc_snake = super::to_snake_case(&service.service_name);
+    let handler_name = method.handler_name(&svc_snake);
+    let trait_path = format!(
+        "{}::{}::{}_server::{}",
+        config.proto_root, service.package_mod, svc_snake, service.service_name
+    );
+    let rt = &config.runtime_crate;
+    let ext_extractor = config.extension_extractor_line();
+    let ext_and_req = config.extension_and_stream_request_lines("body", method);
+    let span = span_line(service, method, config);
+    let doc_lines = doc_comment_lines(service, method, config);
+    let descriptor = route_descriptor(method, &handler_name, config);
+    let vis = handler_vis(config);
+    let (return_type, call_line, ok_expr) = response_shape(method, config);
+
+    let _ = write!(
+        code,
+        "\
+{descriptor}#[expect(clippy::too_many_arguments, clippy::needless_pass_by_value)]
+/// `{proto_name}` — client-streaming endpoint (NDJSON request body).
+///
+/// `{http_method} {path}`
+{doc_lines}{vis}async fn {handler_name}<S>(
+    State(service): State<Arc<S>>,
+    headers: HeaderMap,
+{ext_extractor}    body: axum::body::Body,
+) -> Result<{return_type}, {rt}::RestError>
+where
+    S: {trait_path} + Send + Sync + 'static,
+{{
+{ext_and_req}{span}{call_line}
     {ok_expr}
 }}
 
@@ -325,8 +570,257 @@ where
     );
 }
 
+/// Return type, service call, and final `Ok(..)` expression for a handler
+/// with a unary response.
+///
+/// With `response_headers` configured, the response metadata is copied into
+/// a `HeaderMap` returned alongside the body.
+fn response_shape(method: &MethodRoute, config: &RestCodegenConfig) -> (String, String, String) {
+    let rt = &config.runtime_crate;
+    let map_err = map_err_with_context(method, rt);
+    let call = service_call(method, config);
+    let (body_type, body_expr) = if method.returns_empty {
+        (
+            "StatusCode".to_string(),
+            "StatusCode::NO_CONTENT".to_string(),
+        )
+    } else if let Some(field) = &method.response_field {
+        (
+            format!("Json<{}>", field.rust_type),
+            format!("Json({})", field.field_name),
+        )
+    } else {
+        (
+            format!("Json<{}>", method.output_type),
+            "Json(response.into_inner())".to_string(),
+        )
+    };
+    let select = method
+        .response_field
+        .as_ref()
+        .map(|field| response_field_select(field, method, rt))
+        .unwrap_or_default();
+
+    if config.response_headers.is_empty() {
+        let call_line = if method.returns_empty {
+            format!("    {call}.await{map_err}?;")
+        } else {
+            format!("    let response = {call}.await{map_err}?;{select}")
+        };
+        return (body_type, call_line, format!("Ok({body_expr})"));
+    }
+
+    (
+        format!("(HeaderMap, {body_type})"),
+        format!(
+            "    let response = {call}.await{map_err}?;
+    let mut response_headers = HeaderMap::new();
+    {rt}::apply_response_metadata(&mut response_headers, response.metadata(), RESPONSE_HEADERS);{select}"
+        ),
+        format!("Ok((response_headers, {body_expr}))"),
+    )
+}
+
+/// Binds the `response_body` field to a local of the same name; an unset
+/// message field becomes a 500 tagged with the handler's method and route.
+fn response_field_select(field: &ResponseField, method: &MethodRoute, rt: &str) -> String {
+    let name = &field.field_name;
+    if field.is_message {
+        format!(
+            "
+    let {name} = response.into_inner().{name}.ok_or_else(|| {{
+        {rt}::RestError::new(tonic::Status::internal(\"response field `{name}` is not set\"))
+            .with_context({http_method:?}, {path:?})
+    }})?;",
+            http_method = method.http_method.to_uppercase(),
+            path = method.path,
+        )
+    } else {
+        format!("\n    let {name} = response.into_inner().{name};")
+    }
+}
+
+/// Service-trait call future, wrapped in the handler's span when tracing is on.
+fn service_call(method: &MethodRoute, config: &RestCodegenConfig) -> String {
+    let call = format!("service.{}(req)", method.rust_name);
+    if config.tracing {
+        format!("tracing::Instrument::instrument({call}, span)")
+    } else {
+        call
+    }
+}
+
+/// `pub ` when handlers are exposed for manual routing, else empty.
+const fn handler_vis(config: &RestCodegenConfig) -> &'static str {
+    if config.emit_route_descriptors {
+        "pub "
+    } else {
+        ""
+    }
+}
+
+/// `pub const {HANDLER}_ROUTE: (Method, &str)` pairing a handler with its
+/// HTTP method and Axum path; empty unless `emit_route_descriptors` is on.
+fn route_descriptor(
+    method: &MethodRoute,
+    handler_name: &str,
+    config: &RestCodegenConfig,
+) -> String {
+    if !config.emit_route_descriptors {
+        return String::new();
+    }
+    format!(
+        "/// Route of [`{handler_name}`] as `(method, path)`.
+pub const {upper}_ROUTE: (axum::http::Method, &str) =
+    (axum::http::Method::{http_method}, {path:?});
+
+",
+        upper = handler_name.to_uppercase(),
+        http_method = method.http_method.to_uppercase(),
+        path = method.axum_path,
+    )
+}
+
+/// Extra `///` lines naming the operation ID and description; empty unless
+/// `emit_doc_comments` is on.
+fn doc_comment_lines(
+    service: &ServiceRoute,
+    method: &MethodRoute,
+    config: &RestCodegenConfig,
+) -> String {
+    if !config.emit_doc_comments {
+        return String::new();
+    }
+    let mut out = format!(
+        "///\n/// Operation ID: `{}_{}`\n",
+        service.service_name, method.proto_name
+    );
+    if let Some(description) = config.method_descriptions.get(&method.proto_name) {
+        out.push_str("///\n");
+        for line in description.trim().lines() {
+            let line = line.trim_end();
+            if line.is_empty() {
+                out.push_str("///\n");
+            } else {
+                let _ = writeln!(out, "/// {line}");
+            }
+        }
+    }
+    out
+}
+
+/// `let span = tracing::info_span!(...)` line naming the operation
+/// `{Service}.{Method}`; empty when tracing is off.
+fn span_line(service: &ServiceRoute, method: &MethodRoute, config: &RestCodegenConfig) -> String {
+    if !config.tracing {
+        return String::new();
+    }
+    format!(
+        "    let span = tracing::info_span!(
+        \"{service}.{proto_name}\",
+        http.method = {http_method:?},
+        http.route = {route:?},
+    );\n",
+        service = service.service_name,
+        proto_name = method.proto_name,
+        http_method = method.http_method.to_uppercase(),
+        route = method.axum_path,
+    )
+}
+
+/// Build the `.map_err(...)` adapter that converts a `tonic::Status` into a
+/// `RestError` tagged with the handler's HTTP method and route template.
+fn map_err_with_context(method: &MethodRoute, rt: &str) -> String {
+    format!(
+        ".map_err(|status| {{
+        {rt}::RestError::from(status).with_context({http_method:?}, {path:?})
+    }})",
+        http_method = method.http_method.to_uppercase(),
+        path = method.path,
+    )
+}
+
+/// Name of the generated query-parameter struct for a handler, e.g.
+/// `rest_item_service_list_items` → `RestItemServiceListItemsQuery`.
+fn query_struct_name(handler_name: &str) -> String {
+    let mut name: String = handler_name
+        .split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_ascii_uppercase().to_string() + chars.as_str()
+            })
+        })
+        .collect();
+    name.push_str("Query");
+    name
+}
+
+/// Emit the `#[derive(Deserialize)]` struct holding a handler's query parameters.
+///
+/// Fields keep their proto names; the proto3 JSON (`lowerCamelCase`) spelling
+/// is accepted as an alias.
+fn generate_query_struct(
+    code: &mut String,
+    method: &MethodRoute,
+    name: &str,
+    config: &RestCodegenConfig,
+) {
+    let rt = &config.runtime_crate;
+    if method.query_params.is_empty() {
+        return;
+    }
+
+    let _ = write!(
+        code,
+        "/// Query parameters for `{proto_name}`.\n#[derive(serde::Deserialize)]\n{vis}struct {name} {{\n",
+        proto_name = method.proto_name,
+        vis = handler_vis(config),
+    );
+    for param in &method.query_params {
+        let camel = param
+            .json_name
+            .clone()
+            .unwrap_or_else(|| to_lower_camel_case(&param.field_name));
+        if camel != param.field_name {
+            let _ = writeln!(code, "    #[serde(alias = \"{camel}\")]");
+        }
+        if matches!(param.kind, QueryParamKind::Repeated) {
+            let _ = writeln!(
+                code,
+                "    #[serde(default, deserialize_with = \"{rt}::serde::comma_separated::deserialize\")]"
+            );
+            let _ = writeln!(code, "    {}: Vec<{}>,", param.field_name, param.rust_type);
+        } else {
+            let _ = writeln!(
+                code,
+                "    {}: Option<{}>,",
+                param.field_name, param.rust_type
+            );
+        }
+    }
+    code.push_str("}\n\n");
+}
+
+/// Convert a proto `snake_case` field name to its proto3 JSON name.
+fn to_lower_camel_case(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut upper_next = false;
+    for c in s.chars() {
+        if c == '_' {
+            upper_next = true;
+        } else if upper_next {
+            out.push(c.to_ascii_uppercase());
+            upper_next = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
 /// Build Axum extractor parameters for a JSON handler.
-fn build_extractors(method: &MethodRoute, needs_mut_body: bool) -> String {
+fn build_extractors(method: &MethodRoute, query_struct: &str, needs_mut_body: bool) -> String {
     let mut out = String::new();
 
     // Path extractor
@@ -364,14 +858,36 @@ fn build_extractors(method: &MethodRoute, needs_mut_body: bool) -> String {
 
     // Body/query extractor
     let mut_kw = if needs_mut_body { "mut " } else { "" };
-    if method.has_body && method.http_method != "get" {
-        let _ = writeln!(out, "    Json({mut_kw}body): Json<{}>,", method.input_type);
-    } else if method.http_method == "get" {
+    let body_extractor = if method.form_body { "Form" } else { "Json" };
+    if let Some(field) = &method.body_field {
+        // Sibling fields from the query string; the body extractor consumes
+        // the body and must come last.
         let _ = writeln!(
             out,
             "    Query({mut_kw}body): Query<{}>,",
             method.input_type
         );
+        let _ = writeln!(
+            out,
+            "    {body_extractor}(payload): {body_extractor}<{}>,",
+            field.rust_type
+        );
+    } else if method.has_body && method.http_method != "get" {
+        let _ = writeln!(
+            out,
+            "    {body_extractor}({mut_kw}body): {body_extractor}<{}>,",
+            method.input_type
+        );
+    } else if method.has_body || method.query_binds_request {
+        // GET with `body: "*"`, or a query field the generated struct can't
+        // express — bind the whole message from the query string
+        let _ = writeln!(
+            out,
+            "    Query({mut_kw}body): Query<{}>,",
+            method.input_type
+        );
+    } else if !method.query_params.is_empty() {
+        let _ = writeln!(out, "    Query(query): Query<{query_struct}>,");
     }
 
     out
@@ -379,13 +895,28 @@ fn build_extractors(method: &MethodRoute, needs_mut_body: bool) -> String {
 
 /// Build the `let body = T::default();` line for endpoints without a request body.
 fn build_body_creation(method: &MethodRoute, needs_mut_body: bool) -> String {
-    if method.has_body || method.http_method == "get" {
+    if method.has_body || method.query_binds_request {
         return String::new();
     }
+    if method.input_type == "()" {
+        return "    let body = ();\n".to_string();
+    }
     let mut_kw = if needs_mut_body { "mut " } else { "" };
     format!("    let {mut_kw}body = {}::default();\n", method.input_type)
 }
 
+/// Build the `body.<field> = payload;` line for partial body selectors.
+fn build_body_field_assign(method: &MethodRoute) -> String {
+    let Some(field) = &method.body_field else {
+        return String::new();
+    };
+    if field.is_message {
+        format!("    body.{} = Some(payload);\n", field.field_name)
+    } else {
+        format!("    body.{} = payload;\n", field.field_name)
+    }
+}
+
 /// Build path parameter → request field assignment lines.
 fn build_path_assigns(method: &MethodRoute, config: &RestCodegenConfig) -> String {
     let mut out = String::new();
@@ -411,29 +942,87 @@ fn build_path_assigns(method: &MethodRoute, config: &RestCodegenConfig) -> Strin
             }
             ParamAssignment::StringField { field_name }
             | ParamAssignment::TypedField { field_name, .. } => {
-                let _ = writeln!(
+                let value = if param.optional {
+                    format!("Some({})", param.axum_name)
+                } else {
+                    param.axum_name.clone()
+                };
+                let _ = writeln!(out, "    body.{field_name} = {value};");
+            }
+            ParamAssignment::Typed64 {
+                field_name,
+                rust_type,
+            } => {
+                let value = if param.optional {
+                    format!("Some({})", param.axum_name)
+                } else {
+                    param.axum_name.clone()
+                };
+                let _ = write!(
                     out,
-                    "    body.{field} = {axum};",
+                    "    let {axum} = {axum}.parse::<{rust_type}>().map_err(|_| {{
+        {rt}::RestError::new(tonic::Status::invalid_argument(
+            \"invalid integer value for '{field}'\",
+        ))
+        .with_context({http_method:?}, {path:?})
+    }})?;
+    body.{field} = {value};\n",
                     field = field_name,
                     axum = param.axum_name,
+                    http_method = method.http_method.to_uppercase(),
+                    path = method.path,
                 );
             }
             ParamAssignment::EnumField {
                 field_name,
                 enum_rust_type,
             } => {
-                let _ = write!(
+                write_enum_assign(
+                    &mut out,
+                    "    ",
+                    method,
+                    field_name,
+                    &param.axum_name,
+                    enum_rust_type,
+                    param.optional,
+                    rt,
+                );
+            }
+        }
+    }
+
+    out
+}
+
+/// Build query parameter → request field assignment lines.
+fn build_query_assigns(method: &MethodRoute, rt: &str) -> String {
+    let mut out = String::new();
+
+    for param in &method.query_params {
+        let field = &param.field_name;
+        match &param.kind {
+            QueryParamKind::Singular => {
+                let _ = writeln!(
                     out,
-                    "    body.{field} = match {enum_type}::from_str_name(&{axum}.to_ascii_uppercase()) {{
-        Some(v) => v as i32,
-        None => {axum}.parse::<i32>().map_err(|_| {rt}::RestError::new(tonic::Status::invalid_argument(
-            \"invalid enum value for '{field}'\",
-        )))?,
-    }};\n",
-                    field = field_name,
-                    axum = param.axum_name,
-                    enum_type = enum_rust_type,
+                    "    if let Some(value) = query.{field} {{\n        body.{field} = value;\n    }}"
+                );
+            }
+            QueryParamKind::Optional | QueryParamKind::Repeated => {
+                let _ = writeln!(out, "    body.{field} = query.{field};");
+            }
+            QueryParamKind::Enum { enum_rust_type } => {
+                let _ = writeln!(out, "    if let Some(value) = query.{field} {{");
+                write_enum_assign(
+                    &mut out,
+                    "        ",
+                    method,
+                    field,
+                    "value",
+                    enum_rust_type,
+                    false,
+                    rt,
                 );
+                out.push_str("    }\n");
             }
         }
     }
@@ -441,13 +1030,66 @@ fn build_path_assigns(method: &MethodRoute, config: &RestCodegenConfig) -> Strin
     out
 }
 
+/// Build request header → request field assignment lines.
+///
+/// A header that is missing or not valid UTF-8 leaves the field untouched.
+fn build_header_assigns(method: &MethodRoute) -> String {
+    let mut out = String::new();
+
+    for param in &method.header_params {
+        let value = if param.optional {
+            "Some(value.to_string())"
+        } else {
+            "value.to_string()"
+        };
+        let _ = writeln!(
+            out,
+            "    if let Some(value) = headers.get({header:?}).and_then(|v| v.to_str().ok()) {{
+        body.{field} = {value};
+    }}",
+            header = param.header_name,
+            field = param.field_name,
+        );
+    }
+
+    out
+}
+
+/// Write `body.<field> = <enum parsed from var>;`, accepting the proto enum
+/// name (case-insensitive) or its number, and returning 400 otherwise.
+/// `optional` wraps the value in `Some(..)` for proto3 `optional` fields.
+#[expect(clippy::too_many_arguments)]
+fn write_enum_assign(
+    out: &mut String,
+    indent: &str,
+    method: &MethodRoute,
+    field: &str,
+    var: &str,
+    enum_type: &str,
+    optional: bool,
+    rt: &str,
+) {
+    let (open, close) = if optional { ("Some(", ")") } else { ("", "") };
+    let _ = write!(
+        out,
+        "{indent}body.{field} = {open}match {enum_type}::from_str_name(&{var}.to_ascii_uppercase()) {{
+{indent}    Some(v) => v as i32,
+{indent}    None => {var}.parse::<i32>().map_err(|_| {rt}::RestError::new(tonic::Status::invalid_argument(
+{indent}        \"invalid enum value for '{field}'\",
+{indent}    )).with_context({http_method:?}, {path:?}))?,
+{indent}}}{close};\n",
+        http_method = method.http_method.to_uppercase(),
+        path = method.path,
+    );
+}
+
 fn generate_all_routes(code: &mut String, services: &[ServiceRoute], config: &RestCodegenConfig) {
     // Collect public REST paths from config-specified method names
     let mut public_paths = Vec::new();
     for service in services {
         for method in &service.methods {
             if config.public_methods.contains(method.proto_name.as_str()) {
-                public_paths.push(method.path.clone());
+                public_paths.push(format!("{}{}", config.route_prefix, method.path));
             }
         }
     }
@@ -470,6 +1112,10 @@ pub const PUBLIC_REST_PATHS: &[&str] = &[\n",
     }
     code.push_str("];\n");
 
+    if config.emit_route_manifest {
+        generate_route_manifest(code, services, config);
+    }
+
     // Emit combined router
     if services.is_empty() {
         // No services — emit a trivial router that compiles cleanly
@@ -487,6 +1133,7 @@ pub fn all_rest_routes() -> Router {
 }
 ",
         );
+        generate_unified_state(code, services, config);
         return;
     }
 
@@ -538,6 +1185,199 @@ where
         bounds = bounds.join("\n"),
         merges = router_merges.join("\n"),
     );
+
+    generate_unified_state(code, services, config);
+}
+
+/// Emit the `RestServices` trait and `all_rest_routes_with`, when enabled.
+///
+/// One associated type per service keeps the router generic over concrete
+/// implementations while the caller registers a single state type.
+fn generate_unified_state(
+    code: &mut String,
+    services: &[ServiceRoute],
+    config: &RestCodegenConfig,
+) {
+    if !config.unified_state {
+        return;
+    }
+
+    let mut items = Vec::new();
+    let mut args = Vec::new();
+    for service in services {
+        let svc_snake = super::to_snake_case(&service.service_name);
+        let trait_path = format!(
+            "{}::{}::{}_server::{}",
+            config.proto_root, service.package_mod, svc_snake, service.service_name
+        );
+        items.push(format!(
+            "    /// Implementation of `{name}`.
+    type {name}: {trait_path} + Send + Sync + 'static;
+
+    /// Shared handle to the `{name}` implementation.
+    fn {svc_snake}(&self) -> Arc<Self::{name}>;",
+            name = service.service_name,
+        ));
+        args.push(format!("state.{svc_snake}()"));
+    }
+
+    let body = if services.is_empty() {
+        "    let _ = state;\n    Router::new()".to_string()
+    } else {
+        format!("    all_rest_routes({})", args.join(", "))
+    };
+
+    let _ = write!(
+        code,
+        "
+/// Application state providing every proto service implementation.
+///
+/// Implement once for your app state and pass it to [`all_rest_routes_with`]
+/// instead of one `Arc` per service.
+pub trait RestServices {{
+{items}
+}}
+
+/// Build the combined REST router from a single application state.
+pub fn all_rest_routes_with<State: RestServices>(state: State) -> Router {{
+{body}
+}}
+",
+        items = items.join("\n\n"),
+    );
+}
+
+/// A route registered by the generated routers, as listed in the manifest.
+struct ManifestRoute<'a> {
+    http_method: String,
+    path: &'a str,
+    /// `None` for `cors_preflight` routes, which have no proto operation.
+    operation_id: Option<String>,
+}
+
+/// Every `(method, path)` the generated routers register, in registration
+/// order, including the `HEAD` routes added by `auto_head` and the `OPTIONS`
+/// routes added by `cors_preflight`.
+fn manifest_routes<'a>(
+    services: &'a [ServiceRoute],
+    config: &RestCodegenConfig,
+) -> Vec<ManifestRoute<'a>> {
+    let mut routes = Vec::new();
+    for service in services {
+        for method in &service.methods {
+            let operation_id = format!("{}_{}", service.service_name, method.proto_name);
+            routes.push(ManifestRoute {
+                http_method: method.http_method.to_uppercase(),
+                path: &method.axum_path,
+                operation_id: Some(operation_id.clone()),
+            });
+            if adds_auto_head(service, method, config) {
+                routes.push(ManifestRoute {
+                    http_method: "HEAD".to_string(),
+                    path: &method.axum_path,
+                    operation_id: Some(operation_id),
+                });
+            }
+        }
+        if config.cors_preflight {
+            for (path, _) in preflight_routes(service, config) {
+                routes.push(ManifestRoute {
+                    http_method: "OPTIONS".to_string(),
+                    path,
+                    operation_id: None,
+                });
+            }
+        }
+    }
+    routes
+}
+
+/// Emit `REST_ROUTE_COUNT` and `rest_routes()` describing every registered route.
+///
+/// Gives downstream crates a checkable contract: a test asserting the count or
+/// the `(method, path)` set fails when a proto refactor drops a route.
+fn generate_route_manifest(
+    code: &mut String,
+    services: &[ServiceRoute],
+    config: &RestCodegenConfig,
+) {
+    let routes = manifest_routes(services, config);
+
+    let _ = write!(
+        code,
+        "\n\
+// =============================================================================
+// Route manifest
+// =============================================================================
+
+/// Number of REST routes registered by the generated routers.
+pub const REST_ROUTE_COUNT: usize = {count};
+
+/// All generated REST routes as `(HTTP method, Axum path)` pairs, in
+/// registration order.
+///
+/// Assert on this in tests to catch routes lost during proto refactors.
+#[must_use]
+pub const fn rest_routes() -> &'static [(&'static str, &'static str)] {{
+    &[\n",
+        count = routes.len(),
+    );
+    for route in &routes {
+        let _ = writeln!(
+            code,
+            "        (\"{}\", \"{}\"),",
+            route.http_method, route.path
+        );
+    }
+    code.push_str("    ]\n}\n");
+
+    generate_operation_id_lookup(code, &routes);
+}
+
+/// Emit `operation_id_for`, mapping a matched `(method, route)` back to the
+/// gnostic operation ID (`Service_Method`) for metrics and tracing labels.
+fn generate_operation_id_lookup(code: &mut String, routes: &[ManifestRoute<'_>]) {
+    code.push_str(
+        "
+/// Proto operation ID (`Service_Method`) of a generated REST route.
+///
+/// `matched_path` is the registered route template, as returned by
+/// [`axum::extract::MatchedPath::as_str`]. Routers mounted with `Router::nest`
+/// report the nested path, so strip that prefix first. Lets middleware label
+/// spans and metrics by operation:
+///
+/// ```ignore
+/// let operation_id = request
+///     .extensions()
+///     .get::<axum::extract::MatchedPath>()
+///     .and_then(|path| operation_id_for(request.method(), path.as_str()));
+/// ```
+#[must_use]
+pub fn operation_id_for(method: &axum::http::Method, matched_path: &str) -> Option<&'static str> {
+",
+    );
+
+    let arms: Vec<String> = routes
+        .iter()
+        .filter_map(|route| {
+            let operation_id = route.operation_id.as_ref()?;
+            Some(format!(
+                "        (\"{}\", \"{}\") => Some(\"{operation_id}\"),",
+                route.http_method, route.path,
+            ))
+        })
+        .collect();
+
+    if arms.is_empty() {
+        code.push_str("    let _ = (method, matched_path);\n    None\n}\n");
+        return;
+    }
+    code.push_str("    match (method.as_str(), matched_path) {\n");
+    for arm in &arms {
+        code.push_str(arm);
+        code.push('\n');
+    }
+    code.push_str("        _ => None,\n    }\n}\n");
 }
 
 /// Emit a `use` statement with `{}` braces only when there are multiple items.
@@ -2,11 +2,14 @@
 
 use std::collections::HashMap;
 
-use tonic_rest_core::descriptor::{self, FileDescriptorSet, MethodDescriptorProto, field_type};
+use tonic_rest_core::descriptor::{
+    self, FileDescriptorSet, HttpPattern, HttpRule, MethodDescriptorProto, field_label, field_type,
+};
 
 use super::config::{GenerateError, RestCodegenConfig};
 use super::types::{
-    FieldTypeInfo, MessageFieldTypes, MethodRoute, ParamAssignment, PathParam, ServiceRoute,
+    BodyField, FieldTypeInfo, HeaderParam, MessageFieldTypes, MethodRoute, ParamAssignment,
+    PathParam, QueryParam, QueryParamKind, ResponseField, ServiceRoute, SkippedBinding,
 };
 
 /// Auto-discover packages from a descriptor set by finding services with HTTP annotations.
@@ -69,17 +72,21 @@ fn collect_message_fields(
     let fqn = format!("{parent_path}.{msg_name}");
 
     let mut fields = HashMap::new();
-    for field in &msg.field {
+    for (position, field) in msg.field.iter().enumerate() {
         if let (Some(name), Some(ty)) = (field.name.as_deref(), field.r#type) {
             fields.insert(
                 name.to_string(),
                 FieldTypeInfo {
+                    position,
                     type_id: ty,
-                    enum_type_name: if ty == field_type::ENUM {
+                    type_name: if ty == field_type::ENUM || ty == field_type::MESSAGE {
                         field.type_name.clone()
                     } else {
                         None
                     },
+                    repeated: field.label == Some(field_label::REPEATED),
+                    optional: field.proto3_optional.unwrap_or(false),
+                    json_name: field.json_name.clone(),
                 },
             );
         }
@@ -110,18 +117,23 @@ pub fn extract_services(
         for service in &file.service {
             let service_name = service.name.as_deref().unwrap_or("").to_string();
             let mut methods = Vec::new();
+            let mut skipped_bindings = Vec::new();
 
             for method in &service.method {
-                if let Some(route) = extract_method_route(method, field_types, config)? {
-                    methods.push(route);
-                }
+                methods.extend(extract_method_routes(
+                    method,
+                    field_types,
+                    config,
+                    &mut skipped_bindings,
+                )?);
             }
 
-            if !methods.is_empty() {
+            if !methods.is_empty() || !skipped_bindings.is_empty() {
                 result.push(ServiceRoute {
                     package_mod: package_mod.to_string(),
                     service_name,
                     methods,
+                    skipped_bindings,
                 });
             }
         }
@@ -130,59 +142,236 @@ pub fn extract_services(
     Ok(result)
 }
 
-fn extract_method_route(
+/// Extract one route per `google.api.http` binding of a method.
+///
+/// The primary rule comes first, followed by its `additional_bindings` in
+/// declaration order. Each binding resolves its own body and path params,
+/// since bindings may bind different request fields. `custom` bindings with
+/// an unsupported verb are recorded in `skipped` instead of failing the build.
+fn extract_method_routes(
+    method: &MethodDescriptorProto,
+    field_types: &MessageFieldTypes,
+    config: &RestCodegenConfig,
+    skipped: &mut Vec<SkippedBinding>,
+) -> Result<Vec<MethodRoute>, GenerateError> {
+    let Some(rule) = method.options.as_ref().and_then(|o| o.http.as_ref()) else {
+        return Ok(Vec::new());
+    };
+    if rule.pattern.is_none() {
+        return Ok(Vec::new());
+    }
+
+    let bindings = std::iter::once(rule).chain(&rule.additional_bindings);
+    let mut routes = Vec::new();
+    for (binding_index, binding) in bindings.enumerate() {
+        if let Some(HttpPattern::Custom(custom)) = &binding.pattern
+            && descriptor::http_rule_pattern(binding).is_none()
+        {
+            skipped.push(SkippedBinding {
+                proto_name: method.name.as_deref().unwrap_or("").to_string(),
+                kind: custom.kind.clone(),
+                path: custom.path.clone(),
+            });
+            continue;
+        }
+        if let Some(route) =
+            extract_binding_route(method, binding, binding_index, field_types, config)?
+        {
+            routes.push(route);
+        }
+    }
+    Ok(routes)
+}
+
+fn extract_binding_route(
     method: &MethodDescriptorProto,
+    rule: &HttpRule,
+    binding_index: usize,
     field_types: &MessageFieldTypes,
     config: &RestCodegenConfig,
 ) -> Result<Option<MethodRoute>, GenerateError> {
-    let Some((http_method, path)) = descriptor::extract_http_pattern(method) else {
+    let Some((http_method, path)) = descriptor::http_rule_pattern(rule) else {
         return Ok(None);
     };
-    let body = method
-        .options
-        .as_ref()
-        .and_then(|o| o.http.as_ref())
-        .map_or("", |h| h.body.as_str());
+    let body = rule.body.as_str();
 
     let proto_name = method.name.as_deref().unwrap_or("").to_string();
-
-    // Only `body: "*"` (whole message) is supported. Partial body selectors
-    // (e.g., `body: "user"`) require sub-message deserialization that the
-    // codegen does not implement — reject early with a clear error.
-    if !body.is_empty() && body != "*" {
-        return Err(GenerateError::UnsupportedBodySelector {
-            method: proto_name,
-            body: body.to_string(),
-        });
-    }
     let rust_name = super::to_snake_case(&proto_name);
     let server_streaming = method.server_streaming.unwrap_or(false);
+    let client_streaming = method.client_streaming.unwrap_or(false);
 
     let input_fqn = method.input_type.as_deref().unwrap_or("");
+
+    if client_streaming {
+        let reason = if server_streaming {
+            Some("bidirectional streaming is not supported")
+        } else if body != "*" {
+            Some("client-streaming methods require `body: \"*\"`")
+        } else if path.contains('{') {
+            Some("client-streaming methods cannot bind path parameters")
+        } else {
+            None
+        };
+        if let Some(reason) = reason {
+            return Err(GenerateError::UnsupportedStreaming {
+                method: proto_name,
+                reason: reason.to_string(),
+            });
+        }
+    }
+
+    // Partial body selectors (e.g., `body: "user"`) bind the JSON body to a
+    // single request field. SSE handlers take the whole message, so streaming
+    // methods still require `body: "*"`.
+    let body_field = if body.is_empty() || body == "*" {
+        None
+    } else {
+        let field = (!server_streaming)
+            .then(|| resolve_body_field(body, input_fqn, field_types, config))
+            .flatten();
+        let Some(field) = field else {
+            return Err(GenerateError::UnsupportedBodySelector {
+                method: proto_name,
+                body: body.to_string(),
+            });
+        };
+        Some(field)
+    };
     let input_type = config.proto_type_to_rust(input_fqn);
     let raw_output = method.output_type.as_deref().unwrap_or("");
+    let response_field = if rule.response_body.is_empty() {
+        None
+    } else {
+        let field = (!server_streaming)
+            .then(|| resolve_response_field(&rule.response_body, raw_output, field_types, config))
+            .flatten();
+        let Some(field) = field else {
+            return Err(GenerateError::UnsupportedResponseBody {
+                method: proto_name,
+                response_body: rule.response_body.clone(),
+            });
+        };
+        Some(field)
+    };
     let returns_empty = raw_output == ".google.protobuf.Empty";
     let output_type = config.proto_type_to_rust(raw_output);
 
     let has_body = !body.is_empty();
     let path_params = extract_path_params(path, input_fqn, field_types, config)?;
-    let axum_path = convert_to_axum_path(path);
+    let query_params = if has_body || server_streaming || client_streaming {
+        Some(Vec::new())
+    } else {
+        extract_query_params(input_fqn, &path_params, field_types, config)
+    };
+    let query_binds_request = query_params.is_none();
+    let query_params = query_params.unwrap_or_default();
+    let header_params = if server_streaming || client_streaming {
+        Vec::new()
+    } else {
+        extract_header_params(input_fqn, &path_params, field_types, config)
+    };
+    let axum_path = format!("{}{}", config.route_prefix, convert_to_axum_path(path));
+    let form_body = has_body
+        && http_method != "get"
+        && !server_streaming
+        && !client_streaming
+        && config.form_urlencoded_methods.contains(&proto_name);
 
     Ok(Some(MethodRoute {
         proto_name,
         rust_name,
+        binding_index,
         http_method: http_method.to_string(),
         path: path.to_string(),
         axum_path,
         has_body,
+        body_field,
+        response_field,
         server_streaming,
+        client_streaming,
         input_type,
         output_type,
         returns_empty,
         path_params,
+        query_params,
+        query_binds_request,
+        header_params,
+        form_body,
     }))
 }
 
+/// Resolve a `body: "<field>"` selector against the request message.
+///
+/// Returns `None` when the field does not exist, is repeated, or has a type
+/// the JSON body cannot be bound to.
+fn resolve_body_field(
+    field_name: &str,
+    input_fqn: &str,
+    field_types: &MessageFieldTypes,
+    config: &RestCodegenConfig,
+) -> Option<BodyField> {
+    let info = field_types.get(input_fqn)?.get(field_name)?;
+    if info.repeated {
+        return None;
+    }
+
+    let (rust_type, is_message) = match info.type_id {
+        field_type::MESSAGE => (config.proto_type_to_rust(info.type_name.as_deref()?), true),
+        field_type::STRING => ("String".to_string(), false),
+        field_type::ENUM => ("i32".to_string(), false),
+        ty => (proto_type_to_rust_scalar(ty)?.to_string(), false),
+    };
+
+    Some(BodyField {
+        field_name: field_name.to_string(),
+        rust_type,
+        is_message,
+    })
+}
+
+/// Resolve a `response_body: "<field>"` selector against the response message.
+///
+/// Repeated fields serialize as a JSON array; map fields are not supported.
+fn resolve_response_field(
+    field_name: &str,
+    output_fqn: &str,
+    field_types: &MessageFieldTypes,
+    config: &RestCodegenConfig,
+) -> Option<ResponseField> {
+    let info = field_types.get(output_fqn)?.get(field_name)?;
+
+    let element = match info.type_id {
+        field_type::MESSAGE => config.proto_type_to_rust(info.type_name.as_deref()?),
+        field_type::STRING => "String".to_string(),
+        field_type::ENUM => "i32".to_string(),
+        ty => proto_type_to_rust_scalar(ty)?.to_string(),
+    };
+
+    Some(if info.repeated {
+        // Map fields are repeated synthetic `<Field>Entry` messages nested in
+        // the response message.
+        let is_map = info
+            .type_name
+            .as_deref()
+            .and_then(|t| t.strip_prefix(output_fqn))
+            .is_some_and(|nested| nested.ends_with("Entry"));
+        if is_map {
+            return None;
+        }
+        ResponseField {
+            field_name: field_name.to_string(),
+            rust_type: format!("Vec<{element}>"),
+            is_message: false,
+        }
+    } else {
+        ResponseField {
+            field_name: field_name.to_string(),
+            rust_type: element,
+            is_message: info.type_id == field_type::MESSAGE,
+        }
+    })
+}
+
 pub(super) fn extract_path_params(
     path: &str,
     input_fqn: &str,
@@ -198,6 +387,10 @@ pub(super) fn extract_path_params(
             let field_path = &rest[start + 1..start + end];
             let axum_name = field_path.replace('.', "_");
             let is_nested = field_path.contains('.');
+            let optional = !is_nested
+                && msg_fields
+                    .and_then(|f| f.get(field_path))
+                    .is_some_and(|fi| fi.optional);
 
             let assignment = if is_nested {
                 // Nested field: `user_id.value` → UUID wrapper pattern
@@ -218,12 +411,18 @@ pub(super) fn extract_path_params(
                 if type_id == field_type::ENUM {
                     // Resolve FQN enum type to Rust path
                     let enum_rust_type = field_info
-                        .and_then(|fi| fi.enum_type_name.as_deref())
+                        .and_then(|fi| fi.type_name.as_deref())
                         .map_or_else(|| "i32".to_string(), |fqn| config.proto_type_to_rust(fqn));
                     ParamAssignment::EnumField {
                         field_name: field_path.to_string(),
                         enum_rust_type,
                     }
+                } else if let Some(rust_type) = proto_type_to_rust_int64(type_id) {
+                    // 64-bit: parse in the handler so failures become a JSON 400
+                    ParamAssignment::Typed64 {
+                        field_name: field_path.to_string(),
+                        rust_type,
+                    }
                 } else if let Some(rust_type) = proto_type_to_rust_scalar(type_id) {
                     // Typed scalar: let Axum's Path<T> extractor handle parsing
                     ParamAssignment::TypedField {
@@ -240,6 +439,7 @@ pub(super) fn extract_path_params(
             params.push(PathParam {
                 axum_name,
                 assignment,
+                optional,
             });
             rest = &rest[start + end + 1..];
         } else {
@@ -250,15 +450,123 @@ pub(super) fn extract_path_params(
     Ok(params)
 }
 
-/// Map proto field type IDs to Rust scalar types for path parameter extraction.
+/// Collect the configured `header_fields` present on the request message.
+///
+/// Only singular `string` fields qualify; fields bound by a path parameter
+/// keep their path binding.
+fn extract_header_params(
+    input_fqn: &str,
+    path_params: &[PathParam],
+    field_types: &MessageFieldTypes,
+    config: &RestCodegenConfig,
+) -> Vec<HeaderParam> {
+    let Some(msg_fields) = field_types.get(input_fqn) else {
+        return Vec::new();
+    };
+
+    config
+        .header_fields
+        .iter()
+        .filter(|name| {
+            !path_params
+                .iter()
+                .any(|p| p.assignment.field_name() == name.as_str())
+        })
+        .filter_map(|name| {
+            let info = msg_fields.get(name)?;
+            (info.type_id == field_type::STRING && !info.repeated).then(|| HeaderParam {
+                field_name: name.clone(),
+                header_name: name.replace('_', "-").to_ascii_lowercase(),
+                optional: info.optional,
+            })
+        })
+        .collect()
+}
+
+/// Collect the request fields bound from the query string on no-body routes.
+///
+/// Every scalar field that is not already bound by a path parameter or read
+/// from a header becomes a query parameter, in declaration order. Returns
+/// `None` when any remaining field has no query-struct mapping (message
+/// fields such as `FieldMask`, repeated or `optional` enums, floats, bytes);
+/// the handler then binds the whole request with `Query<T>` instead.
+fn extract_query_params(
+    input_fqn: &str,
+    path_params: &[PathParam],
+    field_types: &MessageFieldTypes,
+    config: &RestCodegenConfig,
+) -> Option<Vec<QueryParam>> {
+    let Some(msg_fields) = field_types.get(input_fqn) else {
+        return Some(Vec::new());
+    };
+
+    let mut fields: Vec<(&String, &FieldTypeInfo)> = msg_fields
+        .iter()
+        .filter(|(name, _)| {
+            !path_params
+                .iter()
+                .any(|p| p.assignment.field_name() == name.as_str())
+                && !config.header_fields.contains(name)
+        })
+        .collect();
+    fields.sort_by_key(|(_, info)| info.position);
+
+    fields
+        .into_iter()
+        .map(|(name, info)| {
+            let (rust_type, kind) = match info.type_id {
+                field_type::ENUM if !info.repeated && !info.optional => {
+                    let enum_rust_type = config.proto_type_to_rust(info.type_name.as_deref()?);
+                    ("String", QueryParamKind::Enum { enum_rust_type })
+                }
+                field_type::STRING => ("String", scalar_query_kind(info)),
+                ty => (proto_type_to_rust_scalar(ty)?, scalar_query_kind(info)),
+            };
+            Some(QueryParam {
+                field_name: name.clone(),
+                json_name: info.json_name.clone(),
+                rust_type: rust_type.to_string(),
+                kind,
+            })
+        })
+        .collect()
+}
+
+const fn scalar_query_kind(info: &FieldTypeInfo) -> QueryParamKind {
+    if info.repeated {
+        QueryParamKind::Repeated
+    } else if info.optional {
+        QueryParamKind::Optional
+    } else {
+        QueryParamKind::Singular
+    }
+}
+
+/// Map 64-bit integer proto field types to their Rust integer type.
+const fn proto_type_to_rust_int64(type_id: i32) -> Option<&'static str> {
+    match type_id {
+        field_type::INT64 | field_type::SINT64 | field_type::SFIXED64 => Some("i64"),
+        field_type::UINT64 | field_type::FIXED64 => Some("u64"),
+        _ => None,
+    }
+}
+
+/// Map proto field type IDs to Rust scalar types for query parameters and
+/// `body` / `response_body` field selectors.
+///
+/// Path parameters only reach this for 32-bit and `bool` fields; 64-bit
+/// ones are caught first by [`proto_type_to_rust_int64`].
 ///
 /// Returns `None` for `STRING` (uses `String` as default) and unsupported types.
 const fn proto_type_to_rust_scalar(type_id: i32) -> Option<&'static str> {
     match type_id {
         field_type::INT32 => Some("i32"),
-        field_type::INT64 => Some("i64"),
         field_type::UINT32 => Some("u32"),
-        field_type::UINT64 => Some("u64"),
+        field_type::INT64
+        | field_type::UINT64
+        | field_type::SINT64
+        | field_type::FIXED64
+        | field_type::SFIXED64 => proto_type_to_rust_int64(type_id),
         field_type::BOOL => Some("bool"),
         _ => None,
     }
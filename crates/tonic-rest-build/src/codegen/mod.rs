@@ -16,7 +16,7 @@ mod emit;
 mod extract;
 mod types;
 
-pub use config::{GenerateError, RestCodegenConfig};
+pub use config::{DEFAULT_LINT_ALLOWS, GenerateError, RestCodegenConfig, StreamingFormat};
 
 use prost::Message as _;
 use tonic_rest_core::descriptor::FileDescriptorSet;
@@ -33,12 +33,11 @@ use tonic_rest_core::descriptor::FileDescriptorSet;
 ///
 /// # Known Limitations
 ///
-/// - **`additional_bindings`**: Proto `HttpRule.additional_bindings` (multiple
-///   REST mappings per gRPC method) is not supported. Only the primary binding
-///   is processed.
-/// - **Partial body selectors**: Only `body: "*"` (full body) and `body: ""`
-///   (no body) are supported. The `body: "field_name"` partial body binding
-///   from the gRPC-HTTP transcoding spec is not implemented.
+/// - **Partial body selectors**: `body: "field_name"` must name a singular
+///   message or scalar field, and is not supported on server-streaming methods.
+/// - **Client streaming**: client-streaming RPCs read an NDJSON body and must
+///   use `body: "*"` without path parameters; bidirectional streaming is not
+///   supported.
 /// - **Repeated WKT fields**: `configure_prost_serde` does not wire serde
 ///   adapters for `repeated google.protobuf.Timestamp` or similar repeated
 ///   well-known type fields.
@@ -49,6 +48,8 @@ use tonic_rest_core::descriptor::FileDescriptorSet;
 /// - `descriptor_bytes` is not a valid protobuf `FileDescriptorSet`
 /// - A nested path param (e.g., `{user_id.value}`) is found but
 ///   [`RestCodegenConfig::wrapper_type`] is not configured
+/// - A partial body selector cannot be bound to a request field
+/// - A streaming method has no REST mapping (see Known Limitations)
 pub fn generate(
     descriptor_bytes: &[u8],
     config: &RestCodegenConfig,
@@ -94,11 +95,17 @@ impl RestCodegenConfig {
     ///
     /// When `packages` is empty, scans the descriptor set for services with
     /// `google.api.http` annotations and infers Rust module paths from proto
-    /// package names (dots → `::`, matching standard `prost-build` output).
+    /// package names (dots → `::`, matching standard `prost-build` output),
+    /// then applies any `module_overrides`.
     fn resolve(&self, fdset: &FileDescriptorSet) -> Self {
         let mut resolved = self.clone();
         if resolved.packages.is_empty() {
             resolved.packages = extract::discover_packages(fdset);
+            resolved.packages.extend(
+                self.module_overrides
+                    .iter()
+                    .map(|(package, module)| (package.clone(), module.clone())),
+            );
         }
         resolved
     }
@@ -112,15 +119,40 @@ mod tests {
     use pretty_assertions::assert_eq;
     use prost::Message;
     use tonic_rest_core::descriptor::{
-        DescriptorProto, EnumDescriptorProto, EnumValueDescriptorProto, FieldDescriptorProto,
-        FileDescriptorProto, FileDescriptorSet, HttpPattern, HttpRule, MethodDescriptorProto,
-        MethodOptions, ServiceDescriptorProto, field_type,
+        CustomHttpPattern, DescriptorProto, EnumDescriptorProto, EnumValueDescriptorProto,
+        FieldDescriptorProto, FileDescriptorProto, FileDescriptorSet, HttpPattern, HttpRule,
+        MethodDescriptorProto, MethodOptions, ServiceDescriptorProto, field_label, field_type,
     };
 
     use super::extract::{collect_field_types, convert_to_axum_path, extract_path_params};
-    use super::types::{FieldTypeInfo, ParamAssignment};
+    use super::types::{FieldTypeInfo, MethodRoute, ParamAssignment};
     use super::*;
 
+    /// Minimal unary route for testing handler-body snippets.
+    fn make_route(http_method: &str, path: &str) -> MethodRoute {
+        MethodRoute {
+            proto_name: "GetItem".to_string(),
+            rust_name: "get_item".to_string(),
+            binding_index: 0,
+            http_method: http_method.to_string(),
+            path: path.to_string(),
+            axum_path: path.to_string(),
+            has_body: false,
+            body_field: None,
+            response_field: None,
+            server_streaming: false,
+            client_streaming: false,
+            input_type: "crate::test::GetItemRequest".to_string(),
+            output_type: "crate::test::Item".to_string(),
+            returns_empty: false,
+            path_params: Vec::new(),
+            query_params: Vec::new(),
+            query_binds_request: false,
+            header_params: Vec::new(),
+            form_body: false,
+        }
+    }
+
     /// Build a method descriptor with an HTTP annotation.
     fn make_method(
         name: &str,
@@ -138,6 +170,8 @@ mod tests {
                 http: Some(HttpRule {
                     pattern: Some(pattern),
                     body: body.to_string(),
+                    response_body: String::new(),
+                    additional_bindings: vec![],
                 }),
             }),
             client_streaming: None,
@@ -153,11 +187,18 @@ mod tests {
                 .iter()
                 .map(|(fname, ftype, type_name)| FieldDescriptorProto {
                     name: Some(fname.to_string()),
+                    label: None,
+                    proto3_optional: None,
+                    default_value: None,
+                    json_name: None,
+                    oneof_index: None,
                     r#type: Some(*ftype),
                     type_name: type_name.map(ToString::to_string),
                     options: None,
                 })
                 .collect(),
+            oneof_decl: vec![],
+            options: None,
             nested_type: vec![],
         }
     }
@@ -287,7 +328,7 @@ mod tests {
     #[test]
     fn extension_request_lines_without_type() {
         let config = RestCodegenConfig::new().runtime_crate("tonic_rest");
-        let lines = config.extension_and_request_lines("body");
+        let lines = config.extension_and_request_lines("body", &make_route("get", "/v1/items"));
         assert!(lines.contains("None"), "should pass None: {lines}");
         assert!(
             lines.contains("build_tonic_request::<_, ()>"),
@@ -304,7 +345,7 @@ mod tests {
         let config = RestCodegenConfig::new()
             .runtime_crate("tonic_rest")
             .extension_type("auth_core::AuthInfo");
-        let lines = config.extension_and_request_lines("query");
+        let lines = config.extension_and_request_lines("query", &make_route("get", "/v1/items"));
         assert!(
             lines.contains("ext.map(|Extension(v)| v)"),
             "should unwrap Extension: {lines}",
@@ -315,6 +356,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn request_lines_with_header_cap() {
+        let config = RestCodegenConfig::new()
+            .runtime_crate("tonic_rest")
+            .max_forwarded_header_bytes(4096);
+        let route = make_route("get", "/v1/items");
+
+        let lines = config.extension_and_request_lines("body", &route);
+        assert_eq!(
+            lines,
+            "    let req = tonic_rest::build_tonic_request_checked::<_, ()>(body, &headers, None, tonic_rest::FORWARDED_HEADERS, 4096)\n        \
+             .map_err(|err| err.with_context(\"GET\", \"/v1/items\"))?;\n",
+        );
+
+        let lines = config.extension_and_stream_request_lines("body", &route);
+        assert!(
+            lines.contains(
+                "build_tonic_request_checked::<_, ()>(tonic_rest::ndjson_streaming(body),"
+            ),
+            "should check the NDJSON stream request: {lines}",
+        );
+
+        let lines = config.extension_and_resume_request_lines("query", &route);
+        assert!(
+            lines.starts_with(
+                "    tonic_rest::check_forwarded_headers(&headers, tonic_rest::FORWARDED_HEADERS, 4096)\n"
+            ),
+            "should check before the resume request: {lines}",
+        );
+        assert!(lines.contains("build_tonic_request_with_resume::<_, ()>(query,"));
+    }
+
     #[test]
     fn test_convert_to_axum_path() {
         assert_eq!(convert_to_axum_path("/v1/users"), "/v1/users");
@@ -371,8 +444,12 @@ mod tests {
         msg_fields.insert(
             "device_id".to_string(),
             FieldTypeInfo {
+                position: 0,
                 type_id: field_type::STRING,
-                enum_type_name: None,
+                type_name: None,
+                repeated: false,
+                optional: false,
+                json_name: None,
             },
         );
         let mut field_types = HashMap::new();
@@ -400,8 +477,12 @@ mod tests {
         msg_fields.insert(
             "provider".to_string(),
             FieldTypeInfo {
+                position: 0,
                 type_id: field_type::ENUM,
-                enum_type_name: Some(".auth.v1.OAuthProvider".to_string()),
+                type_name: Some(".auth.v1.OAuthProvider".to_string()),
+                repeated: false,
+                optional: false,
+                json_name: None,
             },
         );
         let mut field_types = HashMap::new();
@@ -438,8 +519,12 @@ mod tests {
         msg_fields.insert(
             "role".to_string(),
             FieldTypeInfo {
+                position: 0,
                 type_id: field_type::STRING,
-                enum_type_name: None,
+                type_name: None,
+                repeated: false,
+                optional: false,
+                json_name: None,
             },
         );
         let mut field_types = HashMap::new();
@@ -495,8 +580,12 @@ mod tests {
         msg_fields.insert(
             "page".to_string(),
             FieldTypeInfo {
+                position: 0,
                 type_id: field_type::INT32,
-                enum_type_name: None,
+                type_name: None,
+                repeated: false,
+                optional: false,
+                json_name: None,
             },
         );
         let mut field_types = HashMap::new();
@@ -522,6 +611,84 @@ mod tests {
         }
     }
 
+    #[test]
+    fn path_params_int64_field_produces_typed64_param() {
+        let config = RestCodegenConfig::new();
+        let mut field_types = HashMap::new();
+        for (name, ty) in [
+            ("a", field_type::INT64),
+            ("b", field_type::SINT64),
+            ("c", field_type::SFIXED64),
+        ] {
+            field_types
+                .entry(".test.v1.GetRequest".to_string())
+                .or_insert_with(HashMap::new)
+                .insert(
+                    name.to_string(),
+                    FieldTypeInfo {
+                        position: 0,
+                        type_id: ty,
+                        type_name: None,
+                        repeated: false,
+                        optional: false,
+                        json_name: None,
+                    },
+                );
+        }
+
+        let params = extract_path_params(
+            "/v1/items/{a}/{b}/{c}",
+            ".test.v1.GetRequest",
+            &field_types,
+            &config,
+        )
+        .unwrap();
+        assert_eq!(params.len(), 3);
+        for param in &params {
+            match &param.assignment {
+                ParamAssignment::Typed64 { rust_type, .. } => assert_eq!(*rust_type, "i64"),
+                other => panic!("Expected Typed64, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn path_params_uint64_field_produces_typed64_param() {
+        let config = RestCodegenConfig::new();
+        let mut field_types = HashMap::new();
+        for (name, ty) in [("a", field_type::UINT64), ("b", field_type::FIXED64)] {
+            field_types
+                .entry(".test.v1.GetRequest".to_string())
+                .or_insert_with(HashMap::new)
+                .insert(
+                    name.to_string(),
+                    FieldTypeInfo {
+                        position: 0,
+                        type_id: ty,
+                        type_name: None,
+                        repeated: false,
+                        optional: false,
+                        json_name: None,
+                    },
+                );
+        }
+
+        let params = extract_path_params(
+            "/v1/items/{a}/{b}",
+            ".test.v1.GetRequest",
+            &field_types,
+            &config,
+        )
+        .unwrap();
+        assert_eq!(params.len(), 2);
+        for param in &params {
+            match &param.assignment {
+                ParamAssignment::Typed64 { rust_type, .. } => assert_eq!(*rust_type, "u64"),
+                other => panic!("Expected Typed64, got {other:?}"),
+            }
+        }
+    }
+
     #[test]
     fn path_params_bool_field_produces_typed_param() {
         let config = RestCodegenConfig::new();
@@ -529,8 +696,12 @@ mod tests {
         msg_fields.insert(
             "active".to_string(),
             FieldTypeInfo {
+                position: 0,
                 type_id: field_type::BOOL,
-                enum_type_name: None,
+                type_name: None,
+                repeated: false,
+                optional: false,
+                json_name: None,
             },
         );
         let mut field_types = HashMap::new();
@@ -561,6 +732,8 @@ mod tests {
         assert_eq!(config.runtime_crate, "tonic_rest");
         assert!(config.wrapper_type.is_none());
         assert_eq!(config.sse_keep_alive_secs, 15);
+        assert!(config.sse_event_name.is_none());
+        assert_eq!(config.streaming_format, StreamingFormat::Sse);
         assert!(config.extension_type.is_none());
     }
 
@@ -573,6 +746,7 @@ mod tests {
             .runtime_crate("my_runtime")
             .wrapper_type("my::Uuid")
             .sse_keep_alive_secs(30)
+            .sse_event_name("message")
             .extension_type("my::Auth")
             .public_methods(&["Login", "SignUp"]);
 
@@ -584,6 +758,7 @@ mod tests {
         assert_eq!(config.runtime_crate, "my_runtime");
         assert_eq!(config.wrapper_type.as_deref(), Some("my::Uuid"));
         assert_eq!(config.sse_keep_alive_secs, 30);
+        assert_eq!(config.sse_event_name.as_deref(), Some("message"));
         assert_eq!(config.extension_type.as_deref(), Some("my::Auth"));
         assert!(config.public_methods.contains("Login"));
         assert!(config.public_methods.contains("SignUp"));
@@ -635,6 +810,26 @@ mod tests {
         let _: &dyn std::error::Error = &err;
     }
 
+    #[test]
+    fn lint_allows_block_is_opt_in() {
+        let fdset = FileDescriptorSet { file: vec![] };
+        let bytes = encode_fdset(&fdset);
+
+        let code = generate(&bytes, &RestCodegenConfig::new()).unwrap();
+        assert!(!code.contains("#![allow("));
+
+        let config = RestCodegenConfig::new().emit_lint_allows(true);
+        let code = generate(&bytes, &config).unwrap();
+        assert!(code.starts_with(
+            "#![allow(clippy::all, clippy::pedantic, unused, missing_docs, unfulfilled_lint_expectations)]\n"
+        ));
+        syn::parse_file(&code).expect("generated code should be valid Rust syntax");
+
+        let config = config.lint_allows(&["unused_imports"]);
+        let code = generate(&bytes, &config).unwrap();
+        assert!(code.starts_with("#![allow(unused_imports)]\n"));
+    }
+
     #[test]
     fn generate_empty_descriptor() {
         let fdset = FileDescriptorSet { file: vec![] };
@@ -667,7 +862,9 @@ mod tests {
                         "*",
                         false,
                     )],
+                    options: None,
                 }],
+                source_code_info: None,
             }],
         };
         // Config only registers "test.v1", not "other.v1"
@@ -722,7 +919,9 @@ mod tests {
                             false,
                         ),
                     ],
+                    options: None,
                 }],
+                source_code_info: None,
             }],
         };
 
@@ -756,6 +955,141 @@ mod tests {
         syn::parse_file(&code).expect("generated code should be valid Rust syntax");
     }
 
+    /// `route_prefix` applies to router registrations, public paths, and the
+    /// route manifest.
+    #[test]
+    fn snapshot_route_prefix() {
+        let fdset = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("item.proto".to_string()),
+                package: Some("test.v1".to_string()),
+                message_type: vec![
+                    make_message("CreateItemRequest", &[("name", field_type::STRING, None)]),
+                    make_message("GetItemRequest", &[("item_id", field_type::STRING, None)]),
+                    make_message("Item", &[("id", field_type::STRING, None)]),
+                ],
+                enum_type: vec![],
+                service: vec![ServiceDescriptorProto {
+                    name: Some("ItemService".to_string()),
+                    method: vec![
+                        make_method(
+                            "CreateItem",
+                            ".test.v1.CreateItemRequest",
+                            ".test.v1.Item",
+                            HttpPattern::Post("/v1/items".to_string()),
+                            "*",
+                            false,
+                        ),
+                        make_method(
+                            "GetItem",
+                            ".test.v1.GetItemRequest",
+                            ".test.v1.Item",
+                            HttpPattern::Get("/v1/items/{item_id}".to_string()),
+                            "",
+                            false,
+                        ),
+                    ],
+                    options: None,
+                }],
+                source_code_info: None,
+            }],
+        };
+
+        let config = RestCodegenConfig::new()
+            .package("test.v1", "test")
+            .public_methods(&["CreateItem"])
+            .route_prefix("/api/")
+            .emit_route_manifest(true);
+        let code = generate(&encode_fdset(&fdset), &config).unwrap();
+
+        assert!(code.contains(
+            ".route(\"/api/v1/items\", axum::routing::post(rest_item_service_create_item::<S>))"
+        ));
+        assert!(code.contains(".route(\"/api/v1/items/{item_id}\","));
+        assert!(
+            code.contains("pub const PUBLIC_REST_PATHS: &[&str] = &[\n    \"/api/v1/items\",\n];")
+        );
+        assert!(code.contains("(\"GET\", \"/api/v1/items/{item_id}\"),"));
+        // Handler docs and error context keep the proto route template.
+        assert!(code.contains("with_context(\"GET\", \"/v1/items/{item_id}\")"));
+
+        assert_golden("route_prefix.rs", &code);
+        syn::parse_file(&code).expect("generated code should be valid Rust syntax");
+    }
+
+    #[test]
+    fn route_prefix_is_normalized() {
+        let prefix = |p: &str| RestCodegenConfig::new().route_prefix(p).route_prefix;
+        assert_eq!(prefix("api"), "/api");
+        assert_eq!(prefix("/api/"), "/api");
+        assert_eq!(prefix("/"), "");
+        assert_eq!(prefix(""), "");
+    }
+
+    /// `response_headers` forwards response metadata as HTTP headers.
+    #[test]
+    fn snapshot_response_headers() {
+        let fdset = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("item.proto".to_string()),
+                package: Some("test.v1".to_string()),
+                message_type: vec![
+                    make_message("GetItemRequest", &[("item_id", field_type::STRING, None)]),
+                    make_message(
+                        "DeleteItemRequest",
+                        &[("item_id", field_type::STRING, None)],
+                    ),
+                    make_message("Item", &[("id", field_type::STRING, None)]),
+                ],
+                enum_type: vec![],
+                service: vec![ServiceDescriptorProto {
+                    name: Some("ItemService".to_string()),
+                    method: vec![
+                        make_method(
+                            "GetItem",
+                            ".test.v1.GetItemRequest",
+                            ".test.v1.Item",
+                            HttpPattern::Get("/v1/items/{item_id}".to_string()),
+                            "",
+                            false,
+                        ),
+                        make_method(
+                            "DeleteItem",
+                            ".test.v1.DeleteItemRequest",
+                            ".google.protobuf.Empty",
+                            HttpPattern::Delete("/v1/items/{item_id}".to_string()),
+                            "",
+                            false,
+                        ),
+                    ],
+                    options: None,
+                }],
+                source_code_info: None,
+            }],
+        };
+
+        let config = RestCodegenConfig::new()
+            .package("test.v1", "test")
+            .response_headers(&["X-Request-Id", "etag"]);
+
+        let code = generate(&encode_fdset(&fdset), &config).unwrap();
+
+        assert!(code.contains(
+            "const RESPONSE_HEADERS: &[&str] = &[\n    \"x-request-id\",\n    \"etag\",\n];"
+        ));
+        assert!(
+            code.contains("Result<(HeaderMap, Json<crate::test::Item>), tonic_rest::RestError>")
+        );
+        assert!(code.contains("Ok((response_headers, StatusCode::NO_CONTENT))"));
+        assert_eq!(
+            code.matches("tonic_rest::apply_response_metadata(&mut response_headers, response.metadata(), RESPONSE_HEADERS);").count(),
+            2
+        );
+
+        assert_golden("response_headers.rs", &code);
+        syn::parse_file(&code).expect("generated code should be valid Rust syntax");
+    }
+
     /// Streaming SSE endpoint + UUID wrapper path param + auth type + custom keep-alive.
     #[test]
     fn snapshot_streaming_with_uuid_and_auth() {
@@ -796,7 +1130,9 @@ mod tests {
                             false,
                         ),
                     ],
+                    options: None,
                 }],
+                source_code_info: None,
             }],
         };
 
@@ -814,6 +1150,12 @@ mod tests {
         assert!(code.contains("KeepAlive::new()"));
         assert!(code.contains("Duration::from_secs(30)"));
         assert!(code.contains("sse_error_event"));
+        assert!(code.contains("tonic_rest::sse_data_event(None, id, &item)"));
+        assert!(code.contains("let mut next_id = tonic_rest::sse_first_event_id(&headers);"));
+        assert!(code.contains("next_id += 1;"));
+        assert!(code.contains(
+            "tonic_rest::build_tonic_request_with_resume(query, &headers, ext, tonic_rest::FORWARDED_HEADERS)"
+        ));
 
         // Auth type
         assert!(code.contains("Option<Extension<crate::AuthInfo>>"));
@@ -861,7 +1203,9 @@ mod tests {
                         "",
                         false,
                     )],
+                    options: None,
                 }],
+                source_code_info: None,
             }],
         };
 
@@ -878,10 +1222,59 @@ mod tests {
         syn::parse_file(&code).expect("generated code should be valid Rust syntax");
     }
 
-    /// Multiple services from different packages in a single descriptor.
+    /// proto3 `optional` path fields are `Option<T>` in prost and are assigned as `Some(..)`.
     #[test]
-    fn snapshot_multi_service() {
+    fn snapshot_optional_path_param() {
+        let mut request = make_message(
+            "GetItemRequest",
+            &[
+                ("item_id", field_type::STRING, None),
+                ("revision", field_type::INT64, None),
+            ],
+        );
+        request.field[0].proto3_optional = Some(true);
+        request.field[1].proto3_optional = Some(true);
+
         let fdset = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("items.proto".to_string()),
+                package: Some("test.v1".to_string()),
+                message_type: vec![
+                    request,
+                    make_message("Item", &[("name", field_type::STRING, None)]),
+                ],
+                enum_type: vec![],
+                service: vec![ServiceDescriptorProto {
+                    name: Some("ItemService".to_string()),
+                    method: vec![make_method(
+                        "GetItem",
+                        ".test.v1.GetItemRequest",
+                        ".test.v1.Item",
+                        HttpPattern::Get("/v1/items/{item_id}/revisions/{revision}".to_string()),
+                        "",
+                        false,
+                    )],
+                    options: None,
+                }],
+                source_code_info: None,
+            }],
+        };
+
+        let config = RestCodegenConfig::new().package("test.v1", "test");
+        let code = generate(&encode_fdset(&fdset), &config).unwrap();
+
+        assert!(code.contains("body.item_id = Some(item_id);"));
+        assert!(code.contains("let revision = revision.parse::<i64>().map_err(|_| {"));
+        assert!(code.contains("body.revision = Some(revision);"));
+
+        assert_golden("optional_path_param.rs", &code);
+        syn::parse_file(&code).expect("generated code should be valid Rust syntax");
+    }
+
+    /// Two packages, each with one service: `AuthService` (unary) and
+    /// `UserService` (server streaming).
+    fn multi_service_fdset() -> FileDescriptorSet {
+        FileDescriptorSet {
             file: vec![
                 FileDescriptorProto {
                     name: Some("auth.proto".to_string()),
@@ -901,7 +1294,9 @@ mod tests {
                             "*",
                             false,
                         )],
+                        options: None,
                     }],
+                    source_code_info: None,
                 },
                 FileDescriptorProto {
                     name: Some("users.proto".to_string()),
@@ -921,15 +1316,24 @@ mod tests {
                             "",
                             true,
                         )],
+                        options: None,
                     }],
+                    source_code_info: None,
                 },
             ],
-        };
+        }
+    }
+
+    /// Multiple services from different packages in a single descriptor.
+    #[test]
+    fn snapshot_multi_service() {
+        let fdset = multi_service_fdset();
 
         let config = RestCodegenConfig::new()
             .package("auth.v1", "auth")
             .package("users.v1", "users")
-            .public_methods(&["Login"]);
+            .public_methods(&["Login"])
+            .emit_route_manifest(true);
 
         let code = generate(&encode_fdset(&fdset), &config).unwrap();
 
@@ -945,91 +1349,989 @@ mod tests {
         // Public paths
         assert!(code.contains("\"/v1/auth/login\""));
 
+        // Matched route → operation ID lookup
+        assert!(code.contains("(\"POST\", \"/v1/auth/login\") => Some(\"AuthService_Login\"),"));
+        assert!(code.contains("(\"GET\", \"/v1/users\") => Some(\"UserService_ListUsers\"),"));
+
         assert_golden("multi_service.rs", &code);
         syn::parse_file(&code).expect("generated code should be valid Rust syntax");
     }
 
-    /// PUT endpoint with body and path param.
+    /// `StreamingFormat::Ndjson` serves server-streaming methods as
+    /// newline-delimited JSON instead of SSE.
     #[test]
-    fn snapshot_put_with_body_and_path() {
-        let fdset = FileDescriptorSet {
-            file: vec![FileDescriptorProto {
-                name: Some("items.proto".to_string()),
-                package: Some("test.v1".to_string()),
-                message_type: vec![
-                    make_message(
-                        "ReplaceItemRequest",
-                        &[
-                            ("item_id", field_type::STRING, None),
-                            ("name", field_type::STRING, None),
-                        ],
-                    ),
-                    make_message("Item", &[("name", field_type::STRING, None)]),
-                ],
-                enum_type: vec![],
-                service: vec![ServiceDescriptorProto {
-                    name: Some("ItemService".to_string()),
-                    method: vec![make_method(
-                        "ReplaceItem",
-                        ".test.v1.ReplaceItemRequest",
-                        ".test.v1.Item",
-                        HttpPattern::Put("/v1/items/{item_id}".to_string()),
-                        "*",
-                        false,
-                    )],
-                }],
-            }],
-        };
-
-        let config = RestCodegenConfig::new().package("test.v1", "test");
-        let code = generate(&encode_fdset(&fdset), &config).unwrap();
+    fn snapshot_ndjson_stream() {
+        let config = RestCodegenConfig::new()
+            .package("auth.v1", "auth")
+            .package("users.v1", "users")
+            .streaming_format(StreamingFormat::Ndjson);
+        let code = generate(&encode_fdset(&multi_service_fdset()), &config).unwrap();
 
-        assert!(code.contains("axum::routing::put("));
-        assert!(code.contains("Path(item_id): Path<String>"));
-        assert!(code.contains("Json(mut body)"));
-        assert!(code.contains("body.item_id = item_id;"));
+        assert!(code.contains("`GET /v1/users` → `application/x-ndjson`"));
+        assert!(code.contains("Ok(tonic_rest::ndjson_response(response.into_inner()))"));
+        assert!(!code.contains("Sse"));
+        assert!(!code.contains("use std::convert::Infallible;"));
 
-        assert_golden("put_body_path.rs", &code);
+        assert_golden("ndjson_stream.rs", &code);
         syn::parse_file(&code).expect("generated code should be valid Rust syntax");
     }
 
-    /// Nested message types are included in field type resolution.
-    ///
-    /// Before the `collect_message_fields` recursion fix, nested messages
-    /// were not registered in the field-type map, causing path parameter
-    /// type resolution to fall back to `String` instead of the real type.
+    /// `sse_event_name` names streamed messages; without it they stay unnamed.
     #[test]
-    fn collect_field_types_includes_nested_messages() {
-        let fdset = FileDescriptorSet {
-            file: vec![FileDescriptorProto {
+    fn sse_event_name_is_opt_in() {
+        let fdset = encode_fdset(&multi_service_fdset());
+        let config = RestCodegenConfig::new()
+            .package("auth.v1", "auth")
+            .package("users.v1", "users");
+
+        let unnamed = generate(&fdset, &config).unwrap();
+        assert!(unnamed.contains("tonic_rest::sse_data_event(None, id, &item)"));
+
+        let named = generate(&fdset, &config.sse_event_name("user")).unwrap();
+        assert!(named.contains("tonic_rest::sse_data_event(Some(\"user\"), id, &item)"));
+    }
+
+    /// The route manifest is only emitted with `emit_route_manifest`.
+    #[test]
+    fn route_manifest_is_opt_in() {
+        let fdset = encode_fdset(&multi_service_fdset());
+        let config = RestCodegenConfig::new()
+            .package("auth.v1", "auth")
+            .package("users.v1", "users");
+
+        let without = generate(&fdset, &config).unwrap();
+        assert!(!without.contains("REST_ROUTE_COUNT"));
+        assert!(!without.contains("fn rest_routes"));
+        assert!(!without.contains("fn operation_id_for"));
+
+        let with = generate(&fdset, &config.emit_route_manifest(true)).unwrap();
+        assert!(with.contains("pub const REST_ROUTE_COUNT: usize ="));
+        assert!(with.contains("pub const fn rest_routes()"));
+        assert!(with.contains("pub fn operation_id_for("));
+    }
+
+    /// `tracing` wraps unary and SSE service calls in an operation span.
+    #[test]
+    fn snapshot_tracing() {
+        let config = RestCodegenConfig::new()
+            .package("auth.v1", "auth")
+            .package("users.v1", "users")
+            .tracing(true);
+        let code = generate(&encode_fdset(&multi_service_fdset()), &config).unwrap();
+
+        assert!(code.contains("let span = tracing::info_span!(\n        \"AuthService.Login\","));
+        assert!(code.contains("http.method = \"POST\","));
+        assert!(code.contains("http.route = \"/v1/users\","));
+        assert!(code.contains("tracing::Instrument::instrument(service.login(req), span).await"));
+        assert!(
+            code.contains("tracing::Instrument::instrument(service.list_users(req), span).await")
+        );
+
+        assert_golden("tracing.rs", &code);
+        syn::parse_file(&code).expect("generated code should be valid Rust syntax");
+    }
+
+    /// `max_forwarded_header_bytes` rejects oversized forwarded headers with
+    /// a context-tagged `RestError` in unary and SSE handlers.
+    #[test]
+    fn snapshot_forwarded_header_cap() {
+        let config = RestCodegenConfig::new()
+            .package("auth.v1", "auth")
+            .package("users.v1", "users")
+            .extension_type("auth_core::AuthInfo")
+            .max_forwarded_header_bytes(8192);
+        let code = generate(&encode_fdset(&multi_service_fdset()), &config).unwrap();
+
+        assert!(code.contains(
+            "tonic_rest::build_tonic_request_checked(body, &headers, ext, tonic_rest::FORWARDED_HEADERS, 8192)\n        .map_err(|err| err.with_context(\"POST\", \"/v1/auth/login\"))?;"
+        ));
+        assert!(code.contains(
+            "tonic_rest::check_forwarded_headers(&headers, tonic_rest::FORWARDED_HEADERS, 8192)\n        .map_err(|err| err.with_context(\"GET\", \"/v1/users\"))?;"
+        ));
+        assert!(!code.contains("tonic_rest::build_tonic_request("));
+
+        assert_golden("forwarded_header_cap.rs", &code);
+        syn::parse_file(&code).expect("generated code should be valid Rust syntax");
+    }
+
+    /// `auto_head` pairs every GET route with a HEAD route on the same path,
+    /// unless the proto already binds HEAD there.
+    #[test]
+    fn snapshot_auto_head() {
+        let fdset = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("item.proto".to_string()),
+                package: Some("test.v1".to_string()),
+                message_type: vec![
+                    make_message("GetItemRequest", &[("item_id", field_type::STRING, None)]),
+                    make_message(
+                        "ListItemsRequest",
+                        &[("page_size", field_type::INT32, None)],
+                    ),
+                    make_message("Item", &[("id", field_type::STRING, None)]),
+                ],
+                enum_type: vec![],
+                service: vec![ServiceDescriptorProto {
+                    name: Some("ItemService".to_string()),
+                    method: vec![
+                        make_method(
+                            "GetItem",
+                            ".test.v1.GetItemRequest",
+                            ".test.v1.Item",
+                            HttpPattern::Get("/v1/items/{item_id}".to_string()),
+                            "",
+                            false,
+                        ),
+                        make_method(
+                            "ListItems",
+                            ".test.v1.ListItemsRequest",
+                            ".test.v1.Item",
+                            HttpPattern::Get("/v1/items".to_string()),
+                            "",
+                            false,
+                        ),
+                        make_method(
+                            "CountItems",
+                            ".test.v1.ListItemsRequest",
+                            ".google.protobuf.Empty",
+                            HttpPattern::Custom(CustomHttpPattern {
+                                kind: "HEAD".to_string(),
+                                path: "/v1/items".to_string(),
+                            }),
+                            "",
+                            false,
+                        ),
+                    ],
+                    options: None,
+                }],
+                source_code_info: None,
+            }],
+        };
+
+        let config = RestCodegenConfig::new()
+            .package("test.v1", "test")
+            .auto_head(true)
+            .emit_route_manifest(true);
+        let code = generate(&encode_fdset(&fdset), &config).unwrap();
+
+        let item_path = "\"/v1/items/{item_id}\"";
+        assert!(code.contains(&format!(
+            ".route({item_path}, axum::routing::get(rest_item_service_get_item::<S>))"
+        )));
+        assert!(code.contains(&format!(
+            ".route({item_path}, axum::routing::head(rest_item_service_get_item::<S>))"
+        )));
+        // Explicit HEAD binding wins; no duplicate HEAD for the list path
+        assert_eq!(
+            code.matches(".route(\"/v1/items\", axum::routing::head(")
+                .count(),
+            1
+        );
+        assert!(code.contains("axum::routing::head(rest_item_service_count_items::<S>)"));
+
+        // Generated HEAD routes are part of the manifest
+        assert!(code.contains("pub const REST_ROUTE_COUNT: usize = 4;"));
+        assert!(code.contains("(\"HEAD\", \"/v1/items/{item_id}\"),"));
+        assert!(
+            code.contains("(\"HEAD\", \"/v1/items/{item_id}\") => Some(\"ItemService_GetItem\"),")
+        );
+
+        assert_golden("auto_head.rs", &code);
+        syn::parse_file(&code).expect("generated code should be valid Rust syntax");
+
+        let without = generate(
+            &encode_fdset(&fdset),
+            &RestCodegenConfig::new().package("test.v1", "test"),
+        )
+        .unwrap();
+        assert!(!without.contains("axum::routing::head(rest_item_service_get_item"));
+    }
+
+    /// `cors_preflight` adds one OPTIONS route per path listing its methods.
+    #[test]
+    fn snapshot_cors_preflight() {
+        let fdset = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("item.proto".to_string()),
+                package: Some("test.v1".to_string()),
+                message_type: vec![
+                    make_message("CreateItemRequest", &[("name", field_type::STRING, None)]),
+                    make_message(
+                        "ListItemsRequest",
+                        &[("page_size", field_type::INT32, None)],
+                    ),
+                    make_message("GetItemRequest", &[("item_id", field_type::STRING, None)]),
+                    make_message("Item", &[("id", field_type::STRING, None)]),
+                ],
+                enum_type: vec![],
+                service: vec![ServiceDescriptorProto {
+                    name: Some("ItemService".to_string()),
+                    method: vec![
+                        make_method(
+                            "ListItems",
+                            ".test.v1.ListItemsRequest",
+                            ".test.v1.Item",
+                            HttpPattern::Get("/v1/items".to_string()),
+                            "",
+                            false,
+                        ),
+                        make_method(
+                            "CreateItem",
+                            ".test.v1.CreateItemRequest",
+                            ".test.v1.Item",
+                            HttpPattern::Post("/v1/items".to_string()),
+                            "*",
+                            false,
+                        ),
+                        make_method(
+                            "GetItem",
+                            ".test.v1.GetItemRequest",
+                            ".test.v1.Item",
+                            HttpPattern::Get("/v1/items/{item_id}".to_string()),
+                            "",
+                            false,
+                        ),
+                    ],
+                    options: None,
+                }],
+                source_code_info: None,
+            }],
+        };
+
+        let config = RestCodegenConfig::new()
+            .package("test.v1", "test")
+            .cors_preflight(true)
+            .emit_route_manifest(true);
+        let code = generate(&encode_fdset(&fdset), &config).unwrap();
+
+        assert_eq!(code.matches("axum::routing::options(").count(), 2);
+        assert!(code.contains("(axum::http::header::ALLOW, \"GET, POST, OPTIONS\"),"));
+        assert!(code.contains(
+            "(axum::http::header::ACCESS_CONTROL_ALLOW_METHODS, \"GET, POST, OPTIONS\"),"
+        ));
+        assert!(code.contains("(axum::http::header::ALLOW, \"GET, OPTIONS\"),"));
+
+        // Preflight routes are in the manifest but map to no operation
+        assert!(code.contains("pub const REST_ROUTE_COUNT: usize = 5;"));
+        assert!(code.contains("(\"OPTIONS\", \"/v1/items/{item_id}\"),"));
+        assert!(!code.contains("(\"OPTIONS\", \"/v1/items\") =>"));
+
+        assert_golden("cors_preflight.rs", &code);
+        syn::parse_file(&code).expect("generated code should be valid Rust syntax");
+
+        let with_head = generate(&encode_fdset(&fdset), &config.auto_head(true)).unwrap();
+        assert!(with_head.contains("(axum::http::header::ALLOW, \"GET, POST, HEAD, OPTIONS\"),"));
+    }
+
+    /// `emit_route_descriptors` makes handlers and query structs `pub` and
+    /// pairs each handler with a `(Method, &str)` route constant.
+    #[test]
+    fn snapshot_route_descriptors() {
+        let config = RestCodegenConfig::new()
+            .package("auth.v1", "auth")
+            .package("users.v1", "users")
+            .emit_route_descriptors(true);
+        let code = generate(&encode_fdset(&multi_service_fdset()), &config).unwrap();
+
+        assert!(code.contains(
+            "pub const REST_AUTH_SERVICE_LOGIN_ROUTE: (axum::http::Method, &str) =\n    \
+             (axum::http::Method::POST, \"/v1/auth/login\");"
+        ));
+        assert!(code.contains("pub async fn rest_auth_service_login<S>("));
+        assert!(code.contains("pub const REST_USER_SERVICE_LIST_USERS_ROUTE"));
+        assert!(code.contains("pub async fn rest_user_service_list_users<S>("));
+
+        assert_golden("route_descriptors.rs", &code);
+        syn::parse_file(&code).expect("generated code should be valid Rust syntax");
+    }
+
+    /// Without `emit_route_descriptors`, handlers stay private.
+    #[test]
+    fn handlers_private_by_default() {
+        let config = RestCodegenConfig::new()
+            .package("auth.v1", "auth")
+            .package("users.v1", "users");
+        let code = generate(&encode_fdset(&multi_service_fdset()), &config).unwrap();
+
+        assert!(!code.contains("pub async fn"));
+        assert!(!code.contains("_ROUTE:"));
+    }
+
+    /// `emit_doc_comments` adds the operation ID and any registered method
+    /// description to each handler's doc comment.
+    #[test]
+    fn snapshot_doc_comments() {
+        let config = RestCodegenConfig::new()
+            .package("auth.v1", "auth")
+            .package("users.v1", "users")
+            .emit_doc_comments(true)
+            .method_description(
+                "Login",
+                "Exchange credentials for a session.\n\nRate limited per IP.",
+            );
+        let code = generate(&encode_fdset(&multi_service_fdset()), &config).unwrap();
+
+        assert!(code.contains(
+            "/// `POST /v1/auth/login`\n///\n/// Operation ID: `AuthService_Login`\n///\n\
+             /// Exchange credentials for a session.\n///\n/// Rate limited per IP.\nasync fn"
+        ));
+        assert!(code.contains("/// Operation ID: `UserService_ListUsers`\nasync fn"));
+
+        assert_golden("doc_comments.rs", &code);
+        syn::parse_file(&code).expect("generated code should be valid Rust syntax");
+    }
+
+    /// `unified_state` adds a `RestServices` trait and a single-state router
+    /// alongside the multi-generic `all_rest_routes`.
+    #[test]
+    fn snapshot_unified_state() {
+        let config = RestCodegenConfig::new()
+            .package("auth.v1", "auth")
+            .package("users.v1", "users")
+            .unified_state(true);
+        let code = generate(&encode_fdset(&multi_service_fdset()), &config).unwrap();
+
+        assert!(code.contains("fn all_rest_routes<S0, S1>"));
+        assert!(code.contains("pub trait RestServices {"));
+        assert!(code.contains(
+            "type AuthService: crate::auth::auth_service_server::AuthService + Send + Sync + 'static;"
+        ));
+        assert!(code.contains("fn user_service(&self) -> Arc<Self::UserService>;"));
+        assert!(code.contains("all_rest_routes(state.auth_service(), state.user_service())"));
+
+        let without = generate(
+            &encode_fdset(&multi_service_fdset()),
+            &RestCodegenConfig::new()
+                .package("auth.v1", "auth")
+                .package("users.v1", "users"),
+        )
+        .unwrap();
+        assert!(!without.contains("RestServices"));
+
+        assert_golden("unified_state.rs", &code);
+        syn::parse_file(&code).expect("generated code should be valid Rust syntax");
+    }
+
+    /// PUT endpoint with body and path param.
+    #[test]
+    fn snapshot_put_with_body_and_path() {
+        let fdset = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("items.proto".to_string()),
+                package: Some("test.v1".to_string()),
+                message_type: vec![
+                    make_message(
+                        "ReplaceItemRequest",
+                        &[
+                            ("item_id", field_type::STRING, None),
+                            ("name", field_type::STRING, None),
+                        ],
+                    ),
+                    make_message("Item", &[("name", field_type::STRING, None)]),
+                ],
+                enum_type: vec![],
+                service: vec![ServiceDescriptorProto {
+                    name: Some("ItemService".to_string()),
+                    method: vec![make_method(
+                        "ReplaceItem",
+                        ".test.v1.ReplaceItemRequest",
+                        ".test.v1.Item",
+                        HttpPattern::Put("/v1/items/{item_id}".to_string()),
+                        "*",
+                        false,
+                    )],
+                    options: None,
+                }],
+                source_code_info: None,
+            }],
+        };
+
+        let config = RestCodegenConfig::new().package("test.v1", "test");
+        let code = generate(&encode_fdset(&fdset), &config).unwrap();
+
+        assert!(code.contains("axum::routing::put("));
+        assert!(code.contains("Path(item_id): Path<String>"));
+        assert!(code.contains("Json(mut body)"));
+        assert!(code.contains("body.item_id = item_id;"));
+
+        assert_golden("put_body_path.rs", &code);
+        syn::parse_file(&code).expect("generated code should be valid Rust syntax");
+    }
+
+    /// GET without a body binds the remaining scalar fields from the query
+    /// string through a generated struct.
+    #[test]
+    fn snapshot_get_with_query_params() {
+        let mut request = make_message(
+            "ListItemsRequest",
+            &[
+                ("shelf_id", field_type::STRING, None),
+                ("page_size", field_type::INT32, None),
+                ("page_token", field_type::STRING, None),
+                ("tags", field_type::STRING, None),
+                ("min_price", field_type::INT64, None),
+                ("status", field_type::ENUM, Some(".test.v1.Status")),
+            ],
+        );
+        request.field[3].label = Some(field_label::REPEATED);
+        request.field[4].proto3_optional = Some(true);
+
+        let fdset = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("items.proto".to_string()),
+                package: Some("test.v1".to_string()),
+                message_type: vec![request, make_message("ListItemsResponse", &[])],
+                enum_type: vec![],
+                service: vec![ServiceDescriptorProto {
+                    name: Some("ItemService".to_string()),
+                    method: vec![make_method(
+                        "ListItems",
+                        ".test.v1.ListItemsRequest",
+                        ".test.v1.ListItemsResponse",
+                        HttpPattern::Get("/v1/shelves/{shelf_id}/items".to_string()),
+                        "",
+                        false,
+                    )],
+                    options: None,
+                }],
+                source_code_info: None,
+            }],
+        };
+
+        let config = RestCodegenConfig::new().package("test.v1", "test");
+        let code = generate(&encode_fdset(&fdset), &config).unwrap();
+
+        assert!(code.contains("struct RestItemServiceListItemsQuery {"));
+        assert!(code.contains("    page_size: Option<i32>,"));
+        assert!(code.contains("#[serde(alias = \"pageToken\")]"));
+        assert!(code.contains("comma_separated::deserialize"));
+        assert!(code.contains("    tags: Vec<String>,"));
+        assert!(code.contains("Query(query): Query<RestItemServiceListItemsQuery>"));
+        assert!(code.contains("body.min_price = query.min_price;"));
+        assert!(code.contains("crate::test::Status::from_str_name(&value"));
+        assert!(
+            !code.contains("shelf_id: Option"),
+            "path params are not query params"
+        );
+
+        assert_golden("get_with_query.rs", &code);
+        syn::parse_file(&code).expect("generated code should be valid Rust syntax");
+    }
+
+    /// A query field the generated struct can't express (a `FieldMask`
+    /// message, a repeated enum) keeps the whole request bound with
+    /// `Query<T>`, as streaming GET handlers do, so no field is dropped.
+    #[test]
+    fn snapshot_get_with_query_fallback() {
+        let mut request = make_message(
+            "ListItemsRequest",
+            &[
+                ("shelf_id", field_type::STRING, None),
+                ("page_size", field_type::INT32, None),
+                (
+                    "read_mask",
+                    field_type::MESSAGE,
+                    Some(".google.protobuf.FieldMask"),
+                ),
+                ("statuses", field_type::ENUM, Some(".test.v1.Status")),
+            ],
+        );
+        request.field[3].label = Some(field_label::REPEATED);
+
+        let fdset = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("items.proto".to_string()),
+                package: Some("test.v1".to_string()),
+                message_type: vec![request, make_message("ListItemsResponse", &[])],
+                enum_type: vec![],
+                service: vec![ServiceDescriptorProto {
+                    name: Some("ItemService".to_string()),
+                    method: vec![make_method(
+                        "ListItems",
+                        ".test.v1.ListItemsRequest",
+                        ".test.v1.ListItemsResponse",
+                        HttpPattern::Get("/v1/shelves/{shelf_id}/items".to_string()),
+                        "",
+                        false,
+                    )],
+                    options: None,
+                }],
+                source_code_info: None,
+            }],
+        };
+
+        let config = RestCodegenConfig::new().package("test.v1", "test");
+        let code = generate(&encode_fdset(&fdset), &config).unwrap();
+
+        assert!(code.contains("Query(mut body): Query<crate::test::ListItemsRequest>"));
+        assert!(code.contains("body.shelf_id = shelf_id;"));
+        assert!(!code.contains("RestItemServiceListItemsQuery"));
+        assert!(!code.contains("ListItemsRequest::default()"));
+
+        assert_golden("get_with_query_fallback.rs", &code);
+        syn::parse_file(&code).expect("generated code should be valid Rust syntax");
+    }
+
+    /// An explicit `json_name` is the query alias instead of the derived name.
+    #[test]
+    fn query_alias_prefers_json_name() {
+        let mut request = make_message(
+            "GetItemRequest",
+            &[
+                ("id", field_type::STRING, None),
+                ("page_token", field_type::STRING, None),
+            ],
+        );
+        request.field[0].json_name = Some("ID".to_string());
+        request.field[1].json_name = Some("pageToken".to_string());
+
+        let fdset = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("items.proto".to_string()),
+                package: Some("test.v1".to_string()),
+                message_type: vec![request, make_message("Item", &[])],
+                enum_type: vec![],
+                service: vec![ServiceDescriptorProto {
+                    name: Some("ItemService".to_string()),
+                    method: vec![make_method(
+                        "GetItem",
+                        ".test.v1.GetItemRequest",
+                        ".test.v1.Item",
+                        HttpPattern::Get("/v1/item".to_string()),
+                        "",
+                        false,
+                    )],
+                    options: None,
+                }],
+                source_code_info: None,
+            }],
+        };
+
+        let config = RestCodegenConfig::new().package("test.v1", "test");
+        let code = generate(&encode_fdset(&fdset), &config).unwrap();
+
+        assert!(code.contains("    #[serde(alias = \"ID\")]\n    id: Option<String>,"));
+        assert!(code.contains("#[serde(alias = \"pageToken\")]"));
+        syn::parse_file(&code).expect("generated code should be valid Rust syntax");
+    }
+
+    /// `header_fields` are read from request headers on both body and
+    /// query-string routes, and drop out of the query struct.
+    #[test]
+    fn snapshot_header_fields() {
+        let mut list_request = make_message(
+            "ListItemsRequest",
+            &[
+                ("page_size", field_type::INT32, None),
+                ("client_version", field_type::STRING, None),
+            ],
+        );
+        list_request.field[1].proto3_optional = Some(true);
+
+        let fdset = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("items.proto".to_string()),
+                package: Some("test.v1".to_string()),
+                message_type: vec![
+                    make_message(
+                        "CreateItemRequest",
+                        &[
+                            ("name", field_type::STRING, None),
+                            ("client_version", field_type::STRING, None),
+                        ],
+                    ),
+                    list_request,
+                    make_message("Item", &[("name", field_type::STRING, None)]),
+                    make_message("ListItemsResponse", &[]),
+                ],
+                enum_type: vec![],
+                service: vec![ServiceDescriptorProto {
+                    name: Some("ItemService".to_string()),
+                    method: vec![
+                        make_method(
+                            "CreateItem",
+                            ".test.v1.CreateItemRequest",
+                            ".test.v1.Item",
+                            HttpPattern::Post("/v1/items".to_string()),
+                            "*",
+                            false,
+                        ),
+                        make_method(
+                            "ListItems",
+                            ".test.v1.ListItemsRequest",
+                            ".test.v1.ListItemsResponse",
+                            HttpPattern::Get("/v1/items".to_string()),
+                            "",
+                            false,
+                        ),
+                    ],
+                    options: None,
+                }],
+                source_code_info: None,
+            }],
+        };
+
+        let config = RestCodegenConfig::new()
+            .package("test.v1", "test")
+            .header_fields(&["client_version"]);
+        let code = generate(&encode_fdset(&fdset), &config).unwrap();
+
+        assert!(code.contains("Json(mut body): Json<crate::test::CreateItemRequest>"));
+        assert!(code.contains(
+            "if let Some(value) = headers.get(\"client-version\").and_then(|v| v.to_str().ok()) {"
+        ));
+        assert!(code.contains("body.client_version = value.to_string();"));
+        assert!(code.contains("body.client_version = Some(value.to_string());"));
+        assert!(code.contains("    page_size: Option<i32>,"));
+        assert!(
+            !code.contains("client_version: Option"),
+            "header fields are not query params"
+        );
+
+        assert_golden("header_fields.rs", &code);
+        syn::parse_file(&code).expect("generated code should be valid Rust syntax");
+    }
+
+    /// `form_urlencoded_methods` bind the body with `Form<T>`; other body
+    /// methods keep `Json<T>`.
+    #[test]
+    fn snapshot_form_urlencoded() {
+        let fdset = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("auth.proto".to_string()),
+                package: Some("test.v1".to_string()),
+                message_type: vec![
+                    make_message(
+                        "IssueTokenRequest",
+                        &[
+                            ("grant_type", field_type::STRING, None),
+                            ("client_id", field_type::STRING, None),
+                        ],
+                    ),
+                    make_message("Token", &[("access_token", field_type::STRING, None)]),
+                ],
+                enum_type: vec![],
+                service: vec![ServiceDescriptorProto {
+                    name: Some("AuthService".to_string()),
+                    method: vec![
+                        make_method(
+                            "IssueToken",
+                            ".test.v1.IssueTokenRequest",
+                            ".test.v1.Token",
+                            HttpPattern::Post("/oauth/token".to_string()),
+                            "*",
+                            false,
+                        ),
+                        make_method(
+                            "RefreshToken",
+                            ".test.v1.IssueTokenRequest",
+                            ".test.v1.Token",
+                            HttpPattern::Post("/v1/token/refresh".to_string()),
+                            "*",
+                            false,
+                        ),
+                    ],
+                    options: None,
+                }],
+                source_code_info: None,
+            }],
+        };
+
+        let config = RestCodegenConfig::new()
+            .package("test.v1", "test")
+            .form_urlencoded_methods(&["IssueToken"]);
+        let code = generate(&encode_fdset(&fdset), &config).unwrap();
+
+        assert!(code.contains("use axum::extract::{Form, Json, State};"));
+        assert!(code.contains("Form(body): Form<crate::test::IssueTokenRequest>"));
+        assert!(code.contains("Json(body): Json<crate::test::IssueTokenRequest>"));
+
+        assert_golden("form_urlencoded.rs", &code);
+        syn::parse_file(&code).expect("generated code should be valid Rust syntax");
+    }
+
+    /// Client-streaming RPCs take an NDJSON body and build a streaming request.
+    #[test]
+    fn snapshot_client_streaming() {
+        let mut upload = make_method(
+            "UploadChunks",
+            ".test.v1.Chunk",
+            ".test.v1.UploadSummary",
+            HttpPattern::Post("/v1/uploads".to_string()),
+            "*",
+            false,
+        );
+        upload.client_streaming = Some(true);
+
+        let fdset = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("uploads.proto".to_string()),
+                package: Some("test.v1".to_string()),
+                message_type: vec![
+                    make_message("Chunk", &[("data", field_type::STRING, None)]),
+                    make_message("UploadSummary", &[("count", field_type::INT32, None)]),
+                ],
+                enum_type: vec![],
+                service: vec![ServiceDescriptorProto {
+                    name: Some("UploadService".to_string()),
+                    method: vec![upload],
+                    options: None,
+                }],
+                source_code_info: None,
+            }],
+        };
+
+        let config = RestCodegenConfig::new().package("test.v1", "test");
+        let code = generate(&encode_fdset(&fdset), &config).unwrap();
+
+        assert!(code.contains("body: axum::body::Body,"));
+        assert!(
+            code.contains("tonic_rest::build_tonic_request_stream::<_, ()>(body, &headers, None)")
+        );
+        assert!(code.contains("Result<Json<crate::test::UploadSummary>, tonic_rest::RestError>"));
+        assert!(!code.contains("Query"));
+
+        assert_golden("client_streaming.rs", &code);
+        syn::parse_file(&code).expect("generated code should be valid Rust syntax");
+    }
+
+    /// Bidirectional streaming has no REST mapping.
+    #[test]
+    fn bidi_streaming_rejected() {
+        let mut chat = make_method(
+            "Chat",
+            ".test.v1.Msg",
+            ".test.v1.Msg",
+            HttpPattern::Post("/v1/chat".to_string()),
+            "*",
+            true,
+        );
+        chat.client_streaming = Some(true);
+
+        let fdset = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("chat.proto".to_string()),
+                package: Some("test.v1".to_string()),
+                message_type: vec![make_message("Msg", &[])],
+                enum_type: vec![],
+                service: vec![ServiceDescriptorProto {
+                    name: Some("ChatService".to_string()),
+                    method: vec![chat],
+                    options: None,
+                }],
+                source_code_info: None,
+            }],
+        };
+
+        let config = RestCodegenConfig::new().package("test.v1", "test");
+        let err = generate(&encode_fdset(&fdset), &config).unwrap_err();
+        assert!(
+            matches!(err, GenerateError::UnsupportedStreaming { ref method, .. } if method == "Chat"),
+            "unexpected error: {err}"
+        );
+    }
+
+    /// `additional_bindings` each get their own handler and route, with path
+    /// params extracted independently per binding.
+    #[test]
+    fn snapshot_additional_bindings() {
+        let mut get_user = make_method(
+            "GetUser",
+            ".test.v1.GetUserRequest",
+            ".test.v1.User",
+            HttpPattern::Get("/v1/users/{user_id}".to_string()),
+            "",
+            false,
+        );
+        if let Some(rule) = get_user.options.as_mut().and_then(|o| o.http.as_mut()) {
+            rule.additional_bindings = vec![
+                HttpRule {
+                    pattern: Some(HttpPattern::Get("/v1/users:byEmail".to_string())),
+                    body: String::new(),
+                    response_body: String::new(),
+                    additional_bindings: vec![],
+                },
+                HttpRule {
+                    pattern: Some(HttpPattern::Post(
+                        "/v1/orgs/{org_id}/users:lookup".to_string(),
+                    )),
+                    body: "*".to_string(),
+                    response_body: String::new(),
+                    additional_bindings: vec![],
+                },
+            ];
+        }
+
+        let fdset = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("users.proto".to_string()),
+                package: Some("test.v1".to_string()),
+                message_type: vec![
+                    make_message(
+                        "GetUserRequest",
+                        &[
+                            ("user_id", field_type::STRING, None),
+                            ("email", field_type::STRING, None),
+                            ("org_id", field_type::INT64, None),
+                        ],
+                    ),
+                    make_message("User", &[("name", field_type::STRING, None)]),
+                ],
+                enum_type: vec![],
+                service: vec![ServiceDescriptorProto {
+                    name: Some("UserService".to_string()),
+                    method: vec![get_user],
+                    options: None,
+                }],
+                source_code_info: None,
+            }],
+        };
+
+        let config = RestCodegenConfig::new()
+            .package("test.v1", "test")
+            .public_methods(&["GetUser"])
+            .emit_route_manifest(true);
+        let code = generate(&encode_fdset(&fdset), &config).unwrap();
+
+        assert!(code.contains(
+            ".route(\"/v1/users/{user_id}\", axum::routing::get(rest_user_service_get_user::<S>))"
+        ));
+        assert!(code.contains(
+            ".route(\"/v1/users:byEmail\", axum::routing::get(rest_user_service_get_user_binding1::<S>))"
+        ));
+        assert!(code.contains("axum::routing::post(rest_user_service_get_user_binding2::<S>)"));
+        assert!(code.contains("Path(user_id): Path<String>"));
+        assert!(code.contains("Path(org_id): Path<String>"));
+        assert!(code.contains("let org_id = org_id.parse::<i64>().map_err(|_| {"));
+        assert!(code.contains("body.org_id = org_id;"));
+        assert_eq!(code.matches("service.get_user(req)").count(), 3);
+        assert!(code.contains("    \"/v1/users:byEmail\",\n"));
+
+        assert!(code.contains("pub const REST_ROUTE_COUNT: usize = 3;"));
+        assert!(code.contains("(\"GET\", \"/v1/users:byEmail\"),"));
+
+        assert_golden("multi_binding.rs", &code);
+        syn::parse_file(&code).expect("generated code should be valid Rust syntax");
+    }
+
+    /// `custom` bindings route HEAD/OPTIONS through Axum; other verbs are
+    /// left out of the router with a warning comment instead of an error.
+    #[test]
+    fn custom_http_verbs() {
+        let mut get_item = make_method(
+            "GetItem",
+            ".test.v1.GetItemRequest",
+            ".test.v1.Item",
+            HttpPattern::Custom(CustomHttpPattern {
+                kind: "HEAD".to_string(),
+                path: "/v1/items/{item_id}".to_string(),
+            }),
+            "",
+            false,
+        );
+        if let Some(rule) = get_item.options.as_mut().and_then(|o| o.http.as_mut()) {
+            rule.additional_bindings = vec![HttpRule {
+                pattern: Some(HttpPattern::Custom(CustomHttpPattern {
+                    kind: "PURGE".to_string(),
+                    path: "/v1/items/{item_id}".to_string(),
+                })),
+                body: String::new(),
+                response_body: String::new(),
+                additional_bindings: vec![],
+            }];
+        }
+
+        let fdset = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("items.proto".to_string()),
+                package: Some("test.v1".to_string()),
+                message_type: vec![
+                    make_message("GetItemRequest", &[("item_id", field_type::STRING, None)]),
+                    make_message("Item", &[("name", field_type::STRING, None)]),
+                ],
+                enum_type: vec![],
+                service: vec![ServiceDescriptorProto {
+                    name: Some("ItemService".to_string()),
+                    method: vec![get_item],
+                    options: None,
+                }],
+                source_code_info: None,
+            }],
+        };
+
+        let config = RestCodegenConfig::new()
+            .package("test.v1", "test")
+            .emit_route_manifest(true);
+        let code = generate(&encode_fdset(&fdset), &config).unwrap();
+
+        assert!(code.contains(
+            ".route(\"/v1/items/{item_id}\", axum::routing::head(rest_item_service_get_item::<S>))"
+        ));
+        assert!(code.contains("// WARNING: `GetItem` binding `PURGE /v1/items/{item_id}` skipped"));
+        assert!(!code.contains("binding1"));
+        assert!(code.contains("pub const REST_ROUTE_COUNT: usize = 1;"));
+        assert!(code.contains("(\"HEAD\", \"/v1/items/{item_id}\"),"));
+        syn::parse_file(&code).expect("generated code should be valid Rust syntax");
+    }
+
+    /// Nested message types are included in field type resolution.
+    ///
+    /// Before the `collect_message_fields` recursion fix, nested messages
+    /// were not registered in the field-type map, causing path parameter
+    /// type resolution to fall back to `String` instead of the real type.
+    #[test]
+    fn collect_field_types_includes_nested_messages() {
+        let fdset = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
                 name: Some("nested.proto".to_string()),
                 package: Some("test.v1".to_string()),
                 message_type: vec![DescriptorProto {
                     name: Some("Outer".to_string()),
                     field: vec![FieldDescriptorProto {
                         name: Some("name".to_string()),
+                        label: None,
+                        proto3_optional: None,
+                        default_value: None,
+                        json_name: None,
+                        oneof_index: None,
                         r#type: Some(field_type::STRING),
                         type_name: None,
                         options: None,
                     }],
+                    oneof_decl: vec![],
+                    options: None,
                     nested_type: vec![DescriptorProto {
                         name: Some("Inner".to_string()),
                         field: vec![FieldDescriptorProto {
                             name: Some("item_id".to_string()),
+                            label: None,
+                            proto3_optional: None,
+                            default_value: None,
+                            json_name: None,
+                            oneof_index: None,
                             r#type: Some(field_type::INT32),
                             type_name: None,
                             options: None,
                         }],
+                        oneof_decl: vec![],
+                        options: None,
                         nested_type: vec![
                             // Doubly-nested
                             DescriptorProto {
                                 name: Some("Deep".to_string()),
                                 field: vec![FieldDescriptorProto {
                                     name: Some("x".to_string()),
+                                    label: None,
+                                    proto3_optional: None,
+                                    default_value: None,
+                                    json_name: None,
+                                    oneof_index: None,
                                     r#type: Some(field_type::STRING),
                                     type_name: None,
                                     options: None,
                                 }],
+                                oneof_decl: vec![],
+                                options: None,
                                 nested_type: vec![],
                             },
                         ],
@@ -1037,6 +2339,7 @@ mod tests {
                 }],
                 enum_type: vec![],
                 service: vec![],
+                source_code_info: None,
             }],
         };
 
@@ -1080,7 +2383,9 @@ mod tests {
                         client_streaming: None,
                         server_streaming: None,
                     }],
+                    options: None,
                 }],
+                source_code_info: None,
             }],
         };
 
@@ -1114,7 +2419,9 @@ mod tests {
                         "*",
                         false,
                     )],
+                    options: None,
                 }],
+                source_code_info: None,
             }],
         };
 
@@ -1130,7 +2437,151 @@ mod tests {
         assert!(err.to_string().contains("user_id.value"));
     }
 
-    /// Partial body selector (e.g., `body: "user"`) should produce `UnsupportedBodySelector`.
+    /// `body: "user"` binds the JSON body to the `user` sub-message; remaining
+    /// fields come from path and query params.
+    #[test]
+    fn snapshot_partial_body_selector() {
+        let fdset = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("users.proto".to_string()),
+                package: Some("test.v1".to_string()),
+                message_type: vec![
+                    make_message(
+                        "UpdateUserRequest",
+                        &[
+                            ("user_id", field_type::STRING, None),
+                            ("user", field_type::MESSAGE, Some(".test.v1.User")),
+                            ("validate_only", field_type::BOOL, None),
+                        ],
+                    ),
+                    make_message("User", &[("name", field_type::STRING, None)]),
+                ],
+                enum_type: vec![],
+                service: vec![ServiceDescriptorProto {
+                    name: Some("UserService".to_string()),
+                    method: vec![make_method(
+                        "UpdateUser",
+                        ".test.v1.UpdateUserRequest",
+                        ".test.v1.User",
+                        HttpPattern::Patch("/v1/users/{user_id}".to_string()),
+                        "user",
+                        false,
+                    )],
+                    options: None,
+                }],
+                source_code_info: None,
+            }],
+        };
+
+        let config = RestCodegenConfig::new().package("test.v1", "test");
+        let code = generate(&encode_fdset(&fdset), &config).unwrap();
+
+        assert!(code.contains("Path(user_id): Path<String>"));
+        assert!(code.contains("Query(mut body): Query<crate::test::UpdateUserRequest>"));
+        assert!(code.contains("Json(payload): Json<crate::test::User>"));
+        assert!(code.contains("body.user = Some(payload);"));
+        assert!(code.contains("body.user_id = user_id;"));
+
+        assert_golden("partial_body.rs", &code);
+        syn::parse_file(&code).expect("generated code should be valid Rust syntax");
+    }
+
+    /// `response_body: "user"` returns just the `user` sub-message; an unset
+    /// field is a 500.
+    #[test]
+    fn snapshot_response_body_selector() {
+        let mut method = make_method(
+            "CreateUser",
+            ".test.v1.CreateUserRequest",
+            ".test.v1.CreateUserResponse",
+            HttpPattern::Post("/v1/users".to_string()),
+            "*",
+            false,
+        );
+        if let Some(rule) = method.options.as_mut().and_then(|o| o.http.as_mut()) {
+            rule.response_body = "user".to_string();
+        }
+        let fdset = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("users.proto".to_string()),
+                package: Some("test.v1".to_string()),
+                message_type: vec![
+                    make_message("CreateUserRequest", &[("name", field_type::STRING, None)]),
+                    make_message(
+                        "CreateUserResponse",
+                        &[
+                            ("user", field_type::MESSAGE, Some(".test.v1.User")),
+                            ("request_id", field_type::STRING, None),
+                        ],
+                    ),
+                    make_message("User", &[("name", field_type::STRING, None)]),
+                ],
+                enum_type: vec![],
+                service: vec![ServiceDescriptorProto {
+                    name: Some("UserService".to_string()),
+                    method: vec![method],
+                    options: None,
+                }],
+                source_code_info: None,
+            }],
+        };
+
+        let config = RestCodegenConfig::new().package("test.v1", "test");
+        let code = generate(&encode_fdset(&fdset), &config).unwrap();
+
+        assert!(code.contains("-> Result<Json<crate::test::User>, tonic_rest::RestError>"));
+        assert!(code.contains("response.into_inner().user.ok_or_else("));
+        assert!(code.contains("tonic::Status::internal("));
+        assert!(
+            code.contains("is not set\"))\n            .with_context(\"POST\", \"/v1/users\")")
+        );
+
+        assert_golden("response_body.rs", &code);
+        syn::parse_file(&code).expect("generated code should be valid Rust syntax");
+    }
+
+    /// A `response_body` naming an unknown response field is rejected.
+    #[test]
+    fn response_body_selector_rejected() {
+        let mut method = make_method(
+            "GetToken",
+            ".test.v1.GetTokenRequest",
+            ".test.v1.GetTokenResponse",
+            HttpPattern::Get("/v1/token".to_string()),
+            "",
+            false,
+        );
+        if let Some(rule) = method.options.as_mut().and_then(|o| o.http.as_mut()) {
+            rule.response_body = "missing".to_string();
+        }
+        let fdset = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("token.proto".to_string()),
+                package: Some("test.v1".to_string()),
+                message_type: vec![
+                    make_message("GetTokenRequest", &[]),
+                    make_message("GetTokenResponse", &[("token", field_type::STRING, None)]),
+                ],
+                enum_type: vec![],
+                service: vec![ServiceDescriptorProto {
+                    name: Some("TokenService".to_string()),
+                    method: vec![method],
+                    options: None,
+                }],
+                source_code_info: None,
+            }],
+        };
+
+        let config = RestCodegenConfig::new().package("test.v1", "test");
+        let err = generate(&encode_fdset(&fdset), &config).unwrap_err();
+        assert!(
+            matches!(err, GenerateError::UnsupportedResponseBody { .. }),
+            "expected UnsupportedResponseBody, got: {err}",
+        );
+        assert!(err.to_string().contains("missing"));
+    }
+
+    /// Partial body selector naming an unknown field should produce `UnsupportedBodySelector`.
     #[test]
     fn partial_body_selector_rejected() {
         let fdset = FileDescriptorSet {
@@ -1149,10 +2600,12 @@ mod tests {
                         ".test.v1.CreateReq",
                         ".test.v1.Resp",
                         HttpPattern::Post("/v1/items".to_string()),
-                        "name", // partial body selector — not "*"
+                        "missing", // no such field on CreateReq
                         false,
                     )],
+                    options: None,
                 }],
+                source_code_info: None,
             }],
         };
 
@@ -1165,7 +2618,10 @@ mod tests {
             "expected UnsupportedBodySelector, got: {err}",
         );
         let msg = err.to_string();
-        assert!(msg.contains("name"), "should mention body selector: {msg}");
+        assert!(
+            msg.contains("missing"),
+            "should mention body selector: {msg}"
+        );
         assert!(msg.contains("Create"), "should mention method name: {msg}");
     }
 
@@ -1191,7 +2647,9 @@ mod tests {
                         "",
                         false,
                     )],
+                    options: None,
                 }],
+                source_code_info: None,
             }],
         };
 
@@ -1204,4 +2662,72 @@ mod tests {
         );
         syn::parse_file(&code).expect("auto-discovered code should be valid Rust");
     }
+
+    /// `module_override` replaces the inferred module for one package while
+    /// the others stay auto-discovered.
+    #[test]
+    fn module_override_applies_during_auto_discovery() {
+        let fdset = FileDescriptorSet {
+            file: vec![
+                FileDescriptorProto {
+                    name: Some("store.proto".to_string()),
+                    package: Some("store.v1".to_string()),
+                    message_type: vec![
+                        make_message("GetItemRequest", &[("item_id", field_type::STRING, None)]),
+                        make_message("Item", &[("id", field_type::STRING, None)]),
+                    ],
+                    enum_type: vec![],
+                    service: vec![ServiceDescriptorProto {
+                        name: Some("StoreService".to_string()),
+                        method: vec![make_method(
+                            "GetItem",
+                            ".store.v1.GetItemRequest",
+                            ".store.v1.Item",
+                            HttpPattern::Get("/v1/items/{item_id}".to_string()),
+                            "",
+                            false,
+                        )],
+                        options: None,
+                    }],
+                    source_code_info: None,
+                },
+                FileDescriptorProto {
+                    name: Some("auto.proto".to_string()),
+                    package: Some("auto.v1".to_string()),
+                    message_type: vec![
+                        make_message("PingRequest", &[]),
+                        make_message("PingResponse", &[("ok", field_type::BOOL, None)]),
+                    ],
+                    enum_type: vec![],
+                    service: vec![ServiceDescriptorProto {
+                        name: Some("HealthService".to_string()),
+                        method: vec![make_method(
+                            "Ping",
+                            ".auto.v1.PingRequest",
+                            ".auto.v1.PingResponse",
+                            HttpPattern::Get("/v1/health/ping".to_string()),
+                            "",
+                            false,
+                        )],
+                        options: None,
+                    }],
+                    source_code_info: None,
+                },
+            ],
+        };
+
+        let config = RestCodegenConfig::new().module_override("store.v1", "store_api");
+        let code = generate(&encode_fdset(&fdset), &config).unwrap();
+
+        assert!(code.contains("crate::store_api::store_service_server::StoreService"));
+        assert!(code.contains("crate::store_api::GetItemRequest::default()"));
+        assert!(code.contains("Json<crate::store_api::Item>"));
+        assert!(
+            !code.contains("crate::store::v1::"),
+            "override ignored:\n{code}"
+        );
+        // Non-overridden packages keep the inferred module
+        assert!(code.contains("crate::auto::v1::PingResponse"));
+        syn::parse_file(&code).expect("generated code should be valid Rust");
+    }
 }
@@ -11,24 +11,49 @@ pub struct ServiceRoute {
     pub service_name: String,
     /// Individual method routes
     pub methods: Vec<MethodRoute>,
+    /// `custom` bindings whose HTTP verb has no `axum::routing` function
+    pub skipped_bindings: Vec<SkippedBinding>,
 }
 
+/// A `custom: { kind, path }` binding that was not turned into a route.
 #[derive(Debug)]
+pub struct SkippedBinding {
+    /// Proto method name (e.g., `PurgeCache`)
+    pub proto_name: String,
+    /// Custom HTTP verb as written in the proto (e.g., `PURGE`)
+    pub kind: String,
+    /// URL path from proto
+    pub path: String,
+}
+
+#[derive(Debug)]
+#[expect(clippy::struct_excessive_bools)]
 pub struct MethodRoute {
     /// Proto method name (e.g., `ListUsers`)
     pub proto_name: String,
     /// Method name in `snake_case` (e.g., `list_users`)
     pub rust_name: String,
-    /// HTTP method (get, post, put, patch, delete)
+    /// Position in the method's `google.api.http` bindings (0 = primary,
+    /// 1.. = `additional_bindings`)
+    pub binding_index: usize,
+    /// HTTP method (get, post, put, patch, delete, or custom head/options)
     pub http_method: String,
     /// URL path from proto (e.g., `/v1/users/{user_id.value}`)
     pub path: String,
-    /// Axum-compatible path (e.g., `/v1/users/{user_id_value}`)
+    /// Axum-compatible path, including any route prefix (e.g., `/v1/users/{user_id_value}`)
     pub axum_path: String,
-    /// Whether request body is used ("*" = full body)
+    /// Whether request body is used ("*" = full body, or a field selector)
     pub has_body: bool,
+    /// Target of a partial body selector (e.g., `body: "user"`); `None` for
+    /// `body: "*"` or no body
+    pub body_field: Option<BodyField>,
+    /// Response field returned as the HTTP body by a `response_body`
+    /// selector; `None` returns the whole message
+    pub response_field: Option<ResponseField>,
     /// Whether the method returns a stream
     pub server_streaming: bool,
+    /// Whether the method takes a stream (NDJSON request body)
+    pub client_streaming: bool,
     /// Rust input type path
     pub input_type: String,
     /// Rust output type path
@@ -37,6 +62,59 @@ pub struct MethodRoute {
     pub returns_empty: bool,
     /// Path parameters extracted from URL pattern
     pub path_params: Vec<PathParam>,
+    /// Scalar request fields bound from the query string (no-body routes only)
+    pub query_params: Vec<QueryParam>,
+    /// No-body route whose query string binds the whole request (`Query<T>`),
+    /// because some field has no `query_params` mapping (message fields,
+    /// repeated or `optional` enums, floats, bytes)
+    pub query_binds_request: bool,
+    /// String request fields read from HTTP request headers (unary only)
+    pub header_params: Vec<HeaderParam>,
+    /// Whether the request body is bound with `Form<T>` instead of `Json<T>`
+    pub form_body: bool,
+}
+
+impl MethodRoute {
+    /// Generated handler function name, e.g. `rest_user_service_get_user`.
+    ///
+    /// Additional bindings get a stable `_binding{N}` suffix so every route
+    /// has its own handler while calling the same service-trait method.
+    pub fn handler_name(&self, svc_snake: &str) -> String {
+        if self.binding_index == 0 {
+            format!("rest_{svc_snake}_{}", self.rust_name)
+        } else {
+            format!(
+                "rest_{svc_snake}_{}_binding{}",
+                self.rust_name, self.binding_index
+            )
+        }
+    }
+}
+
+/// Request field bound to the HTTP body by a `body: "<field>"` selector.
+///
+/// The JSON body deserializes into the field's type; the remaining request
+/// fields come from path and query parameters.
+#[derive(Debug)]
+pub struct BodyField {
+    /// Proto field name (e.g., `user`)
+    pub field_name: String,
+    /// Rust type the body deserializes into (e.g., `crate::users::User`)
+    pub rust_type: String,
+    /// Singular message fields are `Option<T>` in prost and need `Some(..)`
+    pub is_message: bool,
+}
+
+/// Response field returned as the HTTP body by a `response_body: "<field>"`
+/// selector.
+#[derive(Debug)]
+pub struct ResponseField {
+    /// Proto field name (e.g., `user`)
+    pub field_name: String,
+    /// Rust type serialized as the body (e.g., `crate::users::User`, `Vec<String>`)
+    pub rust_type: String,
+    /// Singular message fields are `Option<T>` in prost; `None` is a 500
+    pub is_message: bool,
 }
 
 /// A path parameter extracted from the URL pattern.
@@ -46,6 +124,48 @@ pub struct PathParam {
     pub axum_name: String,
     /// How to assign this param to the request body
     pub assignment: ParamAssignment,
+    /// proto3 `optional` field (`Option<T>` in prost): assigned as `Some(value)`
+    pub optional: bool,
+}
+
+/// A scalar request field bound from the query string.
+#[derive(Debug)]
+pub struct QueryParam {
+    /// Proto field name (e.g., `page_size`)
+    pub field_name: String,
+    /// JSON name from the descriptor, accepted as a serde alias (e.g., `pageSize`)
+    pub json_name: Option<String>,
+    /// Rust element type (e.g., `i32`, `String`); `String` for enums
+    pub rust_type: String,
+    /// How the parsed value is written to the request
+    pub kind: QueryParamKind,
+}
+
+/// A string request field read from an HTTP request header.
+#[derive(Debug)]
+pub struct HeaderParam {
+    /// Proto field name (e.g., `client_version`)
+    pub field_name: String,
+    /// Lowercase HTTP header name (e.g., `client-version`)
+    pub header_name: String,
+    /// proto3 `optional` field (`Option<String>` in prost)
+    pub optional: bool,
+}
+
+/// How a query parameter maps to a proto request field.
+#[derive(Debug)]
+pub enum QueryParamKind {
+    /// Implicit-presence scalar: `body.page_size = v` only when present
+    Singular,
+    /// proto3 `optional` scalar (`Option<T>` in prost): `body.filter = query.filter`
+    Optional,
+    /// Repeated scalar (`Vec<T>` in prost): comma-separated list
+    Repeated,
+    /// Enum field (i32 in prost): parsed via `EnumType::from_str_name()`, 400 on invalid
+    Enum {
+        /// Rust type path for the enum (e.g., `crate::auth::OAuthProvider`)
+        enum_rust_type: String,
+    },
 }
 
 /// How a path parameter maps to a proto request field.
@@ -58,7 +178,14 @@ pub enum ParamAssignment {
     /// Typed numeric/bool field: `{page}` → parsed by Axum's `Path<i32>` extractor
     TypedField {
         field_name: String,
-        /// Rust type for the path extractor (e.g., `i32`, `u32`, `i64`, `u64`, `bool`)
+        /// Rust type for the path extractor (`i32`, `u32`, or `bool`)
+        rust_type: &'static str,
+    },
+    /// 64-bit integer field: `{order_id}` → extracted as `String`, parsed via
+    /// `str::parse`, 400 on invalid (proto JSON carries 64-bit ints as strings)
+    Typed64 {
+        field_name: String,
+        /// `i64` (`int64`, `sint64`, `sfixed64`) or `u64` (`uint64`, `fixed64`)
         rust_type: &'static str,
     },
     /// Enum field (i32 in prost): `{provider}` → parse via `EnumType::from_str_name()`, 400 on invalid
@@ -69,12 +196,33 @@ pub enum ParamAssignment {
     },
 }
 
-/// Per-field type info: proto type id + optional fully-qualified enum type name.
+impl ParamAssignment {
+    /// Top-level request field this path parameter binds.
+    pub fn field_name(&self) -> &str {
+        match self {
+            Self::UuidWrapper { parent_field } => parent_field,
+            Self::StringField { field_name }
+            | Self::TypedField { field_name, .. }
+            | Self::Typed64 { field_name, .. }
+            | Self::EnumField { field_name, .. } => field_name,
+        }
+    }
+}
+
+/// Per-field type info: proto type id, label, and optional fully-qualified type name.
 #[derive(Debug, Clone)]
 pub struct FieldTypeInfo {
+    /// Declaration order within the message
+    pub position: usize,
     pub type_id: i32,
-    /// For enum fields: the FQN (e.g., `.auth.v1.OAuthProvider`)
-    pub enum_type_name: Option<String>,
+    /// For enum and message fields: the FQN (e.g., `.auth.v1.OAuthProvider`)
+    pub type_name: Option<String>,
+    /// Whether the field is `repeated` (including map fields)
+    pub repeated: bool,
+    /// Whether the field is a proto3 `optional` (explicit presence)
+    pub optional: bool,
+    /// Explicit or protoc-filled JSON name (e.g., `pageToken`)
+    pub json_name: Option<String>,
 }
 
 /// Map of fully-qualified message name → field name → field type info.
@@ -4,6 +4,7 @@
 //! `tonic-rest` with prost-generated types:
 //!
 //! - [`dump_file_descriptor_set`] — invoke `protoc` to produce a binary descriptor set
+//! - [`dump_file_descriptor_set_with_comments`] — same, keeping `source_code_info` (proto comments)
 //! - [`configure_prost_serde`] — auto-discover fields and apply `#[serde(with)]` attributes
 //!
 //! # Typical `build.rs`
@@ -51,6 +52,12 @@ use prost::Message;
 use prost_types::FileDescriptorSet;
 use prost_types::field_descriptor_proto::{Label, Type};
 
+/// Default module path for WKT serde adapters.
+const DEFAULT_WKT_ROOT: &str = "tonic_rest::serde";
+
+/// `vec_` adapter modules shipped in [`DEFAULT_WKT_ROOT`].
+const DEFAULT_REPEATED_WKT_MODULES: &[&str] = &["vec_timestamp", "vec_duration", "vec_field_mask"];
+
 /// Builder for configuring prost serde attributes.
 ///
 /// Provides a cleaner alternative to the positional-parameter
@@ -74,6 +81,7 @@ pub struct ProstSerdeConfig<'a> {
     proto_files: Vec<String>,
     wkt_root: String,
     wkt_map: Vec<(String, String)>,
+    repeated_wkt_map: Vec<(String, String)>,
     enum_map: Vec<(String, String)>,
     rename_all: Option<String>,
 }
@@ -87,8 +95,9 @@ impl<'a> ProstSerdeConfig<'a> {
         Self {
             descriptor_bytes,
             proto_files: proto_files.iter().map(ToString::to_string).collect(),
-            wkt_root: "tonic_rest::serde".to_string(),
+            wkt_root: DEFAULT_WKT_ROOT.to_string(),
             wkt_map: Vec::new(),
+            repeated_wkt_map: Vec::new(),
             enum_map: Vec::new(),
             rename_all: Some("camelCase".to_string()),
         }
@@ -122,6 +131,25 @@ impl<'a> ProstSerdeConfig<'a> {
         self
     }
 
+    /// Register a well-known type → serde module mapping for `repeated` fields.
+    ///
+    /// The module must serialize a `Vec` of the type. With the default
+    /// `wkt_root`, repeated `Timestamp`/`Duration`/`FieldMask` fields whose
+    /// type is registered via [`wkt`](Self::wkt) are wired to
+    /// `tonic_rest::serde::vec_*` without this call; with a custom root,
+    /// repeated WKT fields get no adapter unless mapped here.
+    ///
+    /// # Example
+    /// ```ignore
+    /// .repeated_wkt(".google.protobuf.Timestamp", "vec_timestamp")
+    /// ```
+    #[must_use]
+    pub fn repeated_wkt(mut self, type_fqn: &str, serde_module: &str) -> Self {
+        self.repeated_wkt_map
+            .push((type_fqn.to_string(), serde_module.to_string()));
+        self
+    }
+
     /// Register an enum type → serde module mapping.
     ///
     /// # Example
@@ -174,19 +202,27 @@ impl<'a> ProstSerdeConfig<'a> {
             .iter()
             .map(|(k, v)| (k.as_str(), v.as_str()))
             .collect();
+        let repeated_wkt_refs: Vec<(&str, &str)> = self
+            .repeated_wkt_map
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
         let enum_refs: Vec<(&str, &str)> = self
             .enum_map
             .iter()
             .map(|(k, v)| (k.as_str(), v.as_str()))
             .collect();
 
-        try_configure_prost_serde_with_options(
+        configure_serde(
             config,
             self.descriptor_bytes,
             &proto_files_refs,
-            &self.wkt_root,
-            &wkt_refs,
-            &enum_refs,
+            &SerdeMaps {
+                wkt_root: &self.wkt_root,
+                wkt_map: &wkt_refs,
+                repeated_wkt_map: &repeated_wkt_refs,
+                enum_map: &enum_refs,
+            },
             self.rename_all.as_deref(),
         )
     }
@@ -234,18 +270,60 @@ pub fn try_dump_file_descriptor_set(
     proto_files: &[&str],
     includes: &[&str],
     out_path: &str,
+) -> std::io::Result<Vec<u8>> {
+    run_protoc(proto_files, includes, out_path, false)
+}
+
+/// Like [`dump_file_descriptor_set`], but retains `source_code_info`.
+///
+/// Passes `--include_source_info` to `protoc`, so every file in the set
+/// carries the locations and leading/trailing comments of its declarations.
+/// Use this when downstream tooling extracts descriptions from proto comments.
+///
+/// # Panics
+///
+/// Panics if `protoc` cannot be found or exits with a non-zero status.
+/// Use [`try_dump_file_descriptor_set_with_comments`] for a fallible alternative.
+#[must_use]
+pub fn dump_file_descriptor_set_with_comments(
+    proto_files: &[&str],
+    includes: &[&str],
+    out_path: &str,
+) -> Vec<u8> {
+    try_dump_file_descriptor_set_with_comments(proto_files, includes, out_path)
+        .expect("failed to run protoc and produce descriptor set")
+}
+
+/// Fallible version of [`dump_file_descriptor_set_with_comments`].
+///
+/// # Errors
+///
+/// Returns [`std::io::Error`] if protoc cannot be spawned, fails, or the
+/// descriptor file cannot be read.
+pub fn try_dump_file_descriptor_set_with_comments(
+    proto_files: &[&str],
+    includes: &[&str],
+    out_path: &str,
+) -> std::io::Result<Vec<u8>> {
+    run_protoc(proto_files, includes, out_path, true)
+}
+
+/// Run `protoc --descriptor_set_out` and read back the written set.
+fn run_protoc(
+    proto_files: &[&str],
+    includes: &[&str],
+    out_path: &str,
+    include_source_info: bool,
 ) -> std::io::Result<Vec<u8>> {
     let protoc = prost_build::protoc_from_env();
 
     let mut cmd = std::process::Command::new(&protoc);
-    cmd.arg("--descriptor_set_out").arg(out_path);
-    cmd.arg("--include_imports");
-    for inc in includes {
-        cmd.arg(format!("--proto_path={inc}"));
-    }
-    for file in proto_files {
-        cmd.arg(file);
-    }
+    cmd.args(protoc_args(
+        proto_files,
+        includes,
+        out_path,
+        include_source_info,
+    ));
 
     let output = cmd.output().map_err(|e| {
         std::io::Error::new(
@@ -265,10 +343,34 @@ pub fn try_dump_file_descriptor_set(
     std::fs::read(out_path)
 }
 
+/// Arguments for a `protoc --descriptor_set_out` run.
+fn protoc_args(
+    proto_files: &[&str],
+    includes: &[&str],
+    out_path: &str,
+    include_source_info: bool,
+) -> Vec<String> {
+    let mut args = vec![
+        "--descriptor_set_out".to_string(),
+        out_path.to_string(),
+        "--include_imports".to_string(),
+    ];
+    if include_source_info {
+        args.push("--include_source_info".to_string());
+    }
+    args.extend(includes.iter().map(|inc| format!("--proto_path={inc}")));
+    args.extend(proto_files.iter().map(ToString::to_string));
+    args
+}
+
 /// Configure prost serde attributes by scanning a `FileDescriptorSet`.
 ///
 /// Automatically wires `#[serde(with)]` adapters for:
-/// - **WKT fields**: matched via `wkt_map` (e.g., Timestamp → RFC 3339)
+/// - **WKT fields**: matched via `wkt_map` (e.g., Timestamp → RFC 3339).
+///   With the default `"tonic_rest::serde"` root, repeated WKT fields use the
+///   `vec_` counterpart of the mapped module (`opt_timestamp` → `vec_timestamp`);
+///   under a custom root they get no adapter unless mapped with
+///   [`ProstSerdeConfig::repeated_wkt`]
 /// - **Enum fields**: matched via `enum_map` (e.g., `UserRole` → string name)
 /// - **`skip_serializing_if`**: all proto3 explicit `optional` fields
 ///
@@ -399,6 +501,61 @@ pub fn try_configure_prost_serde_with_options(
     wkt_map: &[(&str, &str)],
     enum_map: &[(&str, &str)],
     rename_all: Option<&str>,
+) -> Result<(), prost::DecodeError> {
+    configure_serde(
+        config,
+        descriptor_bytes,
+        proto_files,
+        &SerdeMaps {
+            wkt_root,
+            wkt_map,
+            repeated_wkt_map: &[],
+            enum_map,
+        },
+        rename_all,
+    )
+}
+
+/// Type → serde module mappings shared by the field scan.
+struct SerdeMaps<'a> {
+    wkt_root: &'a str,
+    wkt_map: &'a [(&'a str, &'a str)],
+    repeated_wkt_map: &'a [(&'a str, &'a str)],
+    enum_map: &'a [(&'a str, &'a str)],
+}
+
+impl SerdeMaps<'_> {
+    /// Adapter module for a repeated well-known type field, if any.
+    ///
+    /// An explicit `repeated_wkt_map` entry wins. Otherwise, under the default
+    /// root, the `vec_` counterpart of the `wkt_map` module is used when
+    /// `tonic_rest::serde` ships it (`opt_timestamp` → `vec_timestamp`).
+    fn repeated_wkt_module(&self, type_name: &str) -> Option<String> {
+        if let Some((_, module)) = self
+            .repeated_wkt_map
+            .iter()
+            .find(|(fqn, _)| *fqn == type_name)
+        {
+            return Some((*module).to_string());
+        }
+        if self.wkt_root != DEFAULT_WKT_ROOT {
+            return None;
+        }
+        let (_, module) = self.wkt_map.iter().find(|(fqn, _)| *fqn == type_name)?;
+        let module = format!("vec_{}", module.strip_prefix("opt_").unwrap_or(module));
+        DEFAULT_REPEATED_WKT_MODULES
+            .contains(&module.as_str())
+            .then_some(module)
+    }
+}
+
+/// Shared implementation of the `configure_prost_serde` family.
+fn configure_serde(
+    config: &mut prost_build::Config,
+    descriptor_bytes: &[u8],
+    proto_files: &[&str],
+    maps: &SerdeMaps<'_>,
+    rename_all: Option<&str>,
 ) -> Result<(), prost::DecodeError> {
     let fds = FileDescriptorSet::decode(descriptor_bytes)?;
 
@@ -436,14 +593,7 @@ pub fn try_configure_prost_serde_with_options(
             continue;
         }
         for msg in &file.message_type {
-            apply_field_attributes(
-                config,
-                &format!(".{package}"),
-                msg,
-                wkt_root,
-                wkt_map,
-                enum_map,
-            );
+            apply_field_attributes(config, &format!(".{package}"), msg, maps);
         }
     }
 
@@ -455,10 +605,9 @@ fn apply_field_attributes(
     config: &mut prost_build::Config,
     parent_path: &str,
     msg: &prost_types::DescriptorProto,
-    wkt_root: &str,
-    wkt_map: &[(&str, &str)],
-    enum_map: &[(&str, &str)],
+    maps: &SerdeMaps<'_>,
 ) {
+    let wkt_root = maps.wkt_root;
     let msg_name = msg.name();
     let msg_path = format!("{parent_path}.{msg_name}");
 
@@ -500,8 +649,16 @@ fn apply_field_attributes(
 
         match field_type {
             // Well-known types: auto-apply serde adapters from wkt_map.
-            Type::Message if !is_repeated => {
-                if let Some((_, module)) = wkt_map.iter().find(|(fqn, _)| *fqn == type_name) {
+            Type::Message => {
+                let module = if is_repeated {
+                    maps.repeated_wkt_module(type_name)
+                } else {
+                    maps.wkt_map
+                        .iter()
+                        .find(|(fqn, _)| *fqn == type_name)
+                        .map(|(_, module)| (*module).to_string())
+                };
+                if let Some(module) = module {
                     config.field_attribute(
                         &field_path,
                         format!("#[serde(with = \"{wkt_root}::{module}\", default)]"),
@@ -510,7 +667,7 @@ fn apply_field_attributes(
             }
             // Enum fields: auto-wire serde module from enum_map.
             Type::Enum => {
-                if let Some((_, module)) = enum_map.iter().find(|(fqn, _)| *fqn == type_name) {
+                if let Some((_, module)) = maps.enum_map.iter().find(|(fqn, _)| *fqn == type_name) {
                     let attr = if is_repeated {
                         format!("#[serde(with = \"{wkt_root}::{module}::repeated\")]")
                     } else if is_optional {
@@ -534,7 +691,7 @@ fn apply_field_attributes(
         {
             continue;
         }
-        apply_field_attributes(config, &msg_path, nested, wkt_root, wkt_map, enum_map);
+        apply_field_attributes(config, &msg_path, nested, maps);
     }
 }
 
@@ -595,6 +752,67 @@ mod tests {
         }
     }
 
+    #[test]
+    fn protoc_args_include_source_info_only_when_requested() {
+        let args = protoc_args(&["a.proto"], &["proto"], "out.bin", true);
+        assert_eq!(
+            args,
+            [
+                "--descriptor_set_out",
+                "out.bin",
+                "--include_imports",
+                "--include_source_info",
+                "--proto_path=proto",
+                "a.proto",
+            ],
+        );
+
+        let args = protoc_args(&["a.proto"], &["proto"], "out.bin", false);
+        assert!(!args.iter().any(|a| a == "--include_source_info"));
+    }
+
+    #[test]
+    #[ignore = "requires protoc"]
+    fn dump_with_comments_keeps_source_code_info() {
+        let dir = std::env::temp_dir().join(format!(
+            "tonic_rest_build_dump_comments_{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("greeter.proto"),
+            "syntax = \"proto3\";\npackage greeter.v1;\n\n// A greeting.\nmessage Hello {\n  string name = 1;\n}\n",
+        )
+        .unwrap();
+        let include = dir.to_str().unwrap();
+        let out_path = dir.join("descriptor.bin");
+
+        let bytes = try_dump_file_descriptor_set_with_comments(
+            &["greeter.proto"],
+            &[include],
+            out_path.to_str().unwrap(),
+        )
+        .unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let fdset = FileDescriptorSet::decode(bytes.as_slice()).unwrap();
+        let file = fdset
+            .file
+            .iter()
+            .find(|f| f.name() == "greeter.proto")
+            .unwrap();
+        let info = file
+            .source_code_info
+            .as_ref()
+            .expect("source_code_info retained");
+        assert!(!info.location.is_empty());
+        assert!(
+            info.location
+                .iter()
+                .any(|l| l.leading_comments().contains("A greeting."))
+        );
+    }
+
     fn make_file(name: &str, package: &str, messages: Vec<DescriptorProto>) -> FileDescriptorProto {
         FileDescriptorProto {
             name: Some(name.to_string()),
@@ -780,6 +998,69 @@ mod tests {
         // No panic = WKT field was matched and attribute applied
     }
 
+    #[test]
+    fn repeated_wkt_field_uses_vec_module() {
+        let file = make_file(
+            "test.proto",
+            "test.v1",
+            vec![DescriptorProto {
+                name: Some("Audit".to_string()),
+                field: vec![make_repeated_field(
+                    "event_times",
+                    Type::Message,
+                    ".google.protobuf.Timestamp",
+                )],
+                ..Default::default()
+            }],
+        );
+        let bytes = encode_fdset(file);
+        let timestamp = &[(".google.protobuf.Timestamp", "opt_timestamp")];
+
+        // Default root: derived from the `wkt_map` module
+        let mut config = prost_build::Config::new();
+        try_configure_prost_serde(
+            &mut config,
+            &bytes,
+            &["test.proto"],
+            "tonic_rest::serde",
+            timestamp,
+            &[],
+        )
+        .unwrap();
+        let attrs = format!("{config:?}");
+        assert!(
+            attrs.contains(r#"serde(with = \"tonic_rest::serde::vec_timestamp\", default)"#),
+            "{attrs}"
+        );
+
+        // Custom root: no `vec_` module is assumed to exist
+        let mut config = prost_build::Config::new();
+        try_configure_prost_serde(
+            &mut config,
+            &bytes,
+            &["test.proto"],
+            "crate::serde_wkt",
+            timestamp,
+            &[],
+        )
+        .unwrap();
+        let attrs = format!("{config:?}");
+        assert!(!attrs.contains("event_times"), "{attrs}");
+
+        // Custom root with an explicit repeated mapping
+        let mut config = prost_build::Config::new();
+        ProstSerdeConfig::new(&bytes, &["test.proto"])
+            .wkt_root("crate::serde_wkt")
+            .wkt(".google.protobuf.Timestamp", "opt_timestamp")
+            .repeated_wkt(".google.protobuf.Timestamp", "timestamps")
+            .apply(&mut config);
+        let attrs = format!("{config:?}");
+        assert!(
+            attrs.contains(r#"serde(with = \"crate::serde_wkt::timestamps\", default)"#),
+            "{attrs}"
+        );
+    }
+
     #[test]
     fn enum_field_gets_serde_with_attribute() {
         let file = FileDescriptorProto {
@@ -20,10 +20,13 @@ pub use tonic_rest_core::descriptor;
 #[cfg(feature = "helpers")]
 mod helpers;
 
-pub use codegen::{GenerateError, RestCodegenConfig, generate};
+pub use codegen::{
+    DEFAULT_LINT_ALLOWS, GenerateError, RestCodegenConfig, StreamingFormat, generate,
+};
 #[cfg(feature = "helpers")]
 pub use helpers::{
     ProstSerdeConfig, configure_prost_serde, configure_prost_serde_with_options,
-    dump_file_descriptor_set, try_configure_prost_serde, try_configure_prost_serde_with_options,
-    try_dump_file_descriptor_set,
+    dump_file_descriptor_set, dump_file_descriptor_set_with_comments, try_configure_prost_serde,
+    try_configure_prost_serde_with_options, try_dump_file_descriptor_set,
+    try_dump_file_descriptor_set_with_comments,
 };
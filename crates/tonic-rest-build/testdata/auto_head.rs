@@ -0,0 +1,186 @@
+// Auto-generated REST routes from proto `google.api.http` annotations.
+//
+// **Do not edit** — regenerated by `build.rs` when proto files change.
+//
+// Each handler transcodes HTTP/JSON <-> proto and calls the Tonic service trait,
+// sharing auth, validation, and business logic with gRPC handlers.
+
+use std::sync::Arc;
+
+use axum::extract::{Json, Path, Query, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::Router;
+
+// =============================================================================
+// ItemService REST routes
+// =============================================================================
+
+/// Build Axum REST routes for `ItemService`.
+///
+/// Generated from `google.api.http` annotations in `test.proto`.
+pub fn item_service_rest_router<S>(service: Arc<S>) -> Router
+where
+    S: crate::test::item_service_server::ItemService + Send + Sync + 'static,
+{
+    Router::new()
+        .route("/v1/items/{item_id}", axum::routing::get(rest_item_service_get_item::<S>))
+        .route("/v1/items/{item_id}", axum::routing::head(rest_item_service_get_item::<S>))
+        .route("/v1/items", axum::routing::get(rest_item_service_list_items::<S>))
+        .route("/v1/items", axum::routing::head(rest_item_service_count_items::<S>))
+        .with_state(service)
+}
+
+#[expect(clippy::too_many_arguments, clippy::needless_pass_by_value)]
+/// `GetItem` — JSON endpoint.
+///
+/// `GET /v1/items/{item_id}`
+async fn rest_item_service_get_item<S>(
+    State(service): State<Arc<S>>,
+    headers: HeaderMap,
+    Path(item_id): Path<String>,
+) -> Result<Json<crate::test::Item>, tonic_rest::RestError>
+where
+    S: crate::test::item_service_server::ItemService + Send + Sync + 'static,
+{
+    let mut body = crate::test::GetItemRequest::default();
+    body.item_id = item_id;
+    let req = tonic_rest::build_tonic_request::<_, ()>(body, &headers, None);
+    let response = service.get_item(req).await.map_err(|status| {
+        tonic_rest::RestError::from(status).with_context("GET", "/v1/items/{item_id}")
+    })?;
+    Ok(Json(response.into_inner()))
+}
+
+/// Query parameters for `ListItems`.
+#[derive(serde::Deserialize)]
+struct RestItemServiceListItemsQuery {
+    #[serde(alias = "pageSize")]
+    page_size: Option<i32>,
+}
+
+#[expect(clippy::too_many_arguments, clippy::needless_pass_by_value)]
+/// `ListItems` — JSON endpoint.
+///
+/// `GET /v1/items`
+async fn rest_item_service_list_items<S>(
+    State(service): State<Arc<S>>,
+    headers: HeaderMap,
+    Query(query): Query<RestItemServiceListItemsQuery>,
+) -> Result<Json<crate::test::Item>, tonic_rest::RestError>
+where
+    S: crate::test::item_service_server::ItemService + Send + Sync + 'static,
+{
+    let mut body = crate::test::ListItemsRequest::default();
+    if let Some(value) = query.page_size {
+        body.page_size = value;
+    }
+    let req = tonic_rest::build_tonic_request::<_, ()>(body, &headers, None);
+    let response = service.list_items(req).await.map_err(|status| {
+        tonic_rest::RestError::from(status).with_context("GET", "/v1/items")
+    })?;
+    Ok(Json(response.into_inner()))
+}
+
+/// Query parameters for `CountItems`.
+#[derive(serde::Deserialize)]
+struct RestItemServiceCountItemsQuery {
+    #[serde(alias = "pageSize")]
+    page_size: Option<i32>,
+}
+
+#[expect(clippy::too_many_arguments, clippy::needless_pass_by_value)]
+/// `CountItems` — JSON endpoint.
+///
+/// `HEAD /v1/items`
+async fn rest_item_service_count_items<S>(
+    State(service): State<Arc<S>>,
+    headers: HeaderMap,
+    Query(query): Query<RestItemServiceCountItemsQuery>,
+) -> Result<StatusCode, tonic_rest::RestError>
+where
+    S: crate::test::item_service_server::ItemService + Send + Sync + 'static,
+{
+    let mut body = crate::test::ListItemsRequest::default();
+    if let Some(value) = query.page_size {
+        body.page_size = value;
+    }
+    let req = tonic_rest::build_tonic_request::<_, ()>(body, &headers, None);
+    service.count_items(req).await.map_err(|status| {
+        tonic_rest::RestError::from(status).with_context("HEAD", "/v1/items")
+    })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+
+// =============================================================================
+// Public REST paths (bypass auth middleware)
+// =============================================================================
+
+/// REST paths that are marked as public (no authentication required).
+///
+/// Auto-generated from `google.api.http` annotations on public RPC methods.
+/// Used by middleware to identify unauthenticated endpoints.
+pub const PUBLIC_REST_PATHS: &[&str] = &[
+];
+
+// =============================================================================
+// Route manifest
+// =============================================================================
+
+/// Number of REST routes registered by the generated routers.
+pub const REST_ROUTE_COUNT: usize = 4;
+
+/// All generated REST routes as `(HTTP method, Axum path)` pairs, in
+/// registration order.
+///
+/// Assert on this in tests to catch routes lost during proto refactors.
+#[must_use]
+pub const fn rest_routes() -> &'static [(&'static str, &'static str)] {
+    &[
+        ("GET", "/v1/items/{item_id}"),
+        ("HEAD", "/v1/items/{item_id}"),
+        ("GET", "/v1/items"),
+        ("HEAD", "/v1/items"),
+    ]
+}
+
+/// Proto operation ID (`Service_Method`) of a generated REST route.
+///
+/// `matched_path` is the registered route template, as returned by
+/// [`axum::extract::MatchedPath::as_str`]. Routers mounted with `Router::nest`
+/// report the nested path, so strip that prefix first. Lets middleware label
+/// spans and metrics by operation:
+///
+/// ```ignore
+/// let operation_id = request
+///     .extensions()
+///     .get::<axum::extract::MatchedPath>()
+///     .and_then(|path| operation_id_for(request.method(), path.as_str()));
+/// ```
+#[must_use]
+pub fn operation_id_for(method: &axum::http::Method, matched_path: &str) -> Option<&'static str> {
+    match (method.as_str(), matched_path) {
+        ("GET", "/v1/items/{item_id}") => Some("ItemService_GetItem"),
+        ("HEAD", "/v1/items/{item_id}") => Some("ItemService_GetItem"),
+        ("GET", "/v1/items") => Some("ItemService_ListItems"),
+        ("HEAD", "/v1/items") => Some("ItemService_CountItems"),
+        _ => None,
+    }
+}
+
+// =============================================================================
+// Combined REST router
+// =============================================================================
+
+/// Build a combined Axum router with REST routes for all proto services.
+///
+/// Each service is generic — pass your concrete implementations as `Arc<T>`.
+pub fn all_rest_routes<S0>(
+    item_service: Arc<S0>,
+) -> Router
+where
+    S0: crate::test::item_service_server::ItemService + Send + Sync + 'static,
+{
+    Router::new()
+        .merge(item_service_rest_router(item_service))
+}
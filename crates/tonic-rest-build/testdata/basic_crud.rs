@@ -7,7 +7,7 @@
 
 use std::sync::Arc;
 
-use axum::extract::{Json, Path, Query, State};
+use axum::extract::{Json, Path, State};
 use axum::http::{HeaderMap, StatusCode};
 use axum::Router;
 
@@ -42,7 +42,9 @@ where
     S: crate::test::item_service_server::ItemService + Send + Sync + 'static,
 {
     let req = tonic_rest::build_tonic_request::<_, ()>(body, &headers, None);
-    let response = service.create_item(req).await.map_err(tonic_rest::RestError::from)?;
+    let response = service.create_item(req).await.map_err(|status| {
+        tonic_rest::RestError::from(status).with_context("POST", "/v1/items")
+    })?;
     Ok(Json(response.into_inner()))
 }
 
@@ -54,14 +56,16 @@ async fn rest_item_service_get_item<S>(
     State(service): State<Arc<S>>,
     headers: HeaderMap,
     Path(item_id): Path<String>,
-    Query(mut body): Query<crate::test::GetItemRequest>,
 ) -> Result<Json<crate::test::Item>, tonic_rest::RestError>
 where
     S: crate::test::item_service_server::ItemService + Send + Sync + 'static,
 {
+    let mut body = crate::test::GetItemRequest::default();
     body.item_id = item_id;
     let req = tonic_rest::build_tonic_request::<_, ()>(body, &headers, None);
-    let response = service.get_item(req).await.map_err(tonic_rest::RestError::from)?;
+    let response = service.get_item(req).await.map_err(|status| {
+        tonic_rest::RestError::from(status).with_context("GET", "/v1/items/{item_id}")
+    })?;
     Ok(Json(response.into_inner()))
 }
 
@@ -80,7 +84,9 @@ where
     let mut body = crate::test::DeleteItemRequest::default();
     body.item_id = item_id;
     let req = tonic_rest::build_tonic_request::<_, ()>(body, &headers, None);
-    service.delete_item(req).await.map_err(tonic_rest::RestError::from)?;
+    service.delete_item(req).await.map_err(|status| {
+        tonic_rest::RestError::from(status).with_context("DELETE", "/v1/items/{item_id}")
+    })?;
     Ok(StatusCode::NO_CONTENT)
 }
 
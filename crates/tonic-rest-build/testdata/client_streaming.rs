@@ -0,0 +1,76 @@
+// Auto-generated REST routes from proto `google.api.http` annotations.
+//
+// **Do not edit** — regenerated by `build.rs` when proto files change.
+//
+// Each handler transcodes HTTP/JSON <-> proto and calls the Tonic service trait,
+// sharing auth, validation, and business logic with gRPC handlers.
+
+use std::sync::Arc;
+
+use axum::extract::{Json, State};
+use axum::http::HeaderMap;
+use axum::Router;
+
+// =============================================================================
+// UploadService REST routes
+// =============================================================================
+
+/// Build Axum REST routes for `UploadService`.
+///
+/// Generated from `google.api.http` annotations in `test.proto`.
+pub fn upload_service_rest_router<S>(service: Arc<S>) -> Router
+where
+    S: crate::test::upload_service_server::UploadService + Send + Sync + 'static,
+{
+    Router::new()
+        .route("/v1/uploads", axum::routing::post(rest_upload_service_upload_chunks::<S>))
+        .with_state(service)
+}
+
+#[expect(clippy::too_many_arguments, clippy::needless_pass_by_value)]
+/// `UploadChunks` — client-streaming endpoint (NDJSON request body).
+///
+/// `POST /v1/uploads`
+async fn rest_upload_service_upload_chunks<S>(
+    State(service): State<Arc<S>>,
+    headers: HeaderMap,
+    body: axum::body::Body,
+) -> Result<Json<crate::test::UploadSummary>, tonic_rest::RestError>
+where
+    S: crate::test::upload_service_server::UploadService + Send + Sync + 'static,
+{
+    let req = tonic_rest::build_tonic_request_stream::<_, ()>(body, &headers, None);
+    let response = service.upload_chunks(req).await.map_err(|status| {
+        tonic_rest::RestError::from(status).with_context("POST", "/v1/uploads")
+    })?;
+    Ok(Json(response.into_inner()))
+}
+
+
+// =============================================================================
+// Public REST paths (bypass auth middleware)
+// =============================================================================
+
+/// REST paths that are marked as public (no authentication required).
+///
+/// Auto-generated from `google.api.http` annotations on public RPC methods.
+/// Used by middleware to identify unauthenticated endpoints.
+pub const PUBLIC_REST_PATHS: &[&str] = &[
+];
+
+// =============================================================================
+// Combined REST router
+// =============================================================================
+
+/// Build a combined Axum router with REST routes for all proto services.
+///
+/// Each service is generic — pass your concrete implementations as `Arc<T>`.
+pub fn all_rest_routes<S0>(
+    upload_service: Arc<S0>,
+) -> Router
+where
+    S0: crate::test::upload_service_server::UploadService + Send + Sync + 'static,
+{
+    Router::new()
+        .merge(upload_service_rest_router(upload_service))
+}
@@ -44,10 +44,12 @@ where
         Some(v) => v as i32,
         None => provider.parse::<i32>().map_err(|_| tonic_rest::RestError::new(tonic::Status::invalid_argument(
             "invalid enum value for 'provider'",
-        )))?,
+        )).with_context("DELETE", "/v1/providers/{provider}"))?,
     };
     let req = tonic_rest::build_tonic_request::<_, ()>(body, &headers, None);
-    service.unlink(req).await.map_err(tonic_rest::RestError::from)?;
+    service.unlink(req).await.map_err(|status| {
+        tonic_rest::RestError::from(status).with_context("DELETE", "/v1/providers/{provider}")
+    })?;
     Ok(StatusCode::NO_CONTENT)
 }
 
@@ -0,0 +1,96 @@
+// Auto-generated REST routes from proto `google.api.http` annotations.
+//
+// **Do not edit** — regenerated by `build.rs` when proto files change.
+//
+// Each handler transcodes HTTP/JSON <-> proto and calls the Tonic service trait,
+// sharing auth, validation, and business logic with gRPC handlers.
+
+use std::sync::Arc;
+
+use axum::extract::{Form, Json, State};
+use axum::http::HeaderMap;
+use axum::Router;
+
+// =============================================================================
+// AuthService REST routes
+// =============================================================================
+
+/// Build Axum REST routes for `AuthService`.
+///
+/// Generated from `google.api.http` annotations in `test.proto`.
+pub fn auth_service_rest_router<S>(service: Arc<S>) -> Router
+where
+    S: crate::test::auth_service_server::AuthService + Send + Sync + 'static,
+{
+    Router::new()
+        .route("/oauth/token", axum::routing::post(rest_auth_service_issue_token::<S>))
+        .route("/v1/token/refresh", axum::routing::post(rest_auth_service_refresh_token::<S>))
+        .with_state(service)
+}
+
+#[expect(clippy::too_many_arguments, clippy::needless_pass_by_value)]
+/// `IssueToken` — JSON endpoint.
+///
+/// `POST /oauth/token`
+async fn rest_auth_service_issue_token<S>(
+    State(service): State<Arc<S>>,
+    headers: HeaderMap,
+    Form(body): Form<crate::test::IssueTokenRequest>,
+) -> Result<Json<crate::test::Token>, tonic_rest::RestError>
+where
+    S: crate::test::auth_service_server::AuthService + Send + Sync + 'static,
+{
+    let req = tonic_rest::build_tonic_request::<_, ()>(body, &headers, None);
+    let response = service.issue_token(req).await.map_err(|status| {
+        tonic_rest::RestError::from(status).with_context("POST", "/oauth/token")
+    })?;
+    Ok(Json(response.into_inner()))
+}
+
+#[expect(clippy::too_many_arguments, clippy::needless_pass_by_value)]
+/// `RefreshToken` — JSON endpoint.
+///
+/// `POST /v1/token/refresh`
+async fn rest_auth_service_refresh_token<S>(
+    State(service): State<Arc<S>>,
+    headers: HeaderMap,
+    Json(body): Json<crate::test::IssueTokenRequest>,
+) -> Result<Json<crate::test::Token>, tonic_rest::RestError>
+where
+    S: crate::test::auth_service_server::AuthService + Send + Sync + 'static,
+{
+    let req = tonic_rest::build_tonic_request::<_, ()>(body, &headers, None);
+    let response = service.refresh_token(req).await.map_err(|status| {
+        tonic_rest::RestError::from(status).with_context("POST", "/v1/token/refresh")
+    })?;
+    Ok(Json(response.into_inner()))
+}
+
+
+// =============================================================================
+// Public REST paths (bypass auth middleware)
+// =============================================================================
+
+/// REST paths that are marked as public (no authentication required).
+///
+/// Auto-generated from `google.api.http` annotations on public RPC methods.
+/// Used by middleware to identify unauthenticated endpoints.
+pub const PUBLIC_REST_PATHS: &[&str] = &[
+];
+
+// =============================================================================
+// Combined REST router
+// =============================================================================
+
+/// Build a combined Axum router with REST routes for all proto services.
+///
+/// Each service is generic — pass your concrete implementations as `Arc<T>`.
+pub fn all_rest_routes<S0>(
+    auth_service: Arc<S0>,
+) -> Router
+where
+    S0: crate::test::auth_service_server::AuthService + Send + Sync + 'static,
+{
+    Router::new()
+        .merge(auth_service_rest_router(auth_service))
+}
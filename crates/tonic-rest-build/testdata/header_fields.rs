@@ -0,0 +1,113 @@
+// Auto-generated REST routes from proto `google.api.http` annotations.
+//
+// **Do not edit** — regenerated by `build.rs` when proto files change.
+//
+// Each handler transcodes HTTP/JSON <-> proto and calls the Tonic service trait,
+// sharing auth, validation, and business logic with gRPC handlers.
+
+use std::sync::Arc;
+
+use axum::extract::{Json, Query, State};
+use axum::http::HeaderMap;
+use axum::Router;
+
+// =============================================================================
+// ItemService REST routes
+// =============================================================================
+
+/// Build Axum REST routes for `ItemService`.
+///
+/// Generated from `google.api.http` annotations in `test.proto`.
+pub fn item_service_rest_router<S>(service: Arc<S>) -> Router
+where
+    S: crate::test::item_service_server::ItemService + Send + Sync + 'static,
+{
+    Router::new()
+        .route("/v1/items", axum::routing::post(rest_item_service_create_item::<S>))
+        .route("/v1/items", axum::routing::get(rest_item_service_list_items::<S>))
+        .with_state(service)
+}
+
+#[expect(clippy::too_many_arguments, clippy::needless_pass_by_value)]
+/// `CreateItem` — JSON endpoint.
+///
+/// `POST /v1/items`
+async fn rest_item_service_create_item<S>(
+    State(service): State<Arc<S>>,
+    headers: HeaderMap,
+    Json(mut body): Json<crate::test::CreateItemRequest>,
+) -> Result<Json<crate::test::Item>, tonic_rest::RestError>
+where
+    S: crate::test::item_service_server::ItemService + Send + Sync + 'static,
+{
+    if let Some(value) = headers.get("client-version").and_then(|v| v.to_str().ok()) {
+        body.client_version = value.to_string();
+    }
+    let req = tonic_rest::build_tonic_request::<_, ()>(body, &headers, None);
+    let response = service.create_item(req).await.map_err(|status| {
+        tonic_rest::RestError::from(status).with_context("POST", "/v1/items")
+    })?;
+    Ok(Json(response.into_inner()))
+}
+
+/// Query parameters for `ListItems`.
+#[derive(serde::Deserialize)]
+struct RestItemServiceListItemsQuery {
+    #[serde(alias = "pageSize")]
+    page_size: Option<i32>,
+}
+
+#[expect(clippy::too_many_arguments, clippy::needless_pass_by_value)]
+/// `ListItems` — JSON endpoint.
+///
+/// `GET /v1/items`
+async fn rest_item_service_list_items<S>(
+    State(service): State<Arc<S>>,
+    headers: HeaderMap,
+    Query(query): Query<RestItemServiceListItemsQuery>,
+) -> Result<Json<crate::test::ListItemsResponse>, tonic_rest::RestError>
+where
+    S: crate::test::item_service_server::ItemService + Send + Sync + 'static,
+{
+    let mut body = crate::test::ListItemsRequest::default();
+    if let Some(value) = query.page_size {
+        body.page_size = value;
+    }
+    if let Some(value) = headers.get("client-version").and_then(|v| v.to_str().ok()) {
+        body.client_version = Some(value.to_string());
+    }
+    let req = tonic_rest::build_tonic_request::<_, ()>(body, &headers, None);
+    let response = service.list_items(req).await.map_err(|status| {
+        tonic_rest::RestError::from(status).with_context("GET", "/v1/items")
+    })?;
+    Ok(Json(response.into_inner()))
+}
+
+
+// =============================================================================
+// Public REST paths (bypass auth middleware)
+// =============================================================================
+
+/// REST paths that are marked as public (no authentication required).
+///
+/// Auto-generated from `google.api.http` annotations on public RPC methods.
+/// Used by middleware to identify unauthenticated endpoints.
+pub const PUBLIC_REST_PATHS: &[&str] = &[
+];
+
+// =============================================================================
+// Combined REST router
+// =============================================================================
+
+/// Build a combined Axum router with REST routes for all proto services.
+///
+/// Each service is generic — pass your concrete implementations as `Arc<T>`.
+pub fn all_rest_routes<S0>(
+    item_service: Arc<S0>,
+) -> Router
+where
+    S0: crate::test::item_service_server::ItemService + Send + Sync + 'static,
+{
+    Router::new()
+        .merge(item_service_rest_router(item_service))
+}
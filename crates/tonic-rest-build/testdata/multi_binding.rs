@@ -0,0 +1,207 @@
+// Auto-generated REST routes from proto `google.api.http` annotations.
+//
+// **Do not edit** — regenerated by `build.rs` when proto files change.
+//
+// Each handler transcodes HTTP/JSON <-> proto and calls the Tonic service trait,
+// sharing auth, validation, and business logic with gRPC handlers.
+
+use std::sync::Arc;
+
+use axum::extract::{Json, Path, Query, State};
+use axum::http::HeaderMap;
+use axum::Router;
+
+// =============================================================================
+// UserService REST routes
+// =============================================================================
+
+/// Build Axum REST routes for `UserService`.
+///
+/// Generated from `google.api.http` annotations in `test.proto`.
+pub fn user_service_rest_router<S>(service: Arc<S>) -> Router
+where
+    S: crate::test::user_service_server::UserService + Send + Sync + 'static,
+{
+    Router::new()
+        .route("/v1/users/{user_id}", axum::routing::get(rest_user_service_get_user::<S>))
+        .route("/v1/users:byEmail", axum::routing::get(rest_user_service_get_user_binding1::<S>))
+        .route("/v1/orgs/{org_id}/users:lookup", axum::routing::post(rest_user_service_get_user_binding2::<S>))
+        .with_state(service)
+}
+
+/// Query parameters for `GetUser`.
+#[derive(serde::Deserialize)]
+struct RestUserServiceGetUserQuery {
+    email: Option<String>,
+    #[serde(alias = "orgId")]
+    org_id: Option<i64>,
+}
+
+#[expect(clippy::too_many_arguments, clippy::needless_pass_by_value)]
+/// `GetUser` — JSON endpoint.
+///
+/// `GET /v1/users/{user_id}`
+async fn rest_user_service_get_user<S>(
+    State(service): State<Arc<S>>,
+    headers: HeaderMap,
+    Path(user_id): Path<String>,
+    Query(query): Query<RestUserServiceGetUserQuery>,
+) -> Result<Json<crate::test::User>, tonic_rest::RestError>
+where
+    S: crate::test::user_service_server::UserService + Send + Sync + 'static,
+{
+    let mut body = crate::test::GetUserRequest::default();
+    body.user_id = user_id;
+    if let Some(value) = query.email {
+        body.email = value;
+    }
+    if let Some(value) = query.org_id {
+        body.org_id = value;
+    }
+    let req = tonic_rest::build_tonic_request::<_, ()>(body, &headers, None);
+    let response = service.get_user(req).await.map_err(|status| {
+        tonic_rest::RestError::from(status).with_context("GET", "/v1/users/{user_id}")
+    })?;
+    Ok(Json(response.into_inner()))
+}
+
+/// Query parameters for `GetUser`.
+#[derive(serde::Deserialize)]
+struct RestUserServiceGetUserBinding1Query {
+    #[serde(alias = "userId")]
+    user_id: Option<String>,
+    email: Option<String>,
+    #[serde(alias = "orgId")]
+    org_id: Option<i64>,
+}
+
+#[expect(clippy::too_many_arguments, clippy::needless_pass_by_value)]
+/// `GetUser` — JSON endpoint.
+///
+/// `GET /v1/users:byEmail`
+async fn rest_user_service_get_user_binding1<S>(
+    State(service): State<Arc<S>>,
+    headers: HeaderMap,
+    Query(query): Query<RestUserServiceGetUserBinding1Query>,
+) -> Result<Json<crate::test::User>, tonic_rest::RestError>
+where
+    S: crate::test::user_service_server::UserService + Send + Sync + 'static,
+{
+    let mut body = crate::test::GetUserRequest::default();
+    if let Some(value) = query.user_id {
+        body.user_id = value;
+    }
+    if let Some(value) = query.email {
+        body.email = value;
+    }
+    if let Some(value) = query.org_id {
+        body.org_id = value;
+    }
+    let req = tonic_rest::build_tonic_request::<_, ()>(body, &headers, None);
+    let response = service.get_user(req).await.map_err(|status| {
+        tonic_rest::RestError::from(status).with_context("GET", "/v1/users:byEmail")
+    })?;
+    Ok(Json(response.into_inner()))
+}
+
+#[expect(clippy::too_many_arguments, clippy::needless_pass_by_value)]
+/// `GetUser` — JSON endpoint.
+///
+/// `POST /v1/orgs/{org_id}/users:lookup`
+async fn rest_user_service_get_user_binding2<S>(
+    State(service): State<Arc<S>>,
+    headers: HeaderMap,
+    Path(org_id): Path<String>,
+    Json(mut body): Json<crate::test::GetUserRequest>,
+) -> Result<Json<crate::test::User>, tonic_rest::RestError>
+where
+    S: crate::test::user_service_server::UserService + Send + Sync + 'static,
+{
+    let org_id = org_id.parse::<i64>().map_err(|_| {
+        tonic_rest::RestError::new(tonic::Status::invalid_argument(
+            "invalid integer value for 'org_id'",
+        ))
+        .with_context("POST", "/v1/orgs/{org_id}/users:lookup")
+    })?;
+    body.org_id = org_id;
+    let req = tonic_rest::build_tonic_request::<_, ()>(body, &headers, None);
+    let response = service.get_user(req).await.map_err(|status| {
+        tonic_rest::RestError::from(status).with_context("POST", "/v1/orgs/{org_id}/users:lookup")
+    })?;
+    Ok(Json(response.into_inner()))
+}
+
+
+// =============================================================================
+// Public REST paths (bypass auth middleware)
+// =============================================================================
+
+/// REST paths that are marked as public (no authentication required).
+///
+/// Auto-generated from `google.api.http` annotations on public RPC methods.
+/// Used by middleware to identify unauthenticated endpoints.
+pub const PUBLIC_REST_PATHS: &[&str] = &[
+    "/v1/users/{user_id}",
+    "/v1/users:byEmail",
+    "/v1/orgs/{org_id}/users:lookup",
+];
+
+// =============================================================================
+// Route manifest
+// =============================================================================
+
+/// Number of REST routes registered by the generated routers.
+pub const REST_ROUTE_COUNT: usize = 3;
+
+/// All generated REST routes as `(HTTP method, Axum path)` pairs, in
+/// registration order.
+///
+/// Assert on this in tests to catch routes lost during proto refactors.
+#[must_use]
+pub const fn rest_routes() -> &'static [(&'static str, &'static str)] {
+    &[
+        ("GET", "/v1/users/{user_id}"),
+        ("GET", "/v1/users:byEmail"),
+        ("POST", "/v1/orgs/{org_id}/users:lookup"),
+    ]
+}
+
+/// Proto operation ID (`Service_Method`) of a generated REST route.
+///
+/// `matched_path` is the registered route template, as returned by
+/// [`axum::extract::MatchedPath::as_str`]. Routers mounted with `Router::nest`
+/// report the nested path, so strip that prefix first. Lets middleware label
+/// spans and metrics by operation:
+///
+/// ```ignore
+/// let operation_id = request
+///     .extensions()
+///     .get::<axum::extract::MatchedPath>()
+///     .and_then(|path| operation_id_for(request.method(), path.as_str()));
+/// ```
+#[must_use]
+pub fn operation_id_for(method: &axum::http::Method, matched_path: &str) -> Option<&'static str> {
+    match (method.as_str(), matched_path) {
+        ("GET", "/v1/users/{user_id}") => Some("UserService_GetUser"),
+        ("GET", "/v1/users:byEmail") => Some("UserService_GetUser"),
+        ("POST", "/v1/orgs/{org_id}/users:lookup") => Some("UserService_GetUser"),
+        _ => None,
+    }
+}
+
+// =============================================================================
+// Combined REST router
+// =============================================================================
+
+/// Build a combined Axum router with REST routes for all proto services.
+///
+/// Each service is generic — pass your concrete implementations as `Arc<T>`.
+pub fn all_rest_routes<S0>(
+    user_service: Arc<S0>,
+) -> Router
+where
+    S0: crate::test::user_service_server::UserService + Send + Sync + 'static,
+{
+    Router::new()
+        .merge(user_service_rest_router(user_service))
+}
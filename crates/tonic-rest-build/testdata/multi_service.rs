@@ -44,7 +44,9 @@ where
     S: crate::auth::auth_service_server::AuthService + Send + Sync + 'static,
 {
     let req = tonic_rest::build_tonic_request::<_, ()>(body, &headers, None);
-    let response = service.login(req).await.map_err(tonic_rest::RestError::from)?;
+    let response = service.login(req).await.map_err(|status| {
+        tonic_rest::RestError::from(status).with_context("POST", "/v1/auth/login")
+    })?;
     Ok(Json(response.into_inner()))
 }
 
@@ -76,15 +78,20 @@ async fn rest_user_service_list_users<S>(
 where
     S: crate::users::user_service_server::UserService + Send + Sync + 'static,
 {
-    let req = tonic_rest::build_tonic_request::<_, ()>(query, &headers, None);
-    let response = service.list_users(req).await.map_err(tonic_rest::RestError::from)?;
+    let req = tonic_rest::build_tonic_request_with_resume::<_, ()>(query, &headers, None, tonic_rest::FORWARDED_HEADERS);
+    let response = service.list_users(req).await.map_err(|status| {
+        tonic_rest::RestError::from(status).with_context("GET", "/v1/users")
+    })?;
     let stream = response.into_inner();
 
-    let sse_stream = stream.map(|result| {
+    let mut next_id = tonic_rest::sse_first_event_id(&headers);
+    let sse_stream = stream.map(move |result| {
         Ok::<_, Infallible>(match result {
-            Ok(item) => Event::default()
-                .json_data(&item)
-                .unwrap_or_else(|_| Event::default().data("{}")),
+            Ok(item) => {
+                let id = next_id;
+                next_id += 1;
+                tonic_rest::sse_data_event(None, id, &item)
+            }
             Err(status) => tonic_rest::sse_error_event(&status),
         })
     });
@@ -109,6 +116,47 @@ pub const PUBLIC_REST_PATHS: &[&str] = &[
     "/v1/auth/login",
 ];
 
+// =============================================================================
+// Route manifest
+// =============================================================================
+
+/// Number of REST routes registered by the generated routers.
+pub const REST_ROUTE_COUNT: usize = 2;
+
+/// All generated REST routes as `(HTTP method, Axum path)` pairs, in
+/// registration order.
+///
+/// Assert on this in tests to catch routes lost during proto refactors.
+#[must_use]
+pub const fn rest_routes() -> &'static [(&'static str, &'static str)] {
+    &[
+        ("POST", "/v1/auth/login"),
+        ("GET", "/v1/users"),
+    ]
+}
+
+/// Proto operation ID (`Service_Method`) of a generated REST route.
+///
+/// `matched_path` is the registered route template, as returned by
+/// [`axum::extract::MatchedPath::as_str`]. Routers mounted with `Router::nest`
+/// report the nested path, so strip that prefix first. Lets middleware label
+/// spans and metrics by operation:
+///
+/// ```ignore
+/// let operation_id = request
+///     .extensions()
+///     .get::<axum::extract::MatchedPath>()
+///     .and_then(|path| operation_id_for(request.method(), path.as_str()));
+/// ```
+#[must_use]
+pub fn operation_id_for(method: &axum::http::Method, matched_path: &str) -> Option<&'static str> {
+    match (method.as_str(), matched_path) {
+        ("POST", "/v1/auth/login") => Some("AuthService_Login"),
+        ("GET", "/v1/users") => Some("UserService_ListUsers"),
+        _ => None,
+    }
+}
+
 // =============================================================================
 // Combined REST router
 // =============================================================================
@@ -0,0 +1,80 @@
+// Auto-generated REST routes from proto `google.api.http` annotations.
+//
+// **Do not edit** — regenerated by `build.rs` when proto files change.
+//
+// Each handler transcodes HTTP/JSON <-> proto and calls the Tonic service trait,
+// sharing auth, validation, and business logic with gRPC handlers.
+
+use std::sync::Arc;
+
+use axum::extract::{Json, Path, Query, State};
+use axum::http::HeaderMap;
+use axum::Router;
+
+// =============================================================================
+// UserService REST routes
+// =============================================================================
+
+/// Build Axum REST routes for `UserService`.
+///
+/// Generated from `google.api.http` annotations in `test.proto`.
+pub fn user_service_rest_router<S>(service: Arc<S>) -> Router
+where
+    S: crate::test::user_service_server::UserService + Send + Sync + 'static,
+{
+    Router::new()
+        .route("/v1/users/{user_id}", axum::routing::patch(rest_user_service_update_user::<S>))
+        .with_state(service)
+}
+
+#[expect(clippy::too_many_arguments, clippy::needless_pass_by_value)]
+/// `UpdateUser` — JSON endpoint.
+///
+/// `PATCH /v1/users/{user_id}`
+async fn rest_user_service_update_user<S>(
+    State(service): State<Arc<S>>,
+    headers: HeaderMap,
+    Path(user_id): Path<String>,
+    Query(mut body): Query<crate::test::UpdateUserRequest>,
+    Json(payload): Json<crate::test::User>,
+) -> Result<Json<crate::test::User>, tonic_rest::RestError>
+where
+    S: crate::test::user_service_server::UserService + Send + Sync + 'static,
+{
+    body.user = Some(payload);
+    body.user_id = user_id;
+    let req = tonic_rest::build_tonic_request::<_, ()>(body, &headers, None);
+    let response = service.update_user(req).await.map_err(|status| {
+        tonic_rest::RestError::from(status).with_context("PATCH", "/v1/users/{user_id}")
+    })?;
+    Ok(Json(response.into_inner()))
+}
+
+
+// =============================================================================
+// Public REST paths (bypass auth middleware)
+// =============================================================================
+
+/// REST paths that are marked as public (no authentication required).
+///
+/// Auto-generated from `google.api.http` annotations on public RPC methods.
+/// Used by middleware to identify unauthenticated endpoints.
+pub const PUBLIC_REST_PATHS: &[&str] = &[
+];
+
+// =============================================================================
+// Combined REST router
+// =============================================================================
+
+/// Build a combined Axum router with REST routes for all proto services.
+///
+/// Each service is generic — pass your concrete implementations as `Arc<T>`.
+pub fn all_rest_routes<S0>(
+    user_service: Arc<S0>,
+) -> Router
+where
+    S0: crate::test::user_service_server::UserService + Send + Sync + 'static,
+{
+    Router::new()
+        .merge(user_service_rest_router(user_service))
+}
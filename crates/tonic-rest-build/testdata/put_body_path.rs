@@ -42,7 +42,9 @@ where
 {
     body.item_id = item_id;
     let req = tonic_rest::build_tonic_request::<_, ()>(body, &headers, None);
-    let response = service.replace_item(req).await.map_err(tonic_rest::RestError::from)?;
+    let response = service.replace_item(req).await.map_err(|status| {
+        tonic_rest::RestError::from(status).with_context("PUT", "/v1/items/{item_id}")
+    })?;
     Ok(Json(response.into_inner()))
 }
 
@@ -0,0 +1,110 @@
+// Auto-generated REST routes from proto `google.api.http` annotations.
+//
+// **Do not edit** — regenerated by `build.rs` when proto files change.
+//
+// Each handler transcodes HTTP/JSON <-> proto and calls the Tonic service trait,
+// sharing auth, validation, and business logic with gRPC handlers.
+
+use std::sync::Arc;
+
+use axum::extract::{Json, Path, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::Router;
+
+/// gRPC response metadata keys copied to HTTP response headers.
+const RESPONSE_HEADERS: &[&str] = &[
+    "x-request-id",
+    "etag",
+];
+
+// =============================================================================
+// ItemService REST routes
+// =============================================================================
+
+/// Build Axum REST routes for `ItemService`.
+///
+/// Generated from `google.api.http` annotations in `test.proto`.
+pub fn item_service_rest_router<S>(service: Arc<S>) -> Router
+where
+    S: crate::test::item_service_server::ItemService + Send + Sync + 'static,
+{
+    Router::new()
+        .route("/v1/items/{item_id}", axum::routing::get(rest_item_service_get_item::<S>))
+        .route("/v1/items/{item_id}", axum::routing::delete(rest_item_service_delete_item::<S>))
+        .with_state(service)
+}
+
+#[expect(clippy::too_many_arguments, clippy::needless_pass_by_value)]
+/// `GetItem` — JSON endpoint.
+///
+/// `GET /v1/items/{item_id}`
+async fn rest_item_service_get_item<S>(
+    State(service): State<Arc<S>>,
+    headers: HeaderMap,
+    Path(item_id): Path<String>,
+) -> Result<(HeaderMap, Json<crate::test::Item>), tonic_rest::RestError>
+where
+    S: crate::test::item_service_server::ItemService + Send + Sync + 'static,
+{
+    let mut body = crate::test::GetItemRequest::default();
+    body.item_id = item_id;
+    let req = tonic_rest::build_tonic_request::<_, ()>(body, &headers, None);
+    let response = service.get_item(req).await.map_err(|status| {
+        tonic_rest::RestError::from(status).with_context("GET", "/v1/items/{item_id}")
+    })?;
+    let mut response_headers = HeaderMap::new();
+    tonic_rest::apply_response_metadata(&mut response_headers, response.metadata(), RESPONSE_HEADERS);
+    Ok((response_headers, Json(response.into_inner())))
+}
+
+#[expect(clippy::too_many_arguments, clippy::needless_pass_by_value)]
+/// `DeleteItem` — JSON endpoint.
+///
+/// `DELETE /v1/items/{item_id}`
+async fn rest_item_service_delete_item<S>(
+    State(service): State<Arc<S>>,
+    headers: HeaderMap,
+    Path(item_id): Path<String>,
+) -> Result<(HeaderMap, StatusCode), tonic_rest::RestError>
+where
+    S: crate::test::item_service_server::ItemService + Send + Sync + 'static,
+{
+    let mut body = crate::test::DeleteItemRequest::default();
+    body.item_id = item_id;
+    let req = tonic_rest::build_tonic_request::<_, ()>(body, &headers, None);
+    let response = service.delete_item(req).await.map_err(|status| {
+        tonic_rest::RestError::from(status).with_context("DELETE", "/v1/items/{item_id}")
+    })?;
+    let mut response_headers = HeaderMap::new();
+    tonic_rest::apply_response_metadata(&mut response_headers, response.metadata(), RESPONSE_HEADERS);
+    Ok((response_headers, StatusCode::NO_CONTENT))
+}
+
+
+// =============================================================================
+// Public REST paths (bypass auth middleware)
+// =============================================================================
+
+/// REST paths that are marked as public (no authentication required).
+///
+/// Auto-generated from `google.api.http` annotations on public RPC methods.
+/// Used by middleware to identify unauthenticated endpoints.
+pub const PUBLIC_REST_PATHS: &[&str] = &[
+];
+
+// =============================================================================
+// Combined REST router
+// =============================================================================
+
+/// Build a combined Axum router with REST routes for all proto services.
+///
+/// Each service is generic — pass your concrete implementations as `Arc<T>`.
+pub fn all_rest_routes<S0>(
+    item_service: Arc<S0>,
+) -> Router
+where
+    S0: crate::test::item_service_server::ItemService + Send + Sync + 'static,
+{
+    Router::new()
+        .merge(item_service_rest_router(item_service))
+}
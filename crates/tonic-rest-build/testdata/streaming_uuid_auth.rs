@@ -46,15 +46,20 @@ where
     S: crate::test::event_service_server::EventService + Send + Sync + 'static,
 {
     let ext = ext.map(|Extension(v)| v);
-    let req = tonic_rest::build_tonic_request(query, &headers, ext);
-    let response = service.list_events(req).await.map_err(tonic_rest::RestError::from)?;
+    let req = tonic_rest::build_tonic_request_with_resume(query, &headers, ext, tonic_rest::FORWARDED_HEADERS);
+    let response = service.list_events(req).await.map_err(|status| {
+        tonic_rest::RestError::from(status).with_context("GET", "/v1/events")
+    })?;
     let stream = response.into_inner();
 
-    let sse_stream = stream.map(|result| {
+    let mut next_id = tonic_rest::sse_first_event_id(&headers);
+    let sse_stream = stream.map(move |result| {
         Ok::<_, Infallible>(match result {
-            Ok(item) => Event::default()
-                .json_data(&item)
-                .unwrap_or_else(|_| Event::default().data("{}")),
+            Ok(item) => {
+                let id = next_id;
+                next_id += 1;
+                tonic_rest::sse_data_event(None, id, &item)
+            }
             Err(status) => tonic_rest::sse_error_event(&status),
         })
     });
@@ -83,7 +88,9 @@ where
     body.user_id = Some(crate::core::Uuid { value: user_id_value });
     let ext = ext.map(|Extension(v)| v);
     let req = tonic_rest::build_tonic_request(body, &headers, ext);
-    let response = service.update_user(req).await.map_err(tonic_rest::RestError::from)?;
+    let response = service.update_user(req).await.map_err(|status| {
+        tonic_rest::RestError::from(status).with_context("PATCH", "/v1/users/{user_id.value}")
+    })?;
     Ok(Json(response.into_inner()))
 }
 
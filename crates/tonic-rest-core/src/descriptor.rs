@@ -22,8 +22,13 @@
 //!
 //! - [`FileDescriptorSet`] / [`FileDescriptorProto`] — top-level descriptor
 //! - [`ServiceDescriptorProto`] / [`MethodDescriptorProto`] — service + method with HTTP rule
+//! - [`ServiceOptions`] — `google.api.default_host` service option
 //! - [`DescriptorProto`] / [`FieldDescriptorProto`] — message + field with validation rules
+//! - [`OneofDescriptorProto`] — `oneof` declaration referenced by `oneof_index`
+//! - [`MessageOptions`] — `map_entry` flag on synthetic map entry messages
 //! - [`HttpRule`] / [`HttpPattern`] — the `google.api.http` annotation itself
+//! - [`CustomHttpPattern`] — `custom: { kind, path }` bindings for other HTTP verbs
+//! - [`SourceCodeInfo`] / [`Location`] — source file positions, when included
 //! - [`FieldOptions`] / [`FieldRules`] — `validate.rules` constraints
 
 #[allow(
@@ -54,6 +59,33 @@ mod types {
         pub enum_type: Vec<EnumDescriptorProto>,
         #[prost(message, repeated, tag = "6")]
         pub service: Vec<ServiceDescriptorProto>,
+        /// Only present when the descriptor set was built with source info
+        /// (e.g., `buf build --as-file-descriptor-set` or `protoc --include_source_info`).
+        #[prost(message, optional, tag = "9")]
+        pub source_code_info: Option<SourceCodeInfo>,
+    }
+
+    /// `google.protobuf.SourceCodeInfo` — source locations for descriptor elements.
+    #[derive(Clone, PartialEq, Message)]
+    pub struct SourceCodeInfo {
+        #[prost(message, repeated, tag = "1")]
+        pub location: Vec<Location>,
+    }
+
+    /// `google.protobuf.SourceCodeInfo.Location` — one element's position.
+    ///
+    /// `path` addresses the element by field numbers and indices (e.g.,
+    /// `[6, 0, 2, 1]` = `service[0].method[1]`); `span` holds zero-based
+    /// `[start_line, start_col, (end_line,) end_col]`. `leading_comments`
+    /// is the comment block directly above the element, with `//` stripped.
+    #[derive(Clone, PartialEq, Message)]
+    pub struct Location {
+        #[prost(int32, repeated, packed = "true", tag = "1")]
+        pub path: Vec<i32>,
+        #[prost(int32, repeated, packed = "true", tag = "2")]
+        pub span: Vec<i32>,
+        #[prost(string, optional, tag = "3")]
+        pub leading_comments: Option<String>,
     }
 
     #[derive(Clone, PartialEq, Message)]
@@ -64,21 +96,58 @@ mod types {
         pub field: Vec<FieldDescriptorProto>,
         #[prost(message, repeated, tag = "3")]
         pub nested_type: Vec<DescriptorProto>,
+        /// `oneof` declarations; fields reference them by [`FieldDescriptorProto::oneof_index`].
+        #[prost(message, repeated, tag = "8")]
+        pub oneof_decl: Vec<OneofDescriptorProto>,
+        /// Message options; `map_entry` marks the synthetic entry type of a `map<K, V>` field.
+        #[prost(message, optional, tag = "7")]
+        pub options: Option<MessageOptions>,
+    }
+
+    #[derive(Clone, PartialEq, Message)]
+    pub struct MessageOptions {
+        /// Set on the `*Entry` message protoc synthesizes for each `map<K, V>` field.
+        #[prost(bool, optional, tag = "7")]
+        pub map_entry: Option<bool>,
+    }
+
+    #[derive(Clone, PartialEq, Message)]
+    pub struct OneofDescriptorProto {
+        #[prost(string, optional, tag = "1")]
+        pub name: Option<String>,
     }
 
     #[derive(Clone, PartialEq, Message)]
     pub struct FieldDescriptorProto {
         #[prost(string, optional, tag = "1")]
         pub name: Option<String>,
+        /// Field cardinality: 1=optional, 2=required, 3=repeated (see [`field_label`](super::field_label)).
+        #[prost(int32, optional, tag = "4")]
+        pub label: Option<i32>,
         /// Protobuf field type enum: 1=double, 5=int32, 9=string, 11=message, 14=enum, …
         #[prost(int32, optional, tag = "5")]
         pub r#type: Option<i32>,
         /// Fully-qualified type name for message/enum fields (e.g., `.auth.v1.OAuthProvider`).
         #[prost(string, optional, tag = "6")]
         pub type_name: Option<String>,
+        /// Declared default as protoc text (`true`, `42`, an enum value name, …).
+        /// Set for proto2 `[default = ...]`; proto3 fields never carry one.
+        #[prost(string, optional, tag = "7")]
+        pub default_value: Option<String>,
         /// Field options including validation rules.
         #[prost(message, optional, tag = "8")]
         pub options: Option<FieldOptions>,
+        /// Index into the parent message's `oneof_decl`. Also set for proto3
+        /// `optional` fields, which are modeled as synthetic single-field oneofs.
+        #[prost(int32, optional, tag = "9")]
+        pub oneof_index: Option<i32>,
+        /// JSON name of the field. protoc fills in the `lowerCamelCase` form
+        /// unless the field sets an explicit `json_name` option.
+        #[prost(string, optional, tag = "10")]
+        pub json_name: Option<String>,
+        /// Set for proto3 `optional` fields (explicit presence, `Option<T>` in prost).
+        #[prost(bool, optional, tag = "17")]
+        pub proto3_optional: Option<bool>,
     }
 
     /// Field-level options, including `validate.rules` extension.
@@ -104,6 +173,8 @@ mod types {
         pub string: Option<StringRules>,
         #[prost(message, optional, tag = "16")]
         pub r#enum: Option<EnumRules>,
+        #[prost(message, optional, tag = "18")]
+        pub repeated: Option<RepeatedRules>,
     }
 
     #[derive(Clone, PartialEq, Message)]
@@ -120,13 +191,51 @@ mod types {
         pub max_len: Option<u64>,
         #[prost(string, optional, tag = "6")]
         pub pattern: Option<String>,
+        /// The `len` rule: exact length in characters, taking precedence over
+        /// `min_len`/`max_len`. Named `exact_len` so prost's accessor isn't a
+        /// bare `len()`.
+        #[prost(uint64, optional, tag = "19")]
+        pub exact_len: Option<u64>,
         #[prost(string, repeated, tag = "10")]
         pub r#in: Vec<String>,
+        /// `well_known` oneof: `email = true` means the field must be an email address.
+        #[prost(bool, optional, tag = "12")]
+        pub email: Option<bool>,
+        /// `well_known` oneof: a valid hostname (RFC 1034).
+        #[prost(bool, optional, tag = "13")]
+        pub hostname: Option<bool>,
+        /// `well_known` oneof: a valid IPv4 address.
+        #[prost(bool, optional, tag = "15")]
+        pub ipv4: Option<bool>,
+        /// `well_known` oneof: a valid IPv6 address.
+        #[prost(bool, optional, tag = "16")]
+        pub ipv6: Option<bool>,
+        /// `well_known` oneof: an absolute URI (RFC 3986).
+        #[prost(bool, optional, tag = "17")]
+        pub uri: Option<bool>,
         /// `well_known` oneof: `uuid = true` means the field must be a valid UUID.
         #[prost(bool, optional, tag = "22")]
         pub uuid: Option<bool>,
     }
 
+    impl StringRules {
+        /// JSON Schema `format` implied by the `well_known` rule, if any.
+        ///
+        /// `uuid` is not included; it carries its own pattern and example.
+        #[must_use]
+        pub fn well_known_format(&self) -> Option<&'static str> {
+            [
+                (self.email, "email"),
+                (self.hostname, "hostname"),
+                (self.ipv4, "ipv4"),
+                (self.ipv6, "ipv6"),
+                (self.uri, "uri"),
+            ]
+            .into_iter()
+            .find_map(|(set, format)| set.unwrap_or(false).then_some(format))
+        }
+    }
+
     #[derive(Clone, PartialEq, Message)]
     pub struct Int32Rules {
         #[prost(int32, optional, tag = "2")]
@@ -163,6 +272,15 @@ mod types {
         pub gte: Option<u64>,
     }
 
+    /// `validate.RepeatedRules` — item count bounds for `repeated` fields.
+    #[derive(Clone, PartialEq, Message)]
+    pub struct RepeatedRules {
+        #[prost(uint64, optional, tag = "1")]
+        pub min_items: Option<u64>,
+        #[prost(uint64, optional, tag = "2")]
+        pub max_items: Option<u64>,
+    }
+
     #[derive(Clone, PartialEq, Message)]
     pub struct EnumRules {
         #[prost(int32, repeated, tag = "4")]
@@ -191,6 +309,16 @@ mod types {
         pub name: Option<String>,
         #[prost(message, repeated, tag = "2")]
         pub method: Vec<MethodDescriptorProto>,
+        #[prost(message, optional, tag = "3")]
+        pub options: Option<ServiceOptions>,
+    }
+
+    /// Service options with the `google.api.default_host` extension (field 1049).
+    #[derive(Clone, PartialEq, Message)]
+    pub struct ServiceOptions {
+        /// Hostname the service is served from (e.g., `"api.example.com"`).
+        #[prost(string, optional, tag = "1049")]
+        pub default_host: Option<String>,
     }
 
     #[derive(Clone, PartialEq, Message)]
@@ -219,10 +347,18 @@ mod types {
     /// `google.api.HttpRule` — defines REST mapping for an RPC.
     #[derive(Clone, PartialEq, Message)]
     pub struct HttpRule {
-        #[prost(oneof = "HttpPattern", tags = "2, 3, 4, 5, 6")]
+        #[prost(oneof = "HttpPattern", tags = "2, 3, 4, 5, 6, 8")]
         pub pattern: Option<HttpPattern>,
         #[prost(string, tag = "7")]
         pub body: String,
+        /// Response field returned as the HTTP body (e.g., `"user"`); empty
+        /// returns the whole response message.
+        #[prost(string, tag = "12")]
+        pub response_body: String,
+        /// Extra REST mappings for the same RPC. Nested bindings inside an
+        /// additional binding are ignored, per the `google.api.http` spec.
+        #[prost(message, repeated, tag = "11")]
+        pub additional_bindings: Vec<HttpRule>,
     }
 
     #[derive(Clone, PartialEq, prost::Oneof)]
@@ -237,6 +373,20 @@ mod types {
         Delete(String),
         #[prost(string, tag = "6")]
         Patch(String),
+        /// `custom: { kind: "HEAD", path: "..." }` — any other HTTP verb.
+        #[prost(message, tag = "8")]
+        Custom(CustomHttpPattern),
+    }
+
+    /// `google.api.CustomHttpPattern` — an HTTP verb outside the five named ones.
+    #[derive(Clone, PartialEq, Message)]
+    pub struct CustomHttpPattern {
+        /// HTTP method name as written in the proto (e.g., `"HEAD"`).
+        #[prost(string, tag = "1")]
+        pub kind: String,
+        /// URL path template.
+        #[prost(string, tag = "2")]
+        pub path: String,
     }
 }
 
@@ -244,42 +394,90 @@ pub use types::*;
 
 /// Proto field type constants (from `google.protobuf.FieldDescriptorProto.Type`).
 pub mod field_type {
+    /// `TYPE_DOUBLE = 1`
+    pub const DOUBLE: i32 = 1;
+    /// `TYPE_FLOAT = 2`
+    pub const FLOAT: i32 = 2;
     /// `TYPE_INT32 = 5`
     pub const INT32: i32 = 5;
     /// `TYPE_INT64 = 3`
     pub const INT64: i32 = 3;
     /// `TYPE_UINT32 = 13`
     pub const UINT32: i32 = 13;
+    /// `TYPE_FIXED32 = 7`
+    pub const FIXED32: i32 = 7;
+    /// `TYPE_SFIXED32 = 15`
+    pub const SFIXED32: i32 = 15;
+    /// `TYPE_SINT32 = 17`
+    pub const SINT32: i32 = 17;
     /// `TYPE_UINT64 = 4`
     pub const UINT64: i32 = 4;
+    /// `TYPE_FIXED64 = 6`
+    pub const FIXED64: i32 = 6;
+    /// `TYPE_SFIXED64 = 16`
+    pub const SFIXED64: i32 = 16;
+    /// `TYPE_SINT64 = 18`
+    pub const SINT64: i32 = 18;
     /// `TYPE_BOOL = 8`
     pub const BOOL: i32 = 8;
     /// `TYPE_STRING = 9`
     pub const STRING: i32 = 9;
     /// `TYPE_MESSAGE = 11`
     pub const MESSAGE: i32 = 11;
+    /// `TYPE_BYTES = 12`
+    pub const BYTES: i32 = 12;
     /// `TYPE_ENUM = 14`
     pub const ENUM: i32 = 14;
 }
 
+/// Proto field label constants (from `google.protobuf.FieldDescriptorProto.Label`).
+pub mod field_label {
+    /// `LABEL_OPTIONAL = 1`
+    pub const OPTIONAL: i32 = 1;
+    /// `LABEL_REQUIRED = 2`
+    pub const REQUIRED: i32 = 2;
+    /// `LABEL_REPEATED = 3`
+    pub const REPEATED: i32 = 3;
+}
+
 /// Extract `(http_method, path)` from a method's `google.api.http` annotation.
+///
+/// Only the primary binding is returned; see [`http_rule_pattern`] for
+/// inspecting [`HttpRule::additional_bindings`].
 #[must_use]
 pub fn extract_http_pattern(method: &MethodDescriptorProto) -> Option<(&'static str, &str)> {
-    let pattern = method
+    method
         .options
         .as_ref()
         .and_then(|o| o.http.as_ref())
-        .and_then(|h| h.pattern.as_ref())?;
+        .and_then(http_rule_pattern)
+}
 
-    Some(match pattern {
+/// Extract `(http_method, path)` from a single [`HttpRule`] binding.
+///
+/// Custom bindings resolve to the lowercase method for the verbs in
+/// [`CUSTOM_HTTP_METHODS`] (matched case-insensitively); any other custom
+/// kind returns `None`.
+#[must_use]
+pub fn http_rule_pattern(rule: &HttpRule) -> Option<(&'static str, &str)> {
+    Some(match rule.pattern.as_ref()? {
         HttpPattern::Get(p) => ("get", p.as_str()),
         HttpPattern::Put(p) => ("put", p.as_str()),
         HttpPattern::Post(p) => ("post", p.as_str()),
         HttpPattern::Delete(p) => ("delete", p.as_str()),
         HttpPattern::Patch(p) => ("patch", p.as_str()),
+        HttpPattern::Custom(c) => {
+            let method = CUSTOM_HTTP_METHODS
+                .iter()
+                .find(|m| m.eq_ignore_ascii_case(&c.kind))?;
+            (*method, c.path.as_str())
+        }
     })
 }
 
+/// HTTP verbs supported in `custom` bindings, as lowercase method names.
+pub const CUSTOM_HTTP_METHODS: &[&str] = &["head", "options"];
+
 #[cfg(test)]
 mod tests {
     use prost::Message as _;
@@ -295,6 +493,8 @@ mod tests {
                 http: Some(HttpRule {
                     pattern: Some(pattern),
                     body: String::new(),
+                    response_body: String::new(),
+                    additional_bindings: vec![],
                 }),
             }),
             client_streaming: None,
@@ -368,6 +568,41 @@ mod tests {
         assert!(extract_http_pattern(&method).is_none());
     }
 
+    #[test]
+    fn extract_custom_pattern() {
+        let custom = |kind: &str| {
+            method_with_pattern(HttpPattern::Custom(CustomHttpPattern {
+                kind: kind.to_string(),
+                path: "/v1/items/{id}".to_string(),
+            }))
+        };
+
+        let head = custom("HEAD");
+        let (http_method, path) = extract_http_pattern(&head).unwrap();
+        assert_eq!(http_method, "head");
+        assert_eq!(path, "/v1/items/{id}");
+        assert_eq!(
+            extract_http_pattern(&custom("options")).unwrap().0,
+            "options"
+        );
+        assert!(extract_http_pattern(&custom("PURGE")).is_none());
+    }
+
+    #[test]
+    fn custom_pattern_round_trip() {
+        let rule = HttpRule {
+            pattern: Some(HttpPattern::Custom(CustomHttpPattern {
+                kind: "HEAD".to_string(),
+                path: "/v1/items".to_string(),
+            })),
+            body: String::new(),
+            response_body: String::new(),
+            additional_bindings: vec![],
+        };
+        let decoded = HttpRule::decode(rule.encode_to_vec().as_slice()).unwrap();
+        assert_eq!(decoded, rule);
+    }
+
     #[test]
     fn returns_none_without_pattern() {
         let method = MethodDescriptorProto {
@@ -378,6 +613,8 @@ mod tests {
                 http: Some(HttpRule {
                     pattern: None,
                     body: "*".to_string(),
+                    response_body: String::new(),
+                    additional_bindings: vec![],
                 }),
             }),
             client_streaming: None,
@@ -386,6 +623,28 @@ mod tests {
         assert!(extract_http_pattern(&method).is_none());
     }
 
+    #[test]
+    fn additional_bindings_round_trip() {
+        let rule = HttpRule {
+            pattern: Some(HttpPattern::Get("/v1/users/{id}".to_string())),
+            body: String::new(),
+            response_body: String::new(),
+            additional_bindings: vec![HttpRule {
+                pattern: Some(HttpPattern::Get("/v1/users:byEmail".to_string())),
+                body: String::new(),
+                response_body: String::new(),
+                additional_bindings: vec![],
+            }],
+        };
+
+        let decoded = HttpRule::decode(rule.encode_to_vec().as_slice()).unwrap();
+        assert_eq!(decoded, rule);
+        assert_eq!(
+            http_rule_pattern(&decoded.additional_bindings[0]),
+            Some(("get", "/v1/users:byEmail"))
+        );
+    }
+
     #[test]
     fn field_type_constants() {
         assert_eq!(field_type::STRING, 9);
@@ -403,10 +662,17 @@ mod tests {
                     name: Some("Req".to_string()),
                     field: vec![FieldDescriptorProto {
                         name: Some("name".to_string()),
+                        label: None,
+                        proto3_optional: None,
+                        default_value: None,
+                        json_name: None,
+                        oneof_index: None,
                         r#type: Some(field_type::STRING),
                         type_name: None,
                         options: None,
                     }],
+                    oneof_decl: vec![],
+                    options: None,
                     nested_type: vec![],
                 }],
                 enum_type: vec![],
@@ -415,7 +681,9 @@ mod tests {
                     method: vec![method_with_pattern(HttpPattern::Post(
                         "/v1/test".to_string(),
                     ))],
+                    options: None,
                 }],
+                source_code_info: None,
             }],
         };
 
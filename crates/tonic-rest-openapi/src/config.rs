@@ -10,6 +10,16 @@
 //! # api/openapi/config.yaml
 //! error_schema_ref: "#/components/schemas/ErrorResponse"
 //!
+//! # Declared responses with these codes also reference the error schema
+//! # (`default` responses always do).
+//! error_response_codes: [401, 403, 404]
+//!
+//! # Opt-in: replace `default` error responses with the codes above.
+//! explicit_error_responses: true
+//!
+//! # Opt-in: document gateway `X-RateLimit-*` headers and a 429 response.
+//! rate_limit_headers: true
+//!
 //! # Proto method names that return UNIMPLEMENTED at runtime.
 //! unimplemented_methods:
 //!   - SetupMfa
@@ -20,27 +30,167 @@
 //!   - Login
 //!   - SignUp
 //!
+//! # Deprecated proto methods; `sunset` adds `x-sunset` and a `Sunset` header.
+//! deprecated_methods:
+//!   - GetLegacyProfile
+//!   - method: ListOldUsers
+//!     sunset: 2027-01-31
+//!
+//! # Removed proto methods, documented as `410 Gone`.
+//! gone_methods:
+//!   - GetV1Profile
+//!
+//! # Override the `201 Created` heuristic per proto method.
+//! created_methods: [ImportUsers]
+//! not_created_methods: [CreateOrUpdateSettings]
+//!
+//! # Security schemes per proto method, replacing the default Bearer.
+//! operation_security:
+//!   RotateKeys: [mtls]
+//!   ExportUsers: [apiKeyAuth]
+//!
+//! # Extra `components.securitySchemes` registered next to `bearerAuth`.
+//! security_schemes:
+//!   apiKeyAuth:
+//!     type: apiKey
+//!     in: header
+//!     name: X-API-Key
+//!   oauth:
+//!     type: oauth2
+//!     flows:
+//!       clientCredentials:
+//!         tokenUrl: https://auth.example.com/oauth/token
+//!         scopes:
+//!           users.read: Read users
+//!
 //! # Endpoints that should use text/plain instead of application/json.
 //! plain_text_endpoints:
 //!   - path: /health/live
 //!     example: "OK"
 //!   - path: /metrics
 //!
+//! # Endpoints whose request body is `application/x-www-form-urlencoded`.
+//! form_urlencoded_endpoints:
+//!   - path: /oauth/token
+//!     method: post
+//!
 //! # Metrics endpoint for response header enrichment.
 //! metrics_path: /metrics
 //!
 //! # Readiness probe path for 503 response addition.
 //! readiness_path: /health/ready
 //!
+//! # Liveness probe path: a plain `200`, never error responses.
+//! liveness_path: /health/live
+//!
+//! # Servers, optionally templated with variables. Defaults to one
+//! # `https://{host}` entry per proto `(google.api.default_host)`.
+//! servers:
+//!   - url: "https://{region}.api.example.com"
+//!     description: Production
+//!     variables:
+//!       region:
+//!         enum: [us, eu]
+//!         default: us
+//!         description: Deployment region
+//!
+//! # Redoc `x-codeSamples` templates, keyed by proto method name
+//! # (or "*" for every operation).
+//! code_sample_templates:
+//!   "*":
+//!     - lang: Shell
+//!       label: curl
+//!       source: "curl -X {method} https://api.example.com{path}"
+//!
+//! # Pinned examples, keyed by operation ID or proto method name; they
+//! # replace the heuristic examples.
+//! operation_examples:
+//!   AuthService_Login:
+//!     request:
+//!       email: jane@example.com
+//!       password: hunter2
+//!     response:
+//!       accessToken: eyJhbGciOi...
+//!
+//! # Doc-tool `x-badges`, keyed by proto method name.
+//! operation_badges:
+//!   RotateKeys:
+//!     - name: Admin-only
+//!       color: "#d9534f"
+//!
+//! # Webhook `callbacks`, keyed by proto method name.
+//! callbacks:
+//!   CreateSubscription:
+//!     - event: onDelivery
+//!       url: "{$request.body#/callbackUrl}"
+//!       request_schema_ref: "#/components/schemas/webhooks.v1.Delivery"
+//!
+//! # Fail instead of emitting a spec with dangling `$ref`s.
+//! strict_refs: true
+//!
+//! # Fail on dangling `$ref`s, empty `required` arrays, or missing `responses`.
+//! validate_output: true
+//!
+//! # Length bounds (36) on UUID path parameters for strict validators.
+//! uuid_length_bounds: true
+//!
+//! # Opt-in: drop `format` values outside the OpenAPI 3.1 set plus these.
+//! allowed_formats: [sku]
+//!
+//! # `contentMediaType` for base64-encoded `bytes` fields.
+//! bytes_content_media_type: application/octet-stream
+//!
+//! # Rename dotted schemas for client generators: underscore | pascal_case.
+//! sanitize_schema_names: underscore
+//!
+//! # Casing of prefix-stripped enum values: lower | upper | camel | original.
+//! enum_case: lower
+//!
+//! # Opt-in: keep original proto names in `x-enum-varnames`.
+//! enum_varnames: true
+//!
+//! # Opt-in: whole-body request examples composed from property examples.
+//! body_level_examples: true
+//!
+//! # Opt-in: `maxItems` on list response arrays from the `page_size` bound.
+//! page_max_items: true
+//!
+//! # Opt-in: document an RFC 8288 `Link` header on paginated list responses.
+//! pagination_link_header: true
+//!
+//! # Opt-in: mark operations rewritten to `201` with `x-rewritten-from: "200"`.
+//! annotate_rewrites: true
+//!
+//! # Redoc `x-tagGroups`: each tag joins the first group with a matching
+//! # pattern (`*` wildcards); unmatched tags land in "Other".
+//! tag_groups:
+//!   - name: Identity
+//!     tags: [Auth*, User*]
+//!   - name: API
+//!     tags: ["*Service"]
+//!
+//! # `writeOnly` even on response schemas (exact `schema.field`).
+//! force_write_only: [auth.v1.SetupMfaResponse.recoveryCode]
+//!
+//! # Request fields sent as HTTP headers (`client_version` → `client-version`).
+//! header_fields: [client_version]
+//!
+//! # Spec `info.version` from CI: `version_env` wins when set, else `version`.
+//! info:
+//!   version_env: BUILD_VERSION
+//!   version: 0.0.0-dev
+//!
 //! # Transform toggles (all default to true).
 //! transforms:
 //!   upgrade_to_3_1: true
 //!   annotate_sse: true
 //! ```
 
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 
 use serde::Deserialize;
+use serde_yaml_ng::Value;
 
 /// Project-level `OpenAPI` generation config.
 ///
@@ -48,28 +198,64 @@ use serde::Deserialize;
 /// [`PatchConfig`](crate::PatchConfig) via [`PatchConfig::with_project_config`](crate::PatchConfig::with_project_config).
 #[derive(Debug, Deserialize)]
 #[serde(default)]
+#[expect(clippy::struct_excessive_bools)]
 pub struct ProjectConfig {
     /// `$ref` path for the REST error response schema.
     pub error_schema_ref: String,
 
+    /// HTTP status codes whose declared responses reference the error
+    /// schema, in addition to `default`. Codes an operation does not declare
+    /// are not added.
+    pub error_response_codes: Vec<u16>,
+
+    /// Replace each `default` error response with explicit entries for
+    /// `error_response_codes` (or `400, 401, 403, 404, 500` when that list is
+    /// empty), all referencing the error schema.
+    pub explicit_error_responses: bool,
+
+    /// Document `X-RateLimit-Limit`/`-Remaining`/`-Reset` on every
+    /// operation's success and `429` responses, adding the `429` if missing.
+    pub rate_limit_headers: bool,
+
     /// Proto method short names for endpoints returning `UNIMPLEMENTED`.
     pub unimplemented_methods: Vec<String>,
 
     /// Proto method short names for public (no-auth) endpoints.
     pub public_methods: Vec<String>,
 
-    /// Proto method short names for deprecated endpoints.
-    pub deprecated_methods: Vec<String>,
+    /// Deprecated endpoints: bare proto method short names, or
+    /// `{method, sunset}` entries carrying a removal date.
+    pub deprecated_methods: Vec<DeprecatedMethod>,
+
+    /// Proto method short names for permanently removed endpoints, kept in
+    /// the proto only to reserve their numbering. Documented as `410 Gone`.
+    pub gone_methods: Vec<String>,
+
+    /// Proto method short names always answered with `201 Created`, whatever
+    /// their name.
+    pub created_methods: Vec<String>,
+
+    /// Proto method short names that keep `200 OK` even when the create
+    /// heuristic matches them (e.g., idempotent upserts).
+    pub not_created_methods: Vec<String>,
 
     /// Endpoints that should use `text/plain` instead of `application/json`.
     pub plain_text_endpoints: Vec<PlainTextEndpoint>,
 
+    /// Endpoints whose request body is `application/x-www-form-urlencoded`
+    /// instead of `application/json`.
+    pub form_urlencoded_endpoints: Vec<FormUrlEncodedEndpoint>,
+
     /// Metrics endpoint path for response header enrichment (e.g., `/metrics`).
     pub metrics_path: Option<String>,
 
     /// Readiness probe path for adding 503 response (e.g., `/health/ready`).
     pub readiness_path: Option<String>,
 
+    /// Liveness probe path documented with only a `200 OK` `text/plain`
+    /// response (e.g., `/health/live`).
+    pub liveness_path: Option<String>,
+
     /// Server entries for the `servers` block.
     pub servers: Vec<ServerEntry>,
 
@@ -82,10 +268,134 @@ pub struct ProjectConfig {
     /// Additional field name patterns to mark as `readOnly`.
     pub read_only_fields: Vec<String>,
 
+    /// Exact `schema.field` entries (e.g., `auth.v1.SetupMfaResponse.secret`)
+    /// marked `writeOnly` even when the schema is a response.
+    pub force_write_only: Vec<String>,
+
+    /// Proto request field names carried in HTTP headers instead of the body
+    /// or query string. The header name is the field name with `_` replaced
+    /// by `-` (e.g. `client_version` → `client-version`).
+    pub header_fields: Vec<String>,
+
+    /// `x-codeSamples` templates keyed by proto method short name, or `"*"`
+    /// for samples applied to every operation.
+    pub code_sample_templates: HashMap<String, Vec<CodeSample>>,
+
+    /// Request/response examples keyed by operation ID or proto method short
+    /// name, overriding the generated heuristic examples.
+    pub operation_examples: HashMap<String, OperationExample>,
+
+    /// `x-badges` keyed by proto method short name (or `Service.Method`).
+    pub operation_badges: HashMap<String, Vec<Badge>>,
+
+    /// `OpenAPI` `callbacks` keyed by proto method short name (or
+    /// `Service.Method`), for webhook-style RPCs.
+    pub callbacks: HashMap<String, Vec<CallbackDef>>,
+
+    /// Per-operation security: proto method short name → security scheme
+    /// names, replacing the default Bearer requirement for that operation.
+    pub operation_security: HashMap<String, Vec<String>>,
+
+    /// Security scheme definitions registered under
+    /// `components.securitySchemes`, keyed by scheme name.
+    pub security_schemes: BTreeMap<String, SecurityScheme>,
+
+    /// Fail the patch when any local `$ref` in the output does not resolve.
+    pub strict_refs: bool,
+
+    /// Fail the patch when the output has structural mistakes: dangling
+    /// `$ref`s, empty `required` arrays, or operations without `responses`.
+    pub validate_output: bool,
+
+    /// Add `minLength`/`maxLength` of 36 to UUID path parameters.
+    pub uuid_length_bounds: bool,
+
+    /// Extra `format` values to keep alongside
+    /// [`STANDARD_FORMATS`](crate::STANDARD_FORMATS). When set (even to an
+    /// empty list), all other formats are removed; when absent, formats are
+    /// left untouched.
+    pub allowed_formats: Option<Vec<String>>,
+
+    /// `contentMediaType` added next to `contentEncoding: base64` on proto
+    /// `bytes` fields. Omitted when absent.
+    pub bytes_content_media_type: Option<String>,
+
+    /// Rewrite dotted `components/schemas` names (and every `$ref` to them)
+    /// for client generators that reject dots. Names are left as-is when absent.
+    pub sanitize_schema_names: Option<SanitizeMode>,
+
+    /// Casing of prefix-stripped enum values; must match the runtime serde
+    /// format of the enum fields.
+    pub enum_case: EnumCase,
+
+    /// Emit `x-enum-varnames` / `x-enum-descriptions` next to rewritten enums.
+    pub enum_varnames: bool,
+
+    /// Compose a whole-body request `example` from per-property examples.
+    pub body_level_examples: bool,
+
+    /// Set `maxItems` on paginated response item arrays to the largest
+    /// `page_size` maximum of the requests returning them.
+    pub page_max_items: bool,
+
+    /// Document a `Link` pagination header (RFC 8288) on the `200` response
+    /// of operations returning a discovered paginated list response.
+    pub pagination_link_header: bool,
+
+    /// Mark operations whose `200` became `201 Created` with
+    /// `x-rewritten-from: "200"`, to audit the create heuristic.
+    pub annotate_rewrites: bool,
+
+    /// Redoc `x-tagGroups` sidebar groups. Non-empty enables the transform.
+    pub tag_groups: Vec<TagGroup>,
+
     /// Transform toggles.
     pub transforms: TransformConfig,
 }
 
+/// How dotted schema names such as `auth.v1.LoginRequest` are rewritten.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SanitizeMode {
+    /// Replace dots with underscores: `auth_v1_LoginRequest`.
+    Underscore,
+    /// Capitalize and join the segments: `AuthV1LoginRequest`.
+    PascalCase,
+}
+
+/// Casing applied to enum values after their shared prefix is stripped.
+///
+/// Given `HEALTH_STATUS_PARTIALLY_HEALTHY` with the `HEALTH_STATUS_` prefix:
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnumCase {
+    /// `partially_healthy` — matches `define_enum_serde!` with a prefix.
+    #[default]
+    Lower,
+    /// `PARTIALLY_HEALTHY` — matches `define_enum_serde!` with a prefix
+    /// and `upper`.
+    Upper,
+    /// `partiallyHealthy` — matches `define_enum_serde!` with a prefix and
+    /// `camel`.
+    Camel,
+    /// `HEALTH_STATUS_PARTIALLY_HEALTHY`, the proto name, unstripped —
+    /// matches `define_enum_serde!` without a prefix.
+    Original,
+}
+
+impl EnumCase {
+    /// Recase a lowercase prefix-stripped value; `None` for [`Self::Original`],
+    /// which keeps the proto name.
+    pub(crate) fn convert(self, stripped: &str) -> Option<String> {
+        match self {
+            Self::Lower => Some(stripped.to_string()),
+            Self::Upper => Some(stripped.to_uppercase()),
+            Self::Camel => Some(crate::discover::snake_to_lower_camel(stripped)),
+            Self::Original => None,
+        }
+    }
+}
+
 /// An endpoint that returns plain text instead of JSON.
 #[derive(Debug, Clone, Deserialize)]
 pub struct PlainTextEndpoint {
@@ -95,6 +405,15 @@ pub struct PlainTextEndpoint {
     pub example: Option<String>,
 }
 
+/// An endpoint that accepts an `application/x-www-form-urlencoded` request body.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FormUrlEncodedEndpoint {
+    /// HTTP path (e.g., `/oauth/token`).
+    pub path: String,
+    /// HTTP method, case-insensitive (e.g., `post`).
+    pub method: String,
+}
+
 /// A server entry for the `OpenAPI` `servers` block.
 #[derive(Debug, Clone, Deserialize)]
 pub struct ServerEntry {
@@ -103,12 +422,252 @@ pub struct ServerEntry {
     /// Optional human-readable server description.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// Substitution variables for `{name}` placeholders in `url`.
+    #[serde(default)]
+    pub variables: BTreeMap<String, ServerVariable>,
+}
+
+impl ServerEntry {
+    /// Server for a proto `(google.api.default_host)`, e.g. `api.example.com`
+    /// → `https://api.example.com`. Hosts that already carry a scheme are
+    /// used as-is.
+    #[must_use]
+    pub fn from_default_host(host: &str) -> Self {
+        let url = if host.contains("://") {
+            host.to_string()
+        } else {
+            format!("https://{host}")
+        };
+        Self {
+            url,
+            description: None,
+            variables: BTreeMap::new(),
+        }
+    }
+
+    /// Check that every variable's `default` is one of its `enum` values.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidServerVariable`](crate::Error::InvalidServerVariable)
+    /// for the first variable whose default is not listed in its `enum`.
+    pub fn validate(&self) -> crate::error::Result<()> {
+        for (name, var) in &self.variables {
+            if !var.enum_values.is_empty() && !var.enum_values.contains(&var.default) {
+                return Err(crate::error::Error::InvalidServerVariable {
+                    url: self.url.clone(),
+                    variable: name.clone(),
+                    reason: format!(
+                        "default '{}' is not one of {:?}",
+                        var.default, var.enum_values
+                    ),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A server URL template variable (`OpenAPI` Server Variable Object).
+#[derive(Debug, Clone, Deserialize)]
+pub struct ServerVariable {
+    /// Allowed values; empty means any value is accepted.
+    #[serde(default, rename = "enum")]
+    pub enum_values: Vec<String>,
+    /// Value used when the client does not supply one.
+    pub default: String,
+    /// Optional human-readable description.
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+/// An `OpenAPI` security scheme declared in the project config.
+///
+/// The Bearer scheme (`bearerAuth`) is always added; these are registered
+/// alongside it and selected per operation through `operation_security`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum SecurityScheme {
+    /// API key sent in a header, query parameter, or cookie.
+    ApiKey {
+        /// Where the key is sent.
+        #[serde(rename = "in")]
+        location: ApiKeyLocation,
+        /// Header, query parameter, or cookie name (e.g., `X-API-Key`).
+        name: String,
+        /// Optional human-readable description.
+        #[serde(default)]
+        description: Option<String>,
+    },
+    /// `OAuth2` with one or more flows.
+    Oauth2 {
+        /// Supported `OAuth2` flows.
+        flows: Box<OAuthFlows>,
+        /// Optional human-readable description.
+        #[serde(default)]
+        description: Option<String>,
+    },
+}
+
+impl SecurityScheme {
+    /// Check that every `OAuth2` flow carries the URLs its type requires.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidSecurityScheme`](crate::Error::InvalidSecurityScheme)
+    /// for an `oauth2` scheme without flows, or a flow missing its
+    /// `authorizationUrl` (`implicit`, `authorizationCode`) or `tokenUrl`
+    /// (`password`, `clientCredentials`, `authorizationCode`).
+    pub fn validate(&self, name: &str) -> crate::error::Result<()> {
+        let Self::Oauth2 { flows, .. } = self else {
+            return Ok(());
+        };
+        let invalid = |reason: String| crate::error::Error::InvalidSecurityScheme {
+            name: name.to_string(),
+            reason,
+        };
+        let checks = [
+            ("implicit", &flows.implicit, true, false),
+            ("password", &flows.password, false, true),
+            ("clientCredentials", &flows.client_credentials, false, true),
+            ("authorizationCode", &flows.authorization_code, true, true),
+        ];
+        if checks.iter().all(|(_, flow, ..)| flow.is_none()) {
+            return Err(invalid("oauth2 scheme has no flows".to_string()));
+        }
+        for (flow_name, flow, needs_authorization, needs_token) in checks {
+            let Some(flow) = flow else { continue };
+            if needs_authorization && flow.authorization_url.is_none() {
+                return Err(invalid(format!(
+                    "{flow_name} flow requires authorizationUrl"
+                )));
+            }
+            if needs_token && flow.token_url.is_none() {
+                return Err(invalid(format!("{flow_name} flow requires tokenUrl")));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Location of an `apiKey` security scheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ApiKeyLocation {
+    /// HTTP request header.
+    Header,
+    /// Query string parameter.
+    Query,
+    /// Cookie.
+    Cookie,
+}
+
+impl ApiKeyLocation {
+    /// `OpenAPI` `in` value.
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            Self::Header => "header",
+            Self::Query => "query",
+            Self::Cookie => "cookie",
+        }
+    }
+}
+
+/// `OAuth2` flows of an `oauth2` security scheme; at least one must be set.
+///
+/// Keys use the `OpenAPI` spelling (`clientCredentials`, `authorizationCode`).
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct OAuthFlows {
+    /// Implicit flow.
+    pub implicit: Option<OAuthFlow>,
+    /// Resource owner password flow.
+    pub password: Option<OAuthFlow>,
+    /// Client credentials flow.
+    pub client_credentials: Option<OAuthFlow>,
+    /// Authorization code flow.
+    pub authorization_code: Option<OAuthFlow>,
+}
+
+/// A single `OAuth2` flow, with `OpenAPI` key spelling (`tokenUrl`).
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct OAuthFlow {
+    /// Authorization endpoint (`implicit`, `authorization_code`).
+    pub authorization_url: Option<String>,
+    /// Token endpoint (`password`, `client_credentials`, `authorization_code`).
+    pub token_url: Option<String>,
+    /// Optional refresh endpoint.
+    pub refresh_url: Option<String>,
+    /// Scope name → description.
+    pub scopes: BTreeMap<String, String>,
+}
+
+/// A code sample template rendered into an operation's `x-codeSamples`.
+///
+/// `source` supports `{method}` (upper-case HTTP method) and `{path}`
+/// placeholders.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CodeSample {
+    /// Sample language (e.g., `"Shell"`, `"JavaScript"`).
+    pub lang: String,
+    /// Optional tab label shown by Redoc (e.g., `"curl"`).
+    pub label: Option<String>,
+    /// Source template (e.g., `"curl -X {method} https://api.example.com{path}"`).
+    pub source: String,
+}
+
+/// Pinned examples for one operation, written as the media-type `example`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct OperationExample {
+    /// Request body example.
+    pub request: Option<Value>,
+    /// Example for the success (first `2xx`) response body.
+    pub response: Option<Value>,
+}
+
+/// A named group of tags for the Redoc sidebar (`x-tagGroups`).
+#[derive(Debug, Clone, Deserialize)]
+pub struct TagGroup {
+    /// Group heading (e.g., `"API"`).
+    pub name: String,
+    /// Tag name patterns; `*` matches any run of characters (e.g., `"*Service"`).
+    pub tags: Vec<String>,
+}
+
+/// A badge rendered next to an operation by doc tools (`x-badges`).
+#[derive(Debug, Clone, Deserialize)]
+pub struct Badge {
+    /// Badge label (e.g., `"Admin-only"`).
+    pub name: String,
+    /// Optional CSS color (e.g., `"#d9534f"`).
+    #[serde(default)]
+    pub color: Option<String>,
+}
+
+/// A webhook delivered to the client after an operation, emitted under the
+/// operation's `callbacks` block as a `POST` with a JSON request body.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CallbackDef {
+    /// Callback name, the key under `callbacks` (e.g., `"onDelivery"`).
+    pub event: String,
+    /// Runtime expression for the callback URL
+    /// (e.g., `"{$request.body#/callbackUrl}"`).
+    pub url: String,
+    /// `$ref` of the payload sent to the callback URL
+    /// (e.g., `"#/components/schemas/webhooks.v1.Delivery"`).
+    pub request_schema_ref: String,
 }
 
 /// Overrides for the `OpenAPI` `info` block.
 #[derive(Debug, Clone, Default, Deserialize)]
 #[serde(default)]
 pub struct InfoOverrides {
+    /// API title, replacing the one derived from the proto package.
+    pub title: Option<String>,
+    /// API description (`CommonMark`).
+    pub description: Option<String>,
     /// API contact information.
     pub contact: Option<ContactInfo>,
     /// API license information.
@@ -117,6 +676,62 @@ pub struct InfoOverrides {
     pub external_docs: Option<ExternalDocsInfo>,
     /// URL to the Terms of Service.
     pub terms_of_service: Option<String>,
+    /// Environment variable holding the spec's `info.version` (e.g.,
+    /// `BUILD_VERSION` or `GIT_TAG`), read at patch time.
+    pub version_env: Option<String>,
+    /// Explicit `info.version`, used when `version_env` is unset or empty.
+    pub version: Option<String>,
+}
+
+impl InfoOverrides {
+    /// The `info.version` to inject: the non-empty value of `version_env`,
+    /// else `version`. `None` keeps the version already in the spec.
+    #[must_use]
+    pub fn resolved_version(&self) -> Option<String> {
+        self.version_env
+            .as_deref()
+            .and_then(|name| std::env::var(name).ok())
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+            .or_else(|| self.version.clone())
+    }
+}
+
+/// A deprecated endpoint entry in [`ProjectConfig::deprecated_methods`].
+///
+/// Deserializes from either a bare method name (`- OldEndpoint`) or a
+/// mapping with an optional sunset date (`- {method: OldEndpoint, sunset: 2027-01-31}`).
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(from = "DeprecatedMethodRepr")]
+pub struct DeprecatedMethod {
+    /// Proto method short name (or `Service.Method`).
+    pub method: String,
+    /// Date after which the endpoint may be removed, emitted verbatim as
+    /// `x-sunset` and as the documented `Sunset` header example.
+    pub sunset: Option<String>,
+}
+
+/// Wire forms accepted for [`DeprecatedMethod`].
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum DeprecatedMethodRepr {
+    Name(String),
+    Entry {
+        method: String,
+        sunset: Option<String>,
+    },
+}
+
+impl From<DeprecatedMethodRepr> for DeprecatedMethod {
+    fn from(repr: DeprecatedMethodRepr) -> Self {
+        match repr {
+            DeprecatedMethodRepr::Name(method) => Self {
+                method,
+                sunset: None,
+            },
+            DeprecatedMethodRepr::Entry { method, sunset } => Self { method, sunset },
+        }
+    }
 }
 
 /// Contact information for the `OpenAPI` `info.contact` block.
@@ -205,6 +820,7 @@ pub struct TransformConfig {
     pub inject_servers: bool,
 
     /// Rewrite `200` → `201 Created` for create/signup endpoints (phase 3).
+    /// `created_methods` / `not_created_methods` override the name heuristic.
     ///
     /// Detects operations named `Create*`, `SignUp*`, or `Register*` and
     /// changes their success response from 200 to 201.
@@ -216,22 +832,89 @@ pub struct TransformConfig {
     /// `writeOnly`. Fields like `created_at`, `updated_at` are marked `readOnly`.
     /// Additional patterns can be configured via `write_only_fields` / `read_only_fields`.
     pub annotate_field_access: bool,
+
+    /// Annotate paginated list responses with `x-pagination` (phase 12).
+    ///
+    /// Responses with a `next_page_token` and a single repeated items field
+    /// get `x-pagination: { token_field, items_field }`, and the token field
+    /// is marked `readOnly`.
+    pub annotate_pagination: bool,
+
+    /// Annotate operations with `x-proto-source` (phase 12).
+    ///
+    /// Records the proto file, fully-qualified service, and method (plus the
+    /// line, when the descriptor set includes source info) each operation was
+    /// generated from. Off by default so source paths stay out of published
+    /// specs.
+    pub annotate_proto_source: bool,
+
+    /// Type proto `bytes` fields as base64 strings (phase 9).
+    ///
+    /// Proto3 JSON carries `bytes` as base64 strings; `format: byte` and
+    /// `contentEncoding: base64` (plus the optional `bytes_content_media_type`)
+    /// let generators decode them. Path and query parameters bound to `bytes`
+    /// fields get `format: byte` as well.
+    pub annotate_bytes_encoding: bool,
+
+    /// Render `google.protobuf.*Value` wrapper fields as nullable scalars (phase 9).
+    ///
+    /// A `BoolValue` field becomes `type: [boolean, "null"]`, matching its
+    /// JSON encoding, and the wrapper component schemas are removed.
+    pub inline_wrapper_types: bool,
+
+    /// Make proto3 `optional` fields nullable (phase 9).
+    ///
+    /// In 3.1 output `null` joins the property's `type` array (and `enum`,
+    /// when present); in 3.0 output the property gets `nullable: true`.
+    pub nullable_optional_fields: bool,
+
+    /// Move proto `oneof` members into `oneOf` alternatives (phase 9).
+    pub oneof_schemas: bool,
 }
 
 impl Default for ProjectConfig {
     fn default() -> Self {
         Self {
             error_schema_ref: crate::DEFAULT_ERROR_SCHEMA_REF.to_string(),
+            error_response_codes: Vec::new(),
+            explicit_error_responses: false,
+            rate_limit_headers: false,
             unimplemented_methods: Vec::new(),
             public_methods: Vec::new(),
             deprecated_methods: Vec::new(),
+            gone_methods: Vec::new(),
+            created_methods: Vec::new(),
+            not_created_methods: Vec::new(),
             plain_text_endpoints: Vec::new(),
+            form_urlencoded_endpoints: Vec::new(),
             metrics_path: None,
             readiness_path: None,
+            liveness_path: None,
             servers: Vec::new(),
             info: InfoOverrides::default(),
             write_only_fields: Vec::new(),
             read_only_fields: Vec::new(),
+            force_write_only: Vec::new(),
+            header_fields: Vec::new(),
+            code_sample_templates: HashMap::new(),
+            operation_examples: HashMap::new(),
+            operation_badges: HashMap::new(),
+            callbacks: HashMap::new(),
+            operation_security: HashMap::new(),
+            security_schemes: BTreeMap::new(),
+            strict_refs: false,
+            validate_output: false,
+            uuid_length_bounds: false,
+            allowed_formats: None,
+            bytes_content_media_type: None,
+            sanitize_schema_names: None,
+            enum_case: EnumCase::Lower,
+            enum_varnames: false,
+            body_level_examples: false,
+            page_max_items: false,
+            tag_groups: Vec::new(),
+            pagination_link_header: false,
+            annotate_rewrites: false,
             transforms: TransformConfig::default(),
         }
     }
@@ -250,6 +933,12 @@ impl Default for TransformConfig {
             inject_servers: true,
             rewrite_create_responses: true,
             annotate_field_access: true,
+            annotate_pagination: true,
+            annotate_proto_source: false,
+            annotate_bytes_encoding: true,
+            inline_wrapper_types: true,
+            nullable_optional_fields: true,
+            oneof_schemas: true,
         }
     }
 }
@@ -274,28 +963,66 @@ mod tests {
     #[test]
     fn deserialize_defaults() {
         let config: ProjectConfig = serde_yaml_ng::from_str("{}").unwrap();
+        assert!(config.error_response_codes.is_empty());
+        assert!(!config.explicit_error_responses);
+        assert!(!config.rate_limit_headers);
         assert!(config.unimplemented_methods.is_empty());
+        assert!(config.gone_methods.is_empty());
+        assert!(config.created_methods.is_empty());
+        assert!(config.not_created_methods.is_empty());
         assert!(config.public_methods.is_empty());
         assert!(config.deprecated_methods.is_empty());
         assert!(config.plain_text_endpoints.is_empty());
+        assert!(config.form_urlencoded_endpoints.is_empty());
         assert!(config.metrics_path.is_none());
         assert!(config.readiness_path.is_none());
+        assert!(config.liveness_path.is_none());
         assert!(config.servers.is_empty());
         assert!(config.info.contact.is_none());
         assert!(config.info.license.is_none());
         assert!(config.write_only_fields.is_empty());
         assert!(config.read_only_fields.is_empty());
+        assert!(config.force_write_only.is_empty());
+        assert!(config.header_fields.is_empty());
+        assert!(config.code_sample_templates.is_empty());
+        assert!(config.operation_examples.is_empty());
+        assert!(config.operation_badges.is_empty());
+        assert!(config.callbacks.is_empty());
+        assert!(config.operation_security.is_empty());
+        assert!(config.security_schemes.is_empty());
+        assert!(!config.strict_refs);
+        assert!(!config.validate_output);
+        assert!(!config.uuid_length_bounds);
+        assert!(config.allowed_formats.is_none());
+        assert!(config.bytes_content_media_type.is_none());
+        assert!(config.sanitize_schema_names.is_none());
+        assert_eq!(config.enum_case, EnumCase::Lower);
+        assert!(!config.enum_varnames);
+        assert!(!config.body_level_examples);
+        assert!(!config.page_max_items);
+        assert!(!config.pagination_link_header);
+        assert!(!config.annotate_rewrites);
         assert!(config.transforms.upgrade_to_3_1);
         assert!(config.transforms.annotate_sse);
         assert!(config.transforms.inject_servers);
         assert!(config.transforms.rewrite_create_responses);
         assert!(config.transforms.annotate_field_access);
+        assert!(config.transforms.annotate_pagination);
+        assert!(!config.transforms.annotate_proto_source);
+        assert!(config.transforms.annotate_bytes_encoding);
+        assert!(config.transforms.inline_wrapper_types);
+        assert!(config.transforms.nullable_optional_fields);
+        assert!(config.transforms.oneof_schemas);
     }
 
     #[test]
+    #[expect(clippy::too_many_lines)]
     fn deserialize_full() {
         let yaml = r##"
 error_schema_ref: "#/components/schemas/MyError"
+error_response_codes: [404, 409]
+explicit_error_responses: true
+rate_limit_headers: true
 unimplemented_methods:
   - SetupMfa
   - DisableMfa
@@ -303,12 +1030,21 @@ public_methods:
   - Authenticate
 deprecated_methods:
   - OldEndpoint
+  - method: RetiringEndpoint
+    sunset: 2027-01-31
+gone_methods: [RemovedEndpoint]
+created_methods: [ImportUsers]
+not_created_methods: [CreateOrUpdateSettings]
 plain_text_endpoints:
   - path: /health/live
     example: "OK"
   - path: /metrics
+form_urlencoded_endpoints:
+  - path: /oauth/token
+    method: POST
 metrics_path: /metrics
 readiness_path: /health/ready
+liveness_path: /health/live
 servers:
   - url: https://api.example.com
     description: Production
@@ -325,19 +1061,92 @@ info:
     url: https://docs.example.com
     description: Full documentation
   terms_of_service: https://example.com/tos
+  version_env: BUILD_VERSION
+  version: 0.0.0-dev
 write_only_fields:
   - apiKey
 read_only_fields:
   - lastSyncAt
+force_write_only:
+  - auth.v1.SetupMfaResponse.recoveryCode
+header_fields: [client_version]
+code_sample_templates:
+  "*":
+    - lang: Shell
+      label: curl
+      source: "curl -X {method} https://api.example.com{path}"
+operation_examples:
+  AuthService_Login:
+    request:
+      email: jane@example.com
+    response:
+      accessToken: token
+operation_badges:
+  RotateKeys:
+    - name: Admin-only
+      color: "#d9534f"
+    - name: New
+callbacks:
+  CreateSubscription:
+    - event: onDelivery
+      url: "{$request.body#/callbackUrl}"
+      request_schema_ref: "#/components/schemas/Delivery"
+operation_security:
+  RotateKeys: [mtls]
+security_schemes:
+  apiKeyAuth:
+    type: apiKey
+    in: header
+    name: X-API-Key
+  oauth:
+    type: oauth2
+    flows:
+      clientCredentials:
+        tokenUrl: https://auth.example.com/oauth/token
+        scopes:
+          users.read: Read users
+strict_refs: true
+validate_output: true
+uuid_length_bounds: true
+allowed_formats: [sku]
+bytes_content_media_type: image/png
+sanitize_schema_names: pascal_case
+enum_case: upper
+enum_varnames: true
+body_level_examples: true
+page_max_items: true
+pagination_link_header: true
+annotate_rewrites: true
+tag_groups:
+  - name: API
+    tags: ["*Service"]
 transforms:
   add_security: false
   inject_servers: false
 "##;
         let config: ProjectConfig = serde_yaml_ng::from_str(yaml).unwrap();
         assert_eq!(config.error_schema_ref, "#/components/schemas/MyError");
+        assert_eq!(config.error_response_codes, vec![404, 409]);
+        assert!(config.explicit_error_responses);
+        assert!(config.rate_limit_headers);
         assert_eq!(config.unimplemented_methods, vec!["SetupMfa", "DisableMfa"]);
+        assert_eq!(config.gone_methods, vec!["RemovedEndpoint"]);
+        assert_eq!(config.created_methods, vec!["ImportUsers"]);
+        assert_eq!(config.not_created_methods, vec!["CreateOrUpdateSettings"]);
         assert_eq!(config.public_methods, vec!["Authenticate"]);
-        assert_eq!(config.deprecated_methods, vec!["OldEndpoint"]);
+        assert_eq!(
+            config.deprecated_methods,
+            vec![
+                DeprecatedMethod {
+                    method: "OldEndpoint".to_string(),
+                    sunset: None,
+                },
+                DeprecatedMethod {
+                    method: "RetiringEndpoint".to_string(),
+                    sunset: Some("2027-01-31".to_string()),
+                },
+            ]
+        );
         assert_eq!(config.plain_text_endpoints.len(), 2);
         assert_eq!(config.plain_text_endpoints[0].path, "/health/live");
         assert_eq!(
@@ -345,8 +1154,12 @@ transforms:
             Some("OK")
         );
         assert!(config.plain_text_endpoints[1].example.is_none());
+        assert_eq!(config.form_urlencoded_endpoints.len(), 1);
+        assert_eq!(config.form_urlencoded_endpoints[0].path, "/oauth/token");
+        assert_eq!(config.form_urlencoded_endpoints[0].method, "POST");
         assert_eq!(config.metrics_path.as_deref(), Some("/metrics"));
         assert_eq!(config.readiness_path.as_deref(), Some("/health/ready"));
+        assert_eq!(config.liveness_path.as_deref(), Some("/health/live"));
         assert_eq!(config.servers.len(), 2);
         assert_eq!(config.servers[0].url, "https://api.example.com");
         assert_eq!(config.servers[0].description.as_deref(), Some("Production"));
@@ -362,8 +1175,62 @@ transforms:
             config.info.terms_of_service.as_deref(),
             Some("https://example.com/tos")
         );
+        assert_eq!(config.info.version_env.as_deref(), Some("BUILD_VERSION"));
+        assert_eq!(config.info.version.as_deref(), Some("0.0.0-dev"));
         assert_eq!(config.write_only_fields, vec!["apiKey"]);
         assert_eq!(config.read_only_fields, vec!["lastSyncAt"]);
+        assert_eq!(
+            config.force_write_only,
+            vec!["auth.v1.SetupMfaResponse.recoveryCode"]
+        );
+        assert_eq!(config.header_fields, vec!["client_version"]);
+        let samples = &config.code_sample_templates["*"];
+        assert_eq!(samples.len(), 1);
+        assert_eq!(samples[0].lang, "Shell");
+        assert_eq!(samples[0].label.as_deref(), Some("curl"));
+        let login = &config.operation_examples["AuthService_Login"];
+        assert_eq!(login.request.as_ref().unwrap()["email"], "jane@example.com");
+        assert_eq!(login.response.as_ref().unwrap()["accessToken"], "token");
+        let badges = &config.operation_badges["RotateKeys"];
+        assert_eq!(badges[0].name, "Admin-only");
+        assert_eq!(badges[0].color.as_deref(), Some("#d9534f"));
+        assert!(badges[1].color.is_none());
+        let callback = &config.callbacks["CreateSubscription"][0];
+        assert_eq!(callback.event, "onDelivery");
+        assert_eq!(callback.url, "{$request.body#/callbackUrl}");
+        assert_eq!(callback.request_schema_ref, "#/components/schemas/Delivery");
+        assert_eq!(config.operation_security["RotateKeys"], vec!["mtls"]);
+        assert!(matches!(
+            &config.security_schemes["apiKeyAuth"],
+            SecurityScheme::ApiKey { location: ApiKeyLocation::Header, name, .. } if name == "X-API-Key"
+        ));
+        let SecurityScheme::Oauth2 { flows, .. } = &config.security_schemes["oauth"] else {
+            panic!("expected oauth2 scheme");
+        };
+        let flow = flows.client_credentials.as_ref().unwrap();
+        assert_eq!(
+            flow.token_url.as_deref(),
+            Some("https://auth.example.com/oauth/token")
+        );
+        assert_eq!(flow.scopes["users.read"], "Read users");
+        assert!(config.strict_refs);
+        assert!(config.validate_output);
+        assert!(config.uuid_length_bounds);
+        assert_eq!(config.allowed_formats, Some(vec!["sku".to_string()]));
+        assert_eq!(
+            config.bytes_content_media_type.as_deref(),
+            Some("image/png")
+        );
+        assert_eq!(config.sanitize_schema_names, Some(SanitizeMode::PascalCase));
+        assert_eq!(config.enum_case, EnumCase::Upper);
+        assert!(config.enum_varnames);
+        assert!(config.body_level_examples);
+        assert!(config.page_max_items);
+        assert!(config.pagination_link_header);
+        assert!(config.annotate_rewrites);
+        assert_eq!(config.tag_groups.len(), 1);
+        assert_eq!(config.tag_groups[0].name, "API");
+        assert_eq!(config.tag_groups[0].tags, vec!["*Service"]);
         assert!(!config.transforms.add_security);
         assert!(!config.transforms.inject_servers);
         // Other transforms keep defaults
@@ -373,6 +1240,64 @@ transforms:
         assert!(config.transforms.annotate_field_access);
     }
 
+    #[test]
+    fn deserialize_server_variables() {
+        let yaml = r#"
+servers:
+  - url: "https://{region}.example.com"
+    variables:
+      region:
+        enum: [us, eu]
+        default: eu
+      port:
+        default: "443"
+"#;
+        let config: ProjectConfig = serde_yaml_ng::from_str(yaml).unwrap();
+        let server = &config.servers[0];
+        assert_eq!(server.variables["region"].enum_values, vec!["us", "eu"]);
+        assert_eq!(server.variables["region"].default, "eu");
+        assert!(server.variables["port"].enum_values.is_empty());
+        assert!(server.validate().is_ok());
+    }
+
+    #[test]
+    fn oauth_flows_reject_snake_case_keys() {
+        let yaml = r"
+security_schemes:
+  oauth:
+    type: oauth2
+    flows:
+      client_credentials:
+        token_url: https://auth.example.com/oauth/token
+";
+        let err = serde_yaml_ng::from_str::<ProjectConfig>(yaml).unwrap_err();
+        assert!(err.to_string().contains("client_credentials"), "{err}");
+    }
+
+    #[test]
+    fn oauth_flow_validation() {
+        let yaml = r"
+security_schemes:
+  code:
+    type: oauth2
+    flows:
+      authorizationCode:
+        authorizationUrl: https://auth.example.com/authorize
+  empty:
+    type: oauth2
+    flows: {}
+";
+        let config: ProjectConfig = serde_yaml_ng::from_str(yaml).unwrap();
+        let err = config.security_schemes["code"]
+            .validate("code")
+            .unwrap_err();
+        assert!(err.to_string().contains("tokenUrl"), "{err}");
+        let err = config.security_schemes["empty"]
+            .validate("empty")
+            .unwrap_err();
+        assert!(err.to_string().contains("no flows"), "{err}");
+    }
+
     #[test]
     fn load_from_file() {
         let dir = std::env::temp_dir().join("tonic-rest-openapi-test");
@@ -8,17 +8,30 @@
 //! - **Redirect paths**: endpoints returning 302 redirects
 //! - **UUID schema**: auto-detected UUID wrapper type
 //! - **Path param constraints**: per-endpoint path parameter metadata
+//! - **Unbound path params**: template variables with no backing request field
+//! - **Repeated fields**: `repeated` fields per schema, for array typing
+//! - **Bytes fields**: `bytes` fields per schema, serialized as base64 strings
+//! - **Bytes params**: `bytes` request fields bound to path or query parameters
+//! - **Wrapper fields**: `google.protobuf.*Value` fields, serialized as nullable scalars
+//! - **Oneofs**: `oneof` groups per schema, with a sibling `type`/`kind` discriminator
+//! - **Map fields**: `map<K, V>` fields and their synthetic `*Entry` messages
+//! - **Field descriptions**: leading proto comments per field, from source info
+//! - **Field defaults**: explicit `[default = ...]` values (proto2)
+//! - **Paginated responses**: list responses with `next_page_token`
+//! - **Method sources**: defining proto file, service, and line per RPC
 //!
 //! This keeps proto files as the **single source of truth** — the `OpenAPI`
 //! post-processor auto-detects streaming endpoints and resolves operation IDs
 //! instead of relying on hardcoded lists.
 
 use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::{Arc, LazyLock, Mutex, PoisonError};
 
 use prost::Message;
 
 use crate::descriptor::{
-    self, DescriptorProto, FieldDescriptorProto, FileDescriptorSet, field_type,
+    self, DescriptorProto, FieldDescriptorProto, FileDescriptorSet, field_label, field_type,
 };
 use crate::error;
 
@@ -63,8 +76,49 @@ pub struct ProtoMetadata {
     /// Field constraints for path parameters, keyed by HTTP path.
     pub(crate) path_param_constraints: Vec<PathParamInfo>,
 
+    /// Path template variables that do not resolve to a request field.
+    pub(crate) unbound_path_params: Vec<UnboundPathParam>,
+
     /// Raw → stripped enum value mapping for all prefix-stripped enums.
     pub(crate) enum_value_map: HashMap<String, String>,
+
+    /// Fields declared `repeated` in proto (excluding map entries).
+    pub(crate) repeated_fields: Vec<RepeatedField>,
+
+    /// Fields of proto type `bytes` (singular or repeated).
+    pub(crate) bytes_fields: Vec<BytesField>,
+
+    /// `bytes` fields of request messages, for path/query parameter typing.
+    pub(crate) bytes_params: Vec<BytesParam>,
+
+    /// Fields typed as a `google.protobuf.*Value` wrapper.
+    pub(crate) wrapper_fields: Vec<WrapperField>,
+
+    /// Fields declared with proto3 `optional`.
+    pub(crate) optional_fields: Vec<OptionalField>,
+
+    /// `oneof` groups declared in messages (synthetic proto3 `optional` oneofs excluded).
+    pub(crate) oneofs: Vec<OneofInfo>,
+
+    /// `map<K, V>` fields, rendered as objects with `additionalProperties`.
+    pub(crate) map_fields: Vec<MapField>,
+
+    /// Leading proto comments per field: schema → camelCase field → text.
+    ///
+    /// Empty unless the descriptor set was built with source info.
+    pub(crate) field_descriptions: HashMap<String, HashMap<String, String>>,
+
+    /// Fields with an explicit `[default = ...]` value.
+    pub(crate) field_defaults: Vec<FieldDefault>,
+
+    /// List responses carrying a page of items plus a `next_page_token`.
+    pub(crate) paginated_responses: Vec<PaginatedResponse>,
+
+    /// Defining proto file and service for every HTTP-annotated RPC.
+    pub(crate) method_sources: Vec<MethodSource>,
+
+    /// Distinct `(google.api.default_host)` service options, in descriptor order.
+    pub(crate) default_hosts: Vec<String>,
 }
 
 impl ProtoMetadata {
@@ -110,11 +164,101 @@ impl ProtoMetadata {
         &self.path_param_constraints
     }
 
+    /// Path template variables with no matching field in the request message.
+    ///
+    /// Each entry is a proto bug: the route compiles, but the path value is
+    /// never bound to the request. Empty for a consistent descriptor set.
+    #[must_use]
+    pub fn unbound_path_params(&self) -> &[UnboundPathParam] {
+        &self.unbound_path_params
+    }
+
     /// Raw → stripped enum value mapping for all prefix-stripped enums.
     #[must_use]
     pub const fn enum_value_map(&self) -> &HashMap<String, String> {
         &self.enum_value_map
     }
+
+    /// Fields declared `repeated` in proto, keyed by schema.
+    #[must_use]
+    pub fn repeated_fields(&self) -> &[RepeatedField] {
+        &self.repeated_fields
+    }
+
+    /// Whether `field` (camelCase) of `schema` is a proto `repeated` field.
+    #[must_use]
+    pub fn is_repeated(&self, schema: &str, field: &str) -> bool {
+        is_repeated_field(&self.repeated_fields, schema, field)
+    }
+
+    /// Fields of proto type `bytes`, keyed by schema.
+    #[must_use]
+    pub fn bytes_fields(&self) -> &[BytesField] {
+        &self.bytes_fields
+    }
+
+    /// `bytes` request fields per operation, which may surface as path or query parameters.
+    #[must_use]
+    pub fn bytes_params(&self) -> &[BytesParam] {
+        &self.bytes_params
+    }
+
+    /// Fields typed as `google.protobuf.*Value` wrappers, keyed by schema.
+    #[must_use]
+    pub fn wrapper_fields(&self) -> &[WrapperField] {
+        &self.wrapper_fields
+    }
+
+    /// Fields declared with proto3 `optional`, keyed by schema.
+    #[must_use]
+    pub fn optional_fields(&self) -> &[OptionalField] {
+        &self.optional_fields
+    }
+
+    /// `oneof` groups per schema, with their discriminator field when one exists.
+    #[must_use]
+    pub fn oneofs(&self) -> &[OneofInfo] {
+        &self.oneofs
+    }
+
+    /// `map<K, V>` fields with their entry schema and value type.
+    #[must_use]
+    pub fn map_fields(&self) -> &[MapField] {
+        &self.map_fields
+    }
+
+    /// Leading proto comments per field, keyed by schema then camelCase field.
+    #[must_use]
+    pub const fn field_descriptions(&self) -> &HashMap<String, HashMap<String, String>> {
+        &self.field_descriptions
+    }
+
+    /// Fields declaring an explicit default value.
+    #[must_use]
+    pub fn field_defaults(&self) -> &[FieldDefault] {
+        &self.field_defaults
+    }
+
+    /// Paginated list response schemas (AIP-158 style).
+    #[must_use]
+    pub fn paginated_responses(&self) -> &[PaginatedResponse] {
+        &self.paginated_responses
+    }
+
+    /// Proto source location of every HTTP-annotated RPC.
+    #[must_use]
+    pub fn method_sources(&self) -> &[MethodSource] {
+        &self.method_sources
+    }
+
+    /// Hosts declared with `option (google.api.default_host)` on services.
+    ///
+    /// [`PatchConfig::new`](crate::PatchConfig::new) seeds one `servers`
+    /// entry per host when no servers are configured.
+    #[must_use]
+    pub fn default_hosts(&self) -> &[String] {
+        &self.default_hosts
+    }
 }
 
 /// Maps a short proto method name to its gnostic operation ID.
@@ -126,6 +270,23 @@ pub struct OperationEntry {
     pub operation_id: String,
 }
 
+/// Where an HTTP-annotated RPC is defined in the proto sources.
+#[derive(Debug, Clone)]
+pub struct MethodSource {
+    /// gnostic operation ID: `ServiceName_MethodName`.
+    pub operation_id: String,
+    /// Proto file path as recorded in the descriptor (e.g., `users/v1/users.proto`).
+    pub file: String,
+    /// Fully-qualified service name (e.g., `users.v1.UserService`).
+    pub service: String,
+    /// Method name from proto (e.g., `GetUser`).
+    pub method: String,
+    /// 1-based line of the `rpc` definition.
+    ///
+    /// `None` unless the descriptor set was built with source info.
+    pub line: Option<u32>,
+}
+
 /// Validation constraints for all fields in one schema.
 #[derive(Debug, Clone)]
 pub struct SchemaConstraints {
@@ -144,6 +305,140 @@ pub struct EnumRewrite {
     pub field: String,
     /// Rewritten enum values matching runtime wire format (e.g., `["healthy", "unhealthy"]`).
     pub values: Vec<String>,
+    /// Original proto value names, parallel to `values` (e.g., `["HEALTH_STATUS_HEALTHY", ...]`).
+    pub raw_values: Vec<String>,
+    /// Leading proto comment of each value, parallel to `values`; empty when absent.
+    pub descriptions: Vec<String>,
+}
+
+/// A proto field declared with the `repeated` label.
+///
+/// Used to keep array typing intact in transforms that would otherwise infer
+/// array-ness from the (possibly rewritten) spec alone.
+#[derive(Debug, Clone)]
+pub struct RepeatedField {
+    /// Schema name in gnostic format (e.g., `items.v1.BatchRequest`).
+    pub schema: String,
+    /// Field name in camelCase (e.g., `itemIds`).
+    pub field: String,
+}
+
+/// A proto field of type `bytes`.
+///
+/// Proto3 JSON encodes `bytes` as a base64 string, which the patcher
+/// documents with `contentEncoding: base64`.
+#[derive(Debug, Clone)]
+pub struct BytesField {
+    /// Schema name in gnostic format (e.g., `files.v1.UploadRequest`).
+    pub schema: String,
+    /// Field name in camelCase (e.g., `content`).
+    pub field: String,
+}
+
+/// A `bytes` field of an HTTP-annotated RPC's request message.
+///
+/// When the field is bound from the URL rather than the body, gnostic emits
+/// it as a path or query parameter, which the patcher types as base64.
+#[derive(Debug, Clone)]
+pub struct BytesParam {
+    /// HTTP method (e.g., `"get"`).
+    pub method: String,
+    /// URL path in gnostic format (e.g., `/v1/files/{fileId}`).
+    pub path: String,
+    /// Parameter name in camelCase (e.g., `checksum`).
+    pub name: String,
+}
+
+/// A proto field typed as a `google.protobuf.*Value` wrapper.
+///
+/// Wrappers serialize as the bare scalar (or `null` when unset), so the
+/// patcher replaces the wrapper object schema with a nullable scalar.
+#[derive(Debug, Clone)]
+pub struct WrapperField {
+    /// Schema name in gnostic format (e.g., `items.v1.Item`).
+    pub schema: String,
+    /// Field name in camelCase (e.g., `archived`).
+    pub field: String,
+    /// Wrapper message name without package (e.g., `BoolValue`).
+    pub wrapper: String,
+}
+
+/// A proto3 `optional` field, which has explicit presence.
+///
+/// Unset optional fields may appear as `null` in JSON, so the patcher makes
+/// these properties nullable.
+#[derive(Debug, Clone)]
+pub struct OptionalField {
+    /// Schema name in gnostic format (e.g., `users.v1.UpdateUserRequest`).
+    pub schema: String,
+    /// Field name in camelCase (e.g., `displayName`).
+    pub field: String,
+}
+
+/// A field with an explicit `[default = ...]` value (proto2 syntax).
+#[derive(Debug, Clone)]
+pub struct FieldDefault {
+    /// Schema name in gnostic format (e.g., `jobs.v1.JobOptions`).
+    pub schema: String,
+    /// Field name in camelCase (e.g., `notify`).
+    pub field: String,
+    /// Default as written by protoc (e.g., `true`, `30`, `JOB_PRIORITY_LOW`).
+    pub value: String,
+    /// Proto field type (a [`field_type`] constant).
+    pub proto_type: i32,
+}
+
+/// A proto `oneof` group and the fields that belong to it.
+#[derive(Debug, Clone)]
+pub struct OneofInfo {
+    /// Schema name in gnostic format (e.g., `auth.v1.LoginRequest`).
+    pub schema: String,
+    /// Proto name of the `oneof` (e.g., `credential`).
+    pub name: String,
+    /// Member field names in camelCase, in declaration order.
+    pub fields: Vec<String>,
+    /// Sibling string or enum field named `type` or `kind` that tells the
+    /// members apart, in camelCase. `None` when the message has no such field.
+    pub discriminator: Option<String>,
+}
+
+/// A proto `map<K, V>` field.
+///
+/// protoc encodes maps as a `repeated` field of a synthetic `*Entry` message.
+/// JSON keys are always strings, so only the value type matters for the schema.
+#[derive(Debug, Clone)]
+pub struct MapField {
+    /// Schema name in gnostic format (e.g., `items.v1.Item`).
+    pub schema: String,
+    /// Field name in camelCase (e.g., `labels`).
+    pub field: String,
+    /// Schema name of the synthetic entry message (e.g., `items.v1.Item.LabelsEntry`).
+    pub entry_schema: String,
+    /// Proto type of the map value (see `field_type`).
+    pub value_type: i32,
+    /// Schema name of a message or enum value type, without the leading dot
+    /// (e.g., `items.v1.Price`). `None` for scalar values.
+    pub value_type_name: Option<String>,
+}
+
+/// A list response message following the `next_page_token` pagination pattern.
+///
+/// Detected when a message has a string `next_page_token` field and exactly
+/// one `repeated` field holding the page items.
+#[derive(Debug, Clone)]
+pub struct PaginatedResponse {
+    /// Schema name in gnostic format (e.g., `users.v1.ListUsersResponse`).
+    pub schema: String,
+    /// Repeated items field in camelCase (e.g., `users`).
+    pub items_field: String,
+    /// Page token field in camelCase (e.g., `nextPageToken`).
+    pub token_field: String,
+    /// Page token field of the list requests returning this response, by its
+    /// JSON name (e.g., `pageToken`). `None` when no such request has one.
+    pub request_token_field: Option<String>,
+    /// Largest `page_size` maximum among the list requests returning this
+    /// response, from their `validate.rules` bounds. `None` when unbounded.
+    pub max_page_size: Option<u64>,
 }
 
 /// Path parameter constraint info for a specific HTTP endpoint.
@@ -155,6 +450,22 @@ pub struct PathParamInfo {
     pub params: Vec<PathParamConstraint>,
 }
 
+/// A path template variable that does not resolve to a request field.
+///
+/// Reported by [`discover()`] when, e.g., `get: "/v1/items/{item_id}"` is
+/// declared on a method whose input message has no `item_id` field.
+#[derive(Debug, Clone)]
+pub struct UnboundPathParam {
+    /// gnostic operation ID: `ServiceName_MethodName`.
+    pub operation_id: String,
+    /// HTTP path template as written in the proto (e.g., `/v1/items/{item_id}`).
+    pub path: String,
+    /// Template variable name (e.g., `item_id` or `parent.name`).
+    pub param: String,
+    /// Fully-qualified input message type (e.g., `.items.v1.GetItemRequest`).
+    pub input_type: String,
+}
+
 /// Constraint for a single path parameter.
 #[derive(Debug, Clone)]
 pub struct PathParamConstraint {
@@ -189,12 +500,23 @@ pub struct FieldConstraint {
     pub is_uuid: bool,
     /// Proto field type: true if numeric (int32/uint32/uint64), false if string.
     pub is_numeric: bool,
+    /// Concrete proto field type (a [`field_type`] constant).
+    ///
+    /// Distinguishes 64-bit integers, which proto3 JSON encodes as strings.
+    pub proto_type: i32,
     /// `minimum` for signed integers (int32). Mutually exclusive with `min`.
     /// When present, the JSON Schema should use this instead of `min`.
     pub signed_min: Option<i64>,
     /// `maximum` for signed integers (int32). Mutually exclusive with `max`.
     /// When present, the JSON Schema should use this instead of `max`.
     pub signed_max: Option<i64>,
+    /// `minItems` for `repeated` fields (from `validate.rules.repeated.min_items`).
+    pub min_items: Option<u64>,
+    /// `maxItems` for `repeated` fields (from `validate.rules.repeated.max_items`).
+    pub max_items: Option<u64>,
+    /// JSON Schema `format` from a `well_known` string rule (`email`, `uri`,
+    /// `hostname`, `ipv4`, `ipv6`).
+    pub format: Option<String>,
 }
 
 /// Parse proto descriptor bytes and extract all RPC metadata.
@@ -215,7 +537,22 @@ pub fn discover(descriptor_bytes: &[u8]) -> error::Result<ProtoMetadata> {
     let (enum_rewrites, enum_value_map) = extract_enum_rewrites(&fdset);
     let redirect_paths = extract_redirect_paths(&fdset);
     let uuid_schema = detect_uuid_schema(&fdset);
-    let path_param_constraints = extract_path_param_constraints(&fdset);
+    let field_descriptions = extract_field_descriptions(&fdset);
+    let path_param_constraints = extract_path_param_constraints(&fdset, &field_descriptions);
+    let unbound_path_params = extract_unbound_path_params(&fdset);
+    let repeated_fields = extract_repeated_fields(&fdset);
+    let bytes_fields = extract_bytes_fields(&fdset);
+    let bytes_params = extract_bytes_params(&fdset);
+    let wrapper_fields = extract_wrapper_fields(&fdset);
+    let optional_fields = extract_optional_fields(&fdset);
+    let oneofs = extract_oneofs(&fdset);
+    let map_fields = extract_map_fields(&fdset);
+    let mut paginated_responses = extract_paginated_responses(&fdset, &repeated_fields);
+    link_page_size_bounds(&fdset, &field_constraints, &mut paginated_responses);
+    link_request_page_tokens(&fdset, &mut paginated_responses);
+    let method_sources = extract_method_sources(&fdset);
+    let field_defaults = extract_field_defaults(&fdset);
+    let default_hosts = extract_default_hosts(&fdset);
 
     Ok(ProtoMetadata {
         streaming_ops,
@@ -225,10 +562,76 @@ pub fn discover(descriptor_bytes: &[u8]) -> error::Result<ProtoMetadata> {
         redirect_paths,
         uuid_schema,
         path_param_constraints,
+        unbound_path_params,
         enum_value_map,
+        repeated_fields,
+        bytes_fields,
+        bytes_params,
+        wrapper_fields,
+        optional_fields,
+        oneofs,
+        map_fields,
+        field_descriptions,
+        field_defaults,
+        paginated_responses,
+        method_sources,
+        default_hosts,
     })
 }
 
+/// A cached [`discover()`] result with the bytes it was computed from.
+struct CachedMetadata {
+    bytes: Box<[u8]>,
+    metadata: Arc<ProtoMetadata>,
+}
+
+/// Process-wide cache behind [`discover_cached()`], keyed by a hash of the input.
+static DISCOVER_CACHE: LazyLock<Mutex<HashMap<u64, CachedMetadata>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Like [`discover()`], but memoizes the result per distinct descriptor.
+///
+/// Tools that patch many specs from one descriptor (e.g., one per service)
+/// decode and walk it once; later calls with identical bytes return the same
+/// shared [`ProtoMetadata`]. The cache is keyed by a hash of the bytes, checked
+/// against the stored bytes on a hit, and safe to use from multiple threads.
+/// Entries live for the rest of the process.
+///
+/// # Errors
+///
+/// Returns an error if the descriptor bytes cannot be decoded. Failures are
+/// not cached.
+pub fn discover_cached(descriptor_bytes: &[u8]) -> error::Result<Arc<ProtoMetadata>> {
+    let mut hasher = DefaultHasher::new();
+    descriptor_bytes.hash(&mut hasher);
+    let key = hasher.finish();
+
+    {
+        let cache = DISCOVER_CACHE
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some(hit) = cache.get(&key).filter(|c| *c.bytes == *descriptor_bytes) {
+            return Ok(Arc::clone(&hit.metadata));
+        }
+    }
+
+    // Decode without holding the lock; if another thread raced us, keep its entry.
+    let metadata = Arc::new(discover(descriptor_bytes)?);
+    let mut cache = DISCOVER_CACHE
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    let entry = cache.entry(key).or_insert_with(|| CachedMetadata {
+        bytes: descriptor_bytes.into(),
+        metadata: Arc::clone(&metadata),
+    });
+    if *entry.bytes == *descriptor_bytes {
+        Ok(Arc::clone(&entry.metadata))
+    } else {
+        // Hash collision with a different descriptor: serve uncached.
+        Ok(metadata)
+    }
+}
+
 /// Resolve short method names to gnostic operation IDs using proto metadata.
 ///
 /// Given `["Authenticate", "SignUp"]` and the proto descriptor mapping,
@@ -256,7 +659,10 @@ pub fn resolve_operation_ids(
 ///
 /// Checks for qualified `Service.Method` format first, then falls back
 /// to bare method name with ambiguity detection.
-fn resolve_single_operation_id(metadata: &ProtoMetadata, name: &str) -> error::Result<String> {
+pub(crate) fn resolve_single_operation_id(
+    metadata: &ProtoMetadata,
+    name: &str,
+) -> error::Result<String> {
     // Check for qualified "Service.Method" format
     if let Some((service, method)) = name.split_once('.') {
         let qualified_id = format!("{service}_{method}");
@@ -349,6 +755,157 @@ fn extract_operation_ids(fdset: &FileDescriptorSet) -> Vec<OperationEntry> {
     entries
 }
 
+/// `FileDescriptorProto.service` field number, for source-info paths.
+const FILE_SERVICE_FIELD: i32 = 6;
+
+/// `ServiceDescriptorProto.method` field number, for source-info paths.
+const SERVICE_METHOD_FIELD: i32 = 2;
+
+/// Extract the defining file, service, and line of every HTTP-annotated RPC.
+fn extract_method_sources(fdset: &FileDescriptorSet) -> Vec<MethodSource> {
+    let mut sources = Vec::new();
+
+    for file in &fdset.file {
+        let file_name = file.name.as_deref().unwrap_or("");
+        let package = file.package.as_deref().unwrap_or("");
+
+        for (service_index, service) in file.service.iter().enumerate() {
+            let service_name = service.name.as_deref().unwrap_or("");
+            let qualified_service = if package.is_empty() {
+                service_name.to_string()
+            } else {
+                format!("{package}.{service_name}")
+            };
+
+            for (method_index, method) in service.method.iter().enumerate() {
+                if descriptor::extract_http_pattern(method).is_none() {
+                    continue;
+                }
+
+                let method_name = method.name.as_deref().unwrap_or("");
+                let path = [
+                    FILE_SERVICE_FIELD,
+                    i32::try_from(service_index).unwrap_or(i32::MAX),
+                    SERVICE_METHOD_FIELD,
+                    i32::try_from(method_index).unwrap_or(i32::MAX),
+                ];
+                sources.push(MethodSource {
+                    operation_id: format!("{service_name}_{method_name}"),
+                    file: file_name.to_string(),
+                    service: qualified_service.clone(),
+                    method: method_name.to_string(),
+                    line: source_line(file, &path),
+                });
+            }
+        }
+    }
+
+    sources
+}
+
+/// `FileDescriptorProto.message_type` field number, for source-info paths.
+const FILE_MESSAGE_FIELD: i32 = 4;
+
+/// `DescriptorProto.field` field number, for source-info paths.
+const MESSAGE_FIELD_FIELD: i32 = 2;
+
+/// `DescriptorProto.nested_type` field number, for source-info paths.
+const MESSAGE_NESTED_FIELD: i32 = 3;
+
+/// Collect the leading comment of every message field, keyed by schema and field.
+///
+/// A field's source-info path is `[4, m, 2, f]` for top-level message `m`,
+/// and gains a `3, n` pair per nesting level (`[4, m, 3, n, 2, f]`), so the
+/// message tree is walked alongside the path prefix.
+fn extract_field_descriptions(
+    fdset: &FileDescriptorSet,
+) -> HashMap<String, HashMap<String, String>> {
+    let mut result = HashMap::new();
+
+    for file in &fdset.file {
+        let Some(info) = &file.source_code_info else {
+            continue;
+        };
+        let comments: HashMap<&[i32], &str> = info
+            .location
+            .iter()
+            .filter_map(|loc| Some((loc.path.as_slice(), loc.leading_comments.as_deref()?)))
+            .collect();
+        if comments.is_empty() {
+            continue;
+        }
+
+        let package = file.package.as_deref().unwrap_or("");
+        collect_field_descriptions(
+            &mut result,
+            &comments,
+            package,
+            &[FILE_MESSAGE_FIELD],
+            &file.message_type,
+        );
+    }
+
+    result
+}
+
+/// Recursively match message fields to their comments (handles nested types).
+fn collect_field_descriptions(
+    result: &mut HashMap<String, HashMap<String, String>>,
+    comments: &HashMap<&[i32], &str>,
+    parent_path: &str,
+    prefix: &[i32],
+    messages: &[DescriptorProto],
+) {
+    for (msg_index, msg) in messages.iter().enumerate() {
+        let msg_name = msg.name.as_deref().unwrap_or("");
+        let schema = format!("{parent_path}.{msg_name}");
+        let mut msg_path = prefix.to_vec();
+        msg_path.push(i32::try_from(msg_index).unwrap_or(i32::MAX));
+
+        for (field_index, field) in msg.field.iter().enumerate() {
+            let mut path = msg_path.clone();
+            path.extend([
+                MESSAGE_FIELD_FIELD,
+                i32::try_from(field_index).unwrap_or(i32::MAX),
+            ]);
+            let Some(text) = comments.get(path.as_slice()).and_then(|c| clean_comment(c)) else {
+                continue;
+            };
+            result
+                .entry(schema.clone())
+                .or_default()
+                .insert(field_json_name(field), text);
+        }
+
+        msg_path.push(MESSAGE_NESTED_FIELD);
+        collect_field_descriptions(result, comments, &schema, &msg_path, &msg.nested_type);
+    }
+}
+
+/// Trim each line of a proto comment; `None` when nothing but whitespace remains.
+fn clean_comment(raw: &str) -> Option<String> {
+    let text = raw
+        .lines()
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string();
+    (!text.is_empty()).then_some(text)
+}
+
+/// Look up the 1-based start line of the element at `path`, if source info exists.
+fn source_line(file: &descriptor::FileDescriptorProto, path: &[i32]) -> Option<u32> {
+    file.source_code_info
+        .as_ref()?
+        .location
+        .iter()
+        .find(|loc| loc.path == path)
+        .and_then(|loc| loc.span.first())
+        .and_then(|&line| u32::try_from(line).ok())
+        .map(|line| line + 1)
+}
+
 /// Walk all messages and extract `validate.rules` as `SchemaConstraints`.
 fn extract_field_constraints(fdset: &FileDescriptorSet) -> Vec<SchemaConstraints> {
     let mut result = Vec::new();
@@ -391,11 +948,8 @@ fn collect_message_constraints(
     clippy::case_sensitive_file_extension_comparisons
 )]
 fn field_to_constraint(field: &FieldDescriptorProto) -> Option<FieldConstraint> {
-    const JSON_SAFE_INT_MAX: u64 = 9_007_199_254_740_991;
-
     let rules = field.options.as_ref()?.rules.as_ref()?;
-    let proto_name = field.name.as_deref().unwrap_or("");
-    let camel_name = snake_to_lower_camel(proto_name);
+    let camel_name = field_json_name(field);
     let field_type_id = field.r#type.unwrap_or(0);
 
     let msg_required = rules
@@ -404,27 +958,58 @@ fn field_to_constraint(field: &FieldDescriptorProto) -> Option<FieldConstraint>
         .and_then(|m| m.required)
         .unwrap_or(false);
 
+    // Repeated rules — item count bounds on the array itself
+    if let Some(rr) = &rules.repeated {
+        if rr.min_items.is_some() || rr.max_items.is_some() || msg_required {
+            return Some(FieldConstraint {
+                field: camel_name,
+                min: None,
+                max: None,
+                signed_min: None,
+                signed_max: None,
+                min_items: rr.min_items,
+                max_items: rr.max_items,
+                format: None,
+                pattern: None,
+                enum_values: Vec::new(),
+                required: msg_required,
+                is_uuid: false,
+                is_numeric: false,
+                proto_type: field_type_id,
+            });
+        }
+    }
+
     // String rules
     if let Some(sr) = &rules.string {
-        let has_content = sr.min_len.is_some()
-            || sr.max_len.is_some()
+        let format = sr.well_known_format();
+        // An exact `len` pins both bounds.
+        let min_len = sr.exact_len.or(sr.min_len);
+        let max_len = sr.exact_len.or(sr.max_len);
+        let has_content = min_len.is_some()
+            || max_len.is_some()
             || sr.pattern.is_some()
             || !sr.r#in.is_empty()
-            || sr.uuid.unwrap_or(false);
+            || sr.uuid.unwrap_or(false)
+            || format.is_some();
 
         if has_content || msg_required {
-            let implied_required = sr.min_len.unwrap_or(0) >= 1 || !sr.r#in.is_empty();
+            let implied_required = min_len.unwrap_or(0) >= 1 || !sr.r#in.is_empty();
             return Some(FieldConstraint {
                 field: camel_name,
-                min: sr.min_len,
-                max: sr.max_len,
+                min: min_len,
+                max: max_len,
                 signed_min: None,
                 signed_max: None,
+                min_items: None,
+                max_items: None,
+                format: format.map(str::to_string),
                 pattern: sr.pattern.clone(),
                 enum_values: sr.r#in.clone(),
                 required: msg_required || implied_required,
                 is_uuid: sr.uuid.unwrap_or(false),
                 is_numeric: false,
+                proto_type: field_type_id,
             });
         }
     }
@@ -446,11 +1031,15 @@ fn field_to_constraint(field: &FieldDescriptorProto) -> Option<FieldConstraint>
                 max: None,
                 signed_min: min,
                 signed_max: max,
+                min_items: None,
+                max_items: None,
+                format: None,
                 pattern: None,
                 enum_values: Vec::new(),
                 required: msg_required,
                 is_uuid: false,
                 is_numeric: true,
+                proto_type: field_type_id,
             });
         }
     }
@@ -472,41 +1061,45 @@ fn field_to_constraint(field: &FieldDescriptorProto) -> Option<FieldConstraint>
                 max,
                 signed_min: None,
                 signed_max: None,
+                min_items: None,
+                max_items: None,
+                format: None,
                 pattern: None,
                 enum_values: Vec::new(),
                 required: msg_required,
                 is_uuid: false,
                 is_numeric: true,
+                proto_type: field_type_id,
             });
         }
     }
 
-    // UInt64 rules — propagate when within JSON safe integer range
+    // UInt64 rules — the full range is kept: proto3 JSON encodes 64-bit
+    // integers as strings, so bounds beyond 2^53 lose no precision.
     // Convert exclusive bounds to inclusive (gt → +1, lt → −1) like int32/uint32.
     if let Some(u64r) = &rules.uint64 {
-        let min = u64r.gte.or_else(|| u64r.gt.map(|v| v.saturating_add(1)));
+        let min = u64r
+            .gte
+            .or_else(|| u64r.gt.map(|v| v.saturating_add(1)))
+            .filter(|&v| v > 0);
         let max = u64r.lte.or_else(|| u64r.lt.map(|v| v.saturating_sub(1)));
-        let min_val = min.unwrap_or(0);
-        let max_val = max;
-
-        let fits_in_json = max_val.is_some_and(|m| m <= JSON_SAFE_INT_MAX);
 
-        if fits_in_json || msg_required {
+        if min.is_some() || max.is_some() || msg_required {
             return Some(FieldConstraint {
                 field: camel_name,
-                min: if fits_in_json && min_val > 0 {
-                    Some(min_val)
-                } else {
-                    None
-                },
-                max: if fits_in_json { max_val } else { None },
+                min,
+                max,
                 signed_min: None,
                 signed_max: None,
+                min_items: None,
+                max_items: None,
+                format: None,
                 pattern: None,
                 enum_values: Vec::new(),
                 required: msg_required,
                 is_uuid: false,
-                is_numeric: fits_in_json,
+                is_numeric: true,
+                proto_type: field_type_id,
             });
         }
     }
@@ -521,11 +1114,15 @@ fn field_to_constraint(field: &FieldDescriptorProto) -> Option<FieldConstraint>
                 max: None,
                 signed_min: None,
                 signed_max: None,
+                min_items: None,
+                max_items: None,
+                format: None,
                 pattern: None,
                 enum_values: Vec::new(),
                 required: enum_required || msg_required,
                 is_uuid: false,
                 is_numeric: false,
+                proto_type: field_type_id,
             });
         }
     }
@@ -544,81 +1141,662 @@ fn field_to_constraint(field: &FieldDescriptorProto) -> Option<FieldConstraint>
             max: None,
             signed_min: None,
             signed_max: None,
+            min_items: None,
+            max_items: None,
+            format: None,
             pattern: None,
             enum_values: Vec::new(),
             required: true,
             is_uuid,
             is_numeric: false,
+            proto_type: field_type_id,
         });
     }
 
     None
 }
 
-/// Extract enum rewrites for schemas containing prefix-stripped enums.
-fn extract_enum_rewrites(fdset: &FileDescriptorSet) -> (Vec<EnumRewrite>, HashMap<String, String>) {
-    let mut prefix_enums: Vec<(String, String, Vec<String>)> = Vec::new();
-    let mut enum_value_map = HashMap::new();
-
-    for file in &fdset.file {
-        let package = file.package.as_deref().unwrap_or("");
-        for enum_desc in &file.enum_type {
-            let values: Vec<&str> = enum_desc
-                .value
-                .iter()
-                .filter_map(|v| v.name.as_deref())
-                .collect();
-
-            let Some(detected_prefix) = detect_enum_prefix(&values) else {
-                continue;
-            };
-
-            if !values.iter().all(|v| v.starts_with(&detected_prefix)) {
-                continue;
-            }
-
-            // Build stripped values for rewrite detection and the global value map
-            let mut stripped = Vec::with_capacity(values.len());
-            for raw in &values {
-                let suffix = raw[detected_prefix.len()..].to_lowercase();
-                enum_value_map.insert(raw.to_string(), suffix.clone());
-                stripped.push(suffix);
-            }
-
-            let enum_name = enum_desc.name.as_deref().unwrap_or("");
-            let fqn = format!(".{package}.{enum_name}");
-            prefix_enums.push((fqn, detected_prefix, stripped));
-        }
-    }
-
-    if prefix_enums.is_empty() {
-        return (Vec::new(), HashMap::new());
-    }
-
-    // Find all message fields referencing these enums (type 14 = TYPE_ENUM)
-    let mut rewrites = Vec::new();
+/// Walk all messages and collect fields with the `repeated` label.
+fn extract_repeated_fields(fdset: &FileDescriptorSet) -> Vec<RepeatedField> {
+    let mut result = Vec::new();
 
     for file in &fdset.file {
         let package = file.package.as_deref().unwrap_or("");
-        collect_enum_rewrite_fields(&mut rewrites, package, &file.message_type, &prefix_enums);
+        collect_repeated_fields(&mut result, package, &file.message_type);
     }
 
-    (rewrites, enum_value_map)
+    result
 }
 
-/// Recursively scan messages for enum fields referencing prefix-stripped enums.
-fn collect_enum_rewrite_fields(
-    rewrites: &mut Vec<EnumRewrite>,
+/// Recursively collect repeated fields from messages (handles nested types).
+///
+/// Map fields are encoded as `repeated` entries of a synthetic `*Entry`
+/// message; they render as objects, not arrays, so they are skipped.
+fn collect_repeated_fields(
+    result: &mut Vec<RepeatedField>,
     parent_path: &str,
     messages: &[DescriptorProto],
-    prefix_enums: &[(String, String, Vec<String>)],
 ) {
     for msg in messages {
         let msg_name = msg.name.as_deref().unwrap_or("");
         let schema = format!("{parent_path}.{msg_name}");
 
         for field in &msg.field {
-            if field.r#type != Some(field_type::ENUM) {
+            if field.label != Some(field_label::REPEATED) {
+                continue;
+            }
+
+            if map_entry(msg, &schema, field).is_some() {
+                continue;
+            }
+
+            result.push(RepeatedField {
+                schema: schema.clone(),
+                field: field_json_name(field),
+            });
+        }
+
+        collect_repeated_fields(result, &schema, &msg.nested_type);
+    }
+}
+
+/// Return the synthetic `*Entry` message backing `field` if it is a map field.
+///
+/// Entries are recognized by the `map_entry` message option, or by the
+/// `*Entry` naming convention for descriptors built without options.
+fn map_entry<'a>(
+    msg: &'a DescriptorProto,
+    schema: &str,
+    field: &FieldDescriptorProto,
+) -> Option<&'a DescriptorProto> {
+    if field.label != Some(field_label::REPEATED) || field.r#type != Some(field_type::MESSAGE) {
+        return None;
+    }
+    let type_name = field.type_name.as_deref()?;
+    msg.nested_type.iter().find(|nested| {
+        let Some(name) = nested.name.as_deref() else {
+            return false;
+        };
+        let flagged = nested
+            .options
+            .as_ref()
+            .and_then(|o| o.map_entry)
+            .unwrap_or_else(|| name.ends_with("Entry"));
+        flagged && type_name == format!(".{schema}.{name}")
+    })
+}
+
+/// Walk all messages and collect `map<K, V>` fields.
+fn extract_map_fields(fdset: &FileDescriptorSet) -> Vec<MapField> {
+    let mut result = Vec::new();
+
+    for file in &fdset.file {
+        let package = file.package.as_deref().unwrap_or("");
+        collect_map_fields(&mut result, package, &file.message_type);
+    }
+
+    result
+}
+
+/// Recursively collect map fields from messages (handles nested types).
+fn collect_map_fields(result: &mut Vec<MapField>, parent_path: &str, messages: &[DescriptorProto]) {
+    for msg in messages {
+        let msg_name = msg.name.as_deref().unwrap_or("");
+        let schema = format!("{parent_path}.{msg_name}");
+
+        for field in &msg.field {
+            let Some(entry) = map_entry(msg, &schema, field) else {
+                continue;
+            };
+            let Some(value) = entry
+                .field
+                .iter()
+                .find(|f| f.name.as_deref() == Some("value"))
+            else {
+                continue;
+            };
+            result.push(MapField {
+                schema: schema.clone(),
+                field: field_json_name(field),
+                entry_schema: format!("{schema}.{}", entry.name.as_deref().unwrap_or("")),
+                value_type: value.r#type.unwrap_or_default(),
+                value_type_name: value
+                    .type_name
+                    .as_deref()
+                    .map(|t| t.trim_start_matches('.').to_string()),
+            });
+        }
+
+        collect_map_fields(result, &schema, &msg.nested_type);
+    }
+}
+
+/// Walk all messages and collect `bytes` fields.
+fn extract_bytes_fields(fdset: &FileDescriptorSet) -> Vec<BytesField> {
+    let mut result = Vec::new();
+
+    for file in &fdset.file {
+        let package = file.package.as_deref().unwrap_or("");
+        collect_bytes_fields(&mut result, package, &file.message_type);
+    }
+
+    result
+}
+
+/// Recursively collect `bytes` fields from messages (handles nested types).
+fn collect_bytes_fields(
+    result: &mut Vec<BytesField>,
+    parent_path: &str,
+    messages: &[DescriptorProto],
+) {
+    for msg in messages {
+        let msg_name = msg.name.as_deref().unwrap_or("");
+        let schema = format!("{parent_path}.{msg_name}");
+
+        result.extend(
+            msg.field
+                .iter()
+                .filter(|f| f.r#type == Some(field_type::BYTES))
+                .map(|f| BytesField {
+                    schema: schema.clone(),
+                    field: field_json_name(f),
+                }),
+        );
+
+        collect_bytes_fields(result, &schema, &msg.nested_type);
+    }
+}
+
+/// Collect the top-level `bytes` fields of every HTTP-annotated request message.
+fn extract_bytes_params(fdset: &FileDescriptorSet) -> Vec<BytesParam> {
+    let mut messages: HashMap<String, &[FieldDescriptorProto]> = HashMap::new();
+    for file in &fdset.file {
+        let package = file.package.as_deref().unwrap_or("");
+        collect_message_fields(&mut messages, package, &file.message_type);
+    }
+
+    let mut result = Vec::new();
+
+    for file in &fdset.file {
+        for service in &file.service {
+            for method in &service.method {
+                let Some((http_method, path)) = descriptor::extract_http_pattern(method) else {
+                    continue;
+                };
+                let input_type = method.input_type.as_deref().unwrap_or("");
+                let fields = messages.get(input_type).copied().unwrap_or_default();
+                let gnostic_path = convert_path_template_to_camel(path);
+
+                result.extend(
+                    fields
+                        .iter()
+                        .filter(|f| f.r#type == Some(field_type::BYTES))
+                        .map(|f| BytesParam {
+                            method: http_method.to_string(),
+                            path: gnostic_path.clone(),
+                            name: field_json_name(f),
+                        }),
+                );
+            }
+        }
+    }
+
+    result
+}
+
+/// `google.protobuf` wrapper message names (`wrappers.proto`).
+pub(crate) const WRAPPER_TYPES: &[&str] = &[
+    "DoubleValue",
+    "FloatValue",
+    "Int64Value",
+    "UInt64Value",
+    "Int32Value",
+    "UInt32Value",
+    "BoolValue",
+    "StringValue",
+    "BytesValue",
+];
+
+/// Walk all messages and collect fields typed as `google.protobuf` wrappers.
+fn extract_wrapper_fields(fdset: &FileDescriptorSet) -> Vec<WrapperField> {
+    let mut result = Vec::new();
+
+    for file in &fdset.file {
+        let package = file.package.as_deref().unwrap_or("");
+        collect_wrapper_fields(&mut result, package, &file.message_type);
+    }
+
+    result
+}
+
+/// Walk all messages and collect proto3 `optional` fields.
+fn extract_optional_fields(fdset: &FileDescriptorSet) -> Vec<OptionalField> {
+    let mut result = Vec::new();
+
+    for file in &fdset.file {
+        let package = file.package.as_deref().unwrap_or("");
+        collect_optional_fields(&mut result, package, &file.message_type);
+    }
+
+    result
+}
+
+/// Recursively collect proto3 `optional` fields from messages (handles nested types).
+fn collect_optional_fields(
+    result: &mut Vec<OptionalField>,
+    parent_path: &str,
+    messages: &[DescriptorProto],
+) {
+    for msg in messages {
+        let msg_name = msg.name.as_deref().unwrap_or("");
+        let schema = format!("{parent_path}.{msg_name}");
+
+        for field in msg.field.iter().filter(|f| f.proto3_optional == Some(true)) {
+            result.push(OptionalField {
+                schema: schema.clone(),
+                field: field_json_name(field),
+            });
+        }
+
+        collect_optional_fields(result, &schema, &msg.nested_type);
+    }
+}
+
+/// Walk all messages and collect fields with an explicit default value.
+fn extract_field_defaults(fdset: &FileDescriptorSet) -> Vec<FieldDefault> {
+    let mut result = Vec::new();
+
+    for file in &fdset.file {
+        let package = file.package.as_deref().unwrap_or("");
+        collect_field_defaults(&mut result, package, &file.message_type);
+    }
+
+    result
+}
+
+/// Recursively collect defaulted fields from messages (handles nested types).
+fn collect_field_defaults(
+    result: &mut Vec<FieldDefault>,
+    parent_path: &str,
+    messages: &[DescriptorProto],
+) {
+    for msg in messages {
+        let msg_name = msg.name.as_deref().unwrap_or("");
+        let schema = format!("{parent_path}.{msg_name}");
+
+        for field in &msg.field {
+            let (Some(value), Some(proto_type)) = (field.default_value.as_deref(), field.r#type)
+            else {
+                continue;
+            };
+            result.push(FieldDefault {
+                schema: schema.clone(),
+                field: field_json_name(field),
+                value: value.to_string(),
+                proto_type,
+            });
+        }
+
+        collect_field_defaults(result, &schema, &msg.nested_type);
+    }
+}
+
+/// Recursively collect wrapper-typed fields from messages (handles nested types).
+fn collect_wrapper_fields(
+    result: &mut Vec<WrapperField>,
+    parent_path: &str,
+    messages: &[DescriptorProto],
+) {
+    for msg in messages {
+        let msg_name = msg.name.as_deref().unwrap_or("");
+        let schema = format!("{parent_path}.{msg_name}");
+
+        for field in &msg.field {
+            let Some(wrapper) = field
+                .type_name
+                .as_deref()
+                .and_then(|t| t.strip_prefix(".google.protobuf."))
+                .filter(|w| WRAPPER_TYPES.contains(w))
+            else {
+                continue;
+            };
+            result.push(WrapperField {
+                schema: schema.clone(),
+                field: field_json_name(field),
+                wrapper: wrapper.to_string(),
+            });
+        }
+
+        collect_wrapper_fields(result, &schema, &msg.nested_type);
+    }
+}
+
+/// Conventional names of a field that discriminates between `oneof` members.
+const DISCRIMINATOR_NAMES: &[&str] = &["type", "kind"];
+
+/// Walk all messages and collect their `oneof` groups.
+fn extract_oneofs(fdset: &FileDescriptorSet) -> Vec<OneofInfo> {
+    let mut result = Vec::new();
+
+    for file in &fdset.file {
+        let package = file.package.as_deref().unwrap_or("");
+        collect_oneofs(&mut result, package, &file.message_type);
+    }
+
+    result
+}
+
+/// Recursively collect `oneof` groups from messages (handles nested types).
+///
+/// proto3 `optional` fields are wrapped in synthetic single-member oneofs;
+/// those are skipped since they carry no mutual exclusion.
+fn collect_oneofs(result: &mut Vec<OneofInfo>, parent_path: &str, messages: &[DescriptorProto]) {
+    for msg in messages {
+        let msg_name = msg.name.as_deref().unwrap_or("");
+        let schema = format!("{parent_path}.{msg_name}");
+
+        let discriminator = msg
+            .field
+            .iter()
+            .filter(|f| f.oneof_index.is_none() || f.proto3_optional == Some(true))
+            .filter(|f| matches!(f.r#type, Some(field_type::STRING | field_type::ENUM)))
+            .find(|f| {
+                f.name
+                    .as_deref()
+                    .is_some_and(|n| DISCRIMINATOR_NAMES.contains(&n))
+            })
+            .map(field_json_name);
+
+        for (index, decl) in msg.oneof_decl.iter().enumerate() {
+            let fields: Vec<String> = msg
+                .field
+                .iter()
+                .filter(|f| f.proto3_optional != Some(true))
+                .filter(|f| f.oneof_index.and_then(|i| usize::try_from(i).ok()) == Some(index))
+                .map(field_json_name)
+                .collect();
+            if fields.is_empty() {
+                continue;
+            }
+            result.push(OneofInfo {
+                schema: schema.clone(),
+                name: decl.name.clone().unwrap_or_default(),
+                fields,
+                discriminator: discriminator.clone(),
+            });
+        }
+
+        collect_oneofs(result, &schema, &msg.nested_type);
+    }
+}
+
+/// Proto field name of the page token in paginated list responses.
+const NEXT_PAGE_TOKEN_FIELD: &str = "next_page_token";
+
+/// Walk all messages and collect paginated list responses.
+fn extract_paginated_responses(
+    fdset: &FileDescriptorSet,
+    repeated: &[RepeatedField],
+) -> Vec<PaginatedResponse> {
+    let mut result = Vec::new();
+
+    for file in &fdset.file {
+        let package = file.package.as_deref().unwrap_or("");
+        collect_paginated_responses(&mut result, repeated, package, &file.message_type);
+    }
+
+    result
+}
+
+/// Recursively collect paginated responses (handles nested types).
+///
+/// Messages with more than one repeated field are ambiguous and skipped.
+fn collect_paginated_responses(
+    result: &mut Vec<PaginatedResponse>,
+    repeated: &[RepeatedField],
+    parent_path: &str,
+    messages: &[DescriptorProto],
+) {
+    for msg in messages {
+        let msg_name = msg.name.as_deref().unwrap_or("");
+        let schema = format!("{parent_path}.{msg_name}");
+
+        let has_token = msg.field.iter().any(|f| {
+            f.name.as_deref() == Some(NEXT_PAGE_TOKEN_FIELD)
+                && f.r#type == Some(field_type::STRING)
+                && f.label != Some(field_label::REPEATED)
+        });
+
+        if has_token {
+            let mut items = repeated.iter().filter(|r| r.schema == schema);
+            if let (Some(items_field), None) = (items.next(), items.next()) {
+                result.push(PaginatedResponse {
+                    schema: schema.clone(),
+                    items_field: items_field.field.clone(),
+                    token_field: snake_to_lower_camel(NEXT_PAGE_TOKEN_FIELD),
+                    request_token_field: None,
+                    max_page_size: None,
+                });
+            }
+        }
+
+        collect_paginated_responses(result, repeated, &schema, &msg.nested_type);
+    }
+}
+
+/// Proto field name of the page size in list requests.
+const PAGE_SIZE_FIELD: &str = "page_size";
+
+/// Record the `page_size` upper bound of each paginated response's requests.
+///
+/// Pairs every RPC returning a paginated response with the `validate.rules`
+/// maximum of its input's `page_size` field. When several requests return the
+/// same response, the largest bound wins; any unbounded request leaves the
+/// response unbounded.
+fn link_page_size_bounds(
+    fdset: &FileDescriptorSet,
+    constraints: &[SchemaConstraints],
+    paginated: &mut [PaginatedResponse],
+) {
+    let page_size_key = snake_to_lower_camel(PAGE_SIZE_FIELD);
+
+    for page in paginated.iter_mut() {
+        let mut bounds = fdset
+            .file
+            .iter()
+            .flat_map(|f| &f.service)
+            .flat_map(|s| &s.method)
+            .filter(|m| {
+                m.output_type.as_deref().and_then(|t| t.strip_prefix('.'))
+                    == Some(page.schema.as_str())
+            })
+            .map(|m| {
+                let input = m
+                    .input_type
+                    .as_deref()
+                    .unwrap_or("")
+                    .trim_start_matches('.');
+                constraints
+                    .iter()
+                    .filter(|c| c.schema == input)
+                    .flat_map(|c| &c.fields)
+                    .find(|f| f.field == page_size_key)
+                    .and_then(|f| {
+                        f.max
+                            .or_else(|| f.signed_max.and_then(|v| u64::try_from(v).ok()))
+                    })
+            })
+            .peekable();
+
+        if bounds.peek().is_some() {
+            page.max_page_size = bounds.try_fold(0, |acc, bound| bound.map(|b| acc.max(b)));
+        }
+    }
+}
+
+/// Proto field name of the page token in list requests.
+const PAGE_TOKEN_FIELD: &str = "page_token";
+
+/// Record the request-side page token of each paginated response.
+///
+/// Takes the JSON name of the string `page_token` field on the input of the
+/// first RPC returning the response that declares one.
+fn link_request_page_tokens(fdset: &FileDescriptorSet, paginated: &mut [PaginatedResponse]) {
+    let mut messages: HashMap<String, &[FieldDescriptorProto]> = HashMap::new();
+    for file in &fdset.file {
+        let package = file.package.as_deref().unwrap_or("");
+        collect_message_fields(&mut messages, package, &file.message_type);
+    }
+
+    for page in paginated.iter_mut() {
+        page.request_token_field = fdset
+            .file
+            .iter()
+            .flat_map(|f| &f.service)
+            .flat_map(|s| &s.method)
+            .filter(|m| {
+                m.output_type.as_deref().and_then(|t| t.strip_prefix('.'))
+                    == Some(page.schema.as_str())
+            })
+            .filter_map(|m| messages.get(m.input_type.as_deref()?))
+            .find_map(|fields| {
+                fields.iter().find(|f| {
+                    f.name.as_deref() == Some(PAGE_TOKEN_FIELD)
+                        && f.r#type == Some(field_type::STRING)
+                        && f.label != Some(field_label::REPEATED)
+                })
+            })
+            .map(field_json_name);
+    }
+}
+
+/// Check whether `field` of `schema` appears in the repeated-field list.
+pub(crate) fn is_repeated_field(repeated: &[RepeatedField], schema: &str, field: &str) -> bool {
+    repeated
+        .iter()
+        .any(|r| r.schema == schema && r.field == field)
+}
+
+/// `FileDescriptorProto.enum_type` field number, for source-info paths.
+const FILE_ENUM_FIELD: i32 = 5;
+
+/// `EnumDescriptorProto.value` field number, for source-info paths.
+const ENUM_VALUE_FIELD: i32 = 2;
+
+/// A top-level enum whose values share a strippable prefix.
+struct PrefixEnum {
+    /// Fully-qualified proto name with leading dot (e.g., `.ops.v1.HealthStatus`).
+    fqn: String,
+    /// Lowercased values with the prefix removed.
+    stripped: Vec<String>,
+    /// Original value names.
+    raw: Vec<String>,
+    /// Leading comment per value; empty when absent.
+    descriptions: Vec<String>,
+}
+
+/// Extract enum rewrites for schemas containing prefix-stripped enums.
+fn extract_enum_rewrites(fdset: &FileDescriptorSet) -> (Vec<EnumRewrite>, HashMap<String, String>) {
+    let mut prefix_enums: Vec<PrefixEnum> = Vec::new();
+    let mut enum_value_map = HashMap::new();
+
+    for file in &fdset.file {
+        let package = file.package.as_deref().unwrap_or("");
+        let comments: HashMap<&[i32], &str> = file
+            .source_code_info
+            .iter()
+            .flat_map(|info| &info.location)
+            .filter_map(|loc| Some((loc.path.as_slice(), loc.leading_comments.as_deref()?)))
+            .collect();
+
+        for (enum_index, enum_desc) in file.enum_type.iter().enumerate() {
+            let values: Vec<&str> = enum_desc
+                .value
+                .iter()
+                .filter_map(|v| v.name.as_deref())
+                .collect();
+
+            let Some(detected_prefix) = detect_enum_prefix(&values) else {
+                continue;
+            };
+
+            if !values.iter().all(|v| v.starts_with(&detected_prefix)) {
+                continue;
+            }
+
+            // Build stripped values for rewrite detection and the global value map.
+            // `allow_alias` enums repeat numbers; aliases map to the first
+            // declared name's value and are left out of the rewritten arrays.
+            let mut canonical: HashMap<Option<i32>, String> = HashMap::new();
+            let mut stripped = Vec::with_capacity(values.len());
+            let mut raw_names = Vec::with_capacity(values.len());
+            let mut descriptions = Vec::with_capacity(values.len());
+            for (value_index, value) in enum_desc.value.iter().enumerate() {
+                let Some(raw) = value.name.as_deref() else {
+                    continue;
+                };
+                if let Some(first) = canonical.get(&value.number) {
+                    enum_value_map.insert(raw.to_string(), first.clone());
+                    continue;
+                }
+                let suffix = raw[detected_prefix.len()..].to_lowercase();
+                enum_value_map.insert(raw.to_string(), suffix.clone());
+                canonical.insert(value.number, suffix.clone());
+                stripped.push(suffix);
+                raw_names.push(raw.to_string());
+
+                let path = [
+                    FILE_ENUM_FIELD,
+                    i32::try_from(enum_index).unwrap_or(i32::MAX),
+                    ENUM_VALUE_FIELD,
+                    i32::try_from(value_index).unwrap_or(i32::MAX),
+                ];
+                descriptions.push(
+                    comments
+                        .get(path.as_slice())
+                        .and_then(|c| clean_comment(c))
+                        .unwrap_or_default(),
+                );
+            }
+
+            let enum_name = enum_desc.name.as_deref().unwrap_or("");
+            prefix_enums.push(PrefixEnum {
+                fqn: format!(".{package}.{enum_name}"),
+                stripped,
+                raw: raw_names,
+                descriptions,
+            });
+        }
+    }
+
+    if prefix_enums.is_empty() {
+        return (Vec::new(), HashMap::new());
+    }
+
+    // Find all message fields referencing these enums (type 14 = TYPE_ENUM)
+    let mut rewrites = Vec::new();
+
+    for file in &fdset.file {
+        let package = file.package.as_deref().unwrap_or("");
+        collect_enum_rewrite_fields(&mut rewrites, package, &file.message_type, &prefix_enums);
+    }
+
+    (rewrites, enum_value_map)
+}
+
+/// Recursively scan messages for enum fields referencing prefix-stripped enums.
+fn collect_enum_rewrite_fields(
+    rewrites: &mut Vec<EnumRewrite>,
+    parent_path: &str,
+    messages: &[DescriptorProto],
+    prefix_enums: &[PrefixEnum],
+) {
+    for msg in messages {
+        let msg_name = msg.name.as_deref().unwrap_or("");
+        let schema = format!("{parent_path}.{msg_name}");
+
+        for field in &msg.field {
+            if field.r#type != Some(field_type::ENUM) {
                 continue;
             }
 
@@ -626,15 +1804,15 @@ fn collect_enum_rewrite_fields(
                 continue;
             };
 
-            if let Some((_, _, stripped_values)) =
-                prefix_enums.iter().find(|(fqn, _, _)| fqn == type_name)
-            {
-                let field_name = snake_to_lower_camel(field.name.as_deref().unwrap_or(""));
+            if let Some(prefix_enum) = prefix_enums.iter().find(|e| e.fqn == type_name) {
+                let field_name = field_json_name(field);
 
                 rewrites.push(EnumRewrite {
                     schema: schema.clone(),
                     field: field_name,
-                    values: stripped_values.clone(),
+                    values: prefix_enum.stripped.clone(),
+                    raw_values: prefix_enum.raw.clone(),
+                    descriptions: prefix_enum.descriptions.clone(),
                 });
             }
         }
@@ -699,6 +1877,22 @@ fn extract_redirect_paths(fdset: &FileDescriptorSet) -> Vec<String> {
     paths
 }
 
+/// Collect distinct non-empty `(google.api.default_host)` values.
+fn extract_default_hosts(fdset: &FileDescriptorSet) -> Vec<String> {
+    let mut hosts: Vec<String> = Vec::new();
+    let declared = fdset
+        .file
+        .iter()
+        .flat_map(|file| &file.service)
+        .filter_map(|service| service.options.as_ref()?.default_host.as_deref());
+    for host in declared.map(str::trim).filter(|h| !h.is_empty()) {
+        if !hosts.iter().any(|h| h == host) {
+            hosts.push(host.to_string());
+        }
+    }
+    hosts
+}
+
 /// Recursively find messages with a `redirect_url` field.
 fn collect_redirect_message_types(
     result: &mut Vec<String>,
@@ -753,7 +1947,10 @@ fn detect_uuid_schema(fdset: &FileDescriptorSet) -> Option<String> {
 
 /// Extract path parameter constraints from proto HTTP path templates.
 #[expect(clippy::case_sensitive_file_extension_comparisons)] // proto type names, not file paths
-fn extract_path_param_constraints(fdset: &FileDescriptorSet) -> Vec<PathParamInfo> {
+fn extract_path_param_constraints(
+    fdset: &FileDescriptorSet,
+    descriptions: &HashMap<String, HashMap<String, String>>,
+) -> Vec<PathParamInfo> {
     let mut messages: HashMap<String, &[FieldDescriptorProto]> = HashMap::new();
     for file in &fdset.file {
         let package = file.package.as_deref().unwrap_or("");
@@ -781,6 +1978,7 @@ fn extract_path_param_constraints(fdset: &FileDescriptorSet) -> Vec<PathParamInf
 
                 let input_type = method.input_type.as_deref().unwrap_or("");
                 let fields = messages.get(input_type).copied().unwrap_or_default();
+                let field_docs = descriptions.get(input_type.trim_start_matches('.'));
 
                 let params: Vec<PathParamConstraint> = param_names
                     .iter()
@@ -809,6 +2007,8 @@ fn extract_path_param_constraints(fdset: &FileDescriptorSet) -> Vec<PathParamInf
                                 .split('.')
                                 .enumerate()
                                 .map(|(i, seg)| {
+                                    // Path templates name the proto field,
+                                    // matching `convert_path_template_to_camel`.
                                     if i == 0 {
                                         snake_to_lower_camel(seg)
                                     } else {
@@ -817,7 +2017,9 @@ fn extract_path_param_constraints(fdset: &FileDescriptorSet) -> Vec<PathParamInf
                                 })
                                 .collect::<Vec<_>>()
                                 .join("."),
-                            description: None,
+                            description: field_docs
+                                .and_then(|docs| docs.get(&field_json_name(field)))
+                                .cloned(),
                             is_uuid,
                             min,
                             max,
@@ -839,6 +2041,83 @@ fn extract_path_param_constraints(fdset: &FileDescriptorSet) -> Vec<PathParamInf
     result
 }
 
+/// Find path template variables that do not resolve to a request field.
+///
+/// Dotted variables (`{parent.name}`) are followed through nested message
+/// fields; every segment must exist for the variable to count as bound.
+fn extract_unbound_path_params(fdset: &FileDescriptorSet) -> Vec<UnboundPathParam> {
+    let mut messages: HashMap<String, &[FieldDescriptorProto]> = HashMap::new();
+    for file in &fdset.file {
+        let package = file.package.as_deref().unwrap_or("");
+        collect_message_fields(&mut messages, package, &file.message_type);
+    }
+
+    let mut result = Vec::new();
+
+    for file in &fdset.file {
+        for service in &file.service {
+            let service_name = service.name.as_deref().unwrap_or("");
+            for method in &service.method {
+                let Some((_, path)) = descriptor::extract_http_pattern(method) else {
+                    continue;
+                };
+                let input_type = method.input_type.as_deref().unwrap_or("");
+
+                let params = path
+                    .split('{')
+                    .skip(1)
+                    .filter_map(|s| s.split('}').next())
+                    .map(|var| var.split_once('=').map_or(var, |(name, _)| name));
+
+                for param in params {
+                    if resolves_to_field(&messages, input_type, param) {
+                        continue;
+                    }
+                    result.push(UnboundPathParam {
+                        operation_id: format!(
+                            "{service_name}_{}",
+                            method.name.as_deref().unwrap_or("")
+                        ),
+                        path: path.to_string(),
+                        param: param.to_string(),
+                        input_type: input_type.to_string(),
+                    });
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/// Whether a (possibly dotted) field path exists on the given message type.
+fn resolves_to_field(
+    messages: &HashMap<String, &[FieldDescriptorProto]>,
+    message_type: &str,
+    field_path: &str,
+) -> bool {
+    let mut current = message_type;
+    let mut segments = field_path.split('.').peekable();
+
+    while let Some(segment) = segments.next() {
+        let Some(field) = messages
+            .get(current)
+            .and_then(|fields| fields.iter().find(|f| f.name.as_deref() == Some(segment)))
+        else {
+            return false;
+        };
+
+        if segments.peek().is_some() {
+            if field.r#type != Some(field_type::MESSAGE) {
+                return false;
+            }
+            current = field.type_name.as_deref().unwrap_or("");
+        }
+    }
+
+    true
+}
+
 /// Convert proto path template variables to gnostic's camelCase format.
 fn convert_path_template_to_camel(path: &str) -> String {
     let mut result = String::with_capacity(path.len());
@@ -883,6 +2162,18 @@ fn collect_message_fields<'a>(
     }
 }
 
+/// JSON property name of a field: the explicit `json_name` when set, otherwise
+/// the `lowerCamelCase` form of the proto name.
+///
+/// gnostic keys properties by `json_name`, so a custom one (e.g., `ID`) must
+/// win over re-deriving the name.
+pub(crate) fn field_json_name(field: &FieldDescriptorProto) -> String {
+    field
+        .json_name
+        .clone()
+        .unwrap_or_else(|| snake_to_lower_camel(field.name.as_deref().unwrap_or("")))
+}
+
 /// Convert `snake_case` to `lowerCamelCase` (matches gnostic JSON field names).
 #[must_use]
 pub fn snake_to_lower_camel(s: &str) -> String {
@@ -912,6 +2203,11 @@ mod tests {
     fn make_field(name: &str, ty: i32) -> FieldDescriptorProto {
         FieldDescriptorProto {
             name: Some(name.to_string()),
+            label: None,
+            proto3_optional: None,
+            default_value: None,
+            json_name: None,
+            oneof_index: None,
             r#type: Some(ty),
             type_name: None,
             options: None,
@@ -934,11 +2230,14 @@ mod tests {
                     http: Some(HttpRule {
                         pattern: Some(pattern),
                         body: String::new(),
+                        response_body: String::new(),
+                        additional_bindings: vec![],
                     }),
                 }),
                 client_streaming: None,
                 server_streaming: Some(server_streaming),
             }],
+            options: None,
         }
     }
 
@@ -950,10 +2249,13 @@ mod tests {
                 message_type: vec![DescriptorProto {
                     name: Some("Request".to_string()),
                     field: vec![make_field("name", field_type::STRING)],
+                    oneof_decl: vec![],
+                    options: None,
                     nested_type: vec![],
                 }],
                 enum_type: vec![],
                 service: services,
+                source_code_info: None,
             }],
         }
     }
@@ -1007,6 +2309,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn discover_captures_method_sources() {
+        let mut fdset = make_fdset_with_services(vec![
+            make_service_with_http(
+                "ItemService",
+                "CreateItem",
+                HttpPattern::Post("/v1/items".to_string()),
+                false,
+            ),
+            make_service_with_http(
+                "TagService",
+                "ListTags",
+                HttpPattern::Get("/v1/tags".to_string()),
+                false,
+            ),
+        ]);
+        fdset.file[0].name = Some("items/v1/items.proto".to_string());
+        // `rpc ListTags` = service[1].method[0], on zero-based line 41
+        fdset.file[0].source_code_info = Some(SourceCodeInfo {
+            location: vec![Location {
+                path: vec![6, 1, 2, 0],
+                span: vec![41, 2, 60],
+                leading_comments: None,
+            }],
+        });
+        let bytes = fdset.encode_to_vec();
+        let metadata = discover(&bytes).unwrap();
+
+        let sources = metadata.method_sources();
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0].operation_id, "ItemService_CreateItem");
+        assert_eq!(sources[0].file, "items/v1/items.proto");
+        assert_eq!(sources[0].service, "test.v1.ItemService");
+        assert_eq!(sources[0].method, "CreateItem");
+        assert_eq!(sources[0].line, None, "no location recorded");
+        assert_eq!(sources[1].service, "test.v1.TagService");
+        assert_eq!(sources[1].line, Some(42));
+    }
+
     #[test]
     fn resolve_operation_ids_success() {
         let fdset = make_fdset_with_services(vec![make_service_with_http(
@@ -1041,6 +2382,8 @@ mod tests {
                 message_type: vec![DescriptorProto {
                     name: Some("Request".to_string()),
                     field: vec![make_field("name", field_type::STRING)],
+                    oneof_decl: vec![],
+                    options: None,
                     nested_type: vec![],
                 }],
                 enum_type: vec![],
@@ -1058,6 +2401,7 @@ mod tests {
                         false,
                     ),
                 ],
+                source_code_info: None,
             }],
         };
         let bytes = fdset.encode_to_vec();
@@ -1080,6 +2424,8 @@ mod tests {
                 message_type: vec![DescriptorProto {
                     name: Some("Request".to_string()),
                     field: vec![make_field("name", field_type::STRING)],
+                    oneof_decl: vec![],
+                    options: None,
                     nested_type: vec![],
                 }],
                 enum_type: vec![],
@@ -1097,6 +2443,7 @@ mod tests {
                         false,
                     ),
                 ],
+                source_code_info: None,
             }],
         };
         let bytes = fdset.encode_to_vec();
@@ -1154,9 +2501,150 @@ mod tests {
     }
 
     #[test]
-    fn detect_enum_prefix_empty() {
-        let values: &[&str] = &[];
-        assert_eq!(detect_enum_prefix(values), None);
+    fn detect_enum_prefix_empty() {
+        let values: &[&str] = &[];
+        assert_eq!(detect_enum_prefix(values), None);
+    }
+
+    #[test]
+    fn discover_cached_shares_metadata_per_descriptor() {
+        let fdset = |service: &str| {
+            make_fdset_with_services(vec![make_service_with_http(
+                service,
+                "GetItem",
+                HttpPattern::Get("/v1/items/{name}".to_string()),
+                false,
+            )])
+            .encode_to_vec()
+        };
+        let items = fdset("CachedItemService");
+        let other = fdset("CachedOtherService");
+
+        let first = discover_cached(&items).unwrap();
+        let second = discover_cached(&items.clone()).unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(
+            first.operation_ids()[0].operation_id,
+            "CachedItemService_GetItem"
+        );
+
+        let different = discover_cached(&other).unwrap();
+        assert!(!Arc::ptr_eq(&first, &different));
+        assert_eq!(
+            different.operation_ids()[0].operation_id,
+            "CachedOtherService_GetItem"
+        );
+
+        assert!(discover_cached(b"\xff\xff").is_err());
+    }
+
+    #[test]
+    fn explicit_json_name_keys_constraints_and_descriptions() {
+        let mut fdset = make_fdset_with_services(vec![make_service_with_http(
+            "ItemService",
+            "GetItem",
+            HttpPattern::Get("/v1/items/{name}".to_string()),
+            false,
+        )]);
+        let mut id = make_field("id", field_type::STRING);
+        id.json_name = Some("ID".to_string());
+        id.options = Some(FieldOptions {
+            rules: Some(FieldRules {
+                string: Some(StringRules {
+                    min_len: Some(1),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+        });
+        let message = &mut fdset.file[0].message_type[0];
+        message.field.push(id);
+        let id_index = i32::try_from(message.field.len() - 1).unwrap();
+        fdset.file[0].source_code_info = Some(SourceCodeInfo {
+            location: vec![Location {
+                path: vec![4, 0, 2, id_index],
+                span: vec![0, 0, 10],
+                leading_comments: Some(" Opaque identifier.\n".to_string()),
+            }],
+        });
+
+        let metadata = discover(&fdset.encode_to_vec()).unwrap();
+
+        let constraint = metadata
+            .field_constraints
+            .iter()
+            .flat_map(|c| &c.fields)
+            .find(|f| f.min == Some(1))
+            .unwrap();
+        assert_eq!(constraint.field, "ID");
+        let docs = metadata.field_descriptions().values().next().unwrap();
+        assert_eq!(docs["ID"], "Opaque identifier.");
+    }
+
+    #[test]
+    fn field_defaults_detected() {
+        let mut fdset = make_fdset_with_services(vec![make_service_with_http(
+            "ItemService",
+            "GetItem",
+            HttpPattern::Get("/v1/items/{name}".to_string()),
+            false,
+        )]);
+        let mut notify = make_field("send_notification", field_type::BOOL);
+        notify.default_value = Some("true".to_string());
+        fdset.file[0].message_type[0].field.push(notify);
+
+        let metadata = discover(&fdset.encode_to_vec()).unwrap();
+
+        let defaults = metadata.field_defaults();
+        assert_eq!(defaults.len(), 1);
+        assert_eq!(defaults[0].field, "sendNotification");
+        assert_eq!(defaults[0].value, "true");
+        assert_eq!(defaults[0].proto_type, field_type::BOOL);
+    }
+
+    #[test]
+    fn aliased_enum_values_deduplicated_by_number() {
+        let value = |name: &str, number: i32| EnumValueDescriptorProto {
+            name: Some(name.to_string()),
+            number: Some(number),
+        };
+        let mut status = make_field("status", field_type::ENUM);
+        status.type_name = Some(".test.v1.Status".to_string());
+        let fdset = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("test.proto".to_string()),
+                package: Some("test.v1".to_string()),
+                message_type: vec![DescriptorProto {
+                    name: Some("Response".to_string()),
+                    field: vec![status],
+                    oneof_decl: vec![],
+                    options: None,
+                    nested_type: vec![],
+                }],
+                enum_type: vec![EnumDescriptorProto {
+                    name: Some("Status".to_string()),
+                    value: vec![
+                        value("STATUS_UNSPECIFIED", 0),
+                        value("STATUS_ACTIVE", 1),
+                        value("STATUS_RUNNING", 1),
+                        value("STATUS_STOPPED", 2),
+                    ],
+                }],
+                service: vec![],
+                source_code_info: None,
+            }],
+        };
+        let metadata = discover(&fdset.encode_to_vec()).unwrap();
+
+        let rewrite = &metadata.enum_rewrites[0];
+        assert_eq!(rewrite.values, ["unspecified", "active", "stopped"]);
+        assert_eq!(
+            rewrite.raw_values,
+            ["STATUS_UNSPECIFIED", "STATUS_ACTIVE", "STATUS_STOPPED"]
+        );
+        assert_eq!(rewrite.descriptions.len(), 3);
+        // The alias still rewrites, to the first declared name's value.
+        assert_eq!(metadata.enum_value_map["STATUS_RUNNING"], "active");
     }
 
     #[test]
@@ -1169,10 +2657,17 @@ mod tests {
                     name: Some("Response".to_string()),
                     field: vec![FieldDescriptorProto {
                         name: Some("status".to_string()),
+                        label: None,
+                        proto3_optional: None,
+                        default_value: None,
+                        json_name: None,
+                        oneof_index: None,
                         r#type: Some(field_type::ENUM),
                         type_name: Some(".test.v1.Status".to_string()),
                         options: None,
                     }],
+                    oneof_decl: vec![],
+                    options: None,
                     nested_type: vec![],
                 }],
                 enum_type: vec![EnumDescriptorProto {
@@ -1189,6 +2684,13 @@ mod tests {
                     ],
                 }],
                 service: vec![],
+                source_code_info: Some(SourceCodeInfo {
+                    location: vec![Location {
+                        path: vec![5, 0, 2, 1],
+                        span: vec![12, 2, 20],
+                        leading_comments: Some(" Serving traffic.\n".to_string()),
+                    }],
+                }),
             }],
         };
         let bytes = fdset.encode_to_vec();
@@ -1201,6 +2703,14 @@ mod tests {
             metadata.enum_rewrites[0].values,
             vec!["unspecified", "active"]
         );
+        assert_eq!(
+            metadata.enum_rewrites[0].raw_values,
+            vec!["STATUS_UNSPECIFIED", "STATUS_ACTIVE"]
+        );
+        assert_eq!(
+            metadata.enum_rewrites[0].descriptions,
+            vec!["", "Serving traffic."]
+        );
     }
 
     #[test]
@@ -1212,6 +2722,8 @@ mod tests {
                 message_type: vec![DescriptorProto {
                     name: Some("RedirectResponse".to_string()),
                     field: vec![make_field("redirect_url", field_type::STRING)],
+                    oneof_decl: vec![],
+                    options: None,
                     nested_type: vec![],
                 }],
                 enum_type: vec![],
@@ -1225,12 +2737,16 @@ mod tests {
                             http: Some(HttpRule {
                                 pattern: Some(HttpPattern::Get("/v1/redirect".to_string())),
                                 body: String::new(),
+                                response_body: String::new(),
+                                additional_bindings: vec![],
                             }),
                         }),
                         client_streaming: None,
                         server_streaming: None,
                     }],
+                    options: None,
                 }],
+                source_code_info: None,
             }],
         };
         let bytes = fdset.encode_to_vec();
@@ -1249,6 +2765,11 @@ mod tests {
                     name: Some("Outer".to_string()),
                     field: vec![FieldDescriptorProto {
                         name: Some("name".to_string()),
+                        label: None,
+                        proto3_optional: None,
+                        default_value: None,
+                        json_name: None,
+                        oneof_index: None,
                         r#type: Some(field_type::STRING),
                         type_name: None,
                         options: Some(FieldOptions {
@@ -1258,16 +2779,23 @@ mod tests {
                                     max_len: Some(100),
                                     pattern: None,
                                     r#in: vec![],
-                                    uuid: None,
+                                    ..Default::default()
                                 }),
                                 ..Default::default()
                             }),
                         }),
                     }],
+                    oneof_decl: vec![],
+                    options: None,
                     nested_type: vec![DescriptorProto {
                         name: Some("Inner".to_string()),
                         field: vec![FieldDescriptorProto {
                             name: Some("value".to_string()),
+                            label: None,
+                            proto3_optional: None,
+                            default_value: None,
+                            json_name: None,
+                            oneof_index: None,
                             r#type: Some(field_type::STRING),
                             type_name: None,
                             options: Some(FieldOptions {
@@ -1277,17 +2805,20 @@ mod tests {
                                         max_len: None,
                                         pattern: None,
                                         r#in: vec![],
-                                        uuid: None,
+                                        ..Default::default()
                                     }),
                                     ..Default::default()
                                 }),
                             }),
                         }],
+                        oneof_decl: vec![],
+                        options: None,
                         nested_type: vec![],
                     }],
                 }],
                 enum_type: vec![],
                 service: vec![],
+                source_code_info: None,
             }],
         };
         let bytes = fdset.encode_to_vec();
@@ -1335,9 +2866,13 @@ mod tests {
                 message_type: vec![DescriptorProto {
                     name: Some("Outer".to_string()),
                     field: vec![make_field("name", field_type::STRING)],
+                    oneof_decl: vec![],
+                    options: None,
                     nested_type: vec![DescriptorProto {
                         name: Some("Inner".to_string()),
                         field: vec![make_field("value", field_type::STRING)],
+                        oneof_decl: vec![],
+                        options: None,
                         nested_type: vec![],
                     }],
                 }],
@@ -1352,12 +2887,16 @@ mod tests {
                             http: Some(HttpRule {
                                 pattern: Some(HttpPattern::Get("/v1/outer/{value}".to_string())),
                                 body: String::new(),
+                                response_body: String::new(),
+                                additional_bindings: vec![],
                             }),
                         }),
                         client_streaming: None,
                         server_streaming: None,
                     }],
+                    options: None,
                 }],
+                source_code_info: None,
             }],
         };
         let bytes = fdset.encode_to_vec();
@@ -1370,6 +2909,201 @@ mod tests {
         );
     }
 
+    #[test]
+    fn discover_extracts_bytes_fields() {
+        let mut fdset = make_fdset_with_services(vec![]);
+        fdset.file[0].message_type[0]
+            .field
+            .push(make_field("raw_content", field_type::BYTES));
+        let bytes = fdset.encode_to_vec();
+        let metadata = discover(&bytes).unwrap();
+
+        assert_eq!(metadata.bytes_fields().len(), 1);
+        assert_eq!(metadata.bytes_fields()[0].schema, "test.v1.Request");
+        assert_eq!(metadata.bytes_fields()[0].field, "rawContent");
+    }
+
+    #[test]
+    fn discover_extracts_oneof_with_kind_discriminator() {
+        let member = |name: &str| FieldDescriptorProto {
+            oneof_index: Some(0),
+            ..make_field(name, field_type::STRING)
+        };
+        let mut fdset = make_fdset_with_services(vec![]);
+        let msg = &mut fdset.file[0].message_type[0];
+        msg.field = vec![
+            make_field("kind", field_type::ENUM),
+            member("password"),
+            member("api_token"),
+            FieldDescriptorProto {
+                proto3_optional: Some(true),
+                default_value: None,
+                json_name: None,
+                oneof_index: Some(1),
+                ..make_field("note", field_type::STRING)
+            },
+        ];
+        msg.oneof_decl = vec![
+            OneofDescriptorProto {
+                name: Some("credential".to_string()),
+            },
+            OneofDescriptorProto {
+                name: Some("_note".to_string()),
+            },
+        ];
+        let metadata = discover(&fdset.encode_to_vec()).unwrap();
+
+        let oneofs = metadata.oneofs();
+        assert_eq!(oneofs.len(), 1, "synthetic oneof skipped: {oneofs:?}");
+        assert_eq!(oneofs[0].schema, "test.v1.Request");
+        assert_eq!(oneofs[0].name, "credential");
+        assert_eq!(oneofs[0].fields, ["password", "apiToken"]);
+        assert_eq!(oneofs[0].discriminator.as_deref(), Some("kind"));
+    }
+
+    #[test]
+    fn oneof_without_type_or_kind_has_no_discriminator() {
+        let mut fdset = make_fdset_with_services(vec![]);
+        let msg = &mut fdset.file[0].message_type[0];
+        msg.field[0].oneof_index = Some(0);
+        msg.oneof_decl = vec![OneofDescriptorProto {
+            name: Some("choice".to_string()),
+        }];
+        let metadata = discover(&fdset.encode_to_vec()).unwrap();
+
+        assert_eq!(metadata.oneofs().len(), 1);
+        assert!(metadata.oneofs()[0].discriminator.is_none());
+    }
+
+    #[test]
+    fn discover_extracts_map_fields() {
+        let mut fdset = make_fdset_with_services(vec![]);
+        let msg = &mut fdset.file[0].message_type[0];
+        msg.field.push(FieldDescriptorProto {
+            label: Some(field_label::REPEATED),
+            type_name: Some(".test.v1.Request.LabelsEntry".to_string()),
+            ..make_field("labels", field_type::MESSAGE)
+        });
+        msg.nested_type.push(DescriptorProto {
+            name: Some("LabelsEntry".to_string()),
+            field: vec![
+                make_field("key", field_type::STRING),
+                make_field("value", field_type::STRING),
+            ],
+            oneof_decl: vec![],
+            options: Some(MessageOptions {
+                map_entry: Some(true),
+            }),
+            nested_type: vec![],
+        });
+        let metadata = discover(&fdset.encode_to_vec()).unwrap();
+
+        let maps = metadata.map_fields();
+        assert_eq!(maps.len(), 1);
+        assert_eq!(maps[0].schema, "test.v1.Request");
+        assert_eq!(maps[0].field, "labels");
+        assert_eq!(maps[0].entry_schema, "test.v1.Request.LabelsEntry");
+        assert_eq!(maps[0].value_type, field_type::STRING);
+        assert!(metadata.repeated_fields().is_empty());
+    }
+
+    #[test]
+    fn discover_attaches_leading_comments_to_fields() {
+        let mut fdset = make_fdset_with_services(vec![make_service_with_http(
+            "ItemService",
+            "GetItem",
+            HttpPattern::Get("/v1/items/{name}".to_string()),
+            false,
+        )]);
+        fdset.file[0].message_type[0]
+            .nested_type
+            .push(DescriptorProto {
+                name: Some("Inner".to_string()),
+                field: vec![
+                    make_field("first_id", field_type::STRING),
+                    make_field("display_name", field_type::STRING),
+                ],
+                oneof_decl: vec![],
+                options: None,
+                nested_type: vec![],
+            });
+        let comment = |path: Vec<i32>, text: &str| Location {
+            path,
+            span: vec![0, 0, 10],
+            leading_comments: Some(text.to_string()),
+        };
+        // message_type[0].field[0], then message_type[0].nested_type[0].field[1]
+        fdset.file[0].source_code_info = Some(SourceCodeInfo {
+            location: vec![
+                comment(vec![4, 0, 2, 0], " Resource name.\n"),
+                comment(
+                    vec![4, 0, 3, 0, 2, 1],
+                    " Shown in the UI.\n  Max 64 chars.\n",
+                ),
+                comment(vec![4, 0, 3, 0, 2, 0], "  \n"),
+            ],
+        });
+        let metadata = discover(&fdset.encode_to_vec()).unwrap();
+
+        let docs = metadata.field_descriptions();
+        assert_eq!(docs["test.v1.Request"]["name"], "Resource name.");
+        assert_eq!(
+            docs["test.v1.Request.Inner"]["displayName"],
+            "Shown in the UI.\nMax 64 chars."
+        );
+        assert!(!docs["test.v1.Request.Inner"].contains_key("firstId"));
+
+        let params = &metadata.path_param_constraints()[0].params;
+        assert_eq!(params[0].description.as_deref(), Some("Resource name."));
+    }
+
+    #[test]
+    fn bytes_request_fields_become_bytes_params() {
+        let mut fdset = make_fdset_with_services(vec![make_service_with_http(
+            "FileService",
+            "GetFile",
+            HttpPattern::Get("/v1/files/{file_id}".to_string()),
+            false,
+        )]);
+        fdset.file[0].message_type[0]
+            .field
+            .push(make_field("checksum", field_type::BYTES));
+        let metadata = discover(&fdset.encode_to_vec()).unwrap();
+
+        let params = metadata.bytes_params();
+        assert_eq!(params.len(), 1);
+        assert_eq!(params[0].method, "get");
+        assert_eq!(params[0].path, "/v1/files/{fileId}");
+        assert_eq!(params[0].name, "checksum");
+    }
+
+    #[test]
+    fn unbound_path_param_is_reported() {
+        let fdset = make_fdset_with_services(vec![
+            make_service_with_http(
+                "TestService",
+                "GetItem",
+                HttpPattern::Get("/v1/items/{item_id}".to_string()),
+                false,
+            ),
+            make_service_with_http(
+                "TestService",
+                "GetByName",
+                HttpPattern::Get("/v1/{name=items/*}".to_string()),
+                false,
+            ),
+        ]);
+        let bytes = fdset.encode_to_vec();
+        let metadata = discover(&bytes).unwrap();
+
+        let unbound = metadata.unbound_path_params();
+        assert_eq!(unbound.len(), 1, "only item_id is missing: {unbound:?}");
+        assert_eq!(unbound[0].operation_id, "TestService_GetItem");
+        assert_eq!(unbound[0].path, "/v1/items/{item_id}");
+        assert_eq!(unbound[0].param, "item_id");
+        assert_eq!(unbound[0].input_type, ".test.v1.Request");
+    }
+
     #[test]
     fn nested_enum_rewrites_use_qualified_schema() {
         let fdset = FileDescriptorSet {
@@ -1379,14 +3113,23 @@ mod tests {
                 message_type: vec![DescriptorProto {
                     name: Some("Outer".to_string()),
                     field: vec![],
+                    oneof_decl: vec![],
+                    options: None,
                     nested_type: vec![DescriptorProto {
                         name: Some("Inner".to_string()),
                         field: vec![FieldDescriptorProto {
                             name: Some("status".to_string()),
+                            label: None,
+                            proto3_optional: None,
+                            default_value: None,
+                            json_name: None,
+                            oneof_index: None,
                             r#type: Some(field_type::ENUM),
                             type_name: Some(".test.v1.Status".to_string()),
                             options: None,
                         }],
+                        oneof_decl: vec![],
+                        options: None,
                         nested_type: vec![],
                     }],
                 }],
@@ -1404,6 +3147,7 @@ mod tests {
                     ],
                 }],
                 service: vec![],
+                source_code_info: None,
             }],
         };
         let bytes = fdset.encode_to_vec();
@@ -1428,6 +3172,11 @@ mod tests {
                     name: Some("Request".to_string()),
                     field: vec![FieldDescriptorProto {
                         name: Some("count".to_string()),
+                        label: None,
+                        proto3_optional: None,
+                        default_value: None,
+                        json_name: None,
+                        oneof_index: None,
                         r#type: Some(field_type::INT32),
                         type_name: None,
                         options: Some(FieldOptions {
@@ -1442,10 +3191,13 @@ mod tests {
                             }),
                         }),
                     }],
+                    oneof_decl: vec![],
+                    options: None,
                     nested_type: vec![],
                 }],
                 enum_type: vec![],
                 service: vec![],
+                source_code_info: None,
             }],
         };
         let bytes = fdset.encode_to_vec();
@@ -1458,6 +3210,166 @@ mod tests {
         assert!(fc.is_numeric);
     }
 
+    #[test]
+    fn well_known_string_rules_become_formats() {
+        let with_rules = |name: &str, rules: StringRules| {
+            let mut field = make_field(name, field_type::STRING);
+            field.options = Some(FieldOptions {
+                rules: Some(FieldRules {
+                    string: Some(rules),
+                    ..Default::default()
+                }),
+            });
+            field
+        };
+        let fdset = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("test.proto".to_string()),
+                package: Some("test.v1".to_string()),
+                message_type: vec![DescriptorProto {
+                    name: Some("Contact".to_string()),
+                    field: vec![
+                        with_rules(
+                            "email",
+                            StringRules {
+                                email: Some(true),
+                                ..Default::default()
+                            },
+                        ),
+                        with_rules(
+                            "website",
+                            StringRules {
+                                uri: Some(true),
+                                max_len: Some(2048),
+                                ..Default::default()
+                            },
+                        ),
+                    ],
+                    oneof_decl: vec![],
+                    options: None,
+                    nested_type: vec![],
+                }],
+                enum_type: vec![],
+                service: vec![],
+                source_code_info: None,
+            }],
+        };
+        let metadata = discover(&fdset.encode_to_vec()).unwrap();
+
+        let fields = &metadata.field_constraints[0].fields;
+        assert_eq!(fields[0].field, "email");
+        assert_eq!(fields[0].format.as_deref(), Some("email"));
+        assert_eq!(fields[1].field, "website");
+        assert_eq!(fields[1].format.as_deref(), Some("uri"));
+        assert_eq!(fields[1].max, Some(2048));
+    }
+
+    #[test]
+    fn exact_string_len_sets_both_bounds() {
+        let mut code = make_field("code", field_type::STRING);
+        code.options = Some(FieldOptions {
+            rules: Some(FieldRules {
+                string: Some(StringRules {
+                    exact_len: Some(6),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+        });
+        let fdset = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("test.proto".to_string()),
+                package: Some("test.v1".to_string()),
+                message_type: vec![DescriptorProto {
+                    name: Some("VerifyRequest".to_string()),
+                    field: vec![code],
+                    oneof_decl: vec![],
+                    options: None,
+                    nested_type: vec![],
+                }],
+                enum_type: vec![],
+                service: vec![],
+                source_code_info: None,
+            }],
+        };
+        let metadata = discover(&fdset.encode_to_vec()).unwrap();
+
+        let fc = &metadata.field_constraints[0].fields[0];
+        assert_eq!(fc.field, "code");
+        assert_eq!(fc.min, Some(6));
+        assert_eq!(fc.max, Some(6));
+        assert!(fc.required, "len >= 1 implies a non-empty value");
+    }
+
+    #[test]
+    fn proto3_optional_fields_discovered() {
+        let mut nickname = make_field("nick_name", field_type::STRING);
+        nickname.proto3_optional = Some(true);
+        let fdset = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("test.proto".to_string()),
+                package: Some("test.v1".to_string()),
+                message_type: vec![DescriptorProto {
+                    name: Some("User".to_string()),
+                    field: vec![make_field("name", field_type::STRING), nickname],
+                    oneof_decl: vec![],
+                    options: None,
+                    nested_type: vec![],
+                }],
+                enum_type: vec![],
+                service: vec![],
+                source_code_info: None,
+            }],
+        };
+        let metadata = discover(&fdset.encode_to_vec()).unwrap();
+
+        let optional = metadata.optional_fields();
+        assert_eq!(optional.len(), 1);
+        assert_eq!(optional[0].schema, "test.v1.User");
+        assert_eq!(optional[0].field, "nickName");
+    }
+
+    #[test]
+    fn repeated_item_bounds_discovered() {
+        let mut roles = make_field("roles", field_type::STRING);
+        roles.label = Some(field_label::REPEATED);
+        roles.options = Some(FieldOptions {
+            rules: Some(FieldRules {
+                repeated: Some(RepeatedRules {
+                    min_items: Some(1),
+                    max_items: Some(10),
+                }),
+                ..Default::default()
+            }),
+        });
+        let fdset = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("test.proto".to_string()),
+                package: Some("test.v1".to_string()),
+                message_type: vec![DescriptorProto {
+                    name: Some("User".to_string()),
+                    field: vec![roles],
+                    oneof_decl: vec![],
+                    options: None,
+                    nested_type: vec![],
+                }],
+                enum_type: vec![],
+                service: vec![],
+                source_code_info: None,
+            }],
+        };
+        let metadata = discover(&fdset.encode_to_vec()).unwrap();
+
+        assert_eq!(metadata.field_constraints.len(), 1);
+        let fc = &metadata.field_constraints[0].fields[0];
+        assert_eq!(fc.field, "roles");
+        assert_eq!(fc.min_items, Some(1));
+        assert_eq!(fc.max_items, Some(10));
+        assert_eq!(fc.min, None);
+        assert!(!fc.required);
+        assert!(!fc.is_numeric);
+    }
+
     #[test]
     fn uint32_lt_zero_no_underflow() {
         // Test lt = 0 should not underflow (saturates to 0)
@@ -1469,6 +3381,11 @@ mod tests {
                     name: Some("Request".to_string()),
                     field: vec![FieldDescriptorProto {
                         name: Some("count".to_string()),
+                        label: None,
+                        proto3_optional: None,
+                        default_value: None,
+                        json_name: None,
+                        oneof_index: None,
                         r#type: Some(field_type::UINT32),
                         type_name: None,
                         options: Some(FieldOptions {
@@ -1483,10 +3400,13 @@ mod tests {
                             }),
                         }),
                     }],
+                    oneof_decl: vec![],
+                    options: None,
                     nested_type: vec![],
                 }],
                 enum_type: vec![],
                 service: vec![],
+                source_code_info: None,
             }],
         };
         let bytes = fdset.encode_to_vec();
@@ -1509,6 +3429,11 @@ mod tests {
                     name: Some("Request".to_string()),
                     field: vec![FieldDescriptorProto {
                         name: Some("content_size".to_string()),
+                        label: None,
+                        proto3_optional: None,
+                        default_value: None,
+                        json_name: None,
+                        oneof_index: None,
                         r#type: Some(field_type::UINT64),
                         type_name: None,
                         options: Some(FieldOptions {
@@ -1523,10 +3448,13 @@ mod tests {
                             }),
                         }),
                     }],
+                    oneof_decl: vec![],
+                    options: None,
                     nested_type: vec![],
                 }],
                 enum_type: vec![],
                 service: vec![],
+                source_code_info: None,
             }],
         };
         let bytes = fdset.encode_to_vec();
@@ -1539,4 +3467,245 @@ mod tests {
         assert_eq!(fc.max, Some(10_485_760));
         assert!(fc.is_numeric);
     }
+
+    #[test]
+    fn uint64_bounds_beyond_json_safe_range_kept() {
+        let fdset = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("test.proto".to_string()),
+                package: Some("test.v1".to_string()),
+                message_type: vec![DescriptorProto {
+                    name: Some("Request".to_string()),
+                    field: vec![FieldDescriptorProto {
+                        name: Some("offset".to_string()),
+                        label: None,
+                        proto3_optional: None,
+                        default_value: None,
+                        json_name: None,
+                        oneof_index: None,
+                        r#type: Some(field_type::UINT64),
+                        type_name: None,
+                        options: Some(FieldOptions {
+                            rules: Some(FieldRules {
+                                uint64: Some(UInt64Rules {
+                                    gt: None,
+                                    gte: Some(1 << 60),
+                                    lt: None,
+                                    lte: Some(u64::MAX),
+                                }),
+                                ..Default::default()
+                            }),
+                        }),
+                    }],
+                    oneof_decl: vec![],
+                    options: None,
+                    nested_type: vec![],
+                }],
+                enum_type: vec![],
+                service: vec![],
+                source_code_info: None,
+            }],
+        };
+        let metadata = discover(&fdset.encode_to_vec()).unwrap();
+
+        let fc = &metadata.field_constraints[0].fields[0];
+        assert_eq!(fc.min, Some(1 << 60));
+        assert_eq!(fc.max, Some(u64::MAX));
+        assert!(fc.is_numeric);
+        assert_eq!(fc.proto_type, field_type::UINT64);
+    }
+
+    #[test]
+    fn repeated_fields_detected() {
+        // Proto:
+        //   message BatchRequest {
+        //     repeated string item_ids = 1;
+        //     string name = 2;
+        //     map<string, string> labels = 3;
+        //   }
+        let repeated = |name: &str, ty: i32, type_name: Option<&str>| FieldDescriptorProto {
+            name: Some(name.to_string()),
+            label: Some(field_label::REPEATED),
+            proto3_optional: None,
+            default_value: None,
+            json_name: None,
+            oneof_index: None,
+            r#type: Some(ty),
+            type_name: type_name.map(ToString::to_string),
+            options: None,
+        };
+        let fdset = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("test.proto".to_string()),
+                package: Some("test.v1".to_string()),
+                message_type: vec![DescriptorProto {
+                    name: Some("BatchRequest".to_string()),
+                    field: vec![
+                        repeated("item_ids", field_type::STRING, None),
+                        make_field("name", field_type::STRING),
+                        repeated(
+                            "labels",
+                            field_type::MESSAGE,
+                            Some(".test.v1.BatchRequest.LabelsEntry"),
+                        ),
+                    ],
+                    oneof_decl: vec![],
+                    options: None,
+                    nested_type: vec![DescriptorProto {
+                        name: Some("LabelsEntry".to_string()),
+                        field: vec![
+                            make_field("key", field_type::STRING),
+                            make_field("value", field_type::STRING),
+                        ],
+                        oneof_decl: vec![],
+                        options: None,
+                        nested_type: vec![],
+                    }],
+                }],
+                enum_type: vec![],
+                service: vec![],
+                source_code_info: None,
+            }],
+        };
+        let bytes = fdset.encode_to_vec();
+        let metadata = discover(&bytes).unwrap();
+
+        assert_eq!(metadata.repeated_fields().len(), 1);
+        assert_eq!(metadata.repeated_fields()[0].schema, "test.v1.BatchRequest");
+        assert_eq!(metadata.repeated_fields()[0].field, "itemIds");
+        assert!(metadata.is_repeated("test.v1.BatchRequest", "itemIds"));
+        assert!(!metadata.is_repeated("test.v1.BatchRequest", "name"));
+        assert!(
+            !metadata.is_repeated("test.v1.BatchRequest", "labels"),
+            "map fields render as objects, not arrays"
+        );
+    }
+
+    #[test]
+    fn paginated_responses_detected() {
+        // Proto:
+        //   message ListUsersResponse {
+        //     repeated User users = 1;
+        //     string next_page_token = 2;
+        //   }
+        //   message BatchResponse {
+        //     repeated string ids = 1;
+        //     repeated string errors = 2;
+        //     string next_page_token = 3;
+        //   }
+        let repeated = |name: &str| FieldDescriptorProto {
+            name: Some(name.to_string()),
+            label: Some(field_label::REPEATED),
+            proto3_optional: None,
+            default_value: None,
+            json_name: None,
+            oneof_index: None,
+            r#type: Some(field_type::STRING),
+            type_name: None,
+            options: None,
+        };
+        let fdset = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("test.proto".to_string()),
+                package: Some("test.v1".to_string()),
+                message_type: vec![
+                    DescriptorProto {
+                        name: Some("ListUsersResponse".to_string()),
+                        field: vec![
+                            repeated("users"),
+                            make_field("next_page_token", field_type::STRING),
+                        ],
+                        oneof_decl: vec![],
+                        options: None,
+                        nested_type: vec![],
+                    },
+                    DescriptorProto {
+                        name: Some("BatchResponse".to_string()),
+                        field: vec![
+                            repeated("ids"),
+                            repeated("errors"),
+                            make_field("next_page_token", field_type::STRING),
+                        ],
+                        oneof_decl: vec![],
+                        options: None,
+                        nested_type: vec![],
+                    },
+                ],
+                enum_type: vec![],
+                service: vec![],
+                source_code_info: None,
+            }],
+        };
+        let bytes = fdset.encode_to_vec();
+        let metadata = discover(&bytes).unwrap();
+
+        let paginated = metadata.paginated_responses();
+        assert_eq!(paginated.len(), 1, "ambiguous item fields are skipped");
+        assert_eq!(paginated[0].schema, "test.v1.ListUsersResponse");
+        assert_eq!(paginated[0].items_field, "users");
+        assert_eq!(paginated[0].token_field, "nextPageToken");
+        assert_eq!(
+            paginated[0].max_page_size, None,
+            "no request bounds page_size"
+        );
+        assert_eq!(paginated[0].request_token_field, None, "no list request");
+    }
+
+    #[test]
+    fn paginated_request_token_linked() {
+        let mut items = make_field("items", field_type::STRING);
+        items.label = Some(field_label::REPEATED);
+        let mut fdset = make_fdset_with_services(vec![make_service_with_http(
+            "ItemService",
+            "ListItems",
+            HttpPattern::Get("/v1/items".to_string()),
+            false,
+        )]);
+        let file = &mut fdset.file[0];
+        file.message_type[0]
+            .field
+            .push(make_field("page_token", field_type::STRING));
+        file.message_type.push(DescriptorProto {
+            name: Some("Response".to_string()),
+            field: vec![items, make_field("next_page_token", field_type::STRING)],
+            oneof_decl: vec![],
+            options: None,
+            nested_type: vec![],
+        });
+
+        let metadata = discover(&fdset.encode_to_vec()).unwrap();
+        let paginated = metadata.paginated_responses();
+        assert_eq!(paginated.len(), 1);
+        assert_eq!(
+            paginated[0].request_token_field.as_deref(),
+            Some("pageToken")
+        );
+    }
+
+    #[test]
+    fn discover_reads_default_host_service_option() {
+        let with_host = |name: &str, host: &str| ServiceDescriptorProto {
+            options: Some(ServiceOptions {
+                default_host: Some(host.to_string()),
+            }),
+            ..make_service_with_http(name, "Get", HttpPattern::Get(format!("/v1/{name}")), false)
+        };
+        let fdset = make_fdset_with_services(vec![
+            with_host("Users", "api.example.com"),
+            with_host("Items", "api.example.com"),
+            with_host("Admin", "admin.example.com"),
+            make_service_with_http(
+                "Plain",
+                "Get",
+                HttpPattern::Get("/v1/plain".to_string()),
+                false,
+            ),
+        ]);
+
+        let metadata = discover(&fdset.encode_to_vec()).unwrap();
+        assert_eq!(
+            metadata.default_hosts(),
+            ["api.example.com", "admin.example.com"]
+        );
+    }
 }
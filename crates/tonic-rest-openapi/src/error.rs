@@ -16,6 +16,19 @@ pub enum Error {
     #[error(transparent)]
     Yaml(#[from] serde_yaml_ng::Error),
 
+    /// JSON serialization failure.
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+
+    /// A YAML construct with no JSON equivalent, found by [`to_json`](crate::to_json).
+    #[error("cannot convert YAML to JSON at {path}: {reason}")]
+    NonJsonYaml {
+        /// JSON-pointer path of the offending node.
+        path: String,
+        /// What could not be converted.
+        reason: String,
+    },
+
     /// Proto `FileDescriptorSet` decoding failure.
     #[error("failed to decode proto descriptor: {0}")]
     ProtoDecode(#[from] prost::DecodeError),
@@ -46,6 +59,60 @@ pub enum Error {
         /// All matching operation IDs.
         candidates: Vec<String>,
     },
+
+    /// A configured server variable is not spec-valid.
+    #[error("invalid variable '{variable}' for server '{url}': {reason}")]
+    InvalidServerVariable {
+        /// URL of the server entry.
+        url: String,
+        /// Name of the offending variable.
+        variable: String,
+        /// What is wrong with it.
+        reason: String,
+    },
+
+    /// A configured security scheme is not spec-valid.
+    #[error("invalid security scheme '{name}': {reason}")]
+    InvalidSecurityScheme {
+        /// Name of the scheme under `security_schemes`.
+        name: String,
+        /// What is wrong with it.
+        reason: String,
+    },
+
+    /// Schema name sanitization mapped several schemas to one name.
+    ///
+    /// Only raised when [`PatchConfig::sanitize_schema_names`](crate::PatchConfig::sanitize_schema_names)
+    /// is set.
+    #[error("sanitized schema name '{name}' is shared by {schemas:?}")]
+    SchemaNameCollision {
+        /// The colliding sanitized name.
+        name: String,
+        /// Original names of the schemas that map to it, sorted.
+        schemas: Vec<String>,
+    },
+
+    /// The patched spec contains `$ref`s that point to missing targets.
+    ///
+    /// Only raised when strict reference checking is enabled via
+    /// [`PatchConfig::strict_refs`](crate::PatchConfig::strict_refs).
+    #[error("patched spec contains unresolved $ref targets: {refs:?}")]
+    UnresolvedRefs {
+        /// Dangling `$ref` values, sorted.
+        refs: Vec<String>,
+    },
+
+    /// The patched spec is structurally invalid.
+    ///
+    /// Only raised when output validation is enabled via
+    /// [`PatchConfig::validate_output`](crate::PatchConfig::validate_output).
+    #[error("patched spec is invalid at {path}: {reason}")]
+    InvalidSpec {
+        /// JSON pointer of the offending node.
+        path: String,
+        /// What is wrong with it.
+        reason: String,
+    },
 }
 
 /// Convenience alias used throughout the library's public API.
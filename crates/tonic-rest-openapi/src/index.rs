@@ -0,0 +1,114 @@
+//! Operation index for documentation tooling.
+//!
+//! Doc sites build search and navigation from a flat list of operations
+//! rather than the nested `paths` tree. [`operation_index`] derives that
+//! list from a finished spec.
+
+use serde_yaml_ng::{Mapping, Value};
+
+use crate::patch::helpers::HTTP_METHODS;
+
+/// Build an `operationId`-keyed index of every operation in `doc`.
+///
+/// Each entry records the upper-case HTTP `method`, the `path` template, the
+/// operation's `tags` (empty when untagged), and its `summary` when present:
+///
+/// ```yaml
+/// UserService_GetUser:
+///   method: GET
+///   path: /v1/users/{user_id}
+///   tags: [UserService]
+///   summary: Get a user by ID.
+/// ```
+///
+/// Entries follow the order of `paths` in the spec. Operations without an
+/// `operationId` are skipped. Render the result with [`to_json`](crate::to_json).
+#[must_use]
+pub fn operation_index(doc: &Value) -> Value {
+    let mut index = Mapping::new();
+
+    let Some(paths) = doc.get("paths").and_then(Value::as_mapping) else {
+        return Value::Mapping(index);
+    };
+
+    for (path, path_item) in paths {
+        let (Some(path), Some(path_item)) = (path.as_str(), path_item.as_mapping()) else {
+            continue;
+        };
+        for (method, operation) in path_item {
+            let Some(method) = method.as_str().filter(|m| HTTP_METHODS.contains(m)) else {
+                continue;
+            };
+            let Some(operation_id) = operation.get("operationId").and_then(Value::as_str) else {
+                continue;
+            };
+
+            let mut entry = Mapping::new();
+            entry.insert("method".into(), method.to_uppercase().into());
+            entry.insert("path".into(), path.into());
+            entry.insert(
+                "tags".into(),
+                operation
+                    .get("tags")
+                    .cloned()
+                    .unwrap_or_else(|| Value::Sequence(Vec::new())),
+            );
+            if let Some(summary) = operation.get("summary") {
+                entry.insert("summary".into(), summary.clone());
+            }
+            index.insert(operation_id.into(), Value::Mapping(entry));
+        }
+    }
+
+    Value::Mapping(index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index_lists_every_operation() {
+        let doc: Value = serde_yaml_ng::from_str(
+            r"
+paths:
+  /v1/items:
+    parameters: []
+    get:
+      operationId: ItemService_ListItems
+      tags: [ItemService]
+      summary: List items.
+    post:
+      operationId: ItemService_CreateItem
+      tags: [ItemService]
+  /v1/items/{item_id}:
+    delete:
+      operationId: ItemService_DeleteItem
+    patch:
+      summary: No operationId.
+",
+        )
+        .unwrap();
+
+        let index = operation_index(&doc);
+        let index = index.as_mapping().unwrap();
+        assert_eq!(index.len(), 3);
+
+        let list = &index["ItemService_ListItems"];
+        assert_eq!(list["method"].as_str(), Some("GET"));
+        assert_eq!(list["path"].as_str(), Some("/v1/items"));
+        assert_eq!(list["tags"][0].as_str(), Some("ItemService"));
+        assert_eq!(list["summary"].as_str(), Some("List items."));
+
+        assert_eq!(
+            index["ItemService_CreateItem"]["method"].as_str(),
+            Some("POST")
+        );
+        assert!(index["ItemService_CreateItem"].get("summary").is_none());
+
+        let delete = &index["ItemService_DeleteItem"];
+        assert_eq!(delete["method"].as_str(), Some("DELETE"));
+        assert_eq!(delete["path"].as_str(), Some("/v1/items/{item_id}"));
+        assert_eq!(delete["tags"], Value::Sequence(Vec::new()));
+    }
+}
@@ -0,0 +1,224 @@
+//! JSON rendering of a patched `OpenAPI` document.
+//!
+//! The pipeline works on YAML, but some consumers only accept JSON specs.
+//! [`to_json`] converts a parsed spec into pretty-printed JSON, resolving or
+//! rejecting the YAML-only constructs that have no JSON equivalent.
+
+use serde_yaml_ng::Value;
+
+use crate::error::{self, Error};
+
+/// Render a YAML `OpenAPI` document as pretty-printed JSON.
+///
+/// The conversion is lossless for any document that round-trips through
+/// JSON, and keys keep their YAML order:
+/// - `<<` merge keys are expanded first, so no merge entries leak into the output.
+/// - Scalar mapping keys (e.g. unquoted `200:` response codes) become strings.
+///
+/// # Errors
+///
+/// Returns [`Error::NonJsonYaml`] for constructs JSON cannot express: tagged
+/// values, non-scalar mapping keys, and non-finite floats. Malformed merge
+/// keys surface as [`Error::Yaml`].
+pub fn to_json(doc: &Value) -> error::Result<String> {
+    let mut doc = doc.clone();
+    doc.apply_merge()?;
+
+    let json = yaml_to_json(&doc, "")?;
+    serde_json::to_string_pretty(&json).map_err(Error::from)
+}
+
+/// Convert one YAML node, tracking its JSON-pointer path for error messages.
+fn yaml_to_json(value: &Value, path: &str) -> error::Result<serde_json::Value> {
+    Ok(match value {
+        Value::Null => serde_json::Value::Null,
+        Value::Bool(b) => serde_json::Value::Bool(*b),
+        Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                i.into()
+            } else if let Some(u) = n.as_u64() {
+                u.into()
+            } else {
+                let f = n.as_f64().unwrap_or(f64::NAN);
+                serde_json::Number::from_f64(f)
+                    .map(serde_json::Value::Number)
+                    .ok_or_else(|| non_json(path, format!("non-finite number {n}")))?
+            }
+        }
+        Value::String(s) => serde_json::Value::String(s.clone()),
+        Value::Sequence(items) => items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| yaml_to_json(item, &format!("{path}/{i}")))
+            .collect::<error::Result<_>>()?,
+        Value::Mapping(map) => {
+            let mut out = serde_json::Map::with_capacity(map.len());
+            for (k, v) in map {
+                let key = match k {
+                    Value::String(s) => s.clone(),
+                    Value::Number(n) => n.to_string(),
+                    Value::Bool(b) => b.to_string(),
+                    _ => return Err(non_json(path, "non-scalar mapping key".to_string())),
+                };
+                let child = yaml_to_json(v, &format!("{path}/{}", escape_pointer(&key)))?;
+                out.insert(key, child);
+            }
+            serde_json::Value::Object(out)
+        }
+        Value::Tagged(tagged) => {
+            return Err(non_json(path, format!("tagged value {}", tagged.tag)));
+        }
+    })
+}
+
+/// Build an [`Error::NonJsonYaml`] for the node at `path` (`/` for the root).
+fn non_json(path: &str, reason: String) -> Error {
+    Error::NonJsonYaml {
+        path: if path.is_empty() {
+            "/".to_string()
+        } else {
+            path.to_string()
+        },
+        reason,
+    }
+}
+
+/// Escape a key for use as a JSON-pointer segment (RFC 6901).
+pub(crate) fn escape_pointer(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC: &str = r"
+openapi: 3.1.0
+info:
+  title: Test
+  version: 1.0.0
+paths:
+  /v1/items:
+    get:
+      operationId: ItemService_ListItems
+      parameters:
+        - name: limit
+          in: query
+          schema:
+            type: integer
+            maximum: 100
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/ItemList'
+components:
+  schemas:
+    ItemList:
+      type: object
+      properties:
+        items:
+          type: array
+          items:
+            type: string
+        ratio:
+          type: number
+          example: 0.5
+        nullable:
+          type: [string, 'null']
+";
+
+    #[test]
+    fn json_and_yaml_represent_same_document() {
+        let doc: Value = serde_yaml_ng::from_str(SPEC).unwrap();
+        let json = to_json(&doc).unwrap();
+
+        let from_json: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let from_yaml: serde_json::Value = serde_yaml_ng::from_str(SPEC).unwrap();
+        assert_eq!(from_json, from_yaml);
+        assert!(
+            json.contains("\n  \"openapi\": \"3.1.0\""),
+            "not pretty: {json}"
+        );
+    }
+
+    /// Collect mapping keys depth-first, in document order.
+    fn yaml_keys(value: &Value, out: &mut Vec<String>) {
+        match value {
+            Value::Mapping(map) => {
+                for (k, v) in map {
+                    out.push(k.as_str().unwrap_or_default().to_string());
+                    yaml_keys(v, out);
+                }
+            }
+            Value::Sequence(items) => items.iter().for_each(|item| yaml_keys(item, out)),
+            _ => {}
+        }
+    }
+
+    fn json_keys(value: &serde_json::Value, out: &mut Vec<String>) {
+        match value {
+            serde_json::Value::Object(map) => {
+                for (k, v) in map {
+                    out.push(k.clone());
+                    json_keys(v, out);
+                }
+            }
+            serde_json::Value::Array(items) => items.iter().for_each(|item| json_keys(item, out)),
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn json_keeps_yaml_key_order() {
+        let doc: Value = serde_yaml_ng::from_str(SPEC).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&to_json(&doc).unwrap()).unwrap();
+
+        let (mut expected, mut actual) = (Vec::new(), Vec::new());
+        yaml_keys(&doc, &mut expected);
+        json_keys(&json, &mut actual);
+        assert_eq!(actual, expected);
+        assert_eq!(
+            &actual[..3],
+            ["openapi", "info", "title"],
+            "keys were sorted"
+        );
+    }
+
+    #[test]
+    fn merge_keys_are_expanded() {
+        let yaml = r"
+base: &base
+  type: string
+derived:
+  <<: *base
+  format: uuid
+";
+        let doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&to_json(&doc).unwrap()).unwrap();
+        assert_eq!(
+            json["derived"],
+            serde_json::json!({ "type": "string", "format": "uuid" })
+        );
+    }
+
+    #[test]
+    fn numeric_keys_become_strings() {
+        let doc: Value =
+            serde_yaml_ng::from_str("responses:\n  200:\n    description: OK\n").unwrap();
+        let json: serde_json::Value = serde_json::from_str(&to_json(&doc).unwrap()).unwrap();
+        assert_eq!(json["responses"]["200"]["description"], "OK");
+    }
+
+    #[test]
+    fn tagged_values_are_rejected() {
+        let doc: Value = serde_yaml_ng::from_str("a:\n  b: !custom 1\n").unwrap();
+        let err = to_json(&doc).unwrap_err();
+        match err {
+            Error::NonJsonYaml { path, .. } => assert_eq!(path, "/a/b"),
+            other => panic!("expected NonJsonYaml, got {other:?}"),
+        }
+    }
+}
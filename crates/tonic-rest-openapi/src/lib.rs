@@ -15,7 +15,10 @@ mod config;
 pub(crate) use tonic_rest_core::descriptor;
 mod discover;
 mod error;
+mod index;
+mod json;
 mod patch;
+mod snapshot;
 
 /// Default `$ref` path for the REST error response schema.
 ///
@@ -24,15 +27,25 @@ mod patch;
 pub const DEFAULT_ERROR_SCHEMA_REF: &str = "#/components/schemas/ErrorResponse";
 
 pub use config::{
-    ContactInfo, ExternalDocsInfo, InfoOverrides, LicenseInfo, PlainTextEndpoint, ProjectConfig,
-    ServerEntry, TransformConfig,
+    ApiKeyLocation, Badge, CallbackDef, CodeSample, ContactInfo, DeprecatedMethod, EnumCase,
+    ExternalDocsInfo, FormUrlEncodedEndpoint, InfoOverrides, LicenseInfo, OAuthFlow, OAuthFlows,
+    OperationExample, PlainTextEndpoint, ProjectConfig, SanitizeMode, SecurityScheme, ServerEntry,
+    ServerVariable, TagGroup, TransformConfig,
 };
 pub use discover::{
-    EnumRewrite, FieldConstraint, OperationEntry, PathParamConstraint, PathParamInfo,
-    ProtoMetadata, SchemaConstraints, StreamingOp, discover,
+    BytesField, BytesParam, EnumRewrite, FieldConstraint, FieldDefault, MapField, MethodSource,
+    OneofInfo, OperationEntry, OptionalField, PaginatedResponse, PathParamConstraint,
+    PathParamInfo, ProtoMetadata, RepeatedField, SchemaConstraints, StreamingOp, UnboundPathParam,
+    WrapperField, discover, discover_cached,
 };
 pub use error::{Error, Result};
-pub use patch::{PatchConfig, patch};
+pub use index::operation_index;
+pub use json::to_json;
+pub use patch::{PatchConfig, PatchReport, STANDARD_FORMATS, patch, patch_with_report};
+pub use snapshot::{
+    EnumRewriteSnapshot, FieldConstraintSnapshot, MetadataSnapshot, OperationSnapshot,
+    SchemaConstraintsSnapshot, StreamingOpSnapshot, UnboundPathParamSnapshot,
+};
 
 /// Test-support utilities for constructing `ProtoMetadata` fixtures.
 ///
@@ -65,4 +78,67 @@ impl ProtoMetadata {
     pub fn set_enum_value_map(&mut self, map: HashMap<String, String>) {
         self.enum_value_map = map;
     }
+
+    /// Set repeated fields (test helper).
+    pub fn set_repeated_fields(&mut self, fields: Vec<RepeatedField>) {
+        self.repeated_fields = fields;
+    }
+
+    /// Set bytes fields (test helper).
+    pub fn set_bytes_fields(&mut self, fields: Vec<BytesField>) {
+        self.bytes_fields = fields;
+    }
+
+    /// Set bytes params (test helper).
+    pub fn set_bytes_params(&mut self, params: Vec<BytesParam>) {
+        self.bytes_params = params;
+    }
+
+    /// Set wrapper fields (test helper).
+    pub fn set_wrapper_fields(&mut self, fields: Vec<WrapperField>) {
+        self.wrapper_fields = fields;
+    }
+
+    /// Set proto3 optional fields (test helper).
+    pub fn set_optional_fields(&mut self, fields: Vec<OptionalField>) {
+        self.optional_fields = fields;
+    }
+
+    /// Set oneof groups (test helper).
+    pub fn set_oneofs(&mut self, oneofs: Vec<OneofInfo>) {
+        self.oneofs = oneofs;
+    }
+
+    /// Set map fields (test helper).
+    pub fn set_map_fields(&mut self, fields: Vec<MapField>) {
+        self.map_fields = fields;
+    }
+
+    /// Set field descriptions (test helper).
+    pub fn set_field_descriptions(
+        &mut self,
+        descriptions: HashMap<String, HashMap<String, String>>,
+    ) {
+        self.field_descriptions = descriptions;
+    }
+
+    /// Set field defaults (test helper).
+    pub fn set_field_defaults(&mut self, defaults: Vec<FieldDefault>) {
+        self.field_defaults = defaults;
+    }
+
+    /// Set paginated responses (test helper).
+    pub fn set_paginated_responses(&mut self, responses: Vec<PaginatedResponse>) {
+        self.paginated_responses = responses;
+    }
+
+    /// Set method sources (test helper).
+    pub fn set_method_sources(&mut self, sources: Vec<MethodSource>) {
+        self.method_sources = sources;
+    }
+
+    /// Set default hosts (test helper).
+    pub fn set_default_hosts(&mut self, hosts: Vec<String>) {
+        self.default_hosts = hosts;
+    }
 }
@@ -14,6 +14,12 @@
 //!   --input openapi.yaml \
 //!   --config api/openapi/config.yaml
 //!
+//! # Preview the patch without writing (exits 1 if the spec would change)
+//! tonic-rest-openapi patch \
+//!   --descriptor descriptor.bin \
+//!   --input openapi.yaml \
+//!   --diff
+//!
 //! tonic-rest-openapi discover --descriptor descriptor.bin
 //!
 //! # Optional: inject Cargo.toml version into buf.gen.yaml
@@ -31,7 +37,7 @@ use std::path::{Path, PathBuf};
 use anyhow::{Context, bail};
 use clap::Parser;
 use serde_yaml_ng::Value;
-use tonic_rest_openapi::{PatchConfig, ProjectConfig};
+use tonic_rest_openapi::{PatchConfig, PatchReport, ProjectConfig};
 
 /// `OpenAPI` 3.1 spec generator and patcher for Tonic gRPC services.
 #[derive(Parser)]
@@ -121,6 +127,19 @@ struct PatchArgs {
     /// Skip UUID wrapper flattening.
     #[arg(long)]
     no_uuid_flatten: bool,
+
+    /// Also write the patched spec as pretty-printed JSON to this path.
+    #[arg(long)]
+    json_output: Option<PathBuf>,
+
+    /// Also write a JSON `operationId` → method/path/tags/summary index to this path.
+    #[arg(long)]
+    index: Option<PathBuf>,
+
+    /// Print a unified diff of the patched spec against `--input` instead of
+    /// writing any files. Exits non-zero when the patch would change the spec.
+    #[arg(long)]
+    diff: bool,
 }
 
 #[derive(Parser)]
@@ -128,6 +147,19 @@ struct DiscoverArgs {
     /// Path to the compiled proto `FileDescriptorSet` (binary).
     #[arg(short, long)]
     descriptor: PathBuf,
+
+    /// Output format: a human-readable summary, or JSON for other tools.
+    #[arg(long, value_enum, default_value_t = DiscoverFormat::Text)]
+    format: DiscoverFormat,
+}
+
+/// Output format of the `discover` subcommand.
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum DiscoverFormat {
+    /// Human-readable summary.
+    Text,
+    /// `MetadataSnapshot` as pretty-printed JSON.
+    Json,
 }
 
 #[derive(Parser)]
@@ -141,6 +173,8 @@ struct InjectVersionArgs {
     version: Option<String>,
 
     /// Read version from this `Cargo.toml` instead of `--version`.
+    ///
+    /// `version.workspace = true` is resolved from the parent workspace manifest.
     #[arg(long, conflicts_with = "version")]
     cargo_toml: Option<PathBuf>,
 
@@ -149,6 +183,15 @@ struct InjectVersionArgs {
     /// Useful with `buf generate --template` to avoid modifying the original file.
     #[arg(short, long)]
     output: Option<PathBuf>,
+
+    /// Append `version=<v>` to the first gnostic plugin's (or the `--plugin`
+    /// match's) `opt` array when no plugin has a `version=` option, instead of failing.
+    #[arg(long)]
+    add_if_missing: bool,
+
+    /// Only inject into plugins whose `remote`/`local` contains this substring.
+    #[arg(long)]
+    plugin: Option<String>,
 }
 
 #[derive(Parser)]
@@ -167,6 +210,7 @@ struct GenerateArgs {
     version: Option<String>,
 
     /// Read version from this `Cargo.toml` (default: auto-detect in current dir).
+    /// `version.workspace = true` is resolved from the parent workspace manifest.
     #[arg(long)]
     cargo_toml: Option<PathBuf>,
 
@@ -182,6 +226,23 @@ struct GenerateArgs {
     /// Skip the `buf lint` step.
     #[arg(long)]
     no_lint: bool,
+
+    /// Append `version=<v>` to the first gnostic plugin's (or the `--plugin`
+    /// match's) `opt` array when `buf.gen.yaml` has no `version=` option, instead of failing.
+    #[arg(long)]
+    add_if_missing: bool,
+
+    /// Only inject into plugins whose `remote`/`local` contains this substring.
+    #[arg(long)]
+    plugin: Option<String>,
+
+    /// Also write the patched spec as pretty-printed JSON to this path.
+    #[arg(long)]
+    json_output: Option<PathBuf>,
+
+    /// Also write a JSON `operationId` → method/path/tags/summary index to this path.
+    #[arg(long)]
+    index: Option<PathBuf>,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -213,7 +274,12 @@ fn run_generate(args: &GenerateArgs) -> anyhow::Result<()> {
     eprintln!("Injecting version={version} into buf.gen.yaml...");
     let buf_gen_content = fs::read_to_string(&args.buf_gen)
         .with_context(|| format!("Failed to read {}", args.buf_gen.display()))?;
-    let versioned_content = inject_version_yaml(&buf_gen_content, &version)?;
+    let versioned_content = inject_version_yaml(
+        &buf_gen_content,
+        &version,
+        args.plugin.as_deref(),
+        args.add_if_missing,
+    )?;
     fs::write(&versioned_buf_gen, versioned_content)
         .with_context(|| format!("Failed to write {}", versioned_buf_gen.display()))?;
 
@@ -259,12 +325,21 @@ fn run_generate(args: &GenerateArgs) -> anyhow::Result<()> {
     );
 
     let config = PatchConfig::new(&metadata).with_project_config(&project);
-    let output = tonic_rest_openapi::patch(&input_yaml, &config).context("Failed to patch spec")?;
+    let (output, report) = tonic_rest_openapi::patch_with_report(&input_yaml, &config)
+        .context("Failed to patch spec")?;
+    print_patch_report(&report);
 
     fs::write(&args.spec, &output)
         .with_context(|| format!("Failed to write spec: {}", args.spec.display()))?;
     eprintln!("OpenAPI 3.1 spec ready: {}", args.spec.display());
 
+    if let Some(json_path) = &args.json_output {
+        write_json_spec(&output, json_path)?;
+    }
+    if let Some(index_path) = &args.index {
+        write_operation_index(&output, index_path)?;
+    }
+
     Ok(())
 }
 
@@ -309,7 +384,16 @@ fn run_buf(args: &[&str]) -> anyhow::Result<()> {
 /// Inject a version string into `buf.gen.yaml` content.
 ///
 /// Finds all `opt` entries starting with `version=` and replaces the value.
-fn inject_version_yaml(content: &str, version: &str) -> anyhow::Result<String> {
+/// With a `plugin` filter, only plugins whose `remote`/`local` contains it are
+/// touched. With `add_if_missing`, a missing entry is appended to the first
+/// targeted plugin's `opt` array (the first gnostic plugin when unfiltered)
+/// instead of being an error.
+fn inject_version_yaml(
+    content: &str,
+    version: &str,
+    plugin: Option<&str>,
+    add_if_missing: bool,
+) -> anyhow::Result<String> {
     let mut doc: Value =
         serde_yaml_ng::from_str(content).context("Failed to parse buf.gen.yaml")?;
 
@@ -319,13 +403,24 @@ fn inject_version_yaml(content: &str, version: &str) -> anyhow::Result<String> {
         .and_then(Value::as_sequence_mut)
         .context("buf.gen.yaml: missing 'plugins' array")?;
 
+    if let Some(filter) = plugin
+        && !plugins
+            .iter()
+            .filter_map(Value::as_mapping)
+            .any(|m| plugin_source_contains(m, &["remote", "local"], filter))
+    {
+        bail!("No buf.gen.yaml plugin `remote`/`local` matches `{filter}`");
+    }
+
     let mut replaced = false;
-    for plugin in plugins {
-        let Some(opts) = plugin
-            .as_mapping_mut()
-            .and_then(|m| m.get_mut("opt"))
-            .and_then(Value::as_sequence_mut)
-        else {
+    for plugin_entry in plugins.iter_mut() {
+        let Some(map) = plugin_entry.as_mapping_mut() else {
+            continue;
+        };
+        if plugin.is_some_and(|f| !plugin_source_contains(map, &["remote", "local"], f)) {
+            continue;
+        }
+        let Some(opts) = map.get_mut("opt").and_then(Value::as_sequence_mut) else {
             continue;
         };
 
@@ -338,12 +433,58 @@ fn inject_version_yaml(content: &str, version: &str) -> anyhow::Result<String> {
     }
 
     if !replaced {
-        bail!("No `version=` option found in buf.gen.yaml plugins");
+        if !add_if_missing {
+            bail!("No `version=` option found in buf.gen.yaml plugins");
+        }
+        append_version_opt(plugins, version, plugin)?;
     }
 
     serde_yaml_ng::to_string(&doc).context("Failed to serialize buf.gen.yaml")
 }
 
+/// Whether any of the given plugin keys (`remote`, `local`, ...) contains `needle`.
+fn plugin_source_contains(plugin: &serde_yaml_ng::Mapping, keys: &[&str], needle: &str) -> bool {
+    keys.iter().any(|key| {
+        plugin
+            .get(*key)
+            .and_then(Value::as_str)
+            .is_some_and(|s| s.contains(needle))
+    })
+}
+
+/// Append `version=<v>` to the `opt` array of the first plugin matching
+/// `filter`, or of the first gnostic plugin when there is no filter.
+///
+/// A missing `opt` key or a single-string `opt` is converted to an array.
+fn append_version_opt(
+    plugins: &mut [Value],
+    version: &str,
+    filter: Option<&str>,
+) -> anyhow::Result<()> {
+    let plugin = plugins
+        .iter_mut()
+        .filter_map(Value::as_mapping_mut)
+        .find(|m| match filter {
+            Some(f) => plugin_source_contains(m, &["remote", "local"], f),
+            None => plugin_source_contains(m, &["remote", "plugin", "name", "local"], "gnostic"),
+        })
+        .context("buf.gen.yaml: no gnostic plugin to add `version=` to")?;
+
+    let entry = Value::String(format!("version={version}"));
+    match plugin.get_mut("opt") {
+        Some(Value::Sequence(opts)) => opts.push(entry),
+        Some(single @ Value::String(_)) => {
+            let existing = std::mem::replace(single, Value::Null);
+            *single = Value::Sequence(vec![existing, entry]);
+        }
+        Some(_) => bail!("buf.gen.yaml: plugin `opt` must be a string or array"),
+        None => {
+            plugin.insert(Value::String("opt".into()), Value::Sequence(vec![entry]));
+        }
+    }
+    Ok(())
+}
+
 fn run_patch(args: &PatchArgs) -> anyhow::Result<()> {
     // Load project config (if provided), otherwise use defaults
     let project = match &args.config {
@@ -376,7 +517,21 @@ fn run_patch(args: &PatchArgs) -> anyhow::Result<()> {
     let config = apply_cli_overrides(config, args);
 
     // Patch
-    let output = tonic_rest_openapi::patch(&input_yaml, &config).context("Failed to patch spec")?;
+    let (output, report) = tonic_rest_openapi::patch_with_report(&input_yaml, &config)
+        .context("Failed to patch spec")?;
+    print_patch_report(&report);
+
+    // Dry run: show what would change, write nothing
+    if args.diff {
+        let input_name = args.input.display().to_string();
+        let diff = unified_diff(&input_yaml, &output, &input_name, &input_name);
+        if diff.is_empty() {
+            eprintln!("No changes to {input_name}");
+            return Ok(());
+        }
+        print!("{diff}");
+        bail!("Patched spec differs from {input_name}");
+    }
 
     // Write output
     let output_path = args.output.as_ref().unwrap_or(&args.input);
@@ -384,6 +539,50 @@ fn run_patch(args: &PatchArgs) -> anyhow::Result<()> {
         .with_context(|| format!("Failed to write output: {}", output_path.display()))?;
     eprintln!("Wrote patched spec to {}", output_path.display());
 
+    if let Some(json_path) = &args.json_output {
+        write_json_spec(&output, json_path)?;
+    }
+    if let Some(index_path) = &args.index {
+        write_operation_index(&output, index_path)?;
+    }
+
+    Ok(())
+}
+
+/// Render a unified diff between two texts. Empty when they are identical.
+fn unified_diff(old: &str, new: &str, old_name: &str, new_name: &str) -> String {
+    similar::TextDiff::from_lines(old, new)
+        .unified_diff()
+        .context_radius(3)
+        .header(old_name, new_name)
+        .to_string()
+}
+
+/// Print the notes collected while patching.
+fn print_patch_report(report: &PatchReport) {
+    for (pointer, format) in &report.removed_formats {
+        eprintln!("Removed `format: {format}` at {pointer}");
+    }
+}
+
+/// Write a patched YAML spec as pretty-printed JSON.
+fn write_json_spec(yaml: &str, path: &Path) -> anyhow::Result<()> {
+    let doc: Value = serde_yaml_ng::from_str(yaml).context("Failed to re-parse patched spec")?;
+    let json = tonic_rest_openapi::to_json(&doc).context("Failed to convert spec to JSON")?;
+    fs::write(path, json)
+        .with_context(|| format!("Failed to write JSON spec: {}", path.display()))?;
+    eprintln!("Wrote JSON spec to {}", path.display());
+    Ok(())
+}
+
+/// Write the `operationId` index of a patched YAML spec as JSON.
+fn write_operation_index(yaml: &str, path: &Path) -> anyhow::Result<()> {
+    let doc: Value = serde_yaml_ng::from_str(yaml).context("Failed to re-parse patched spec")?;
+    let index = tonic_rest_openapi::operation_index(&doc);
+    let json = tonic_rest_openapi::to_json(&index).context("Failed to convert index to JSON")?;
+    fs::write(path, json)
+        .with_context(|| format!("Failed to write operation index: {}", path.display()))?;
+    eprintln!("Wrote operation index to {}", path.display());
     Ok(())
 }
 
@@ -434,6 +633,28 @@ fn run_discover(args: &DiscoverArgs) -> anyhow::Result<()> {
     let metadata = tonic_rest_openapi::discover(&descriptor_bytes)
         .context("Failed to discover proto metadata")?;
 
+    match args.format {
+        DiscoverFormat::Text => print_metadata_summary(&metadata),
+        DiscoverFormat::Json => println!(
+            "{}",
+            metadata
+                .to_json()
+                .context("Failed to serialize proto metadata")?
+        ),
+    }
+
+    let unbound = metadata.unbound_path_params();
+    if !unbound.is_empty() {
+        bail!(
+            "{} path parameter(s) have no backing request field",
+            unbound.len()
+        );
+    }
+
+    Ok(())
+}
+
+fn print_metadata_summary(metadata: &tonic_rest_openapi::ProtoMetadata) {
     println!("=== Proto Metadata ===");
     println!();
 
@@ -467,7 +688,17 @@ fn run_discover(args: &DiscoverArgs) -> anyhow::Result<()> {
     println!("Redirect paths: {:?}", metadata.redirect_paths());
     println!("UUID schema: {:?}", metadata.uuid_schema());
 
-    Ok(())
+    let unbound = metadata.unbound_path_params();
+    if !unbound.is_empty() {
+        println!();
+        println!("Unbound path params: {}", unbound.len());
+        for p in unbound {
+            println!(
+                "  {} {}: {{{}}} has no field in {}",
+                p.operation_id, p.path, p.param, p.input_type
+            );
+        }
+    }
 }
 
 fn run_inject_version(args: &InjectVersionArgs) -> anyhow::Result<()> {
@@ -485,7 +716,12 @@ fn run_inject_version(args: &InjectVersionArgs) -> anyhow::Result<()> {
     let content = fs::read_to_string(&args.buf_gen)
         .with_context(|| format!("Failed to read {}", args.buf_gen.display()))?;
 
-    let output = inject_version_yaml(&content, &version)?;
+    let output = inject_version_yaml(
+        &content,
+        &version,
+        args.plugin.as_deref(),
+        args.add_if_missing,
+    )?;
 
     let output_path = args.output.as_ref().unwrap_or(&args.buf_gen);
     fs::write(output_path, output)
@@ -496,31 +732,74 @@ fn run_inject_version(args: &InjectVersionArgs) -> anyhow::Result<()> {
 }
 
 /// Read `version` from a Cargo.toml `[package]` or `[workspace.package]`.
+///
+/// A member crate with `version.workspace = true` resolves the version from
+/// the nearest parent directory whose `Cargo.toml` has a `[workspace]` table.
 fn read_cargo_version(path: &Path) -> anyhow::Result<String> {
-    let content =
-        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
-
-    let doc: toml::Table =
-        toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))?;
+    let doc = read_cargo_toml(path)?;
+
+    match doc.get("package").and_then(|p| p.get("version")) {
+        Some(toml::Value::String(v)) => return Ok(v.clone()),
+        Some(v) if v.get("workspace").and_then(toml::Value::as_bool) == Some(true) => {
+            // A root package may inherit from the workspace table in the same file
+            return match workspace_package_version(&doc) {
+                Some(v) => Ok(v.to_string()),
+                None => read_inherited_version(path),
+            };
+        }
+        _ => {}
+    }
 
-    if let Some(v) = doc
-        .get("package")
-        .and_then(|p| p.get("version"))
-        .and_then(toml::Value::as_str)
-    {
+    if let Some(v) = workspace_package_version(&doc) {
         return Ok(v.to_string());
     }
 
-    if let Some(v) = doc
-        .get("workspace")
+    bail!("No version found in {}", path.display());
+}
+
+/// Resolve an inherited version by walking up from a member `Cargo.toml`.
+fn read_inherited_version(member: &Path) -> anyhow::Result<String> {
+    let member = member
+        .canonicalize()
+        .with_context(|| format!("Failed to resolve {}", member.display()))?;
+
+    for dir in member.ancestors().skip(2) {
+        let candidate = dir.join("Cargo.toml");
+        if !candidate.is_file() {
+            continue;
+        }
+        let doc = read_cargo_toml(&candidate)?;
+        if !doc.contains_key("workspace") {
+            continue;
+        }
+        return workspace_package_version(&doc)
+            .map(str::to_string)
+            .with_context(|| {
+                format!(
+                    "{} inherits its version, but workspace {} has no [workspace.package] version",
+                    member.display(),
+                    candidate.display()
+                )
+            });
+    }
+
+    bail!(
+        "{} inherits its version, but no workspace Cargo.toml was found in parent directories",
+        member.display()
+    );
+}
+
+fn read_cargo_toml(path: &Path) -> anyhow::Result<toml::Table> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))
+}
+
+fn workspace_package_version(doc: &toml::Table) -> Option<&str> {
+    doc.get("workspace")
         .and_then(|w| w.get("package"))
         .and_then(|p| p.get("version"))
         .and_then(toml::Value::as_str)
-    {
-        return Ok(v.to_string());
-    }
-
-    bail!("No version found in {}", path.display());
 }
 
 #[cfg(test)]
@@ -545,7 +824,7 @@ plugins:
       - version=0.0.0
       - naming=proto
 ";
-        let result = inject_version_yaml(input, "1.2.3").unwrap();
+        let result = inject_version_yaml(input, "1.2.3", None, false).unwrap();
         assert!(
             result.contains("version=1.2.3"),
             "version should be replaced"
@@ -567,9 +846,17 @@ plugins:
     opt:
       - version=0.0.0
 ";
-        let result = inject_version_yaml(input, "2.0.0").unwrap();
+        let result = inject_version_yaml(input, "2.0.0", None, false).unwrap();
         let count = result.matches("version=2.0.0").count();
         assert_eq!(count, 2, "both plugins should be updated");
+
+        let result = inject_version_yaml(input, "2.0.0", Some("plugin-b"), false).unwrap();
+        let doc: Value = serde_yaml_ng::from_str(&result).unwrap();
+        assert_eq!(doc["plugins"][0]["opt"][0].as_str(), Some("version=0.0.0"));
+        assert_eq!(doc["plugins"][1]["opt"][0].as_str(), Some("version=2.0.0"));
+
+        let err = inject_version_yaml(input, "2.0.0", Some("gnostic"), false).unwrap_err();
+        assert!(err.to_string().contains("matches `gnostic`"), "{err}");
     }
 
     #[test]
@@ -580,21 +867,62 @@ plugins:
     opt:
       - naming=proto
 ";
-        let result = inject_version_yaml(input, "1.0.0");
+        let result = inject_version_yaml(input, "1.0.0", None, false);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("No `version=`"));
     }
 
+    #[test]
+    fn inject_version_add_if_missing_appends_to_gnostic_plugin() {
+        let input = r"
+plugins:
+  - remote: buf.build/protocolbuffers/go
+    opt:
+      - paths=source_relative
+  - remote: buf.build/community/google-gnostic-openapi
+    opt:
+      - naming=proto
+";
+        let result = inject_version_yaml(input, "1.0.0", None, true).unwrap();
+        let doc: Value = serde_yaml_ng::from_str(&result).unwrap();
+        let opts = |i: usize| -> Vec<&str> {
+            doc["plugins"][i]["opt"]
+                .as_sequence()
+                .unwrap()
+                .iter()
+                .filter_map(Value::as_str)
+                .collect()
+        };
+        assert_eq!(opts(1), ["naming=proto", "version=1.0.0"]);
+        assert_eq!(
+            opts(0),
+            ["paths=source_relative"],
+            "non-gnostic plugin untouched"
+        );
+    }
+
+    #[test]
+    fn inject_version_add_if_missing_without_gnostic_plugin_errors() {
+        let input = r"
+plugins:
+  - remote: plugin-a
+    opt:
+      - naming=proto
+";
+        let err = inject_version_yaml(input, "1.0.0", None, true).unwrap_err();
+        assert!(err.to_string().contains("no gnostic plugin"));
+    }
+
     #[test]
     fn inject_version_no_plugins_errors() {
         let input = "version: v2\n";
-        let result = inject_version_yaml(input, "1.0.0");
+        let result = inject_version_yaml(input, "1.0.0", None, false);
         assert!(result.is_err());
     }
 
     #[test]
     fn inject_version_invalid_yaml_errors() {
-        let result = inject_version_yaml("{{invalid yaml", "1.0.0");
+        let result = inject_version_yaml("{{invalid yaml", "1.0.0", None, false);
         assert!(result.is_err());
     }
 
@@ -626,6 +954,55 @@ plugins:
         assert!(result.unwrap_err().to_string().contains("No version"));
     }
 
+    #[test]
+    fn read_cargo_version_inherited_from_workspace() {
+        let root = std::env::temp_dir().join("tonic_rest_test_ws_inherit");
+        let member_dir = root.join("crates").join("api");
+        fs::create_dir_all(&member_dir).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\n\n[workspace.package]\nversion = \"1.4.2\"\n",
+        )
+        .unwrap();
+
+        let dotted = member_dir.join("Cargo.toml");
+        fs::write(
+            &dotted,
+            "[package]\nname = \"api\"\nversion.workspace = true\n",
+        )
+        .unwrap();
+        assert_eq!(read_cargo_version(&dotted).unwrap(), "1.4.2");
+
+        let inline = member_dir.join("Inline.toml");
+        fs::write(
+            &inline,
+            "[package]\nname = \"api\"\nversion = { workspace = true }\n",
+        )
+        .unwrap();
+        assert_eq!(read_cargo_version(&inline).unwrap(), "1.4.2");
+    }
+
+    #[test]
+    fn read_cargo_version_inherited_without_workspace_version_errors() {
+        let root = std::env::temp_dir().join("tonic_rest_test_ws_no_version");
+        let member_dir = root.join("member");
+        fs::create_dir_all(&member_dir).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"member\"]\n",
+        )
+        .unwrap();
+        let member = member_dir.join("Cargo.toml");
+        fs::write(
+            &member,
+            "[package]\nname = \"member\"\nversion.workspace = true\n",
+        )
+        .unwrap();
+
+        let err = read_cargo_version(&member).unwrap_err().to_string();
+        assert!(err.contains("no [workspace.package] version"), "{err}");
+    }
+
     #[test]
     fn resolve_version_explicit() {
         let v = "1.0.0".to_string();
@@ -642,6 +1019,23 @@ plugins:
         assert_eq!(result, "2.0.0");
     }
 
+    #[test]
+    fn unified_diff_shows_changed_lines() {
+        let old = "openapi: 3.0.3\ninfo:\n  title: API\n";
+        let new = "openapi: 3.1.0\ninfo:\n  title: API\n";
+        let diff = unified_diff(old, new, "openapi.yaml", "openapi.yaml");
+        assert!(diff.starts_with("--- openapi.yaml\n+++ openapi.yaml\n"));
+        assert!(diff.contains("-openapi: 3.0.3\n"));
+        assert!(diff.contains("+openapi: 3.1.0\n"));
+        assert!(diff.contains(" info:\n"));
+    }
+
+    #[test]
+    fn unified_diff_empty_when_unchanged() {
+        let text = "openapi: 3.1.0\n";
+        assert!(unified_diff(text, text, "a", "b").is_empty());
+    }
+
     #[test]
     fn resolve_version_from_cargo_toml() {
         let path = write_temp_file(
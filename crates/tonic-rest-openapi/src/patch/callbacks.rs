@@ -0,0 +1,74 @@
+//! `OpenAPI` `callbacks` for webhook-style operations.
+//!
+//! Purely additive: each configured callback becomes a `POST` path item under
+//! the operation's `callbacks` block, keyed by event name and URL expression.
+
+use std::collections::HashMap;
+
+use serde_yaml_ng::Value;
+
+use crate::config::CallbackDef;
+
+use super::helpers::{for_each_operation, val_s};
+
+/// Write `callbacks` onto operations, keyed by operation ID.
+///
+/// Existing callbacks with a different event name are preserved; a configured
+/// event replaces a same-named one.
+pub fn inject_callbacks(doc: &mut Value, callbacks: &HashMap<String, Vec<CallbackDef>>) {
+    if callbacks.is_empty() {
+        return;
+    }
+
+    for_each_operation(doc, |_path, _method, op_map| {
+        let Some(defs) = op_map
+            .get("operationId")
+            .and_then(Value::as_str)
+            .and_then(|id| callbacks.get(id))
+        else {
+            return;
+        };
+
+        let block = op_map
+            .entry(val_s("callbacks"))
+            .or_insert_with(|| Value::Mapping(serde_yaml_ng::Mapping::new()));
+        let Some(block) = block.as_mapping_mut() else {
+            return;
+        };
+        for def in defs {
+            block.insert(val_s(&def.event), callback_path_item(def));
+        }
+    });
+}
+
+/// Build `{ <url>: { post: { requestBody, responses } } }` for one callback.
+fn callback_path_item(def: &CallbackDef) -> Value {
+    let mut schema = serde_yaml_ng::Mapping::new();
+    schema.insert(val_s("$ref"), val_s(&def.request_schema_ref));
+
+    let mut media_type = serde_yaml_ng::Mapping::new();
+    media_type.insert(val_s("schema"), Value::Mapping(schema));
+
+    let mut content = serde_yaml_ng::Mapping::new();
+    content.insert(val_s("application/json"), Value::Mapping(media_type));
+
+    let mut request_body = serde_yaml_ng::Mapping::new();
+    request_body.insert(val_s("required"), Value::Bool(true));
+    request_body.insert(val_s("content"), Value::Mapping(content));
+
+    let mut ok = serde_yaml_ng::Mapping::new();
+    ok.insert(val_s("description"), val_s("Callback received"));
+    let mut responses = serde_yaml_ng::Mapping::new();
+    responses.insert(val_s("200"), Value::Mapping(ok));
+
+    let mut operation = serde_yaml_ng::Mapping::new();
+    operation.insert(val_s("requestBody"), Value::Mapping(request_body));
+    operation.insert(val_s("responses"), Value::Mapping(responses));
+
+    let mut path_item = serde_yaml_ng::Mapping::new();
+    path_item.insert(val_s("post"), Value::Mapping(operation));
+
+    let mut expression = serde_yaml_ng::Mapping::new();
+    expression.insert(val_s(&def.url), Value::Mapping(path_item));
+    Value::Mapping(expression)
+}
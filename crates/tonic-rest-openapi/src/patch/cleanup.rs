@@ -6,13 +6,20 @@
 //! - Empty request body removal
 //! - Unused schema removal
 //! - `format: enum` noise removal
+//! - Opt-in `format` allowlist normalization
 //! - Request body inlining with example generation
+//! - Form-urlencoded request body media types
+//! - Pinned per-operation examples
+//! - Dotted schema name sanitization
 
 use std::collections::{HashMap, HashSet};
 
 use serde_yaml_ng::Value;
 
-use crate::discover::ProtoMetadata;
+use crate::config::{EnumCase, FormUrlEncodedEndpoint, OperationExample, SanitizeMode};
+use crate::discover::{EnumRewrite, ProtoMetadata};
+use crate::error;
+use crate::json::escape_pointer;
 
 use super::helpers::{
     UUID_EXAMPLE, collect_empty_schema_names, collect_refs, for_each_operation,
@@ -192,21 +199,58 @@ pub fn strip_unspecified_from_query_enums(doc: &mut Value) {
 }
 
 /// Remove unspecified sentinel values from a schema's enum array.
+///
+/// `x-enum-varnames` / `x-enum-descriptions` lose the same positions so they
+/// stay aligned with `enum`.
 fn strip_unspecified_enum(schema: &mut serde_yaml_ng::Mapping) {
-    if let Some(enum_vals) = schema.get_mut("enum").and_then(Value::as_sequence_mut) {
-        enum_vals.retain(|v| {
+    let Some(enum_vals) = schema.get_mut("enum").and_then(Value::as_sequence_mut) else {
+        return;
+    };
+    let keep: Vec<bool> = enum_vals
+        .iter()
+        .map(|v| {
             v.as_str().is_none_or(|s| {
-                s != "unspecified" && !s.ends_with("_UNSPECIFIED") && !s.ends_with("_unspecified")
+                !s.eq_ignore_ascii_case("unspecified")
+                    && !s.ends_with("_UNSPECIFIED")
+                    && !s.ends_with("_unspecified")
             })
-        });
+        })
+        .collect();
+    if keep.iter().all(|&k| k) {
+        return;
+    }
+
+    for key in ["enum", "x-enum-varnames", "x-enum-descriptions"] {
+        if let Some(seq) = schema.get_mut(key).and_then(Value::as_sequence_mut) {
+            if seq.len() == keep.len() {
+                let mut flags = keep.iter();
+                seq.retain(|_| flags.next().copied().unwrap_or(true));
+            }
+        }
     }
 }
 
 /// Rewrite enum values in component schemas from raw proto names to clean names.
 ///
 /// Uses [`ProtoMetadata::enum_rewrites`] for targeted property rewrites and
-/// [`ProtoMetadata::enum_value_map`] for global inline enum rewrites.
-pub fn rewrite_enum_values(doc: &mut Value, metadata: &ProtoMetadata) {
+/// [`ProtoMetadata::enum_value_map`] for global inline enum rewrites, both
+/// recased per `case`. [`EnumCase::Original`] leaves gnostic's proto names
+/// untouched.
+///
+/// With `varnames`, each rewritten property enum also gets `x-enum-varnames`
+/// (the original proto names) and, when any value carries a proto comment,
+/// `x-enum-descriptions`, both in the same order as `enum`.
+pub fn rewrite_enum_values(
+    doc: &mut Value,
+    metadata: &ProtoMetadata,
+    case: EnumCase,
+    varnames: bool,
+) {
+    if case == EnumCase::Original {
+        return;
+    }
+    let recase = |stripped: &String| val_s(&case.convert(stripped).unwrap_or_default());
+
     let rewrites = &metadata.enum_rewrites;
     if !rewrites.is_empty() {
         let Some(schema_map) = schemas_mut(doc) else {
@@ -227,23 +271,45 @@ pub fn rewrite_enum_values(doc: &mut Value, metadata: &ProtoMetadata) {
 
             // Direct enum on the property (scalar field)
             if let Some(enum_vals) = prop.get_mut("enum").and_then(Value::as_sequence_mut) {
-                *enum_vals = rewrite.values.iter().map(|s| val_s(s)).collect();
+                *enum_vals = rewrite.values.iter().map(recase).collect();
+                if varnames {
+                    insert_enum_varnames(prop, rewrite);
+                }
             }
 
             // Enum inside `items` (repeated/array field)
-            if let Some(enum_vals) = prop
-                .get_mut("items")
-                .and_then(Value::as_mapping_mut)
-                .and_then(|items| items.get_mut("enum"))
-                .and_then(Value::as_sequence_mut)
-            {
-                *enum_vals = rewrite.values.iter().map(|s| val_s(s)).collect();
+            if let Some(items) = prop.get_mut("items").and_then(Value::as_mapping_mut) {
+                if let Some(enum_vals) = items.get_mut("enum").and_then(Value::as_sequence_mut) {
+                    *enum_vals = rewrite.values.iter().map(recase).collect();
+                    if varnames {
+                        insert_enum_varnames(items, rewrite);
+                    }
+                }
             }
         }
     }
 
     // Also rewrite inline enums in path/query parameters.
-    rewrite_inline_enums(doc, &metadata.enum_value_map);
+    let value_map: HashMap<String, String> = metadata
+        .enum_value_map
+        .iter()
+        .filter_map(|(raw, stripped)| Some((raw.clone(), case.convert(stripped)?)))
+        .collect();
+    rewrite_inline_enums(doc, &value_map);
+}
+
+/// Add `x-enum-varnames` (and `x-enum-descriptions` if any) next to an `enum`.
+fn insert_enum_varnames(schema: &mut serde_yaml_ng::Mapping, rewrite: &EnumRewrite) {
+    schema.insert(
+        val_s("x-enum-varnames"),
+        Value::Sequence(rewrite.raw_values.iter().map(|v| val_s(v)).collect()),
+    );
+    if rewrite.descriptions.iter().any(|d| !d.is_empty()) {
+        schema.insert(
+            val_s("x-enum-descriptions"),
+            Value::Sequence(rewrite.descriptions.iter().map(|d| val_s(d)).collect()),
+        );
+    }
 }
 
 /// Rewrite inline enum values in path and query parameters.
@@ -266,6 +332,9 @@ fn rewrite_inline_enums_recursive(value: &mut Value, map: &HashMap<String, Strin
                         }
                     }
                 }
+                // Enum aliases map onto the same stripped value; keep the first.
+                let mut seen = HashSet::new();
+                enum_vals.retain(|v| seen.insert(v.clone()));
             }
             for (_, v) in m.iter_mut() {
                 rewrite_inline_enums_recursive(v, map);
@@ -331,13 +400,52 @@ pub fn mark_unimplemented_operations(
     });
 }
 
+/// Mark permanently removed operations with a `410 Gone` response.
+///
+/// Adds `x-gone: true` and a `410` entry referencing the error schema, and
+/// drops every `2xx` response since the endpoint can no longer succeed.
+/// Used for RPCs kept in the proto only to reserve their field numbers.
+pub fn mark_gone_operations(doc: &mut Value, gone_ops: &[String], error_schema_ref: &str) {
+    for_each_operation(doc, |_path, _method, op_map| {
+        let op_id = op_map
+            .get(Value::String("operationId".to_string()))
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+
+        if !gone_ops.iter().any(|id| id == op_id) {
+            return;
+        }
+
+        op_map.insert(val_s("x-gone"), Value::Bool(true));
+
+        if let Some(responses) = op_map.get_mut("responses").and_then(Value::as_mapping_mut) {
+            responses.retain(|code, _| !code.as_str().is_some_and(|c| c.starts_with('2')));
+            if !responses.contains_key("410") {
+                responses.insert(
+                    val_s("410"),
+                    json_response_with_schema_ref("Gone", error_schema_ref),
+                );
+            }
+        }
+    });
+}
+
 /// Mark operations as deprecated in the `OpenAPI` spec.
 ///
 /// Sets `deprecated: true` on matching operations, which renders as
 /// strikethrough in Swagger UI. This is the standard `OpenAPI` mechanism
 /// for indicating deprecated endpoints.
-pub fn mark_deprecated_operations(doc: &mut Value, deprecated_ops: &[String]) {
-    if deprecated_ops.is_empty() {
+///
+/// Operations with an entry in `sunsets` (keyed by operation ID) are
+/// deprecated too, and additionally get `x-sunset: <date>` plus an
+/// [RFC 8594](https://www.rfc-editor.org/rfc/rfc8594) `Sunset` header on
+/// each `2xx` response.
+pub fn mark_deprecated_operations(
+    doc: &mut Value,
+    deprecated_ops: &[String],
+    sunsets: &HashMap<String, String>,
+) {
+    if deprecated_ops.is_empty() && sunsets.is_empty() {
         return;
     }
 
@@ -347,14 +455,55 @@ pub fn mark_deprecated_operations(doc: &mut Value, deprecated_ops: &[String]) {
             .and_then(Value::as_str)
             .unwrap_or_default();
 
-        if !deprecated_ops.iter().any(|id| id == op_id) {
+        let sunset = sunsets.get(op_id).cloned();
+        if sunset.is_none() && !deprecated_ops.iter().any(|id| id == op_id) {
             return;
         }
 
         op_map.insert(Value::String("deprecated".to_string()), Value::Bool(true));
+
+        let Some(sunset) = sunset else {
+            return;
+        };
+        op_map.insert(val_s("x-sunset"), val_s(&sunset));
+
+        let Some(responses) = op_map.get_mut("responses").and_then(Value::as_mapping_mut) else {
+            return;
+        };
+        for (code, response) in responses.iter_mut() {
+            if !code.as_str().is_some_and(|c| c.starts_with('2')) {
+                continue;
+            }
+            let Some(headers) = response
+                .as_mapping_mut()
+                .map(|r| {
+                    r.entry(val_s("headers"))
+                        .or_insert_with(|| Value::Mapping(serde_yaml_ng::Mapping::new()))
+                })
+                .and_then(Value::as_mapping_mut)
+            else {
+                continue;
+            };
+            headers.insert(val_s("Sunset"), sunset_header(&sunset));
+        }
     });
 }
 
+/// Build the documented `Sunset` response header for a deprecated operation.
+fn sunset_header(date: &str) -> Value {
+    let mut schema = serde_yaml_ng::Mapping::new();
+    schema.insert(val_s("type"), val_s("string"));
+    schema.insert(val_s("example"), val_s(date));
+
+    let mut header = serde_yaml_ng::Mapping::new();
+    header.insert(
+        val_s("description"),
+        val_s("Date after which this deprecated endpoint may be removed (RFC 8594)."),
+    );
+    header.insert(val_s("schema"), Value::Mapping(schema));
+    Value::Mapping(header)
+}
+
 /// Remove `requestBody` from operations whose request schema has no properties.
 pub fn remove_empty_request_bodies(doc: &mut Value) {
     let empty_schemas = collect_empty_schema_names(doc);
@@ -393,6 +542,144 @@ pub fn remove_empty_inlined_request_bodies(doc: &mut Value) {
     });
 }
 
+/// Switch request bodies of the configured endpoints to
+/// `application/x-www-form-urlencoded`.
+///
+/// Runs after inlining so the body schema and its examples are carried over
+/// unchanged under the new media type key.
+pub fn set_form_urlencoded_request_bodies(doc: &mut Value, endpoints: &[FormUrlEncodedEndpoint]) {
+    if endpoints.is_empty() {
+        return;
+    }
+
+    for_each_operation(doc, |path, method, op| {
+        if !endpoints
+            .iter()
+            .any(|e| e.path == path && e.method.eq_ignore_ascii_case(method))
+        {
+            return;
+        }
+
+        let Some(content) = op
+            .get_mut("requestBody")
+            .and_then(Value::as_mapping_mut)
+            .and_then(|rb| rb.get_mut("content"))
+            .and_then(Value::as_mapping_mut)
+        else {
+            return;
+        };
+
+        if let Some(media_type) = content.remove("application/json") {
+            content.insert(val_s("application/x-www-form-urlencoded"), media_type);
+        }
+    });
+}
+
+/// Set pinned request/response examples on operations, keyed by operation ID.
+///
+/// The example becomes the media-type `example` of every request body
+/// content type and of the first `2xx` response with content, replacing any
+/// `example`/`examples` there. Doc tools prefer it over the per-property
+/// heuristic examples, which are left in place.
+pub fn pin_operation_examples(doc: &mut Value, examples: &HashMap<String, OperationExample>) {
+    if examples.is_empty() {
+        return;
+    }
+
+    for_each_operation(doc, |_path, _method, op_map| {
+        let Some(pinned) = op_map
+            .get("operationId")
+            .and_then(Value::as_str)
+            .and_then(|id| examples.get(id))
+        else {
+            return;
+        };
+
+        if let Some(example) = &pinned.request {
+            let content = op_map
+                .get_mut("requestBody")
+                .and_then(Value::as_mapping_mut)
+                .and_then(|rb| rb.get_mut("content"));
+            set_media_type_examples(content, example);
+        }
+
+        if let Some(example) = &pinned.response {
+            let content = op_map
+                .get_mut("responses")
+                .and_then(Value::as_mapping_mut)
+                .and_then(|responses| {
+                    responses
+                        .iter_mut()
+                        .filter(|(code, _)| code.as_str().is_some_and(|c| c.starts_with('2')))
+                        .find_map(|(_, response)| response.get_mut("content"))
+                });
+            set_media_type_examples(content, example);
+        }
+    });
+}
+
+/// Compose a whole-body `example` for JSON request bodies from the
+/// per-property examples.
+///
+/// Works on inline bodies and on `$ref` bodies pointing at a component
+/// schema. Properties without an `example` are left out; bodies that already
+/// carry an `example`/`examples` (e.g., pinned per operation) are kept.
+pub fn compose_body_examples(doc: &mut Value) {
+    let components = schemas(doc).cloned().unwrap_or_default();
+
+    for_each_operation(doc, |_path, _method, op_map| {
+        let Some(media_type) = op_map
+            .get_mut("requestBody")
+            .and_then(Value::as_mapping_mut)
+            .and_then(|rb| rb.get_mut("content"))
+            .and_then(Value::as_mapping_mut)
+            .and_then(|c| c.get_mut("application/json"))
+            .and_then(Value::as_mapping_mut)
+        else {
+            return;
+        };
+        if media_type.contains_key("example") || media_type.contains_key("examples") {
+            return;
+        }
+
+        let schema = media_type.get("schema").and_then(Value::as_mapping);
+        let schema = match schema.and_then(|s| s.get("$ref")).and_then(Value::as_str) {
+            Some(schema_ref) => schema_ref
+                .strip_prefix("#/components/schemas/")
+                .and_then(|name| components.get(name))
+                .and_then(Value::as_mapping),
+            None => schema,
+        };
+        let Some(props) = schema
+            .and_then(|s| s.get("properties"))
+            .and_then(Value::as_mapping)
+        else {
+            return;
+        };
+
+        let body: serde_yaml_ng::Mapping = props
+            .iter()
+            .filter_map(|(name, prop)| Some((name.clone(), prop.get("example")?.clone())))
+            .collect();
+        if !body.is_empty() {
+            media_type.insert(val_s("example"), Value::Mapping(body));
+        }
+    });
+}
+
+/// Replace the example of every media type in a `content` map.
+fn set_media_type_examples(content: Option<&mut Value>, example: &Value) {
+    let Some(content) = content.and_then(Value::as_mapping_mut) else {
+        return;
+    };
+    for (_, media_type) in content.iter_mut() {
+        if let Some(media_type) = media_type.as_mapping_mut() {
+            media_type.remove("examples");
+            media_type.insert(val_s("example"), example.clone());
+        }
+    }
+}
+
 /// Remove empty-property schemas from components that are no longer referenced.
 pub fn remove_unused_empty_schemas(doc: &mut Value) {
     let empty = collect_empty_schema_names(doc);
@@ -453,6 +740,88 @@ fn strip_format_enum_recursive(value: &mut Value) {
     }
 }
 
+/// `format` values defined by `OpenAPI` 3.1 and the JSON Schema 2020-12
+/// format vocabulary. Always allowed by [`normalize_formats`].
+pub const STANDARD_FORMATS: &[&str] = &[
+    "int32",
+    "int64",
+    "float",
+    "double",
+    "byte",
+    "binary",
+    "password",
+    "date",
+    "date-time",
+    "time",
+    "duration",
+    "email",
+    "idn-email",
+    "hostname",
+    "idn-hostname",
+    "ipv4",
+    "ipv6",
+    "uri",
+    "uri-reference",
+    "iri",
+    "iri-reference",
+    "uri-template",
+    "uuid",
+    "json-pointer",
+    "relative-json-pointer",
+    "regex",
+];
+
+/// Numeric formats, which are meaningless on string enums.
+const NUMERIC_FORMATS: &[&str] = &["int32", "int64", "float", "double"];
+
+/// Remove `format` values outside [`STANDARD_FORMATS`] plus `extra_allowed`.
+///
+/// Numeric formats on string enums (e.g., `format: int32` on a proto enum
+/// rendered by name) are removed as well; `type: string, format: int64` on
+/// plain fields is the proto3 JSON encoding of 64-bit integers and is kept.
+///
+/// Returns the `(JSON pointer, format)` of each removal so callers can report
+/// them, since dropping a format silently can hide a proto mapping bug.
+pub fn normalize_formats(doc: &mut Value, extra_allowed: &[String]) -> Vec<(String, String)> {
+    let mut removed = Vec::new();
+    normalize_formats_recursive(doc, extra_allowed, "#", &mut removed);
+    removed
+}
+
+fn normalize_formats_recursive(
+    value: &mut Value,
+    extra_allowed: &[String],
+    path: &str,
+    removed: &mut Vec<(String, String)>,
+) {
+    match value {
+        Value::Mapping(map) => {
+            if let Some(format) = map.get("format").and_then(Value::as_str) {
+                let allowed =
+                    STANDARD_FORMATS.contains(&format) || extra_allowed.iter().any(|f| f == format);
+                let numeric_mismatch = NUMERIC_FORMATS.contains(&format)
+                    && map.contains_key("enum")
+                    && map.get("type").and_then(Value::as_str) == Some("string");
+                if !allowed || numeric_mismatch {
+                    removed.push((path.to_string(), format.to_string()));
+                    map.remove("format");
+                }
+            }
+
+            for (k, v) in map.iter_mut() {
+                let key = escape_pointer(k.as_str().unwrap_or_default());
+                normalize_formats_recursive(v, extra_allowed, &format!("{path}/{key}"), removed);
+            }
+        }
+        Value::Sequence(seq) => {
+            for (i, item) in seq.iter_mut().enumerate() {
+                normalize_formats_recursive(item, extra_allowed, &format!("{path}/{i}"), removed);
+            }
+        }
+        _ => {}
+    }
+}
+
 /// Inline request body schemas directly into operations for better Swagger UI.
 ///
 /// Replaces `$ref` to component schemas with the full inline schema,
@@ -516,6 +885,9 @@ pub fn inline_request_bodies(doc: &mut Value) {
         if let Some(d) = desc {
             rb.insert(val_s("description"), val_s(d));
         }
+        if has_properties(schema) {
+            rb.insert(val_s("required"), Value::Bool(true));
+        }
 
         let Some(media_type) = rb
             .get_mut("content")
@@ -932,14 +1304,29 @@ pub fn enrich_schema_examples(doc: &mut Value) {
 /// message) end up with inline `type: object` request bodies rather than a
 /// `$ref` to a named schema. [`enrich_schema_examples`] only touches named
 /// component schemas, so this function fills the gap for inline bodies using
-/// the same [`meaningful_field_example`] heuristics.
+/// the same [`meaningful_field_example`] heuristics. Non-empty inline bodies
+/// are also marked `required: true`.
 pub fn enrich_inline_request_body_examples(doc: &mut Value) {
     for_each_operation(doc, |_path, _method, op_map| {
-        // Navigate: requestBody → content → application/json → schema → properties
-        let Some(props) = op_map
+        let Some(rb) = op_map
             .get_mut("requestBody")
             .and_then(Value::as_mapping_mut)
-            .and_then(|rb| rb.get_mut("content"))
+        else {
+            return;
+        };
+
+        let inline_non_empty = rb
+            .get("content")
+            .and_then(|c| c.get("application/json"))
+            .and_then(|mt| mt.get("schema"))
+            .is_some_and(|s| s.get("$ref").is_none() && has_properties(s));
+        if inline_non_empty {
+            rb.insert(val_s("required"), Value::Bool(true));
+        }
+
+        // Navigate: content → application/json → schema → properties
+        let Some(props) = rb
+            .get_mut("content")
             .and_then(Value::as_mapping_mut)
             .and_then(|c| c.get_mut("application/json"))
             .and_then(Value::as_mapping_mut)
@@ -987,6 +1374,14 @@ pub fn enrich_inline_request_body_examples(doc: &mut Value) {
     });
 }
 
+/// Whether a schema declares at least one property.
+fn has_properties(schema: &Value) -> bool {
+    schema
+        .get("properties")
+        .and_then(Value::as_mapping)
+        .is_some_and(|p| !p.is_empty())
+}
+
 /// Generate a meaningful example for a field, returning `None` for generic defaults.
 ///
 /// Unlike [`generate_field_example`] (which always returns a value, including a
@@ -1099,10 +1494,9 @@ pub fn remove_orphaned_schemas(doc: &mut Value) {
     let external_refs = collect_external_schema_refs(doc);
 
     // Step 2: seed the reachable set with externally-referenced schema names
-    let prefix = "#/components/schemas/";
     let mut reachable: HashSet<String> = external_refs
         .iter()
-        .filter_map(|r| r.strip_prefix(prefix).map(str::to_string))
+        .filter_map(|r| referenced_schema_name(r))
         .collect();
 
     // Step 3: transitively follow $refs inside reachable schemas
@@ -1112,11 +1506,9 @@ pub fn remove_orphaned_schemas(doc: &mut Value) {
         if let Some(schema_val) = schemas_snapshot.get(name.as_str()) {
             let mut inner_refs = HashSet::new();
             collect_refs(schema_val, &mut inner_refs);
-            for r in inner_refs {
-                if let Some(dep) = r.strip_prefix(prefix) {
-                    if reachable.insert(dep.to_string()) {
-                        frontier.push(dep.to_string());
-                    }
+            for dep in inner_refs.iter().filter_map(|r| referenced_schema_name(r)) {
+                if reachable.insert(dep.clone()) {
+                    frontier.push(dep);
                 }
             }
         }
@@ -1134,15 +1526,40 @@ pub fn remove_orphaned_schemas(doc: &mut Value) {
                 schema_map.remove(name.as_str());
             }
         }
+
+        debug_assert!(
+            {
+                let mut remaining = HashSet::new();
+                collect_refs(doc, &mut remaining);
+                !remaining
+                    .iter()
+                    .filter_map(|r| referenced_schema_name(r))
+                    .any(|name| orphans.contains(&name))
+            },
+            "orphan removal dropped a schema that is still referenced"
+        );
     }
 }
 
+/// Schema name a local `$ref` points into, if it targets `components.schemas`.
+///
+/// Handles pointers into a schema's interior
+/// (`#/components/schemas/Foo/properties/bar` → `Foo`) and JSON-pointer
+/// escapes (`~1` → `/`, `~0` → `~`).
+fn referenced_schema_name(reference: &str) -> Option<String> {
+    let rest = reference.strip_prefix(SCHEMA_REF_PREFIX)?;
+    let token = rest.split('/').next().unwrap_or(rest);
+    Some(token.replace("~1", "/").replace("~0", "~"))
+}
+
 /// Collect `$ref` strings from every part of the document EXCEPT
 /// `components.schemas`.
 ///
 /// This lets [`remove_orphaned_schemas`] detect self-referential schema
 /// clusters that have no external consumers (e.g., `google.rpc.Status` →
 /// `google.protobuf.Any` where neither is used by any path or response).
+/// Every other top-level key (`paths`, `webhooks`, vendor `x-` keys) is walked
+/// in full, `examples` and extensions included.
 fn collect_external_schema_refs(doc: &Value) -> HashSet<String> {
     let mut refs = HashSet::new();
 
@@ -1169,6 +1586,104 @@ fn collect_external_schema_refs(doc: &Value) -> HashSet<String> {
     refs
 }
 
+/// Prefix of every local schema `$ref`.
+const SCHEMA_REF_PREFIX: &str = "#/components/schemas/";
+
+/// Rename dotted `components/schemas` entries and rewrite every `$ref` to them.
+///
+/// Schemas keep their position in the mapping. Names without dots are left
+/// alone, but still count towards collisions.
+pub fn sanitize_schema_names(doc: &mut Value, mode: SanitizeMode) -> error::Result<()> {
+    let Some(schemas) = schemas_mut(doc) else {
+        return Ok(());
+    };
+
+    let mut renames = HashMap::new();
+    let mut owners: HashMap<String, Vec<String>> = HashMap::new();
+    for name in schemas.keys().filter_map(Value::as_str) {
+        let sanitized = sanitize_schema_name(name, mode);
+        owners
+            .entry(sanitized.clone())
+            .or_default()
+            .push(name.to_string());
+        if sanitized != name {
+            renames.insert(name.to_string(), sanitized);
+        }
+    }
+    if let Some((name, mut originals)) = owners.into_iter().find(|(_, v)| v.len() > 1) {
+        originals.sort();
+        return Err(error::Error::SchemaNameCollision {
+            name,
+            schemas: originals,
+        });
+    }
+    if renames.is_empty() {
+        return Ok(());
+    }
+
+    let rebuilt = std::mem::take(schemas)
+        .into_iter()
+        .map(
+            |(key, schema)| match key.as_str().and_then(|k| renames.get(k)) {
+                Some(new) => (val_s(new), schema),
+                None => (key, schema),
+            },
+        )
+        .collect();
+    *schemas = rebuilt;
+
+    rewrite_schema_refs(doc, &renames);
+    Ok(())
+}
+
+/// Sanitized form of one schema name.
+fn sanitize_schema_name(name: &str, mode: SanitizeMode) -> String {
+    match mode {
+        SanitizeMode::Underscore => name.replace('.', "_"),
+        SanitizeMode::PascalCase => name
+            .split('.')
+            .map(|segment| {
+                let mut chars = segment.chars();
+                chars.next().map_or_else(String::new, |first| {
+                    first.to_uppercase().chain(chars).collect()
+                })
+            })
+            .collect(),
+    }
+}
+
+/// Point every `$ref` at a renamed schema (including JSON-pointer suffixes
+/// like `/properties/id`) to its new name.
+fn rewrite_schema_refs(value: &mut Value, renames: &HashMap<String, String>) {
+    match value {
+        Value::Mapping(map) => {
+            for (key, child) in map.iter_mut() {
+                if key.as_str() == Some("$ref") {
+                    if let Some(target) = child.as_str().and_then(|r| renamed_ref(r, renames)) {
+                        *child = Value::String(target);
+                    }
+                } else {
+                    rewrite_schema_refs(child, renames);
+                }
+            }
+        }
+        Value::Sequence(seq) => {
+            for item in seq {
+                rewrite_schema_refs(item, renames);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// New `$ref` for `reference` if it targets a renamed schema.
+fn renamed_ref(reference: &str, renames: &HashMap<String, String>) -> Option<String> {
+    let rest = reference.strip_prefix(SCHEMA_REF_PREFIX)?;
+    let (name, suffix) = rest.find('/').map_or((rest, ""), |i| rest.split_at(i));
+    let new = renames.get(name)?;
+    Some(format!("{SCHEMA_REF_PREFIX}{new}{suffix}"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1227,6 +1742,56 @@ paths:
         );
     }
 
+    #[test]
+    fn normalize_formats_removes_unknown_and_keeps_standard() {
+        let yaml = r"
+components:
+  schemas:
+    test.v1.Item:
+      type: object
+      properties:
+        code:
+          type: string
+          format: custom
+        total:
+          type: string
+          format: int64
+        count:
+          type: integer
+          format: int32
+        kind:
+          type: string
+          format: int32
+          enum: [A, B]
+        sku:
+          type: string
+          format: sku
+";
+        let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        let removed = normalize_formats(&mut doc, &["sku".to_string()]);
+        assert_eq!(
+            removed,
+            vec![
+                (
+                    "#/components/schemas/test.v1.Item/properties/code".to_string(),
+                    "custom".to_string(),
+                ),
+                (
+                    "#/components/schemas/test.v1.Item/properties/kind".to_string(),
+                    "int32".to_string(),
+                ),
+            ],
+        );
+
+        let props = &doc["components"]["schemas"]["test.v1.Item"]["properties"];
+        assert!(!props["code"].as_mapping().unwrap().contains_key("format"));
+        assert_eq!(props["count"]["format"].as_str(), Some("int32"));
+        assert!(!props["kind"].as_mapping().unwrap().contains_key("format"));
+        assert_eq!(props["sku"]["format"].as_str(), Some("sku"));
+        // int64 on a string is the proto3 JSON encoding for 64-bit integers
+        assert_eq!(props["total"]["format"].as_str(), Some("int64"));
+    }
+
     #[test]
     fn format_enum_removed() {
         let yaml = r"
@@ -1328,6 +1893,7 @@ components:
             rb.get("description").unwrap().as_str().unwrap(),
             "Authentication request body."
         );
+        assert_eq!(rb.get("required"), Some(&Value::Bool(true)));
 
         // Examples should be on individual properties, not media-type level
         assert!(
@@ -1387,6 +1953,48 @@ paths:
         );
     }
 
+    #[test]
+    fn gone_operations_replace_success_with_410() {
+        let yaml = r"
+paths:
+  /v1/removed:
+    get:
+      operationId: OldService_GetRemoved
+      responses:
+        '200':
+          description: OK
+        default:
+          description: Error
+  /v1/kept:
+    get:
+      operationId: OldService_GetKept
+      responses:
+        '200':
+          description: OK
+";
+        let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        mark_gone_operations(
+            &mut doc,
+            &["OldService_GetRemoved".to_string()],
+            "#/components/schemas/ErrorResponse",
+        );
+
+        let removed = &doc["paths"]["/v1/removed"]["get"];
+        assert_eq!(removed["x-gone"], Value::Bool(true));
+        let responses = removed["responses"].as_mapping().unwrap();
+        assert!(!responses.contains_key("200"));
+        assert!(responses.contains_key("default"));
+        assert_eq!(responses["410"]["description"].as_str(), Some("Gone"));
+        assert_eq!(
+            responses["410"]["content"]["application/json"]["schema"]["$ref"].as_str(),
+            Some("#/components/schemas/ErrorResponse")
+        );
+
+        let kept = &doc["paths"]["/v1/kept"]["get"];
+        assert!(kept.get("x-gone").is_none());
+        assert!(kept["responses"].as_mapping().unwrap().contains_key("200"));
+    }
+
     #[test]
     fn deprecated_operations_marked() {
         let yaml = r"
@@ -1405,7 +2013,11 @@ paths:
           description: OK
 ";
         let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
-        mark_deprecated_operations(&mut doc, &["OldService_GetOld".to_string()]);
+        mark_deprecated_operations(
+            &mut doc,
+            &["OldService_GetOld".to_string()],
+            &HashMap::new(),
+        );
 
         let old_op = doc["paths"]["/v1/old"]["get"].as_mapping().unwrap();
         assert!(old_op.get("deprecated").unwrap().as_bool().unwrap());
@@ -1417,6 +2029,45 @@ paths:
         );
     }
 
+    #[test]
+    fn deprecated_operation_with_sunset() {
+        let yaml = r"
+paths:
+  /v1/old:
+    get:
+      operationId: OldService_GetOld
+      responses:
+        '200':
+          description: OK
+        '404':
+          description: Not Found
+  /v1/legacy:
+    get:
+      operationId: OldService_GetLegacy
+      responses:
+        '200':
+          description: OK
+";
+        let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        let sunsets = HashMap::from([("OldService_GetOld".to_string(), "2027-01-31".to_string())]);
+        mark_deprecated_operations(&mut doc, &["OldService_GetLegacy".to_string()], &sunsets);
+
+        let old_op = &doc["paths"]["/v1/old"]["get"];
+        assert_eq!(old_op["deprecated"], Value::Bool(true));
+        assert_eq!(old_op["x-sunset"].as_str(), Some("2027-01-31"));
+        let header = &old_op["responses"]["200"]["headers"]["Sunset"];
+        assert_eq!(header["schema"]["example"].as_str(), Some("2027-01-31"));
+        assert!(
+            old_op["responses"]["404"].get("headers").is_none(),
+            "error responses should not document Sunset"
+        );
+
+        let legacy_op = &doc["paths"]["/v1/legacy"]["get"];
+        assert_eq!(legacy_op["deprecated"], Value::Bool(true));
+        assert!(legacy_op.get("x-sunset").is_none());
+        assert!(legacy_op["responses"]["200"].get("headers").is_none());
+    }
+
     #[test]
     fn field_example_locale() {
         assert_eq!(
@@ -1881,6 +2532,50 @@ components:
         );
     }
 
+    #[test]
+    fn orphan_removal_follows_webhooks_and_interior_pointers() {
+        let yaml = r"
+webhooks:
+  itemCreated:
+    post:
+      requestBody:
+        content:
+          application/json:
+            schema:
+              $ref: '#/components/schemas/test.v1.ItemEvent'
+      responses:
+        '200':
+          description: OK
+x-internal:
+  status:
+    $ref: '#/components/schemas/test.v1.Status/properties/code'
+components:
+  schemas:
+    test.v1.ItemEvent:
+      type: object
+    test.v1.Status:
+      type: object
+      properties:
+        code:
+          type: integer
+    test.v1.Orphan:
+      type: object
+";
+        let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        remove_orphaned_schemas(&mut doc);
+
+        let schema_map = schemas(&doc).unwrap();
+        assert!(
+            schema_map.contains_key("test.v1.ItemEvent"),
+            "schema referenced from webhooks should survive"
+        );
+        assert!(
+            schema_map.contains_key("test.v1.Status"),
+            "schema referenced by an interior pointer should survive"
+        );
+        assert!(!schema_map.contains_key("test.v1.Orphan"));
+    }
+
     #[test]
     fn orphan_removal_preserves_cross_component_refs() {
         // A schema referenced from a response component (not paths) should survive.
@@ -2252,6 +2947,9 @@ paths:
         let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
         enrich_inline_request_body_examples(&mut doc);
 
+        let rb = &doc["paths"]["/v1/users/{id}"]["patch"]["requestBody"];
+        assert_eq!(rb["required"], Value::Bool(true));
+
         let schema = &doc["paths"]["/v1/users/{id}"]["patch"]["requestBody"]["content"]["application/json"]
             ["schema"]["properties"];
 
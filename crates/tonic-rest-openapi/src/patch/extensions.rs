@@ -0,0 +1,615 @@
+//! Vendor extension transforms.
+//!
+//! - Operation-level `x-codeSamples` from configurable templates
+//! - Schema-level `x-pagination` for paginated list responses
+//! - Operation-level `x-proto-source` pointing back at the defining RPC
+//! - Operation-level `x-badges` from config
+//! - Top-level `x-tagGroups` grouping service tags for Redoc
+
+use std::collections::HashMap;
+
+use serde_yaml_ng::Value;
+
+use crate::config::{Badge, CodeSample, TagGroup};
+use crate::discover::{MethodSource, PaginatedResponse};
+
+use super::helpers::{for_each_operation, schemas_mut, val_n, val_s};
+
+/// Template key that applies code samples to every operation.
+pub const ALL_OPERATIONS: &str = "*";
+
+/// Inject `x-codeSamples` into operations from templates.
+///
+/// `templates` is keyed by operation ID, or by [`ALL_OPERATIONS`] for samples
+/// applied to every operation. Operation-specific templates take precedence
+/// over the global set. Each `source` template supports two placeholders:
+/// - `{method}` — upper-case HTTP method (e.g., `POST`)
+/// - `{path}` — final path template (e.g., `/v1/users/{userId}`)
+pub fn inject_code_samples(doc: &mut Value, templates: &HashMap<String, Vec<CodeSample>>) {
+    if templates.is_empty() {
+        return;
+    }
+
+    let global = templates.get(ALL_OPERATIONS);
+
+    for_each_operation(doc, |path, method, op_map| {
+        let op_id = op_map
+            .get("operationId")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+
+        let Some(samples) = templates.get(op_id).or(global) else {
+            return;
+        };
+
+        let rendered: Vec<Value> = samples
+            .iter()
+            .map(|sample| render_code_sample(sample, path, method))
+            .collect();
+        op_map.insert(val_s("x-codeSamples"), Value::Sequence(rendered));
+    });
+}
+
+/// Group name used when no tag group patterns are configured.
+const DEFAULT_TAG_GROUP: &str = "API";
+
+/// Group collecting tags that match none of the configured patterns.
+const OTHER_TAG_GROUP: &str = "Other";
+
+/// Add a top-level `x-tagGroups: [{ name, tags }]` block.
+///
+/// Tags are taken from the `tags` block in order, then from operations for
+/// any tag not declared there. Each tag joins the first group with a
+/// matching pattern; groups that end up empty are omitted. With no groups
+/// configured, every tag goes into a single [`DEFAULT_TAG_GROUP`].
+pub fn inject_tag_groups(doc: &mut Value, groups: &[TagGroup]) {
+    let mut tags: Vec<String> = doc
+        .get("tags")
+        .and_then(Value::as_sequence)
+        .into_iter()
+        .flatten()
+        .filter_map(|t| t.get("name").and_then(Value::as_str))
+        .map(str::to_string)
+        .collect();
+    for_each_operation(doc, |_path, _method, op_map| {
+        let op_tags = op_map.get("tags").and_then(Value::as_sequence);
+        for tag in op_tags.into_iter().flatten().filter_map(Value::as_str) {
+            if !tags.iter().any(|t| t == tag) {
+                tags.push(tag.to_string());
+            }
+        }
+    });
+    if tags.is_empty() {
+        return;
+    }
+
+    let mut grouped: Vec<(&str, Vec<Value>)> = if groups.is_empty() {
+        vec![(DEFAULT_TAG_GROUP, Vec::new())]
+    } else {
+        groups
+            .iter()
+            .map(|g| (g.name.as_str(), Vec::new()))
+            .collect()
+    };
+    let mut other = Vec::new();
+    for tag in &tags {
+        let slot = if groups.is_empty() {
+            Some(0)
+        } else {
+            groups
+                .iter()
+                .position(|g| g.tags.iter().any(|p| glob_match(p, tag)))
+        };
+        match slot {
+            Some(i) => grouped[i].1.push(val_s(tag)),
+            None => other.push(val_s(tag)),
+        }
+    }
+    if !other.is_empty() {
+        grouped.push((OTHER_TAG_GROUP, other));
+    }
+
+    let entries: Vec<Value> = grouped
+        .into_iter()
+        .filter(|(_, tags)| !tags.is_empty())
+        .map(|(name, tags)| {
+            let mut entry = serde_yaml_ng::Mapping::new();
+            entry.insert(val_s("name"), val_s(name));
+            entry.insert(val_s("tags"), Value::Sequence(tags));
+            Value::Mapping(entry)
+        })
+        .collect();
+    if let Some(root) = doc.as_mapping_mut() {
+        root.insert(val_s("x-tagGroups"), Value::Sequence(entries));
+    }
+}
+
+/// Match `text` against a pattern where `*` stands for any run of characters.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+    let mut parts: Vec<&str> = parts.collect();
+    let Some(last) = parts.pop() else {
+        // No `*`: the prefix must be the whole text.
+        return rest.is_empty();
+    };
+    for part in parts {
+        let Some(at) = rest.find(part) else {
+            return false;
+        };
+        rest = &rest[at + part.len()..];
+    }
+    rest.ends_with(last)
+}
+
+/// Add `x-badges: [{ name, color }]` to operations, keyed by operation ID.
+pub fn inject_badges(doc: &mut Value, badges: &HashMap<String, Vec<Badge>>) {
+    if badges.is_empty() {
+        return;
+    }
+
+    for_each_operation(doc, |_path, _method, op_map| {
+        let Some(op_badges) = op_map
+            .get("operationId")
+            .and_then(Value::as_str)
+            .and_then(|id| badges.get(id))
+        else {
+            return;
+        };
+
+        let rendered = op_badges
+            .iter()
+            .map(|badge| {
+                let mut entry = serde_yaml_ng::Mapping::new();
+                entry.insert(val_s("name"), val_s(&badge.name));
+                if let Some(color) = &badge.color {
+                    entry.insert(val_s("color"), val_s(color));
+                }
+                Value::Mapping(entry)
+            })
+            .collect();
+        op_map.insert(val_s("x-badges"), Value::Sequence(rendered));
+    });
+}
+
+/// Annotate operations with `x-proto-source: { file, service, method }`.
+///
+/// `line` is added when the descriptor set carried source info. Operations
+/// without a matching proto method are left untouched.
+pub fn annotate_proto_source(doc: &mut Value, sources: &[MethodSource]) {
+    if sources.is_empty() {
+        return;
+    }
+
+    let by_op_id: HashMap<&str, &MethodSource> = sources
+        .iter()
+        .map(|s| (s.operation_id.as_str(), s))
+        .collect();
+
+    for_each_operation(doc, |_path, _method, op_map| {
+        let Some(source) = op_map
+            .get("operationId")
+            .and_then(Value::as_str)
+            .and_then(|id| by_op_id.get(id))
+        else {
+            return;
+        };
+
+        let mut ext = serde_yaml_ng::Mapping::new();
+        ext.insert(val_s("file"), val_s(&source.file));
+        ext.insert(val_s("service"), val_s(&source.service));
+        ext.insert(val_s("method"), val_s(&source.method));
+        if let Some(line) = source.line {
+            ext.insert(val_s("line"), val_n(u64::from(line)));
+        }
+        op_map.insert(val_s("x-proto-source"), Value::Mapping(ext));
+    });
+}
+
+/// Description applied to page token fields that have none, naming the
+/// request field to pass the token back in when one was discovered.
+fn page_token_description(page: &PaginatedResponse) -> String {
+    match &page.request_token_field {
+        Some(field) => format!(
+            "Opaque token for fetching the next page. Pass it as `{field}` in the \
+             next request; empty when there are no more results."
+        ),
+        None => "Opaque token for fetching the next page; empty when there are no \
+                 more results."
+            .to_string(),
+    }
+}
+
+/// Annotate paginated list response schemas with `x-pagination`.
+///
+/// Adds `x-pagination: { token_field, items_field }` to each detected schema
+/// and marks the token property `readOnly`, documenting its semantics when the
+/// proto comment did not.
+pub fn annotate_pagination(doc: &mut Value, paginated: &[PaginatedResponse]) {
+    let Some(schemas) = schemas_mut(doc) else {
+        return;
+    };
+
+    for page in paginated {
+        let Some(schema) = schemas
+            .get_mut(page.schema.as_str())
+            .and_then(Value::as_mapping_mut)
+        else {
+            continue;
+        };
+
+        let Some(token) = schema
+            .get_mut("properties")
+            .and_then(Value::as_mapping_mut)
+            .and_then(|p| p.get_mut(page.token_field.as_str()))
+            .and_then(Value::as_mapping_mut)
+        else {
+            continue;
+        };
+        token.insert(val_s("readOnly"), Value::Bool(true));
+        if !token.contains_key("description") {
+            token.insert(val_s("description"), val_s(&page_token_description(page)));
+        }
+
+        let mut pagination = serde_yaml_ng::Mapping::new();
+        pagination.insert(val_s("token_field"), val_s(&page.token_field));
+        pagination.insert(val_s("items_field"), val_s(&page.items_field));
+        schema.insert(val_s("x-pagination"), Value::Mapping(pagination));
+    }
+}
+
+/// Render a single code sample with `{method}` / `{path}` substitution.
+fn render_code_sample(sample: &CodeSample, path: &str, method: &str) -> Value {
+    let source = sample
+        .source
+        .replace("{method}", &method.to_uppercase())
+        .replace("{path}", path);
+
+    let mut entry = serde_yaml_ng::Mapping::new();
+    entry.insert(val_s("lang"), val_s(&sample.lang));
+    if let Some(label) = &sample.label {
+        entry.insert(val_s("label"), val_s(label));
+    }
+    entry.insert(val_s("source"), val_s(&source));
+    Value::Mapping(entry)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn curl_sample() -> CodeSample {
+        CodeSample {
+            lang: "Shell".to_string(),
+            label: Some("curl".to_string()),
+            source: "curl -X {method} https://api.example.com{path}".to_string(),
+        }
+    }
+
+    fn tag_group(name: &str, tags: &[&str]) -> TagGroup {
+        TagGroup {
+            name: name.to_string(),
+            tags: tags.iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    #[test]
+    fn tag_groups_follow_patterns_in_order() {
+        let yaml = r"
+tags:
+  - name: AuthService
+  - name: UserService
+  - name: BillingService
+  - name: Health
+paths:
+  /v1/events:
+    get:
+      tags: [EventService]
+      responses: {}
+";
+        let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        inject_tag_groups(
+            &mut doc,
+            &[
+                tag_group("Identity", &["Auth*", "User*"]),
+                tag_group("API", &["*Service"]),
+                tag_group("Empty", &["Nothing*"]),
+            ],
+        );
+
+        let expected: Value = serde_yaml_ng::from_str(
+            r"
+- name: Identity
+  tags: [AuthService, UserService]
+- name: API
+  tags: [BillingService, EventService]
+- name: Other
+  tags: [Health]
+",
+        )
+        .unwrap();
+        assert_eq!(doc["x-tagGroups"], expected);
+    }
+
+    #[test]
+    fn tag_groups_default_to_single_api_group() {
+        let yaml = r"
+tags:
+  - name: AuthService
+  - name: UserService
+";
+        let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        inject_tag_groups(&mut doc, &[]);
+
+        let groups = doc["x-tagGroups"].as_sequence().unwrap();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0]["name"].as_str(), Some("API"));
+        assert_eq!(groups[0]["tags"].as_sequence().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn glob_match_wildcards() {
+        assert!(glob_match("*", "AnyService"));
+        assert!(glob_match("*Service", "UserService"));
+        assert!(glob_match("User*", "UserService"));
+        assert!(glob_match("U*r*e", "UserService"));
+        assert!(glob_match("Health", "Health"));
+        assert!(!glob_match("Health", "HealthCheck"));
+        assert!(!glob_match("*Service", "Health"));
+        assert!(!glob_match("*x*x", "x"));
+    }
+
+    #[test]
+    fn global_template_applied_to_all_operations() {
+        let yaml = r"
+paths:
+  /v1/items:
+    get:
+      operationId: ItemService_ListItems
+    post:
+      operationId: ItemService_CreateItem
+";
+        let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        let templates = HashMap::from([(ALL_OPERATIONS.to_string(), vec![curl_sample()])]);
+        inject_code_samples(&mut doc, &templates);
+
+        let get = &doc["paths"]["/v1/items"]["get"]["x-codeSamples"][0];
+        assert_eq!(get["lang"].as_str().unwrap(), "Shell");
+        assert_eq!(get["label"].as_str().unwrap(), "curl");
+        assert_eq!(
+            get["source"].as_str().unwrap(),
+            "curl -X GET https://api.example.com/v1/items"
+        );
+        let post = &doc["paths"]["/v1/items"]["post"]["x-codeSamples"][0];
+        assert_eq!(
+            post["source"].as_str().unwrap(),
+            "curl -X POST https://api.example.com/v1/items"
+        );
+    }
+
+    #[test]
+    fn operation_template_overrides_global() {
+        let yaml = r"
+paths:
+  /v1/items:
+    get:
+      operationId: ItemService_ListItems
+    post:
+      operationId: ItemService_CreateItem
+";
+        let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        let specific = CodeSample {
+            lang: "JavaScript".to_string(),
+            label: None,
+            source: "fetch('{path}', { method: '{method}' })".to_string(),
+        };
+        let templates = HashMap::from([
+            (ALL_OPERATIONS.to_string(), vec![curl_sample()]),
+            ("ItemService_CreateItem".to_string(), vec![specific]),
+        ]);
+        inject_code_samples(&mut doc, &templates);
+
+        let post = doc["paths"]["/v1/items"]["post"]["x-codeSamples"]
+            .as_sequence()
+            .unwrap();
+        assert_eq!(post.len(), 1);
+        assert_eq!(post[0]["lang"].as_str().unwrap(), "JavaScript");
+        assert!(post[0].get("label").is_none());
+        assert_eq!(
+            post[0]["source"].as_str().unwrap(),
+            "fetch('/v1/items', { method: 'POST' })"
+        );
+
+        // GET still receives the global sample
+        assert_eq!(
+            doc["paths"]["/v1/items"]["get"]["x-codeSamples"][0]["lang"]
+                .as_str()
+                .unwrap(),
+            "Shell"
+        );
+    }
+
+    #[test]
+    fn no_templates_leaves_operations_untouched() {
+        let yaml = r"
+paths:
+  /v1/items:
+    get:
+      operationId: ItemService_ListItems
+";
+        let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        inject_code_samples(&mut doc, &HashMap::new());
+        assert!(
+            doc["paths"]["/v1/items"]["get"]
+                .get("x-codeSamples")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn pagination_annotates_list_response() {
+        let yaml = r"
+components:
+  schemas:
+    users.v1.ListUsersResponse:
+      type: object
+      properties:
+        users:
+          type: array
+          items:
+            $ref: '#/components/schemas/users.v1.User'
+        nextPageToken:
+          type: string
+";
+        let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        annotate_pagination(
+            &mut doc,
+            &[PaginatedResponse {
+                schema: "users.v1.ListUsersResponse".to_string(),
+                items_field: "users".to_string(),
+                token_field: "nextPageToken".to_string(),
+                request_token_field: Some("pageToken".to_string()),
+                max_page_size: None,
+            }],
+        );
+
+        let schema = &doc["components"]["schemas"]["users.v1.ListUsersResponse"];
+        assert_eq!(
+            schema["x-pagination"]["token_field"].as_str().unwrap(),
+            "nextPageToken"
+        );
+        assert_eq!(
+            schema["x-pagination"]["items_field"].as_str().unwrap(),
+            "users"
+        );
+        let token = &schema["properties"]["nextPageToken"];
+        assert_eq!(token["readOnly"].as_bool(), Some(true));
+        assert!(token["description"].as_str().unwrap().contains("next page"));
+        assert!(
+            token["description"]
+                .as_str()
+                .unwrap()
+                .contains("`pageToken`")
+        );
+    }
+
+    #[test]
+    fn pagination_description_uses_discovered_token_names() {
+        let yaml = r"
+components:
+  schemas:
+    feed.v1.ListPostsResponse:
+      type: object
+      properties:
+        nextCursor:
+          type: string
+    feed.v1.ListTagsResponse:
+      type: object
+      properties:
+        nextCursor:
+          type: string
+";
+        let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        let page = |schema: &str, request_token_field: Option<&str>| PaginatedResponse {
+            schema: schema.to_string(),
+            items_field: "items".to_string(),
+            token_field: "nextCursor".to_string(),
+            request_token_field: request_token_field.map(ToString::to_string),
+            max_page_size: None,
+        };
+        annotate_pagination(
+            &mut doc,
+            &[
+                page("feed.v1.ListPostsResponse", Some("cursor")),
+                page("feed.v1.ListTagsResponse", None),
+            ],
+        );
+
+        let schemas = &doc["components"]["schemas"];
+        let posts = schemas["feed.v1.ListPostsResponse"]["properties"]["nextCursor"]["description"]
+            .as_str()
+            .unwrap();
+        assert!(posts.contains("Pass it as `cursor`"), "{posts}");
+        let tags = schemas["feed.v1.ListTagsResponse"]["properties"]["nextCursor"]["description"]
+            .as_str()
+            .unwrap();
+        assert!(!tags.contains("Pass it as"), "{tags}");
+        assert!(!posts.contains("pageToken") && !tags.contains("pageToken"));
+    }
+
+    #[test]
+    fn pagination_keeps_existing_token_description() {
+        let yaml = r"
+components:
+  schemas:
+    users.v1.ListUsersResponse:
+      type: object
+      properties:
+        nextPageToken:
+          type: string
+          description: Cursor for the next page.
+";
+        let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        annotate_pagination(
+            &mut doc,
+            &[PaginatedResponse {
+                schema: "users.v1.ListUsersResponse".to_string(),
+                items_field: "users".to_string(),
+                token_field: "nextPageToken".to_string(),
+                request_token_field: None,
+                max_page_size: None,
+            }],
+        );
+
+        let token = &doc["components"]["schemas"]["users.v1.ListUsersResponse"]["properties"]["nextPageToken"];
+        assert_eq!(
+            token["description"].as_str().unwrap(),
+            "Cursor for the next page."
+        );
+    }
+
+    #[test]
+    fn proto_source_annotates_matching_operations() {
+        let yaml = r"
+paths:
+  /v1/items:
+    get:
+      operationId: ItemService_ListItems
+    post:
+      operationId: ItemService_CreateItem
+  /v1/other:
+    get:
+      operationId: Unknown_Op
+";
+        let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        let source = |method: &str, line| MethodSource {
+            operation_id: format!("ItemService_{method}"),
+            file: "items/v1/items.proto".to_string(),
+            service: "items.v1.ItemService".to_string(),
+            method: method.to_string(),
+            line,
+        };
+        annotate_proto_source(
+            &mut doc,
+            &[source("ListItems", Some(12)), source("CreateItem", None)],
+        );
+
+        let list = &doc["paths"]["/v1/items"]["get"]["x-proto-source"];
+        assert_eq!(list["file"].as_str().unwrap(), "items/v1/items.proto");
+        assert_eq!(list["service"].as_str().unwrap(), "items.v1.ItemService");
+        assert_eq!(list["method"].as_str().unwrap(), "ListItems");
+        assert_eq!(list["line"].as_u64(), Some(12));
+
+        let create = &doc["paths"]["/v1/items"]["post"]["x-proto-source"];
+        assert!(create.get("line").is_none());
+
+        assert!(
+            doc["paths"]["/v1/other"]["get"]
+                .get("x-proto-source")
+                .is_none()
+        );
+    }
+}
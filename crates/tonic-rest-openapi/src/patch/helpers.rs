@@ -11,6 +11,9 @@ pub const UUID_PATTERN: &str =
 /// Example UUID v4 value for OpenAPI schema `example` fields.
 pub const UUID_EXAMPLE: &str = "550e8400-e29b-41d4-a716-446655440000";
 
+/// Example base64 value for proto `bytes` schemas.
+pub const BYTES_EXAMPLE: &str = "SGVsbG8sIHdvcmxkIQ==";
+
 /// Access `doc.components.schemas` immutably.
 pub fn schemas(doc: &Value) -> Option<&serde_yaml_ng::Mapping> {
     doc.as_mapping()
@@ -83,7 +86,7 @@ pub fn response_header(description: &str, default_value: &str) -> Value {
 ///
 /// Path items can also contain `summary`, `description`, `parameters`, and
 /// `servers` keys — we skip those so callbacks only receive actual operations.
-const HTTP_METHODS: &[&str] = &[
+pub(crate) const HTTP_METHODS: &[&str] = &[
     "get", "put", "post", "delete", "options", "head", "patch", "trace",
 ];
 
@@ -162,6 +165,41 @@ pub fn snake_to_lower_camel_dotted(s: &str) -> String {
         .join(".")
 }
 
+/// Collect local `$ref`s (`#/...`) whose JSON pointer does not resolve in `doc`.
+///
+/// External references (other files or URLs) are ignored. The result is
+/// sorted for deterministic error messages.
+pub fn collect_unresolved_refs(doc: &Value) -> Vec<String> {
+    let mut refs = HashSet::new();
+    collect_refs(doc, &mut refs);
+
+    let mut unresolved: Vec<String> = refs
+        .into_iter()
+        .filter(|r| is_unresolved_ref(doc, r))
+        .collect();
+    unresolved.sort();
+    unresolved
+}
+
+/// Whether `target` is a local `$ref` (`#/...`) that does not resolve in `doc`.
+pub fn is_unresolved_ref(doc: &Value, target: &str) -> bool {
+    target
+        .strip_prefix("#/")
+        .is_some_and(|pointer| resolve_pointer(doc, pointer).is_none())
+}
+
+/// Resolve a JSON pointer (without the leading `#/`) against a YAML value.
+pub fn resolve_pointer<'a>(doc: &'a Value, pointer: &str) -> Option<&'a Value> {
+    pointer.split('/').try_fold(doc, |node, token| {
+        let key = token.replace("~1", "/").replace("~0", "~");
+        match node {
+            Value::Mapping(map) => map.get(key.as_str()),
+            Value::Sequence(seq) => seq.get(key.parse::<usize>().ok()?),
+            _ => None,
+        }
+    })
+}
+
 /// Recursively walk a YAML value tree and collect all `$ref` string values.
 pub fn collect_refs(value: &Value, refs: &mut HashSet<String>) {
     match value {
@@ -183,3 +221,45 @@ pub fn collect_refs(value: &Value, refs: &mut HashSet<String>) {
         _ => {}
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unresolved_refs_reports_only_missing_local_targets() {
+        let yaml = r"
+paths:
+  /v1/a~b/{id}:
+    get:
+      responses:
+        '200':
+          $ref: '#/components/responses/Ok'
+        '404':
+          $ref: '#/components/responses/NotFound'
+      parameters:
+        - $ref: '#/components/parameters/Missing'
+        - $ref: 'common.yaml#/components/parameters/External'
+      callbacks:
+        cb:
+          $ref: '#/paths/~1v1~1a~0b~1{id}'
+components:
+  responses:
+    Ok:
+      description: OK
+      content:
+        application/json:
+          schema:
+            $ref: '#/components/schemas/Gone'
+";
+        let doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        assert_eq!(
+            collect_unresolved_refs(&doc),
+            vec![
+                "#/components/parameters/Missing",
+                "#/components/responses/NotFound",
+                "#/components/schemas/Gone",
+            ]
+        );
+    }
+}
@@ -10,20 +10,32 @@
 //! - [`responses`] — Response status codes, redirects, plain text, error schemas
 //! - [`security`] — Bearer auth schemes and per-operation overrides
 //! - [`validation`] — Proto validation constraints → JSON Schema
+//! - [`callbacks`] — Webhook `callbacks` on configured operations
 //! - [`cleanup`] — Tag cleanup, orphan removal, formatting normalization
+//! - [`extensions`] — Vendor extensions (`x-codeSamples`, `x-pagination`, `x-proto-source`, `x-badges`)
+//! - [`verify`] — Optional structural checks on the finished spec
 
+mod callbacks;
 mod cleanup;
-mod helpers;
+mod extensions;
+pub(crate) mod helpers;
 mod oas31;
 mod responses;
 mod security;
 mod streaming;
 mod validation;
+mod verify;
+
+pub use cleanup::STANDARD_FORMATS;
+
+use std::collections::{BTreeMap, HashMap};
 
 use serde_yaml_ng::Value;
 
-use crate::config::PlainTextEndpoint;
-use crate::config::{InfoOverrides, ServerEntry};
+use crate::config::{
+    Badge, CallbackDef, CodeSample, FormUrlEncodedEndpoint, OperationExample, PlainTextEndpoint,
+};
+use crate::config::{EnumCase, InfoOverrides, SanitizeMode, SecurityScheme, ServerEntry, TagGroup};
 use crate::discover::ProtoMetadata;
 use crate::error;
 
@@ -42,6 +54,7 @@ use crate::error;
 ///     .error_schema_ref("#/components/schemas/ErrorResponse");
 /// ```
 #[derive(Debug)]
+#[expect(clippy::struct_excessive_bools)]
 pub struct PatchConfig<'a> {
     /// Proto metadata extracted via [`crate::discover()`].
     metadata: &'a ProtoMetadata,
@@ -55,18 +68,46 @@ pub struct PatchConfig<'a> {
     /// Raw proto method names — resolved to operation IDs at [`patch()`] time.
     deprecated_method_names: Vec<String>,
 
+    /// Raw proto method names — resolved to operation IDs at [`patch()`] time.
+    gone_method_names: Vec<String>,
+
+    /// Raw proto method names — resolved to operation IDs at [`patch()`] time.
+    created_method_names: Vec<String>,
+
+    /// Raw proto method names — resolved to operation IDs at [`patch()`] time.
+    not_created_method_names: Vec<String>,
+
+    /// Sunset dates keyed by proto method name — resolved to operation IDs
+    /// at [`patch()`] time.
+    deprecated_sunsets: HashMap<String, String>,
+
     /// `$ref` path for the REST error response schema.
     error_schema_ref: String,
 
+    /// Status codes whose declared responses reference the error schema.
+    error_response_codes: Vec<u16>,
+
+    /// Expand `default` error responses into explicit status codes.
+    explicit_error_responses: bool,
+
+    /// Document `X-RateLimit-*` headers and a `429` response on every operation.
+    rate_limit_headers: bool,
+
     /// Endpoints that should use `text/plain` instead of `application/json`.
     plain_text_endpoints: Vec<PlainTextEndpoint>,
 
+    /// Endpoints whose request body is `application/x-www-form-urlencoded`.
+    form_urlencoded_endpoints: Vec<FormUrlEncodedEndpoint>,
+
     /// Metrics endpoint path for response header enrichment (e.g., `/metrics`).
     metrics_path: Option<String>,
 
     /// Readiness probe path for adding 503 response (e.g., `/health/ready`).
     readiness_path: Option<String>,
 
+    /// Liveness probe path documented as a bare `200` (e.g., `/health/live`).
+    liveness_path: Option<String>,
+
     /// Transform toggles (all default to `true`).
     transforms: crate::config::TransformConfig,
 
@@ -86,10 +127,87 @@ pub struct PatchConfig<'a> {
 
     /// Additional field name patterns to mark as `readOnly`.
     read_only_fields: Vec<String>,
+
+    /// `schema.field` entries marked `writeOnly` even on response schemas.
+    force_write_only: Vec<String>,
+
+    /// Proto request field names moved into `in: header` parameters.
+    header_fields: Vec<String>,
+
+    /// `x-codeSamples` templates keyed by proto method name (or `"*"`) —
+    /// resolved to operation IDs at [`patch()`] time.
+    code_sample_templates: HashMap<String, Vec<CodeSample>>,
+
+    /// Pinned examples keyed by operation ID or proto method name — resolved
+    /// to operation IDs at [`patch()`] time.
+    operation_examples: HashMap<String, OperationExample>,
+
+    /// `x-badges` keyed by proto method name — resolved to operation IDs at
+    /// [`patch()`] time.
+    operation_badges: HashMap<String, Vec<Badge>>,
+
+    /// Webhook callbacks keyed by proto method name — resolved to operation
+    /// IDs at [`patch()`] time.
+    callbacks: HashMap<String, Vec<CallbackDef>>,
+
+    /// Security scheme names per proto method, overriding the default Bearer
+    /// requirement — resolved to operation IDs at [`patch()`] time.
+    operation_security: HashMap<String, Vec<String>>,
+
+    /// Extra security schemes registered next to `bearerAuth`.
+    security_schemes: BTreeMap<String, SecurityScheme>,
+
+    /// Fail with [`Error::UnresolvedRefs`](error::Error::UnresolvedRefs) when
+    /// the output contains dangling local `$ref`s.
+    strict_refs: bool,
+
+    /// Fail with [`Error::InvalidSpec`](error::Error::InvalidSpec) on
+    /// structural mistakes in the output.
+    validate_output: bool,
+
+    /// Extra `format` values allowed on top of [`STANDARD_FORMATS`]; `None`
+    /// leaves formats untouched.
+    allowed_formats: Option<Vec<String>>,
+
+    /// `contentMediaType` for base64-encoded `bytes` fields.
+    bytes_content_media_type: Option<String>,
+
+    /// Add `minLength`/`maxLength` of 36 to UUID path parameters.
+    uuid_length_bounds: bool,
+
+    /// Emit `x-enum-varnames` / `x-enum-descriptions` on rewritten enums.
+    enum_varnames: bool,
+
+    /// Compose a whole-body request `example` from property examples.
+    body_level_examples: bool,
+
+    /// Bound paginated item arrays with `maxItems` from `page_size` limits.
+    page_max_items: bool,
+
+    /// Document a `Link` header on paginated list responses.
+    pagination_link_header: bool,
+
+    /// Mark `201 Created` rewrites with `x-rewritten-from`.
+    annotate_rewrites: bool,
+
+    /// Emit a top-level `x-tagGroups` block.
+    tag_groups: bool,
+
+    /// Group name → tag patterns for `x-tagGroups`.
+    tag_group_patterns: Vec<TagGroup>,
+
+    /// Dotted schema name rewriting; `None` keeps names as-is.
+    sanitize_schema_names: Option<SanitizeMode>,
+
+    /// Casing of prefix-stripped enum values.
+    enum_case: EnumCase,
 }
 
 impl<'a> PatchConfig<'a> {
     /// Create a new config with all transforms enabled and default settings.
+    ///
+    /// Servers are seeded from the proto `(google.api.default_host)` options
+    /// (`https://{host}`); configured servers replace them.
     #[must_use]
     pub fn new(metadata: &'a ProtoMetadata) -> Self {
         Self {
@@ -97,16 +215,51 @@ impl<'a> PatchConfig<'a> {
             unimplemented_method_names: Vec::new(),
             public_method_names: Vec::new(),
             deprecated_method_names: Vec::new(),
+            gone_method_names: Vec::new(),
+            created_method_names: Vec::new(),
+            not_created_method_names: Vec::new(),
+            deprecated_sunsets: HashMap::new(),
             error_schema_ref: crate::DEFAULT_ERROR_SCHEMA_REF.to_string(),
+            error_response_codes: Vec::new(),
+            explicit_error_responses: false,
+            rate_limit_headers: false,
             plain_text_endpoints: Vec::new(),
+            form_urlencoded_endpoints: Vec::new(),
             metrics_path: None,
             readiness_path: None,
+            liveness_path: None,
             transforms: crate::config::TransformConfig::default(),
             bearer_description: None,
-            servers: Vec::new(),
+            servers: metadata
+                .default_hosts
+                .iter()
+                .map(|host| ServerEntry::from_default_host(host))
+                .collect(),
             info: InfoOverrides::default(),
             write_only_fields: Vec::new(),
             read_only_fields: Vec::new(),
+            force_write_only: Vec::new(),
+            header_fields: Vec::new(),
+            code_sample_templates: HashMap::new(),
+            operation_examples: HashMap::new(),
+            operation_badges: HashMap::new(),
+            callbacks: HashMap::new(),
+            operation_security: HashMap::new(),
+            security_schemes: BTreeMap::new(),
+            strict_refs: false,
+            validate_output: false,
+            allowed_formats: None,
+            bytes_content_media_type: None,
+            uuid_length_bounds: false,
+            sanitize_schema_names: None,
+            enum_case: EnumCase::Lower,
+            enum_varnames: false,
+            body_level_examples: false,
+            page_max_items: false,
+            pagination_link_header: false,
+            annotate_rewrites: false,
+            tag_groups: false,
+            tag_group_patterns: Vec::new(),
         }
     }
 
@@ -125,15 +278,52 @@ impl<'a> PatchConfig<'a> {
     #[must_use]
     pub fn with_project_config(mut self, project: &crate::ProjectConfig) -> Self {
         self.error_schema_ref.clone_from(&project.error_schema_ref);
+        self.error_response_codes
+            .clone_from(&project.error_response_codes);
+        self.explicit_error_responses = project.explicit_error_responses;
+        self.rate_limit_headers = project.rate_limit_headers;
         self.plain_text_endpoints
             .clone_from(&project.plain_text_endpoints);
+        self.form_urlencoded_endpoints
+            .clone_from(&project.form_urlencoded_endpoints);
         self.metrics_path.clone_from(&project.metrics_path);
         self.readiness_path.clone_from(&project.readiness_path);
-        self.servers.clone_from(&project.servers);
+        self.liveness_path.clone_from(&project.liveness_path);
+        if !project.servers.is_empty() {
+            self.servers.clone_from(&project.servers);
+        }
         self.info = project.info.clone();
         self.write_only_fields
             .clone_from(&project.write_only_fields);
         self.read_only_fields.clone_from(&project.read_only_fields);
+        self.force_write_only.clone_from(&project.force_write_only);
+        self.header_fields.clone_from(&project.header_fields);
+        self.code_sample_templates
+            .clone_from(&project.code_sample_templates);
+        self.operation_examples
+            .clone_from(&project.operation_examples);
+        self.operation_badges.clone_from(&project.operation_badges);
+        self.callbacks.clone_from(&project.callbacks);
+        self.operation_security
+            .clone_from(&project.operation_security);
+        self.security_schemes.clone_from(&project.security_schemes);
+        self.strict_refs = project.strict_refs;
+        self.validate_output = project.validate_output;
+        self.allowed_formats.clone_from(&project.allowed_formats);
+        self.bytes_content_media_type
+            .clone_from(&project.bytes_content_media_type);
+        self.sanitize_schema_names = project.sanitize_schema_names;
+        self.enum_case = project.enum_case;
+        self.enum_varnames = project.enum_varnames;
+        self.body_level_examples = project.body_level_examples;
+        self.page_max_items = project.page_max_items;
+        self.pagination_link_header = project.pagination_link_header;
+        self.annotate_rewrites = project.annotate_rewrites;
+        if !project.tag_groups.is_empty() {
+            self.tag_groups = true;
+            self.tag_group_patterns.clone_from(&project.tag_groups);
+        }
+        self.uuid_length_bounds = project.uuid_length_bounds;
         self.transforms = project.transforms;
 
         if !project.unimplemented_methods.is_empty() {
@@ -144,8 +334,27 @@ impl<'a> PatchConfig<'a> {
             self.public_method_names.clone_from(&project.public_methods);
         }
         if !project.deprecated_methods.is_empty() {
-            self.deprecated_method_names
-                .clone_from(&project.deprecated_methods);
+            self.deprecated_method_names = project
+                .deprecated_methods
+                .iter()
+                .map(|d| d.method.clone())
+                .collect();
+            self.deprecated_sunsets = project
+                .deprecated_methods
+                .iter()
+                .filter_map(|d| Some((d.method.clone(), d.sunset.clone()?)))
+                .collect();
+        }
+        if !project.gone_methods.is_empty() {
+            self.gone_method_names.clone_from(&project.gone_methods);
+        }
+        if !project.created_methods.is_empty() {
+            self.created_method_names
+                .clone_from(&project.created_methods);
+        }
+        if !project.not_created_methods.is_empty() {
+            self.not_created_method_names
+                .clone_from(&project.not_created_methods);
         }
 
         self
@@ -181,6 +390,50 @@ impl<'a> PatchConfig<'a> {
         self
     }
 
+    /// Set proto method names of permanently removed endpoints.
+    ///
+    /// Method names are resolved to gnostic operation IDs at [`patch()`] time.
+    /// These operations get `x-gone: true` and a `410 Gone` response in place
+    /// of their success responses.
+    #[must_use]
+    pub fn gone_methods(mut self, methods: &[&str]) -> Self {
+        self.gone_method_names = methods.iter().map(ToString::to_string).collect();
+        self
+    }
+
+    /// Set proto method names whose success response is always `201 Created`.
+    ///
+    /// Method names are resolved to gnostic operation IDs at [`patch()`] time.
+    /// Listed operations are rewritten even when their name does not match
+    /// the create heuristic.
+    #[must_use]
+    pub fn created_methods(mut self, methods: &[&str]) -> Self {
+        self.created_method_names = methods.iter().map(ToString::to_string).collect();
+        self
+    }
+
+    /// Set proto method names that keep `200 OK` despite looking like creates.
+    ///
+    /// Method names are resolved to gnostic operation IDs at [`patch()`] time.
+    /// Useful for idempotent upserts named `Create*`. Takes precedence over
+    /// [`created_methods`](Self::created_methods).
+    #[must_use]
+    pub fn not_created_methods(mut self, methods: &[&str]) -> Self {
+        self.not_created_method_names = methods.iter().map(ToString::to_string).collect();
+        self
+    }
+
+    /// Set sunset dates for deprecated endpoints, keyed by proto method name.
+    ///
+    /// Each operation is also marked deprecated, gets `x-sunset: <date>`, and
+    /// documents a `Sunset` header on its success responses. Keys are
+    /// resolved to operation IDs at [`patch()`] time.
+    #[must_use]
+    pub fn deprecated_sunsets(mut self, sunsets: HashMap<String, String>) -> Self {
+        self.deprecated_sunsets = sunsets;
+        self
+    }
+
     /// Set the `$ref` path for the REST error response schema.
     #[must_use]
     pub fn error_schema_ref(mut self, ref_path: &str) -> Self {
@@ -188,6 +441,41 @@ impl<'a> PatchConfig<'a> {
         self
     }
 
+    /// Set status codes (e.g. `401`, `404`) whose declared responses should
+    /// reference the error schema, in addition to `default`.
+    ///
+    /// Only responses an operation already declares are rewritten.
+    #[must_use]
+    pub fn error_response_codes(mut self, codes: &[u16]) -> Self {
+        self.error_response_codes = codes.to_vec();
+        self
+    }
+
+    /// Replace `default` error responses with explicit status codes.
+    ///
+    /// Each operation's `default` response is removed and one entry per
+    /// [`error_response_codes`](Self::error_response_codes) code is added
+    /// (`400, 401, 403, 404, 500` when none are set), all referencing the
+    /// error schema. Codes the operation already declares are kept as-is.
+    /// Off by default.
+    #[must_use]
+    pub const fn explicit_error_responses(mut self, enabled: bool) -> Self {
+        self.explicit_error_responses = enabled;
+        self
+    }
+
+    /// Document gateway rate-limit headers on every operation.
+    ///
+    /// Adds `X-RateLimit-Limit`, `X-RateLimit-Remaining`, and
+    /// `X-RateLimit-Reset` to each `2xx` and `429` response, and a
+    /// `429 Too Many Requests` error response where an operation lacks one.
+    /// Off by default.
+    #[must_use]
+    pub const fn rate_limit_headers(mut self, enabled: bool) -> Self {
+        self.rate_limit_headers = enabled;
+        self
+    }
+
     /// Enable or disable the 3.0 → 3.1 upgrade transform.
     #[must_use]
     pub const fn upgrade_to_3_1(mut self, enabled: bool) -> Self {
@@ -258,6 +546,48 @@ impl<'a> PatchConfig<'a> {
         self
     }
 
+    /// Enable or disable `x-pagination` annotation of list responses.
+    #[must_use]
+    pub const fn annotate_pagination(mut self, enabled: bool) -> Self {
+        self.transforms.annotate_pagination = enabled;
+        self
+    }
+
+    /// Enable or disable `x-proto-source` operation annotation (default: disabled).
+    #[must_use]
+    pub const fn annotate_proto_source(mut self, enabled: bool) -> Self {
+        self.transforms.annotate_proto_source = enabled;
+        self
+    }
+
+    /// Enable or disable base64 typing (`format: byte`, `contentEncoding`) of `bytes` fields.
+    #[must_use]
+    pub const fn annotate_bytes_encoding(mut self, enabled: bool) -> Self {
+        self.transforms.annotate_bytes_encoding = enabled;
+        self
+    }
+
+    /// Enable or disable nullable-scalar rendering of wrapper type fields.
+    #[must_use]
+    pub const fn inline_wrapper_types(mut self, enabled: bool) -> Self {
+        self.transforms.inline_wrapper_types = enabled;
+        self
+    }
+
+    /// Enable or disable nullable rendering of proto3 `optional` fields.
+    #[must_use]
+    pub const fn nullable_optional_fields(mut self, enabled: bool) -> Self {
+        self.transforms.nullable_optional_fields = enabled;
+        self
+    }
+
+    /// Enable or disable `oneOf` schemas for proto `oneof` groups.
+    #[must_use]
+    pub const fn oneof_schemas(mut self, enabled: bool) -> Self {
+        self.transforms.oneof_schemas = enabled;
+        self
+    }
+
     /// Skip the 3.0 → 3.1 upgrade transform.
     #[must_use]
     pub const fn skip_upgrade(self) -> Self {
@@ -318,6 +648,42 @@ impl<'a> PatchConfig<'a> {
         self.annotate_field_access(false)
     }
 
+    /// Skip `x-pagination` annotation.
+    #[must_use]
+    pub const fn skip_pagination_annotation(self) -> Self {
+        self.annotate_pagination(false)
+    }
+
+    /// Skip `x-proto-source` operation annotation.
+    #[must_use]
+    pub const fn skip_proto_source_annotation(self) -> Self {
+        self.annotate_proto_source(false)
+    }
+
+    /// Skip base64 typing of `bytes` fields and parameters.
+    #[must_use]
+    pub const fn skip_bytes_encoding_annotation(self) -> Self {
+        self.annotate_bytes_encoding(false)
+    }
+
+    /// Skip nullable-scalar rendering of wrapper type fields.
+    #[must_use]
+    pub const fn skip_wrapper_type_inlining(self) -> Self {
+        self.inline_wrapper_types(false)
+    }
+
+    /// Skip nullable rendering of proto3 `optional` fields.
+    #[must_use]
+    pub const fn skip_nullable_optional_fields(self) -> Self {
+        self.nullable_optional_fields(false)
+    }
+
+    /// Skip `oneOf` schemas for proto `oneof` groups.
+    #[must_use]
+    pub const fn skip_oneof_schemas(self) -> Self {
+        self.oneof_schemas(false)
+    }
+
     /// Set a custom description for the Bearer auth scheme.
     ///
     /// When `None`, defaults to `"Bearer authentication token"`.
@@ -355,6 +721,252 @@ impl<'a> PatchConfig<'a> {
         self
     }
 
+    /// Mark exact `schema.field` entries `writeOnly`, even on response schemas.
+    ///
+    /// Field access annotation normally leaves response schemas alone so that
+    /// returned secrets (e.g., `SetupMfaResponse.secret`) stay visible; listed
+    /// fields override that skip.
+    #[must_use]
+    pub fn force_write_only(mut self, fields: &[&str]) -> Self {
+        self.force_write_only = fields.iter().map(ToString::to_string).collect();
+        self
+    }
+
+    /// Set proto request field names carried in HTTP headers.
+    ///
+    /// Matching body properties and query parameters become `in: header`
+    /// parameters named after the field with `_` replaced by `-`.
+    #[must_use]
+    pub fn header_fields(mut self, fields: &[&str]) -> Self {
+        self.header_fields = fields.iter().map(ToString::to_string).collect();
+        self
+    }
+
+    /// Set `x-codeSamples` templates.
+    ///
+    /// Keys are proto method names (resolved to operation IDs at [`patch()`]
+    /// time) or `"*"` for samples applied to every operation. Templates support
+    /// `{method}` and `{path}` placeholders.
+    #[must_use]
+    pub fn code_sample_templates(mut self, templates: HashMap<String, Vec<CodeSample>>) -> Self {
+        self.code_sample_templates = templates;
+        self
+    }
+
+    /// Pin request/response examples per operation.
+    ///
+    /// Keys are operation IDs (e.g., `AuthService_Login`) or proto method
+    /// names, resolved to operation IDs at [`patch()`] time. Pinned examples
+    /// replace the heuristic ones as the media-type `example`.
+    #[must_use]
+    pub fn operation_examples(mut self, examples: HashMap<String, OperationExample>) -> Self {
+        self.operation_examples = examples;
+        self
+    }
+
+    /// Set `x-badges` per operation, keyed by proto method name.
+    ///
+    /// Keys are resolved to operation IDs at [`patch()`] time.
+    #[must_use]
+    pub fn operation_badges(mut self, badges: HashMap<String, Vec<Badge>>) -> Self {
+        self.operation_badges = badges;
+        self
+    }
+
+    /// Register `apiKey` / `oauth2` security schemes next to `bearerAuth`.
+    ///
+    /// Bearer stays the global default; select these per operation with
+    /// [`operation_security`](Self::operation_security). `OAuth2` flows are
+    /// checked with [`SecurityScheme::validate`] when [`patch()`] runs.
+    #[must_use]
+    pub fn security_schemes(mut self, schemes: BTreeMap<String, SecurityScheme>) -> Self {
+        self.security_schemes = schemes;
+        self
+    }
+
+    /// Set webhook `callbacks`, keyed by proto method name.
+    ///
+    /// Keys are resolved to operation IDs at [`patch()`] time; each
+    /// [`CallbackDef`] becomes a `POST` entry in the operation's `callbacks`.
+    #[must_use]
+    pub fn callbacks(mut self, callbacks: HashMap<String, Vec<CallbackDef>>) -> Self {
+        self.callbacks = callbacks;
+        self
+    }
+
+    /// Set security schemes for specific operations, keyed by proto method name.
+    ///
+    /// Each listed operation gets one `security` requirement per scheme name
+    /// (any one satisfies it) instead of the default Bearer requirement. The
+    /// schemes themselves must be registered with
+    /// [`security_schemes`](Self::security_schemes) or defined under
+    /// `components.securitySchemes` in the input spec. Methods also listed in [`public_methods`](Self::public_methods)
+    /// stay public.
+    ///
+    /// Requirements carry no scopes (`- oauth: []`), so an operation cannot
+    /// demand particular `OAuth2` scopes; a flow's `scopes` only document
+    /// what the authorization server offers.
+    ///
+    /// Method names are resolved to gnostic operation IDs at [`patch()`] time.
+    #[must_use]
+    pub fn operation_security(mut self, security: HashMap<String, Vec<String>>) -> Self {
+        self.operation_security = security;
+        self
+    }
+
+    /// Enable or disable strict `$ref` checking.
+    ///
+    /// When enabled, [`patch()`] checks every local `$ref` in the final spec
+    /// and returns [`Error::UnresolvedRefs`](crate::Error::UnresolvedRefs)
+    /// instead of emitting a spec that points at missing components.
+    #[must_use]
+    pub const fn strict_refs(mut self, enabled: bool) -> Self {
+        self.strict_refs = enabled;
+        self
+    }
+
+    /// Enable or disable structural validation of the patched spec.
+    ///
+    /// When enabled, [`patch()`] walks the final document and returns
+    /// [`Error::InvalidSpec`](crate::Error::InvalidSpec), with the JSON
+    /// pointer of the offending node, for a dangling local `$ref`, an empty
+    /// `required` array (outside `example`/`examples` values), or an
+    /// operation without `responses`.
+    #[must_use]
+    pub const fn validate_output(mut self, enabled: bool) -> Self {
+        self.validate_output = enabled;
+        self
+    }
+
+    /// Remove `format` values outside [`STANDARD_FORMATS`] and `extra_allowed`.
+    ///
+    /// Off by default. Removals are listed in
+    /// [`PatchReport::removed_formats`] (see [`patch_with_report`]).
+    #[must_use]
+    pub fn normalize_formats(mut self, extra_allowed: &[&str]) -> Self {
+        self.allowed_formats = Some(extra_allowed.iter().map(ToString::to_string).collect());
+        self
+    }
+
+    /// Set the `contentMediaType` emitted alongside `contentEncoding: base64`
+    /// on `bytes` fields (e.g., `application/octet-stream`).
+    #[must_use]
+    pub fn bytes_content_media_type(mut self, media_type: &str) -> Self {
+        self.bytes_content_media_type = Some(media_type.to_string());
+        self
+    }
+
+    /// Add `minLength: 36` / `maxLength: 36` to UUID path parameters.
+    ///
+    /// Off by default; `format` and `pattern` already describe the value, but
+    /// some strict validators only enforce length keywords.
+    #[must_use]
+    pub const fn uuid_length_bounds(mut self, enabled: bool) -> Self {
+        self.uuid_length_bounds = enabled;
+        self
+    }
+
+    /// Rewrite dotted schema names for client generators that reject dots.
+    ///
+    /// Runs last, after every name-based transform, and rewrites all `$ref`s
+    /// to match. Fails with
+    /// [`Error::SchemaNameCollision`](error::Error::SchemaNameCollision) when
+    /// two schemas would end up with the same name.
+    #[must_use]
+    pub const fn sanitize_schema_names(mut self, mode: SanitizeMode) -> Self {
+        self.sanitize_schema_names = Some(mode);
+        self
+    }
+
+    /// Set the casing of prefix-stripped enum values (default:
+    /// [`EnumCase::Lower`]).
+    ///
+    /// Pick the casing the runtime serializes: `Lower` matches
+    /// `define_enum_serde!` with a prefix, `Original` matches it without one.
+    #[must_use]
+    pub const fn enum_case(mut self, case: EnumCase) -> Self {
+        self.enum_case = case;
+        self
+    }
+
+    /// Emit `x-enum-varnames` (original proto names) and, where proto comments
+    /// exist, `x-enum-descriptions` alongside each rewritten `enum` array.
+    ///
+    /// Off by default. Generators such as openapi-generator use these to name
+    /// enum constants after the proto values instead of the wire strings.
+    #[must_use]
+    pub const fn enum_varnames(mut self, enabled: bool) -> Self {
+        self.enum_varnames = enabled;
+        self
+    }
+
+    /// Also set a whole-body `example` on JSON request bodies, composed from
+    /// the per-property examples.
+    ///
+    /// Off by default; per-property examples are kept either way. Bodies with
+    /// a pinned [`operation_examples`](Self::operation_examples) entry keep it.
+    #[must_use]
+    pub const fn body_level_examples(mut self, enabled: bool) -> Self {
+        self.body_level_examples = enabled;
+        self
+    }
+
+    /// Set `maxItems` on the items array of paginated list responses.
+    ///
+    /// The bound is the `validate.rules` maximum of the request's `page_size`
+    /// field, so clients can size buffers for a page. Off by default; responses
+    /// whose requests have an unbounded `page_size` are left alone.
+    #[must_use]
+    pub const fn page_max_items(mut self, enabled: bool) -> Self {
+        self.page_max_items = enabled;
+        self
+    }
+
+    /// Emit a top-level `x-tagGroups` block for the Redoc sidebar.
+    ///
+    /// Tags come from the spec's `tags` block (one per proto service) plus any
+    /// used by operations. Without [`tag_group_patterns`](Self::tag_group_patterns)
+    /// they all go into a single `API` group. Off by default.
+    #[must_use]
+    pub const fn tag_groups(mut self, enabled: bool) -> Self {
+        self.tag_groups = enabled;
+        self
+    }
+
+    /// Set the groups used by [`tag_groups`](Self::tag_groups).
+    ///
+    /// Each tag joins the first group with a matching pattern; `*` matches
+    /// any run of characters. Tags matching no group are collected under
+    /// `Other`, since Redoc hides tags that belong to no group.
+    #[must_use]
+    pub fn tag_group_patterns(mut self, groups: &[TagGroup]) -> Self {
+        self.tag_group_patterns = groups.to_vec();
+        self
+    }
+
+    /// Document a `Link` response header (RFC 8288) on list operations.
+    ///
+    /// Operations whose `200` response references a paginated response
+    /// detected by [`discover()`](crate::discover) get a `Link` header entry
+    /// describing the `rel="next"` page URL. Off by default, since the
+    /// header is set by the application rather than the generated handlers.
+    #[must_use]
+    pub const fn pagination_link_header(mut self, enabled: bool) -> Self {
+        self.pagination_link_header = enabled;
+        self
+    }
+
+    /// Mark operations rewritten to `201 Created` with `x-rewritten-from: "200"`.
+    ///
+    /// An audit aid for the create heuristic and the
+    /// [`created_methods`](Self::created_methods) overrides: the marker shows
+    /// exactly which operations the transform changed. Off by default.
+    #[must_use]
+    pub const fn annotate_rewrites(mut self, enabled: bool) -> Self {
+        self.annotate_rewrites = enabled;
+        self
+    }
+
     /// Set endpoints that should use `text/plain` content type.
     #[must_use]
     pub fn plain_text_endpoints(mut self, endpoints: &[PlainTextEndpoint]) -> Self {
@@ -362,6 +974,13 @@ impl<'a> PatchConfig<'a> {
         self
     }
 
+    /// Set endpoints whose request body is `application/x-www-form-urlencoded`.
+    #[must_use]
+    pub fn form_urlencoded_endpoints(mut self, endpoints: &[FormUrlEncodedEndpoint]) -> Self {
+        self.form_urlencoded_endpoints = endpoints.to_vec();
+        self
+    }
+
     /// Set the metrics endpoint path for response header enrichment.
     #[must_use]
     pub fn metrics_path(mut self, path: &str) -> Self {
@@ -376,6 +995,13 @@ impl<'a> PatchConfig<'a> {
         self
     }
 
+    /// Set the liveness probe path, documented with only a `200` response.
+    #[must_use]
+    pub fn liveness_path(mut self, path: &str) -> Self {
+        self.liveness_path = Some(path.to_string());
+        self
+    }
+
     /// Resolve deferred method names to operation IDs.
     fn resolved_ops(&self) -> error::Result<(Vec<String>, Vec<String>, Vec<String>)> {
         let unimplemented = self.resolve_method_list(&self.unimplemented_method_names)?;
@@ -384,6 +1010,86 @@ impl<'a> PatchConfig<'a> {
         Ok((unimplemented, public, deprecated))
     }
 
+    /// Re-key code sample templates from method names to operation IDs.
+    fn resolved_code_samples(&self) -> error::Result<HashMap<String, Vec<CodeSample>>> {
+        self.code_sample_templates
+            .iter()
+            .map(|(name, samples)| {
+                let key = if name == extensions::ALL_OPERATIONS {
+                    name.clone()
+                } else {
+                    crate::discover::resolve_single_operation_id(self.metadata, name)?
+                };
+                Ok((key, samples.clone()))
+            })
+            .collect()
+    }
+
+    /// Re-key pinned examples to operation IDs; keys that already name a
+    /// known operation ID are kept as-is.
+    fn resolved_examples(&self) -> error::Result<HashMap<String, OperationExample>> {
+        self.operation_examples
+            .iter()
+            .map(|(key, example)| {
+                let is_op_id = self
+                    .metadata
+                    .operation_ids
+                    .iter()
+                    .any(|e| e.operation_id == *key);
+                let op_id = if is_op_id {
+                    key.clone()
+                } else {
+                    crate::discover::resolve_single_operation_id(self.metadata, key)?
+                };
+                Ok((op_id, example.clone()))
+            })
+            .collect()
+    }
+
+    /// Re-key badges from method names to operation IDs.
+    fn resolved_badges(&self) -> error::Result<HashMap<String, Vec<Badge>>> {
+        self.operation_badges
+            .iter()
+            .map(|(name, badges)| {
+                let op_id = crate::discover::resolve_single_operation_id(self.metadata, name)?;
+                Ok((op_id, badges.clone()))
+            })
+            .collect()
+    }
+
+    /// Re-key deprecation sunset dates from method names to operation IDs.
+    fn resolved_sunsets(&self) -> error::Result<HashMap<String, String>> {
+        self.deprecated_sunsets
+            .iter()
+            .map(|(name, date)| {
+                let op_id = crate::discover::resolve_single_operation_id(self.metadata, name)?;
+                Ok((op_id, date.clone()))
+            })
+            .collect()
+    }
+
+    /// Re-key callbacks from method names to operation IDs.
+    fn resolved_callbacks(&self) -> error::Result<HashMap<String, Vec<CallbackDef>>> {
+        self.callbacks
+            .iter()
+            .map(|(name, defs)| {
+                let op_id = crate::discover::resolve_single_operation_id(self.metadata, name)?;
+                Ok((op_id, defs.clone()))
+            })
+            .collect()
+    }
+
+    /// Re-key per-operation security schemes from method names to operation IDs.
+    fn resolved_operation_security(&self) -> error::Result<HashMap<String, Vec<String>>> {
+        self.operation_security
+            .iter()
+            .map(|(name, schemes)| {
+                let op_id = crate::discover::resolve_single_operation_id(self.metadata, name)?;
+                Ok((op_id, schemes.clone()))
+            })
+            .collect()
+    }
+
     /// Resolve a list of method names to gnostic operation IDs.
     fn resolve_method_list(&self, names: &[String]) -> error::Result<Vec<String>> {
         if names.is_empty() {
@@ -405,33 +1111,74 @@ impl<'a> PatchConfig<'a> {
 /// - **Phase 1** (structural): 3.0 → 3.1 upgrade, server/info injection.
 /// - **Phase 2** (streaming): SSE annotations, `Last-Event-ID` header.
 /// - **Phase 3** (responses): status codes, plain text, redirects, error
-///   schemas, `201 Created` rewrite.
+///   schemas, `201 Created` rewrite; the liveness probe is reduced to `200`
+///   last so injected error responses are dropped.
 /// - **Phase 4** (enum rewrites): must run before inlining (phase 11) so that
 ///   inlined schemas contain the rewritten enum values.
-/// - **Phase 5** (markers): unimplemented (`501`) and deprecated flags; must
+/// - **Phase 5** (markers): unimplemented (`501`), gone (`410`) and deprecated flags; must
 ///   run after response fixes (phase 3).
-/// - **Phase 6** (security): bearer auth schemes; independent of validation.
-/// - **Phase 7** (cleanup): removes empty bodies before constraint injection.
+/// - **Phase 6** (security): bearer auth schemes and per-operation overrides;
+///   independent of validation.
+/// - **Phase 7** (cleanup): removes empty bodies before constraint injection,
+///   and nonstandard `format` values when [`PatchConfig::normalize_formats`]
+///   is set.
 /// - **Phase 8** (UUID flattening): path template `.value` stripping, `$ref`
 ///   flattening, query param simplification; must run before validation.
 /// - **Phase 9** (validation): constraint injection, `writeOnly`/`readOnly`
-///   annotation, `Duration` field rewriting.
+///   annotation, `Duration` field rewriting, `repeated` array typing,
+///   `map` objects, `bytes` encoding, wrapper type inlining, `oneof` → `oneOf`.
 /// - **Phase 10** (path field stripping): must run after constraint injection
-///   (phase 9) since it clones schemas before removing path fields.
+///   (phase 9) since it clones schemas before removing path and header fields.
 /// - **Phase 11** (inlining): must run after path stripping (phase 10) to
 ///   correctly detect emptied bodies; runs last among content transforms.
-/// - **Phase 12** (normalization): always runs last as a final cleanup pass.
+/// - **Phase 12** (vendor extensions + normalization): `x-codeSamples`,
+///   `x-pagination`, and `x-proto-source`, then line ending normalization as
+///   the final cleanup pass.
 ///
 /// # Errors
 ///
 /// Returns an error if the input YAML cannot be parsed, processing fails,
 /// or any deferred method name (from [`PatchConfig::unimplemented_methods`]
 /// or [`PatchConfig::public_methods`]) cannot be resolved against proto metadata.
+/// A server variable whose `default` is not in its `enum` is rejected.
+/// With [`PatchConfig::strict_refs`] enabled, also returns an error if the
+/// patched spec contains dangling local `$ref`s; with
+/// [`PatchConfig::validate_output`], if it fails the structural checks.
 pub fn patch(input_yaml: &str, config: &PatchConfig<'_>) -> error::Result<String> {
+    patch_with_report(input_yaml, config).map(|(yaml, _)| yaml)
+}
+
+/// Notes collected while patching, for callers to surface to the user.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct PatchReport {
+    /// `(JSON pointer, format)` of each `format` value removed by
+    /// [`PatchConfig::normalize_formats`].
+    pub removed_formats: Vec<(String, String)>,
+}
+
+/// Like [`patch`], but also returns a [`PatchReport`] of what was changed.
+///
+/// # Errors
+///
+/// Same as [`patch`].
+#[expect(clippy::too_many_lines)]
+pub fn patch_with_report(
+    input_yaml: &str,
+    config: &PatchConfig<'_>,
+) -> error::Result<(String, PatchReport)> {
     let mut doc: Value = serde_yaml_ng::from_str(input_yaml)?;
+    let mut report = PatchReport::default();
 
     // Resolve deferred method names to operation IDs
     let (unimplemented_ops, public_ops, deprecated_ops) = config.resolved_ops()?;
+    let gone_ops = config.resolve_method_list(&config.gone_method_names)?;
+    let code_samples = config.resolved_code_samples()?;
+    let operation_security = config.resolved_operation_security()?;
+    let callbacks = config.resolved_callbacks()?;
+    let badges = config.resolved_badges()?;
+    let examples = config.resolved_examples()?;
+    let sunsets = config.resolved_sunsets()?;
 
     // Phase 1: Structural transforms (3.0 → 3.1)
     if config.transforms.upgrade_to_3_1 {
@@ -439,6 +1186,9 @@ pub fn patch(input_yaml: &str, config: &PatchConfig<'_>) -> error::Result<String
         oas31::convert_nullable(&mut doc);
     }
     if config.transforms.inject_servers {
+        for server in &config.servers {
+            server.validate()?;
+        }
         oas31::inject_servers_and_info(&mut doc, &config.servers, &config.info);
     }
 
@@ -455,17 +1205,47 @@ pub fn patch(input_yaml: &str, config: &PatchConfig<'_>) -> error::Result<String
     responses::patch_readiness_probe_responses(&mut doc, config.readiness_path.as_deref());
     responses::patch_redirect_endpoints(&mut doc, &config.metadata.redirect_paths);
     responses::ensure_rest_error_schema(&mut doc, &config.error_schema_ref);
-    responses::rewrite_default_error_responses(&mut doc, &config.error_schema_ref);
+    responses::rewrite_error_responses(
+        &mut doc,
+        &config.error_schema_ref,
+        &config.error_response_codes,
+    );
+    if config.pagination_link_header {
+        responses::annotate_pagination_headers(&mut doc, &config.metadata.paginated_responses);
+    }
+    if config.explicit_error_responses {
+        responses::expand_default_error_responses(
+            &mut doc,
+            &config.error_schema_ref,
+            &config.error_response_codes,
+        );
+    }
     if config.transforms.rewrite_create_responses {
-        responses::rewrite_create_responses(&mut doc);
+        let created_ops = config.resolve_method_list(&config.created_method_names)?;
+        let not_created_ops = config.resolve_method_list(&config.not_created_method_names)?;
+        responses::rewrite_create_responses(
+            &mut doc,
+            &created_ops,
+            &not_created_ops,
+            config.annotate_rewrites,
+        );
     }
+    if config.rate_limit_headers {
+        responses::add_rate_limit_headers(&mut doc, &config.error_schema_ref);
+    }
+    responses::patch_liveness_probe_responses(&mut doc, config.liveness_path.as_deref());
 
     // Phase 4: Enum value rewrites
     // Rewrite first (prefix-stripping), then strip unspecified sentinels.
     // Order matters: rewrite_enum_values replaces enum arrays wholesale on
     // component schemas (including the lowercased "unspecified" value), so
     // stripping must run after to remove them from all locations.
-    cleanup::rewrite_enum_values(&mut doc, config.metadata);
+    cleanup::rewrite_enum_values(
+        &mut doc,
+        config.metadata,
+        config.enum_case,
+        config.enum_varnames,
+    );
     cleanup::strip_unspecified_from_query_enums(&mut doc);
 
     // Phase 5: Unimplemented operation markers
@@ -477,13 +1257,26 @@ pub fn patch(input_yaml: &str, config: &PatchConfig<'_>) -> error::Result<String
         );
     }
 
-    if !deprecated_ops.is_empty() {
-        cleanup::mark_deprecated_operations(&mut doc, &deprecated_ops);
+    if !gone_ops.is_empty() {
+        cleanup::mark_gone_operations(&mut doc, &gone_ops, &config.error_schema_ref);
+    }
+
+    if !deprecated_ops.is_empty() || !sunsets.is_empty() {
+        cleanup::mark_deprecated_operations(&mut doc, &deprecated_ops, &sunsets);
     }
 
     // Phase 6: Security
     if config.transforms.add_security {
-        security::add_security_schemes(&mut doc, &public_ops, config.bearer_description.as_deref());
+        for (name, scheme) in &config.security_schemes {
+            scheme.validate(name)?;
+        }
+        security::add_security_schemes(
+            &mut doc,
+            &public_ops,
+            &operation_security,
+            config.bearer_description.as_deref(),
+            &config.security_schemes,
+        );
     }
 
     // Phase 7: Cleanup (tags, summaries, empty bodies, format noise)
@@ -492,6 +1285,9 @@ pub fn patch(input_yaml: &str, config: &PatchConfig<'_>) -> error::Result<String
     cleanup::remove_empty_request_bodies(&mut doc);
     cleanup::remove_unused_empty_schemas(&mut doc);
     cleanup::remove_format_enum(&mut doc);
+    if let Some(allowed) = &config.allowed_formats {
+        report.removed_formats = cleanup::normalize_formats(&mut doc, allowed);
+    }
 
     // Phase 8: UUID flattening
     validation::flatten_uuid_path_templates(&mut doc);
@@ -501,6 +1297,13 @@ pub fn patch(input_yaml: &str, config: &PatchConfig<'_>) -> error::Result<String
     validation::simplify_uuid_query_params(&mut doc);
 
     // Phase 9: Validation constraint injection
+    validation::inject_field_descriptions(&mut doc, &config.metadata.field_descriptions);
+    validation::inject_field_defaults(
+        &mut doc,
+        &config.metadata.field_defaults,
+        &config.metadata.enum_value_map,
+        config.enum_case,
+    );
     if config.transforms.inject_validation {
         validation::inject_validation_constraints(&mut doc, &config.metadata.field_constraints);
     }
@@ -509,13 +1312,41 @@ pub fn patch(input_yaml: &str, config: &PatchConfig<'_>) -> error::Result<String
             &mut doc,
             &config.write_only_fields,
             &config.read_only_fields,
+            &config.force_write_only,
         );
     }
     validation::annotate_duration_fields(&mut doc);
+    validation::enforce_repeated_arrays(&mut doc, &config.metadata.repeated_fields);
+    validation::apply_map_fields(&mut doc, &config.metadata.map_fields);
+    if config.page_max_items {
+        validation::inject_page_max_items(&mut doc, &config.metadata.paginated_responses);
+    }
+    if config.transforms.inline_wrapper_types {
+        validation::inline_wrapper_fields(&mut doc, &config.metadata.wrapper_fields);
+    }
+    if config.transforms.nullable_optional_fields {
+        validation::mark_optional_fields_nullable(&mut doc, &config.metadata.optional_fields);
+    }
+    if config.transforms.annotate_bytes_encoding {
+        validation::annotate_bytes_encoding(
+            &mut doc,
+            &config.metadata.bytes_fields,
+            config.bytes_content_media_type.as_deref(),
+        );
+        validation::annotate_bytes_params(&mut doc, &config.metadata.bytes_params);
+    }
+    if config.transforms.oneof_schemas {
+        validation::apply_oneof_schemas(&mut doc, &config.metadata.oneofs);
+    }
 
     // Phase 10: Path field stripping (must run after constraint injection)
-    validation::strip_path_fields_from_body(&mut doc);
-    validation::enrich_path_params(&mut doc, &config.metadata.path_param_constraints);
+    validation::strip_path_fields_from_body(&mut doc, &config.metadata.repeated_fields);
+    validation::move_header_fields(&mut doc, &config.header_fields);
+    validation::enrich_path_params(
+        &mut doc,
+        &config.metadata.path_param_constraints,
+        config.uuid_length_bounds,
+    );
 
     // Phase 11: Request body handling
     //
@@ -528,7 +1359,9 @@ pub fn patch(input_yaml: &str, config: &PatchConfig<'_>) -> error::Result<String
     // Empty body removal and orphan cleanup always run regardless of the
     // inlining mode — path-field stripping (phase 10) can leave empty
     // bodies, and self-referential schema clusters (e.g., google.rpc.Status)
-    // should always be pruned.
+    // should always be pruned. Callbacks are injected first so the schemas
+    // their payloads reference survive orphan removal.
+    callbacks::inject_callbacks(&mut doc, &callbacks);
     if config.transforms.inline_request_bodies {
         cleanup::inline_request_bodies(&mut doc);
     } else {
@@ -536,12 +1369,45 @@ pub fn patch(input_yaml: &str, config: &PatchConfig<'_>) -> error::Result<String
     }
     cleanup::enrich_inline_request_body_examples(&mut doc);
     cleanup::remove_empty_inlined_request_bodies(&mut doc);
+    cleanup::set_form_urlencoded_request_bodies(&mut doc, &config.form_urlencoded_endpoints);
+    cleanup::pin_operation_examples(&mut doc, &examples);
+    if config.body_level_examples {
+        cleanup::compose_body_examples(&mut doc);
+    }
     cleanup::remove_orphaned_schemas(&mut doc);
 
-    // Phase 12: Final normalization
+    // Phase 12: Vendor extensions + final normalization
+    //
+    // Code samples render the final path templates, so they run after UUID
+    // path flattening (phase 8); normalization then covers their sources too.
+    extensions::inject_code_samples(&mut doc, &code_samples);
+    extensions::inject_badges(&mut doc, &badges);
+    if config.transforms.annotate_pagination {
+        extensions::annotate_pagination(&mut doc, &config.metadata.paginated_responses);
+    }
+    if config.transforms.annotate_proto_source {
+        extensions::annotate_proto_source(&mut doc, &config.metadata.method_sources);
+    }
+    if config.tag_groups {
+        extensions::inject_tag_groups(&mut doc, &config.tag_group_patterns);
+    }
     if config.transforms.normalize_line_endings {
         oas31::normalize_line_endings(&mut doc);
     }
+    if let Some(mode) = config.sanitize_schema_names {
+        cleanup::sanitize_schema_names(&mut doc, mode)?;
+    }
+
+    if config.strict_refs {
+        let refs = helpers::collect_unresolved_refs(&doc);
+        if !refs.is_empty() {
+            return Err(error::Error::UnresolvedRefs { refs });
+        }
+    }
+    if config.validate_output {
+        verify::validate_output(&doc)?;
+    }
 
-    serde_yaml_ng::to_string(&doc).map_err(error::Error::from)
+    let yaml = serde_yaml_ng::to_string(&doc)?;
+    Ok((yaml, report))
 }
@@ -61,8 +61,11 @@ pub fn convert_nullable(value: &mut Value) {
 /// Inject `servers` block and enrich `info` with contact, license, and external docs.
 ///
 /// If no servers are configured, a default `http://localhost:8080` entry is added.
-/// Info overrides are merged into the existing `info` block without replacing
-/// fields already present (e.g., `title` and `version` from gnostic).
+/// Info overrides are merged into the existing `info` block field by field:
+/// only keys the overrides set are written, so anything gnostic emitted and
+/// the overrides leave unset (e.g., an existing `license`) is kept. `contact`
+/// and `license` merge per sub-field the same way. `version` is replaced
+/// only when [`InfoOverrides::resolved_version`] yields one.
 pub fn inject_servers_and_info(doc: &mut Value, servers: &[ServerEntry], info: &InfoOverrides) {
     let Some(root) = doc.as_mapping_mut() else {
         return;
@@ -83,6 +86,24 @@ pub fn inject_servers_and_info(doc: &mut Value, servers: &[ServerEntry], info: &
                 if let Some(desc) = &s.description {
                     entry.insert(val_s("description"), val_s(desc));
                 }
+                if !s.variables.is_empty() {
+                    let mut vars = serde_yaml_ng::Mapping::new();
+                    for (name, var) in &s.variables {
+                        let mut v = serde_yaml_ng::Mapping::new();
+                        if !var.enum_values.is_empty() {
+                            v.insert(
+                                val_s("enum"),
+                                Value::Sequence(var.enum_values.iter().map(|e| val_s(e)).collect()),
+                            );
+                        }
+                        v.insert(val_s("default"), val_s(&var.default));
+                        if let Some(desc) = &var.description {
+                            v.insert(val_s("description"), val_s(desc));
+                        }
+                        vars.insert(val_s(name), Value::Mapping(v));
+                    }
+                    entry.insert(val_s("variables"), Value::Mapping(vars));
+                }
                 Value::Mapping(entry)
             })
             .collect()
@@ -97,12 +118,24 @@ pub fn inject_servers_and_info(doc: &mut Value, servers: &[ServerEntry], info: &
         return;
     };
 
+    if let Some(title) = &info.title {
+        info_map.insert(val_s("title"), val_s(title));
+    }
+
+    if let Some(description) = &info.description {
+        info_map.insert(val_s("description"), val_s(description));
+    }
+
+    if let Some(version) = info.resolved_version() {
+        info_map.insert(val_s("version"), val_s(&version));
+    }
+
     if let Some(tos) = &info.terms_of_service {
         info_map.insert(val_s("termsOfService"), val_s(tos));
     }
 
     if let Some(contact) = &info.contact {
-        let mut c = serde_yaml_ng::Mapping::new();
+        let c = sub_mapping(info_map, "contact");
         if let Some(name) = &contact.name {
             c.insert(val_s("name"), val_s(name));
         }
@@ -112,16 +145,14 @@ pub fn inject_servers_and_info(doc: &mut Value, servers: &[ServerEntry], info: &
         if let Some(url) = &contact.url {
             c.insert(val_s("url"), val_s(url));
         }
-        info_map.insert(val_s("contact"), Value::Mapping(c));
     }
 
     if let Some(license) = &info.license {
-        let mut l = serde_yaml_ng::Mapping::new();
+        let l = sub_mapping(info_map, "license");
         l.insert(val_s("name"), val_s(&license.name));
         if let Some(url) = &license.url {
             l.insert(val_s("url"), val_s(url));
         }
-        info_map.insert(val_s("license"), Value::Mapping(l));
     }
 
     if let Some(ext) = &info.external_docs {
@@ -134,13 +165,27 @@ pub fn inject_servers_and_info(doc: &mut Value, servers: &[ServerEntry], info: &
     }
 }
 
+/// The mapping under `key`, created (or replacing a non-mapping value) if needed.
+fn sub_mapping<'a>(
+    map: &'a mut serde_yaml_ng::Mapping,
+    key: &str,
+) -> &'a mut serde_yaml_ng::Mapping {
+    let entry = map
+        .entry(val_s(key))
+        .or_insert_with(|| Value::Mapping(serde_yaml_ng::Mapping::new()));
+    if !entry.is_mapping() {
+        *entry = Value::Mapping(serde_yaml_ng::Mapping::new());
+    }
+    entry
+        .as_mapping_mut()
+        .expect("entry was just made a mapping")
+}
+
 /// Normalize CRLF → LF in all string values within the YAML document.
 pub fn normalize_line_endings(value: &mut Value) {
     match value {
-        Value::String(s) => {
-            if s.contains("\r\n") {
-                *s = s.replace("\r\n", "\n");
-            }
+        Value::String(s) if s.contains("\r\n") => {
+            *s = s.replace("\r\n", "\n");
         }
         Value::Mapping(map) => {
             for (_, v) in map.iter_mut() {
@@ -158,6 +203,8 @@ pub fn normalize_line_endings(value: &mut Value) {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+
     use super::*;
 
     #[test]
@@ -228,13 +275,17 @@ mod tests {
             ServerEntry {
                 url: "https://api.example.com".to_string(),
                 description: Some("Production".to_string()),
+                variables: BTreeMap::new(),
             },
             ServerEntry {
                 url: "http://localhost:8080".to_string(),
                 description: Some("Development".to_string()),
+                variables: BTreeMap::new(),
             },
         ];
         let info = InfoOverrides {
+            title: None,
+            description: None,
             contact: Some(ContactInfo {
                 name: Some("API Team".to_string()),
                 email: Some("api@example.com".to_string()),
@@ -246,6 +297,8 @@ mod tests {
             }),
             external_docs: None,
             terms_of_service: Some("https://example.com/tos".to_string()),
+            version_env: None,
+            version: None,
         };
 
         inject_servers_and_info(&mut doc, &servers, &info);
@@ -265,4 +318,48 @@ mod tests {
         // title should be preserved
         assert_eq!(info_map.get("title").unwrap().as_str().unwrap(), "Test");
     }
+
+    #[test]
+    fn info_overrides_merge_into_existing_block() {
+        use crate::config::ContactInfo;
+
+        let mut doc: Value = serde_yaml_ng::from_str(
+            r"
+info:
+  title: Generated
+  version: 0.0.1
+  contact:
+    name: Platform Team
+    url: https://example.com/team
+  license:
+    name: Apache 2.0
+",
+        )
+        .unwrap();
+        let info = InfoOverrides {
+            title: Some("Users API".to_string()),
+            contact: Some(ContactInfo {
+                name: None,
+                email: Some("api@example.com".to_string()),
+                url: None,
+            }),
+            ..InfoOverrides::default()
+        };
+
+        inject_servers_and_info(&mut doc, &[], &info);
+
+        let info_map = &doc["info"];
+        assert_eq!(info_map["title"].as_str(), Some("Users API"));
+        assert_eq!(info_map["version"].as_str(), Some("0.0.1"));
+        assert_eq!(info_map["license"]["name"].as_str(), Some("Apache 2.0"));
+        assert_eq!(info_map["contact"]["name"].as_str(), Some("Platform Team"));
+        assert_eq!(
+            info_map["contact"]["url"].as_str(),
+            Some("https://example.com/team")
+        );
+        assert_eq!(
+            info_map["contact"]["email"].as_str(),
+            Some("api@example.com")
+        );
+    }
 }
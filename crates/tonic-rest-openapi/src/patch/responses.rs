@@ -5,11 +5,16 @@
 //! - Plain text content types (configurable endpoints)
 //! - Redirect endpoints → 302
 //! - REST error schema injection
+//! - `default` error responses → explicit status codes (opt-in)
 //! - Readiness probe 503
+//! - Liveness probe reduced to a plain `200`
+//! - `Link` pagination header on list responses (opt-in)
+//! - `X-RateLimit-*` headers and `429` responses (opt-in)
 
 use serde_yaml_ng::Value;
 
 use crate::config::PlainTextEndpoint;
+use crate::discover::PaginatedResponse;
 
 use super::helpers::{
     for_each_operation, json_content_with_schema_ref, json_response_with_schema_ref,
@@ -132,7 +137,14 @@ pub fn patch_plain_text_endpoints(doc: &mut Value, endpoints: &[PlainTextEndpoin
     });
 }
 
-/// Add response headers for the metrics endpoint.
+/// Prometheus text exposition media type, used as the metrics content key.
+const PROMETHEUS_MEDIA_TYPE: &str = "text/plain; version=0.0.4";
+
+/// Document the metrics endpoint as Prometheus text exposition.
+///
+/// The `200` body becomes [`PROMETHEUS_MEDIA_TYPE`] with a string schema
+/// (keeping any existing `text/plain` example), and `Content-Type` /
+/// `Cache-Control` response headers are added.
 ///
 /// Skipped if `metrics_path` is `None`.
 pub fn patch_metrics_response_headers(doc: &mut Value, metrics_path: Option<&str>) {
@@ -154,6 +166,26 @@ pub fn patch_metrics_response_headers(doc: &mut Value, metrics_path: Option<&str
             return;
         };
 
+        let example = response_200
+            .get("content")
+            .and_then(Value::as_mapping)
+            .and_then(|c| {
+                c.iter()
+                    .find(|(k, _)| k.as_str().is_some_and(|k| k.starts_with("text/plain")))
+            })
+            .and_then(|(_, m)| m.get("example"))
+            .cloned();
+        let mut schema = serde_yaml_ng::Mapping::new();
+        schema.insert(val_s("type"), val_s("string"));
+        let mut media_type = serde_yaml_ng::Mapping::new();
+        media_type.insert(val_s("schema"), Value::Mapping(schema));
+        if let Some(example) = example {
+            media_type.insert(val_s("example"), example);
+        }
+        let mut content = serde_yaml_ng::Mapping::new();
+        content.insert(val_s(PROMETHEUS_MEDIA_TYPE), Value::Mapping(media_type));
+        response_200.insert(val_s("content"), Value::Mapping(content));
+
         if !response_200.contains_key("headers") {
             response_200.insert(
                 val_s("headers"),
@@ -185,6 +217,63 @@ pub fn patch_metrics_response_headers(doc: &mut Value, metrics_path: Option<&str
     });
 }
 
+/// Description of the documented `Link` pagination header.
+const LINK_HEADER_DESCRIPTION: &str = "Pagination links (RFC 8288). Carries the URL of the \
+     next page as `rel=\"next\"` while more results remain.";
+
+/// Document a `Link` header on the `200` response of paginated list operations.
+///
+/// An operation qualifies when its `200` JSON response references one of the
+/// `paginated` response schemas. An existing `Link` header is kept.
+pub fn annotate_pagination_headers(doc: &mut Value, paginated: &[PaginatedResponse]) {
+    if paginated.is_empty() {
+        return;
+    }
+    let list_refs: Vec<String> = paginated
+        .iter()
+        .map(|page| format!("#/components/schemas/{}", page.schema))
+        .collect();
+
+    for_each_operation(doc, |_path, _method, op_map| {
+        let Some(response_200) = op_map
+            .get_mut("responses")
+            .and_then(Value::as_mapping_mut)
+            .and_then(|r| r.get_mut("200"))
+            .and_then(Value::as_mapping_mut)
+        else {
+            return;
+        };
+
+        let is_list = response_200
+            .get("content")
+            .and_then(|c| c.get("application/json"))
+            .and_then(|m| m.get("schema"))
+            .and_then(|s| s.get("$ref"))
+            .and_then(Value::as_str)
+            .is_some_and(|r| list_refs.iter().any(|l| l == r));
+        if !is_list {
+            return;
+        }
+
+        let headers = response_200
+            .entry(val_s("headers"))
+            .or_insert_with(|| Value::Mapping(serde_yaml_ng::Mapping::new()));
+        let Some(headers) = headers.as_mapping_mut() else {
+            return;
+        };
+        if headers.contains_key("Link") {
+            return;
+        }
+
+        let mut schema = serde_yaml_ng::Mapping::new();
+        schema.insert(val_s("type"), val_s("string"));
+        let mut header = serde_yaml_ng::Mapping::new();
+        header.insert(val_s("description"), val_s(LINK_HEADER_DESCRIPTION));
+        header.insert(val_s("schema"), Value::Mapping(schema));
+        headers.insert(val_s("Link"), Value::Mapping(header));
+    });
+}
+
 /// Add 503 response to readiness probe.
 ///
 /// Skipped if `readiness_path` is `None`.
@@ -229,6 +318,51 @@ pub fn patch_readiness_probe_responses(doc: &mut Value, readiness_path: Option<&
     });
 }
 
+/// Reduce the liveness probe to a single `200 OK` `text/plain` response.
+///
+/// Unlike readiness, a liveness probe either answers or the process is
+/// restarted, so every other response — the `default`, explicit error
+/// codes, `429` — is dropped. An existing `text/plain` example is kept.
+/// Runs after the error-response transforms so their additions are removed.
+///
+/// Skipped if `liveness_path` is `None`.
+pub fn patch_liveness_probe_responses(doc: &mut Value, liveness_path: Option<&str>) {
+    let Some(liveness_path) = liveness_path else {
+        return;
+    };
+
+    for_each_operation(doc, |path, method, op_map| {
+        if path != liveness_path || method != "get" {
+            return;
+        }
+
+        let example = op_map
+            .get("responses")
+            .and_then(|r| r.get("200"))
+            .and_then(|r| r.get("content"))
+            .and_then(|c| c.get("text/plain"))
+            .and_then(|m| m.get("example"))
+            .cloned();
+
+        let mut schema = serde_yaml_ng::Mapping::new();
+        schema.insert(val_s("type"), val_s("string"));
+        let mut media_type = serde_yaml_ng::Mapping::new();
+        media_type.insert(val_s("schema"), Value::Mapping(schema));
+        if let Some(example) = example {
+            media_type.insert(val_s("example"), example);
+        }
+        let mut content = serde_yaml_ng::Mapping::new();
+        content.insert(val_s("text/plain"), Value::Mapping(media_type));
+        let mut ok = serde_yaml_ng::Mapping::new();
+        ok.insert(val_s("description"), val_s("OK"));
+        ok.insert(val_s("content"), Value::Mapping(content));
+
+        let mut responses = serde_yaml_ng::Mapping::new();
+        responses.insert(val_s("200"), Value::Mapping(ok));
+        op_map.insert(val_s("responses"), Value::Mapping(responses));
+    });
+}
+
 /// Patch redirect endpoints: convert `200` to `302` with `Location` header.
 pub fn patch_redirect_endpoints(doc: &mut Value, redirect_paths: &[String]) {
     let Some(paths) = doc
@@ -346,51 +480,202 @@ description: REST error response envelope.
     schemas.insert(val_s(schema_name), schema);
 }
 
-/// Rewrite operation `default` responses to use the REST error schema.
-pub fn rewrite_default_error_responses(doc: &mut Value, error_schema_ref: &str) {
+/// Rewrite operation error responses to use the REST error schema.
+///
+/// Covers the `default` response plus any of `codes` the operation declares;
+/// numeric codes match both quoted (`'404'`) and bare (`404`) response keys.
+pub fn rewrite_error_responses(doc: &mut Value, error_schema_ref: &str, codes: &[u16]) {
     for_each_operation(doc, |_path, _method, op_map| {
-        let Some(default_response) = op_map
-            .get_mut("responses")
-            .and_then(Value::as_mapping_mut)
-            .and_then(|r| r.get_mut("default"))
-            .and_then(Value::as_mapping_mut)
-        else {
+        let Some(responses) = op_map.get_mut("responses").and_then(Value::as_mapping_mut) else {
             return;
         };
 
-        if !default_response.contains_key("description") {
-            default_response.insert(val_s("description"), val_s("Default error response"));
+        for (key, response) in responses.iter_mut() {
+            let fallback_description = if key.as_str() == Some("default") {
+                "Default error response"
+            } else if response_code(key).is_some_and(|c| codes.contains(&c)) {
+                "Error response"
+            } else {
+                continue;
+            };
+            let Some(response) = response.as_mapping_mut() else {
+                continue;
+            };
+
+            if !response.contains_key("description") {
+                response.insert(val_s("description"), val_s(fallback_description));
+            }
+
+            response.insert(
+                val_s("content"),
+                json_content_with_schema_ref(error_schema_ref),
+            );
         }
+    });
+}
 
-        default_response.insert(
-            val_s("content"),
-            json_content_with_schema_ref(error_schema_ref),
-        );
+/// Error codes used by [`expand_default_error_responses`] when none are configured.
+const DEFAULT_EXPLICIT_ERROR_CODES: &[u16] = &[400, 401, 403, 404, 500];
+
+/// Replace each operation's `default` response with explicit error entries.
+///
+/// Adds one response per code in `codes` (or [`DEFAULT_EXPLICIT_ERROR_CODES`]
+/// when empty) referencing the error schema, skipping codes the operation
+/// already declares, then drops `default`. Operations without a `default`
+/// response are left alone.
+pub fn expand_default_error_responses(doc: &mut Value, error_schema_ref: &str, codes: &[u16]) {
+    let codes = if codes.is_empty() {
+        DEFAULT_EXPLICIT_ERROR_CODES
+    } else {
+        codes
+    };
+
+    for_each_operation(doc, |_path, _method, op_map| {
+        let Some(responses) = op_map.get_mut("responses").and_then(Value::as_mapping_mut) else {
+            return;
+        };
+        if responses.remove("default").is_none() {
+            return;
+        }
+
+        for &code in codes {
+            if responses.keys().any(|key| response_code(key) == Some(code)) {
+                continue;
+            }
+            responses.insert(
+                val_s(&code.to_string()),
+                json_response_with_schema_ref(error_description(code), error_schema_ref),
+            );
+        }
     });
 }
 
+/// Description for an expanded error response.
+fn error_description(code: u16) -> &'static str {
+    match code {
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        409 => "Conflict",
+        429 => "Too Many Requests",
+        500 => "Internal Server Error",
+        501 => "Not Implemented",
+        503 => "Service Unavailable",
+        504 => "Gateway Timeout",
+        _ => "Error response",
+    }
+}
+
+/// Rate-limit headers documented by [`add_rate_limit_headers`].
+const RATE_LIMIT_HEADERS: &[(&str, &str)] = &[
+    (
+        "X-RateLimit-Limit",
+        "Requests allowed in the current rate-limit window.",
+    ),
+    (
+        "X-RateLimit-Remaining",
+        "Requests left in the current rate-limit window.",
+    ),
+    (
+        "X-RateLimit-Reset",
+        "Seconds until the current rate-limit window resets.",
+    ),
+];
+
+/// Document rate-limit headers on every operation's `2xx` and `429` responses.
+///
+/// Operations without a `429` response get one referencing the error schema.
+/// Headers already declared on a response are left untouched.
+pub fn add_rate_limit_headers(doc: &mut Value, error_schema_ref: &str) {
+    for_each_operation(doc, |_path, _method, op_map| {
+        let Some(responses) = op_map.get_mut("responses").and_then(Value::as_mapping_mut) else {
+            return;
+        };
+
+        if !responses.keys().any(|key| response_code(key) == Some(429)) {
+            responses.insert(
+                val_s("429"),
+                json_response_with_schema_ref("Too Many Requests", error_schema_ref),
+            );
+        }
+
+        for (key, response) in responses.iter_mut() {
+            if !response_code(key).is_some_and(|c| (200..300).contains(&c) || c == 429) {
+                continue;
+            }
+            let Some(response) = response.as_mapping_mut() else {
+                continue;
+            };
+            let Some(headers) = response
+                .entry(val_s("headers"))
+                .or_insert_with(|| Value::Mapping(serde_yaml_ng::Mapping::new()))
+                .as_mapping_mut()
+            else {
+                continue;
+            };
+            for (name, description) in RATE_LIMIT_HEADERS {
+                if !headers.contains_key(*name) {
+                    headers.insert(val_s(name), integer_header(description));
+                }
+            }
+        }
+    });
+}
+
+/// Build an integer-valued response header.
+fn integer_header(description: &str) -> Value {
+    let mut schema = serde_yaml_ng::Mapping::new();
+    schema.insert(val_s("type"), val_s("integer"));
+
+    let mut header = serde_yaml_ng::Mapping::new();
+    header.insert(val_s("description"), val_s(description));
+    header.insert(val_s("schema"), Value::Mapping(schema));
+    Value::Mapping(header)
+}
+
+/// Parse a response key (`'404'` or `404`) as an HTTP status code.
+fn response_code(key: &Value) -> Option<u16> {
+    match key {
+        Value::String(s) => s.parse().ok(),
+        Value::Number(n) => n.as_u64().and_then(|c| u16::try_from(c).ok()),
+        _ => None,
+    }
+}
+
 /// Rewrite `200 OK` to `201 Created` for resource-creation endpoints.
 ///
 /// Detection is convention-based: `POST` operations whose `operationId`
 /// contains `Create`, `SignUp`, or `Register` (case-sensitive prefix match
-/// after the service name separator `_`).
-pub fn rewrite_create_responses(doc: &mut Value) {
+/// after the service name separator `_`). Operation IDs in `created_ops` are
+/// rewritten regardless of name or method; those in `not_created_ops` never
+/// are, which wins over both the heuristic and `created_ops`.
+///
+/// With `annotate`, each rewritten operation gets `x-rewritten-from: "200"`.
+pub fn rewrite_create_responses(
+    doc: &mut Value,
+    created_ops: &[String],
+    not_created_ops: &[String],
+    annotate: bool,
+) {
     for_each_operation(doc, |_path, method, op_map| {
-        if method != "post" {
-            return;
-        }
-
         let op_id = op_map
             .get("operationId")
             .and_then(Value::as_str)
             .unwrap_or_default();
 
+        if not_created_ops.iter().any(|id| id == op_id) {
+            return;
+        }
+
         // Extract method part after "ServiceName_"
         let method_part = op_id.split('_').nth(1).unwrap_or(op_id);
 
-        let is_create = method_part.starts_with("Create")
-            || method_part.starts_with("SignUp")
-            || method_part.starts_with("Register");
+        let is_create = created_ops.iter().any(|id| id == op_id)
+            || (method == "post"
+                && (method_part.starts_with("Create")
+                    || method_part.starts_with("SignUp")
+                    || method_part.starts_with("Register")));
 
         if !is_create {
             return;
@@ -407,6 +692,9 @@ pub fn rewrite_create_responses(doc: &mut Value) {
                 resp_map.insert(val_s("description"), val_s("Created"));
             }
             responses.insert(Value::String("201".to_string()), ok_response);
+            if annotate {
+                op_map.insert(val_s("x-rewritten-from"), val_s("200"));
+            }
         }
     });
 }
@@ -523,6 +811,83 @@ paths:
         assert!(schema.as_mapping().is_some());
     }
 
+    #[test]
+    fn metrics_response_documented_as_prometheus_text() {
+        let yaml = r"
+paths:
+  /metrics:
+    get:
+      operationId: MetricsService_Scrape
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/MetricsResponse'
+";
+        let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        patch_metrics_response_headers(&mut doc, Some("/metrics"));
+
+        let ok = &doc["paths"]["/metrics"]["get"]["responses"]["200"];
+        let content = ok["content"].as_mapping().unwrap();
+        assert_eq!(content.len(), 1);
+        let (media_type, body) = content.iter().next().unwrap();
+        let media_type = media_type.as_str().unwrap();
+        assert!(media_type.starts_with("text/plain"));
+        assert!(media_type.contains("version=0.0.4"));
+        assert_eq!(body["schema"]["type"].as_str(), Some("string"));
+        assert!(ok["headers"]["Content-Type"].is_mapping());
+    }
+
+    #[test]
+    fn liveness_probe_keeps_only_plain_200() {
+        let yaml = r"
+paths:
+  /health/live:
+    get:
+      operationId: HealthService_Live
+      responses:
+        '200':
+          description: OK
+          content:
+            text/plain:
+              schema:
+                type: string
+              example: OK
+        '503':
+          description: Service Unavailable
+        default:
+          description: Default error response
+  /health/ready:
+    get:
+      operationId: HealthService_Ready
+      responses:
+        '200':
+          description: OK
+        default:
+          description: Default error response
+";
+        let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        patch_liveness_probe_responses(&mut doc, Some("/health/live"));
+
+        let responses = doc["paths"]["/health/live"]["get"]["responses"]
+            .as_mapping()
+            .unwrap();
+        assert_eq!(responses.len(), 1);
+        assert!(!responses.contains_key("503"));
+        assert!(!responses.contains_key("default"));
+        let plain = &responses["200"]["content"]["text/plain"];
+        assert_eq!(plain["schema"]["type"].as_str(), Some("string"));
+        assert_eq!(plain["example"].as_str(), Some("OK"));
+
+        // Other paths are untouched
+        let ready = doc["paths"]["/health/ready"]["get"]["responses"]
+            .as_mapping()
+            .unwrap();
+        assert!(ready.contains_key("default"));
+    }
+
     #[test]
     fn create_response_rewritten_to_201() {
         let yaml = r"
@@ -539,7 +904,7 @@ paths:
                 $ref: '#/components/schemas/User'
 ";
         let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
-        rewrite_create_responses(&mut doc);
+        rewrite_create_responses(&mut doc, &[], &[], false);
 
         let responses = doc["paths"]["/v1/users"]["post"]["responses"]
             .as_mapping()
@@ -561,7 +926,7 @@ paths:
           description: OK
 ";
         let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
-        rewrite_create_responses(&mut doc);
+        rewrite_create_responses(&mut doc, &[], &[], false);
 
         let responses = doc["paths"]["/v1/auth/signup"]["post"]["responses"]
             .as_mapping()
@@ -581,7 +946,7 @@ paths:
           description: OK
 ";
         let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
-        rewrite_create_responses(&mut doc);
+        rewrite_create_responses(&mut doc, &[], &[], false);
 
         let responses = doc["paths"]["/v1/auth"]["post"]["responses"]
             .as_mapping()
@@ -590,6 +955,83 @@ paths:
         assert!(!responses.contains_key("201"));
     }
 
+    #[test]
+    fn excluded_create_keeps_200() {
+        let yaml = r"
+paths:
+  /v1/settings:
+    post:
+      operationId: SettingsService_CreateOrUpdateSettings
+      responses:
+        '200':
+          description: OK
+";
+        let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        rewrite_create_responses(
+            &mut doc,
+            &[],
+            &["SettingsService_CreateOrUpdateSettings".to_string()],
+            false,
+        );
+
+        let responses = doc["paths"]["/v1/settings"]["post"]["responses"]
+            .as_mapping()
+            .unwrap();
+        assert!(responses.contains_key("200"));
+        assert!(!responses.contains_key("201"));
+    }
+
+    #[test]
+    fn included_method_rewritten_without_heuristic_match() {
+        let yaml = r"
+paths:
+  /v1/users:import:
+    post:
+      operationId: UserService_ImportUsers
+      responses:
+        '200':
+          description: OK
+";
+        let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        rewrite_create_responses(
+            &mut doc,
+            &["UserService_ImportUsers".to_string()],
+            &[],
+            false,
+        );
+
+        let responses = doc["paths"]["/v1/users:import"]["post"]["responses"]
+            .as_mapping()
+            .unwrap();
+        assert!(responses.contains_key("201"));
+    }
+
+    #[test]
+    fn annotated_rewrite_marks_only_changed_operations() {
+        let yaml = r"
+paths:
+  /v1/users:
+    post:
+      operationId: UserService_CreateUser
+      responses:
+        '200':
+          description: OK
+  /v1/auth:
+    post:
+      operationId: AuthService_Authenticate
+      responses:
+        '200':
+          description: OK
+";
+        let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        rewrite_create_responses(&mut doc, &[], &[], true);
+
+        let create = &doc["paths"]["/v1/users"]["post"];
+        assert_eq!(create["x-rewritten-from"].as_str(), Some("200"));
+        let auth = doc["paths"]["/v1/auth"]["post"].as_mapping().unwrap();
+        assert!(!auth.contains_key("x-rewritten-from"));
+    }
+
     #[test]
     fn put_with_create_keeps_200() {
         let yaml = r"
@@ -602,7 +1044,7 @@ paths:
           description: OK
 ";
         let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
-        rewrite_create_responses(&mut doc);
+        rewrite_create_responses(&mut doc, &[], &[], false);
 
         let responses = doc["paths"]["/v1/items/{id}"]["put"]["responses"]
             .as_mapping()
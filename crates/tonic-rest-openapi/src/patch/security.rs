@@ -1,23 +1,38 @@
 //! Security scheme transforms.
 //!
-//! Adds Bearer JWT security scheme and per-operation overrides
-//! for public (no-auth) endpoints.
+//! Adds Bearer JWT and configured `apiKey` / `oauth2` security schemes, and
+//! per-operation overrides for public (no-auth) endpoints and operations
+//! requiring named schemes.
+
+use std::collections::{BTreeMap, HashMap};
 
 use serde_yaml_ng::Value;
 
+use crate::config::{OAuthFlow, SecurityScheme};
+
 use super::helpers::{for_each_operation, val_s};
 
 /// Add `securitySchemes` and per-operation `security` requirements.
 ///
 /// Sets Bearer JWT as the global default, then overrides public endpoints
-/// with empty security (`security: []`).
+/// with empty security (`security: []`). Operations listed in
+/// `operation_security` (keyed by operation ID) get one requirement per named
+/// scheme instead of the default, so any of the listed schemes is accepted.
+/// Public endpoints take precedence over `operation_security`.
+///
+/// `security_schemes` are registered under `components.securitySchemes` next to
+/// `bearerAuth`. Other named schemes are referenced as-is; their definitions
+/// must already exist in the input spec (e.g., a `mutualTLS` scheme).
 ///
 /// Merges into existing `securitySchemes` rather than replacing, so
-/// user-defined schemes (e.g., `apiKey`, `oauth2`) are preserved.
+/// user-defined schemes are preserved unless `security_schemes` redefines
+/// them.
 pub fn add_security_schemes(
     doc: &mut Value,
     public_ops: &[String],
+    operation_security: &HashMap<String, Vec<String>>,
     bearer_description: Option<&str>,
+    security_schemes: &BTreeMap<String, SecurityScheme>,
 ) {
     let description = bearer_description.unwrap_or("Bearer authentication token");
 
@@ -43,6 +58,9 @@ pub fn add_security_schemes(
 
         if let Some(schemes_map) = schemes.as_mapping_mut() {
             schemes_map.insert(val_s("bearerAuth"), Value::Mapping(bearer_scheme));
+            for (name, scheme) in security_schemes {
+                schemes_map.insert(val_s(name), scheme_value(scheme));
+            }
         }
     }
 
@@ -73,7 +91,8 @@ pub fn add_security_schemes(
         }
     }
 
-    // Override public operations with empty security
+    // Override public operations with empty security, and operations with
+    // dedicated schemes with their own requirements
     for_each_operation(doc, |_path, _method, op_map| {
         let op_id = op_map
             .get(Value::String("operationId".to_string()))
@@ -85,10 +104,78 @@ pub fn add_security_schemes(
                 Value::String("security".to_string()),
                 Value::Sequence(vec![]),
             );
+        } else if let Some(schemes) = operation_security.get(op_id) {
+            let requirements = schemes
+                .iter()
+                .map(|scheme| {
+                    let mut requirement = serde_yaml_ng::Mapping::new();
+                    requirement.insert(val_s(scheme), Value::Sequence(vec![]));
+                    Value::Mapping(requirement)
+                })
+                .collect();
+            op_map.insert(val_s("security"), Value::Sequence(requirements));
         }
     });
 }
 
+/// Render a configured scheme as an `OpenAPI` Security Scheme Object.
+fn scheme_value(scheme: &SecurityScheme) -> Value {
+    let mut map = serde_yaml_ng::Mapping::new();
+    let description = match scheme {
+        SecurityScheme::ApiKey {
+            location,
+            name,
+            description,
+        } => {
+            map.insert(val_s("type"), val_s("apiKey"));
+            map.insert(val_s("in"), val_s(location.as_str()));
+            map.insert(val_s("name"), val_s(name));
+            description
+        }
+        SecurityScheme::Oauth2 { flows, description } => {
+            let mut flows_map = serde_yaml_ng::Mapping::new();
+            for (key, flow) in [
+                ("implicit", &flows.implicit),
+                ("password", &flows.password),
+                ("clientCredentials", &flows.client_credentials),
+                ("authorizationCode", &flows.authorization_code),
+            ] {
+                if let Some(flow) = flow {
+                    flows_map.insert(val_s(key), flow_value(flow));
+                }
+            }
+            map.insert(val_s("type"), val_s("oauth2"));
+            map.insert(val_s("flows"), Value::Mapping(flows_map));
+            description
+        }
+    };
+    if let Some(description) = description {
+        map.insert(val_s("description"), val_s(description));
+    }
+    Value::Mapping(map)
+}
+
+/// Render one `OAuth2` flow; `scopes` is always present, as the spec requires.
+fn flow_value(flow: &OAuthFlow) -> Value {
+    let mut map = serde_yaml_ng::Mapping::new();
+    for (key, url) in [
+        ("authorizationUrl", &flow.authorization_url),
+        ("tokenUrl", &flow.token_url),
+        ("refreshUrl", &flow.refresh_url),
+    ] {
+        if let Some(url) = url {
+            map.insert(val_s(key), val_s(url));
+        }
+    }
+    let scopes = flow
+        .scopes
+        .iter()
+        .map(|(scope, description)| (val_s(scope), val_s(description)))
+        .collect();
+    map.insert(val_s("scopes"), Value::Mapping(scopes));
+    Value::Mapping(map)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -104,7 +191,13 @@ paths:
       operationId: AuthService_Authenticate
 ";
         let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
-        add_security_schemes(&mut doc, &["AuthService_Authenticate".to_string()], None);
+        add_security_schemes(
+            &mut doc,
+            &["AuthService_Authenticate".to_string()],
+            &HashMap::new(),
+            None,
+            &BTreeMap::new(),
+        );
 
         // Global security should be set
         assert!(doc["security"].as_sequence().is_some());
@@ -135,7 +228,7 @@ components:
 paths: {}
 ";
         let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
-        add_security_schemes(&mut doc, &[], None);
+        add_security_schemes(&mut doc, &[], &HashMap::new(), None, &BTreeMap::new());
 
         let schemes = doc["components"]["securitySchemes"].as_mapping().unwrap();
 
@@ -160,7 +253,7 @@ security:
 paths: {}
 ";
         let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
-        add_security_schemes(&mut doc, &[], None);
+        add_security_schemes(&mut doc, &[], &HashMap::new(), None, &BTreeMap::new());
 
         let security = doc["security"].as_sequence().unwrap();
         assert_eq!(security.len(), 2, "should have both apiKey and bearerAuth");
@@ -187,7 +280,13 @@ paths: {}
 
         // These characters would break YAML string interpolation
         let tricky_description = "Use: colons\nnewlines # comments {braces}";
-        add_security_schemes(&mut doc, &[], Some(tricky_description));
+        add_security_schemes(
+            &mut doc,
+            &[],
+            &HashMap::new(),
+            Some(tricky_description),
+            &BTreeMap::new(),
+        );
 
         let desc = doc["components"]["securitySchemes"]["bearerAuth"]["description"]
             .as_str()
@@ -204,8 +303,8 @@ paths: {}
 ";
         let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
 
-        add_security_schemes(&mut doc, &[], None);
-        add_security_schemes(&mut doc, &[], None);
+        add_security_schemes(&mut doc, &[], &HashMap::new(), None, &BTreeMap::new());
+        add_security_schemes(&mut doc, &[], &HashMap::new(), None, &BTreeMap::new());
 
         let security = doc["security"].as_sequence().unwrap();
         let bearer_count = security
@@ -217,4 +316,147 @@ paths: {}
             .count();
         assert_eq!(bearer_count, 1, "bearerAuth should not be duplicated");
     }
+
+    #[test]
+    fn operation_security_overrides_default_bearer() {
+        let yaml = r"
+components:
+  schemas: {}
+  securitySchemes:
+    mtls:
+      type: mutualTLS
+paths:
+  /v1/admin/keys:
+    post:
+      operationId: AdminService_RotateKeys
+  /v1/users:
+    get:
+      operationId: UserService_ListUsers
+  /v1/auth/login:
+    post:
+      operationId: AuthService_Authenticate
+";
+        let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        let operation_security = HashMap::from([
+            (
+                "AdminService_RotateKeys".to_string(),
+                vec!["mtls".to_string()],
+            ),
+            (
+                "AuthService_Authenticate".to_string(),
+                vec!["mtls".to_string()],
+            ),
+        ]);
+        add_security_schemes(
+            &mut doc,
+            &["AuthService_Authenticate".to_string()],
+            &operation_security,
+            None,
+            &BTreeMap::new(),
+        );
+
+        let admin = doc["paths"]["/v1/admin/keys"]["post"]["security"]
+            .as_sequence()
+            .unwrap();
+        assert_eq!(admin.len(), 1);
+        assert!(admin[0].as_mapping().unwrap().contains_key("mtls"));
+        assert!(!admin[0].as_mapping().unwrap().contains_key("bearerAuth"));
+
+        // Other operations inherit the global Bearer requirement
+        assert!(doc["paths"]["/v1/users"]["get"].get("security").is_none());
+
+        // Public wins over operation_security
+        assert!(
+            doc["paths"]["/v1/auth/login"]["post"]["security"]
+                .as_sequence()
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn registers_configured_api_key_scheme() {
+        use crate::config::ApiKeyLocation;
+
+        let yaml = r"
+components:
+  schemas: {}
+paths:
+  /v1/exports:
+    post:
+      operationId: UserService_ExportUsers
+  /v1/users:
+    get:
+      operationId: UserService_ListUsers
+  /v1/auth/login:
+    post:
+      operationId: AuthService_Authenticate
+";
+        let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        let schemes = BTreeMap::from([(
+            "apiKeyAuth".to_string(),
+            SecurityScheme::ApiKey {
+                location: ApiKeyLocation::Header,
+                name: "X-API-Key".to_string(),
+                description: Some("Service API key".to_string()),
+            },
+        )]);
+        let operation_security = HashMap::from([(
+            "UserService_ExportUsers".to_string(),
+            vec!["apiKeyAuth".to_string()],
+        )]);
+        add_security_schemes(
+            &mut doc,
+            &["AuthService_Authenticate".to_string()],
+            &operation_security,
+            None,
+            &schemes,
+        );
+
+        let scheme = &doc["components"]["securitySchemes"]["apiKeyAuth"];
+        assert_eq!(scheme["type"], "apiKey");
+        assert_eq!(scheme["in"], "header");
+        assert_eq!(scheme["name"], "X-API-Key");
+        assert_eq!(scheme["description"], "Service API key");
+        assert!(doc["components"]["securitySchemes"]["bearerAuth"].is_mapping());
+
+        let export = doc["paths"]["/v1/exports"]["post"]["security"]
+            .as_sequence()
+            .unwrap();
+        assert_eq!(export.len(), 1);
+        assert!(export[0].as_mapping().unwrap().contains_key("apiKeyAuth"));
+
+        assert!(doc["paths"]["/v1/users"]["get"].get("security").is_none());
+        assert!(
+            doc["paths"]["/v1/auth/login"]["post"]["security"]
+                .as_sequence()
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn renders_oauth2_flows() {
+        use crate::config::OAuthFlows;
+
+        let scheme = SecurityScheme::Oauth2 {
+            flows: Box::new(OAuthFlows {
+                client_credentials: Some(OAuthFlow {
+                    token_url: Some("https://auth.example.com/token".to_string()),
+                    scopes: BTreeMap::from([("users.read".to_string(), "Read users".to_string())]),
+                    ..OAuthFlow::default()
+                }),
+                ..OAuthFlows::default()
+            }),
+            description: None,
+        };
+        let value = scheme_value(&scheme);
+
+        assert_eq!(value["type"], "oauth2");
+        let flow = &value["flows"]["clientCredentials"];
+        assert_eq!(flow["tokenUrl"], "https://auth.example.com/token");
+        assert_eq!(flow["scopes"]["users.read"], "Read users");
+        assert!(flow.get("authorizationUrl").is_none());
+        assert!(value.get("description").is_none());
+    }
 }
@@ -5,17 +5,33 @@
 //! - Flatten UUID path template variables (remove `.value` suffix)
 //! - Simplify UUID query parameters from dot-notation
 //! - Strip path-bound fields from request body schemas
+//! - Move configured header fields from bodies and query strings to header parameters
 //! - Enrich path parameters with proto constraints
+//! - Inject proto field comments as property descriptions
 //! - Annotate `writeOnly`/`readOnly` fields based on naming conventions
 //! - Annotate `google.protobuf.Duration` fields with format and example
+//! - Restore array typing for proto `repeated` fields
+//! - Type proto `bytes` properties and parameters as base64 strings
+//! - Inline `google.protobuf.*Value` wrappers as nullable scalars
+//! - Rewrite proto `oneof` groups as `oneOf` alternatives
+//! - Render proto `map<K, V>` fields as `additionalProperties` objects
+//! - Bound paginated item arrays with `maxItems` from `page_size` limits
+
+use std::collections::{HashMap, HashSet};
 
 use serde_yaml_ng::Value;
 
-use crate::discover::{PathParamInfo, SchemaConstraints};
+use crate::config::EnumCase;
+use crate::descriptor::field_type;
+use crate::discover::{
+    BytesField, BytesParam, FieldDefault, MapField, OneofInfo, OptionalField, PaginatedResponse,
+    PathParamInfo, RepeatedField, SchemaConstraints, WRAPPER_TYPES, WrapperField,
+    is_repeated_field,
+};
 
 use super::helpers::{
-    UUID_EXAMPLE, UUID_PATTERN, for_each_operation, schemas_mut, snake_to_lower_camel_dotted,
-    val_i64, val_n, val_s,
+    BYTES_EXAMPLE, UUID_EXAMPLE, UUID_PATTERN, collect_refs, for_each_operation, schemas,
+    schemas_mut, snake_to_lower_camel_dotted, val_i64, val_n, val_s,
 };
 
 /// Flatten UUID wrapper references to inline `type: string, format: uuid`.
@@ -263,10 +279,14 @@ fn is_write_only_field(lower: &str) -> bool {
 ///
 /// Additional patterns from `extra_write_only` / `extra_read_only` are matched
 /// as case-insensitive substrings.
+///
+/// `writeOnly` is never set on response schemas, except for the exact
+/// `schema.field` entries in `force_write_only`, which are always marked.
 pub fn annotate_field_access(
     doc: &mut Value,
     extra_write_only: &[String],
     extra_read_only: &[String],
+    force_write_only: &[String],
 ) {
     let Some(schemas) = schemas_mut(doc) else {
         return;
@@ -321,7 +341,13 @@ pub fn annotate_field_access(
             let is_response_schema =
                 name.contains("Response") || name.contains("Reply") || name.contains("Result");
 
-            if is_write_only && !is_response_schema {
+            let is_forced = force_write_only.iter().any(|f| {
+                f.strip_prefix(name.as_str())
+                    .and_then(|rest| rest.strip_prefix('.'))
+                    == Some(prop_name.as_str())
+            });
+
+            if is_forced || (is_write_only && !is_response_schema) {
                 prop.insert(val_s("writeOnly"), Value::Bool(true));
             } else if is_read_only {
                 prop.insert(val_s("readOnly"), Value::Bool(true));
@@ -480,7 +506,15 @@ pub fn inject_validation_constraints(doc: &mut Value, constraints: &[SchemaConst
                 continue;
             };
 
-            if fc.is_numeric {
+            if let Some(format) = fc.is_numeric.then(|| int64_format(fc.proto_type)).flatten() {
+                // proto3 JSON writes 64-bit integers as strings; JSON Schema
+                // has no numeric bounds for strings, so `min`/`max` are only
+                // kept in the metadata, whatever their range.
+                prop.insert(val_s("type"), val_s("string"));
+                prop.insert(val_s("format"), val_s(format));
+                prop.remove("minimum");
+                prop.remove("maximum");
+            } else if fc.is_numeric {
                 prop.insert(val_s("type"), val_s("integer"));
                 prop.remove("format");
 
@@ -503,10 +537,21 @@ pub fn inject_validation_constraints(doc: &mut Value, constraints: &[SchemaConst
                 }
             }
 
+            if let Some(v) = fc.min_items {
+                prop.insert(val_s("minItems"), val_n(v));
+            }
+            if let Some(v) = fc.max_items {
+                prop.insert(val_s("maxItems"), val_n(v));
+            }
+
             if let Some(pattern) = &fc.pattern {
                 prop.insert(val_s("pattern"), val_s(pattern));
             }
 
+            if let Some(format) = &fc.format {
+                prop.insert(val_s("format"), val_s(format));
+            }
+
             if !fc.enum_values.is_empty() {
                 let variants: Vec<Value> = fc.enum_values.iter().map(|s| val_s(s)).collect();
                 prop.insert(val_s("enum"), Value::Sequence(variants));
@@ -526,14 +571,27 @@ pub fn inject_validation_constraints(doc: &mut Value, constraints: &[SchemaConst
     }
 }
 
+/// `format` of a proto 64-bit integer type, which proto3 JSON encodes as a string.
+const fn int64_format(proto_type: i32) -> Option<&'static str> {
+    match proto_type {
+        field_type::INT64 | field_type::SINT64 | field_type::SFIXED64 => Some("int64"),
+        field_type::UINT64 | field_type::FIXED64 => Some("uint64"),
+        _ => None,
+    }
+}
+
 /// Strip path-bound fields from request body schemas.
 ///
 /// Instead of mutating shared component schemas globally (which would break
 /// other operations referencing the same schema), this inlines a modified
 /// copy of the schema into each operation that has path parameters.
 /// Operations without path parameters keep referencing the original schema.
+///
+/// Fields declared `repeated` in proto are never stripped: a path variable can
+/// only bind a single value, so a name collision with a repeated body field
+/// must not drop the array from the body.
 #[expect(clippy::too_many_lines)]
-pub fn strip_path_fields_from_body(doc: &mut Value) {
+pub fn strip_path_fields_from_body(doc: &mut Value, repeated: &[RepeatedField]) {
     // Phase 1: collect operation locations and their path fields + schema refs
     struct StripInfo {
         path: String,
@@ -620,6 +678,15 @@ pub fn strip_path_fields_from_body(doc: &mut Value) {
     for info in &strip_ops {
         let schema_name = info.schema_ref.trim_start_matches("#/components/schemas/");
 
+        let fields_to_remove: Vec<&String> = info
+            .fields_to_remove
+            .iter()
+            .filter(|f| !is_repeated_field(repeated, schema_name, f))
+            .collect();
+        if fields_to_remove.is_empty() {
+            continue;
+        }
+
         // Clone the component schema
         let original_schema = doc
             .as_mapping()
@@ -640,7 +707,7 @@ pub fn strip_path_fields_from_body(doc: &mut Value) {
                 .get_mut("properties")
                 .and_then(Value::as_mapping_mut)
             {
-                for field in &info.fields_to_remove {
+                for field in &fields_to_remove {
                     props.remove(field.as_str());
                 }
             }
@@ -651,7 +718,7 @@ pub fn strip_path_fields_from_body(doc: &mut Value) {
             {
                 required.retain(|v| {
                     v.as_str()
-                        .is_none_or(|s| !info.fields_to_remove.iter().any(|f| f == s))
+                        .is_none_or(|s| !fields_to_remove.iter().any(|f| *f == s))
                 });
             }
 
@@ -686,6 +753,682 @@ pub fn strip_path_fields_from_body(doc: &mut Value) {
     }
 }
 
+/// Move configured request fields into `in: header` parameters.
+///
+/// `fields` are proto field names; each becomes a header named after the
+/// field with `_` replaced by `-` (`client_version` → `client-version`), the
+/// same name the generated handlers read. Matching query parameters are
+/// re-homed in place. A matching body property is removed from the request
+/// body schema; a `$ref` body is inlined first so other operations sharing
+/// the component schema are unaffected.
+pub fn move_header_fields(doc: &mut Value, fields: &[String]) {
+    if fields.is_empty() {
+        return;
+    }
+    let components = schemas(doc).cloned().unwrap_or_default();
+
+    for_each_operation(doc, |_, _, op| {
+        let mut headers = Vec::new();
+
+        for field in fields {
+            let property = snake_to_lower_camel_dotted(field);
+            let header = field.replace('_', "-");
+
+            if let Some(params) = op.get_mut("parameters").and_then(Value::as_sequence_mut) {
+                for param in params.iter_mut().filter_map(Value::as_mapping_mut) {
+                    let in_query = param.get("in").and_then(Value::as_str) == Some("query");
+                    let name_matches = param
+                        .get("name")
+                        .and_then(Value::as_str)
+                        .is_some_and(|n| n == property || n == field.as_str());
+                    if in_query && name_matches {
+                        param.insert(val_s("in"), val_s("header"));
+                        param.insert(val_s("name"), val_s(&header));
+                    }
+                }
+            }
+
+            if let Some((schema, required)) = take_body_property(op, &components, &property) {
+                headers.push(header_param(&header, schema, required));
+            }
+        }
+
+        if headers.is_empty() {
+            return;
+        }
+        let params = op
+            .entry(val_s("parameters"))
+            .or_insert_with(|| Value::Sequence(Vec::new()));
+        if let Some(params) = params.as_sequence_mut() {
+            params.extend(headers);
+        }
+    });
+}
+
+/// Remove `property` from an operation's JSON request body schema.
+///
+/// Returns the property schema and whether it was listed in `required`.
+fn take_body_property(
+    op: &mut serde_yaml_ng::Mapping,
+    components: &serde_yaml_ng::Mapping,
+    property: &str,
+) -> Option<(Value, bool)> {
+    let slot = op
+        .get_mut("requestBody")
+        .and_then(Value::as_mapping_mut)
+        .and_then(|rb| rb.get_mut("content"))
+        .and_then(Value::as_mapping_mut)
+        .and_then(|c| c.get_mut("application/json"))
+        .and_then(Value::as_mapping_mut)
+        .and_then(|mt| mt.get_mut("schema"))?;
+
+    if let Some(name) = slot
+        .get("$ref")
+        .and_then(Value::as_str)
+        .and_then(|r| r.strip_prefix("#/components/schemas/"))
+    {
+        let target = components.get(name)?;
+        target.get("properties")?.get(property)?;
+        *slot = target.clone();
+    }
+
+    let schema = slot.as_mapping_mut()?;
+    let removed = schema
+        .get_mut("properties")
+        .and_then(Value::as_mapping_mut)?
+        .remove(property)?;
+
+    let mut required = false;
+    if let Some(list) = schema.get_mut("required").and_then(Value::as_sequence_mut) {
+        let before = list.len();
+        list.retain(|v| v.as_str() != Some(property));
+        required = list.len() < before;
+        if list.is_empty() {
+            schema.remove("required");
+        }
+    }
+    Some((removed, required))
+}
+
+/// Build an `in: header` parameter, lifting the property's description.
+fn header_param(name: &str, mut schema: Value, required: bool) -> Value {
+    let description = schema
+        .as_mapping_mut()
+        .and_then(|s| s.remove("description"));
+
+    let mut param = serde_yaml_ng::Mapping::new();
+    param.insert(val_s("name"), val_s(name));
+    param.insert(val_s("in"), val_s("header"));
+    if let Some(description) = description {
+        param.insert(val_s("description"), description);
+    }
+    if required {
+        param.insert(val_s("required"), Value::Bool(true));
+    }
+    param.insert(val_s("schema"), schema);
+    Value::Mapping(param)
+}
+
+/// Restore `type: array` on component schema properties backed by proto
+/// `repeated` fields.
+///
+/// Earlier transforms (e.g., UUID or Duration flattening) rewrite property
+/// schemas in place and can leave a repeated field typed as its element.
+/// Wrapping those back into `{type: array, items: ...}` keeps downstream
+/// example generation and inlining from treating them as scalars.
+pub fn enforce_repeated_arrays(doc: &mut Value, repeated: &[RepeatedField]) {
+    let Some(schemas) = schemas_mut(doc) else {
+        return;
+    };
+
+    for rf in repeated {
+        let Some(prop) = schemas
+            .get_mut(rf.schema.as_str())
+            .and_then(Value::as_mapping_mut)
+            .and_then(|s| s.get_mut("properties"))
+            .and_then(Value::as_mapping_mut)
+            .and_then(|p| p.get_mut(rf.field.as_str()))
+            .and_then(Value::as_mapping_mut)
+        else {
+            continue;
+        };
+
+        let is_array = prop
+            .get("type")
+            .and_then(Value::as_str)
+            .is_some_and(|t| t == "array");
+        if is_array {
+            continue;
+        }
+
+        let description = prop.remove("description");
+        let mut items = std::mem::take(prop);
+        // Element-level examples describe one item, not the whole list.
+        let example = items.remove("example");
+
+        prop.insert(val_s("type"), val_s("array"));
+        prop.insert(val_s("items"), Value::Mapping(items));
+        if let Some(d) = description {
+            prop.insert(val_s("description"), d);
+        }
+        if let Some(ex) = example {
+            prop.insert(val_s("example"), Value::Sequence(vec![ex]));
+        }
+    }
+}
+
+/// Mark component schema properties backed by proto `bytes` fields as
+/// base64 strings: `type: string`, `format: byte`, `contentEncoding: base64`
+/// (plus `contentMediaType` when configured) and an example value.
+///
+/// Runs after [`enforce_repeated_arrays`], so `repeated bytes` fields are
+/// annotated on their `items`.
+pub fn annotate_bytes_encoding(doc: &mut Value, bytes: &[BytesField], media_type: Option<&str>) {
+    let Some(schemas) = schemas_mut(doc) else {
+        return;
+    };
+
+    for bf in bytes {
+        let Some(prop) = schemas
+            .get_mut(bf.schema.as_str())
+            .and_then(Value::as_mapping_mut)
+            .and_then(|s| s.get_mut("properties"))
+            .and_then(Value::as_mapping_mut)
+            .and_then(|p| p.get_mut(bf.field.as_str()))
+            .and_then(Value::as_mapping_mut)
+        else {
+            continue;
+        };
+
+        let is_array = prop.get("type").and_then(Value::as_str) == Some("array");
+        let target = if is_array {
+            match prop.get_mut("items").and_then(Value::as_mapping_mut) {
+                Some(items) => items,
+                None => continue,
+            }
+        } else {
+            prop
+        };
+
+        target.insert(val_s("type"), val_s("string"));
+        target.insert(val_s("format"), val_s("byte"));
+        target.insert(val_s("contentEncoding"), val_s("base64"));
+        if let Some(mt) = media_type {
+            target.insert(val_s("contentMediaType"), val_s(mt));
+        }
+        if !target.contains_key("example") {
+            target.insert(val_s("example"), val_s(BYTES_EXAMPLE));
+        }
+    }
+}
+
+/// Type path and query parameters bound to proto `bytes` fields as base64
+/// strings (`format: byte`); repeated parameters are typed on their `items`.
+pub fn annotate_bytes_params(doc: &mut Value, params: &[BytesParam]) {
+    if params.is_empty() {
+        return;
+    }
+
+    for_each_operation(doc, |path, method, op| {
+        let Some(op_params) = op.get_mut("parameters").and_then(Value::as_sequence_mut) else {
+            return;
+        };
+
+        for param in op_params.iter_mut().filter_map(Value::as_mapping_mut) {
+            let name = param
+                .get("name")
+                .and_then(Value::as_str)
+                .unwrap_or_default();
+            let located = matches!(
+                param.get("in").and_then(Value::as_str),
+                Some("path" | "query")
+            );
+            if !located
+                || !params
+                    .iter()
+                    .any(|bp| bp.path == path && bp.method == method && bp.name == name)
+            {
+                continue;
+            }
+
+            let Some(schema) = param.get_mut("schema").and_then(Value::as_mapping_mut) else {
+                continue;
+            };
+            let target = if schema.get("type").and_then(Value::as_str) == Some("array") {
+                match schema.get_mut("items").and_then(Value::as_mapping_mut) {
+                    Some(items) => items,
+                    None => continue,
+                }
+            } else {
+                schema
+            };
+            target.insert(val_s("type"), val_s("string"));
+            target.insert(val_s("format"), val_s("byte"));
+            if !target.contains_key("example") {
+                target.insert(val_s("example"), val_s(BYTES_EXAMPLE));
+            }
+        }
+    });
+}
+
+/// Replace `google.protobuf.*Value` wrapper fields with nullable scalars.
+///
+/// Wrappers serialize as their bare value or `null`, so a `BoolValue`
+/// property becomes `type: [boolean, "null"]`. 64-bit wrappers stay strings
+/// per the proto3 JSON mapping. Wrapper component schemas left without
+/// references are removed afterwards.
+pub fn inline_wrapper_fields(doc: &mut Value, wrappers: &[WrapperField]) {
+    let Some(schemas) = schemas_mut(doc) else {
+        return;
+    };
+
+    for wf in wrappers {
+        let Some((json_type, extra)) = wrapper_json_type(&wf.wrapper) else {
+            continue;
+        };
+        let Some(prop) = schemas
+            .get_mut(wf.schema.as_str())
+            .and_then(Value::as_mapping_mut)
+            .and_then(|s| s.get_mut("properties"))
+            .and_then(Value::as_mapping_mut)
+            .and_then(|p| p.get_mut(wf.field.as_str()))
+            .and_then(Value::as_mapping_mut)
+        else {
+            continue;
+        };
+
+        let is_array = prop.get("type").and_then(Value::as_str) == Some("array");
+        let target = if is_array {
+            match prop.get_mut("items").and_then(Value::as_mapping_mut) {
+                Some(items) => items,
+                None => continue,
+            }
+        } else {
+            prop
+        };
+
+        let description = target.remove("description");
+        target.clear();
+        target.insert(
+            val_s("type"),
+            Value::Sequence(vec![val_s(json_type), val_s("null")]),
+        );
+        if let Some((key, value)) = extra {
+            target.insert(val_s(key), val_s(value));
+        }
+        if let Some(desc) = description {
+            target.insert(val_s("description"), desc);
+        }
+    }
+
+    let mut refs = HashSet::new();
+    collect_refs(doc, &mut refs);
+    let Some(schemas) = schemas_mut(doc) else {
+        return;
+    };
+    for wrapper in WRAPPER_TYPES {
+        let name = format!("google.protobuf.{wrapper}");
+        if !refs.contains(&format!("#/components/schemas/{name}")) {
+            schemas.remove(name.as_str());
+        }
+    }
+}
+
+/// Make proto3 `optional` properties accept `null`.
+///
+/// Runs after the 3.1 upgrade, so it follows the document's own version:
+/// 3.1 specs get `null` appended to the `type` array (as `convert_nullable`
+/// produces for gnostic's `nullable: true`), 3.0 specs get `nullable: true`.
+/// Properties without a `type` (e.g. `$ref`s) are left alone.
+pub fn mark_optional_fields_nullable(doc: &mut Value, optional: &[OptionalField]) {
+    let is_31 = doc
+        .get("openapi")
+        .and_then(Value::as_str)
+        .is_some_and(|v| v.starts_with("3.1"));
+    let Some(schemas) = schemas_mut(doc) else {
+        return;
+    };
+
+    for of in optional {
+        let Some(prop) = schemas
+            .get_mut(of.schema.as_str())
+            .and_then(Value::as_mapping_mut)
+            .and_then(|s| s.get_mut("properties"))
+            .and_then(Value::as_mapping_mut)
+            .and_then(|p| p.get_mut(of.field.as_str()))
+            .and_then(Value::as_mapping_mut)
+        else {
+            continue;
+        };
+        let Some(ty) = prop.get("type").cloned() else {
+            continue;
+        };
+
+        if !is_31 {
+            prop.insert(val_s("nullable"), Value::Bool(true));
+            continue;
+        }
+
+        let mut types = match ty {
+            Value::Sequence(types) => types,
+            single => vec![single],
+        };
+        if !types.contains(&val_s("null")) {
+            types.push(val_s("null"));
+        }
+        prop.insert(val_s("type"), Value::Sequence(types));
+
+        if let Some(values) = prop.get_mut("enum").and_then(Value::as_sequence_mut) {
+            if !values.contains(&Value::Null) {
+                values.push(Value::Null);
+            }
+        }
+    }
+}
+
+/// Set each property's `description` from its proto field comment.
+///
+/// Only fills properties that have no description yet, so text that gnostic
+/// already emitted, or that a config override set, is kept as-is.
+pub fn inject_field_descriptions(
+    doc: &mut Value,
+    descriptions: &HashMap<String, HashMap<String, String>>,
+) {
+    let Some(schemas) = schemas_mut(doc) else {
+        return;
+    };
+
+    for (schema, fields) in descriptions {
+        let Some(props) = schemas
+            .get_mut(schema.as_str())
+            .and_then(Value::as_mapping_mut)
+            .and_then(|s| s.get_mut("properties"))
+            .and_then(Value::as_mapping_mut)
+        else {
+            continue;
+        };
+
+        for (field, text) in fields {
+            let Some(prop) = props
+                .get_mut(field.as_str())
+                .and_then(Value::as_mapping_mut)
+            else {
+                continue;
+            };
+            if !prop.contains_key("description") {
+                prop.insert(val_s("description"), val_s(text));
+            }
+        }
+    }
+}
+
+/// Set each property's `default` from its proto2 `[default = ...]` value.
+///
+/// Values take their proto3 JSON form: booleans and 32-bit numbers stay
+/// native, 64-bit integers become strings, and enum defaults are rewritten
+/// like the `enum` array (via `enum_value_map` and `case`). `bytes` defaults
+/// and non-finite floats are skipped; an existing `default` is kept.
+pub fn inject_field_defaults(
+    doc: &mut Value,
+    defaults: &[FieldDefault],
+    enum_value_map: &HashMap<String, String>,
+    case: EnumCase,
+) {
+    let Some(schemas) = schemas_mut(doc) else {
+        return;
+    };
+
+    for fd in defaults {
+        let Some(value) = default_json_value(fd, enum_value_map, case) else {
+            continue;
+        };
+        let Some(prop) = schemas
+            .get_mut(fd.schema.as_str())
+            .and_then(Value::as_mapping_mut)
+            .and_then(|s| s.get_mut("properties"))
+            .and_then(Value::as_mapping_mut)
+            .and_then(|p| p.get_mut(fd.field.as_str()))
+            .and_then(Value::as_mapping_mut)
+        else {
+            continue;
+        };
+        if !prop.contains_key("default") {
+            prop.insert(val_s("default"), value);
+        }
+    }
+}
+
+/// Convert a protoc default string to its JSON value for the field's type.
+fn default_json_value(
+    fd: &FieldDefault,
+    enum_value_map: &HashMap<String, String>,
+    case: EnumCase,
+) -> Option<Value> {
+    let raw = fd.value.as_str();
+    match fd.proto_type {
+        field_type::BOOL => raw.parse().ok().map(Value::Bool),
+        field_type::INT32 | field_type::SINT32 | field_type::SFIXED32 => {
+            raw.parse().ok().map(val_i64)
+        }
+        field_type::UINT32 | field_type::FIXED32 => raw.parse().ok().map(val_n),
+        field_type::INT64
+        | field_type::SINT64
+        | field_type::SFIXED64
+        | field_type::UINT64
+        | field_type::FIXED64
+        | field_type::STRING => Some(val_s(raw)),
+        field_type::DOUBLE | field_type::FLOAT => raw
+            .parse::<f64>()
+            .ok()
+            .filter(|f| f.is_finite())
+            .map(|f| Value::Number(f.into())),
+        field_type::ENUM => {
+            let name = enum_value_map
+                .get(raw)
+                .and_then(|stripped| case.convert(stripped))
+                .unwrap_or_else(|| raw.to_string());
+            Some(Value::String(name))
+        }
+        _ => None,
+    }
+}
+
+/// Set `maxItems` on the items array of paginated list responses.
+///
+/// Uses the `page_size` bound recorded in [`PaginatedResponse::max_page_size`];
+/// an existing `maxItems` is kept.
+pub fn inject_page_max_items(doc: &mut Value, paginated: &[PaginatedResponse]) {
+    let Some(schemas) = schemas_mut(doc) else {
+        return;
+    };
+
+    for page in paginated {
+        let Some(max) = page.max_page_size else {
+            continue;
+        };
+        let Some(items) = schemas
+            .get_mut(page.schema.as_str())
+            .and_then(|s| s.get_mut("properties"))
+            .and_then(|p| p.get_mut(page.items_field.as_str()))
+            .and_then(Value::as_mapping_mut)
+        else {
+            continue;
+        };
+        if items.get("type").and_then(Value::as_str) == Some("array")
+            && !items.contains_key("maxItems")
+        {
+            items.insert(val_s("maxItems"), val_n(max));
+        }
+    }
+}
+
+/// Render proto `map<K, V>` fields as `type: object` with `additionalProperties`.
+///
+/// gnostic models a map as a list of synthetic `*Entry` messages; JSON maps
+/// are plain objects, so the property is replaced with an object whose
+/// values follow the map's value type. Entry schemas left without references
+/// are removed.
+pub fn apply_map_fields(doc: &mut Value, maps: &[MapField]) {
+    let Some(schemas) = schemas_mut(doc) else {
+        return;
+    };
+
+    for map in maps {
+        let Some(prop) = schemas
+            .get_mut(map.schema.as_str())
+            .and_then(Value::as_mapping_mut)
+            .and_then(|s| s.get_mut("properties"))
+            .and_then(Value::as_mapping_mut)
+            .and_then(|p| p.get_mut(map.field.as_str()))
+            .and_then(Value::as_mapping_mut)
+        else {
+            continue;
+        };
+
+        let description = prop.remove("description");
+        prop.clear();
+        prop.insert(val_s("type"), val_s("object"));
+        prop.insert(
+            val_s("additionalProperties"),
+            map_value_schema(map.value_type, map.value_type_name.as_deref()),
+        );
+        if let Some(desc) = description {
+            prop.insert(val_s("description"), desc);
+        }
+    }
+
+    let mut refs = HashSet::new();
+    collect_refs(doc, &mut refs);
+    let Some(schemas) = schemas_mut(doc) else {
+        return;
+    };
+    for map in maps {
+        if !refs.contains(&format!("#/components/schemas/{}", map.entry_schema)) {
+            schemas.remove(map.entry_schema.as_str());
+        }
+    }
+}
+
+/// JSON Schema for a map value of the given proto type, per the proto3 JSON mapping.
+fn map_value_schema(value_type: i32, type_name: Option<&str>) -> Value {
+    let mut schema = serde_yaml_ng::Mapping::new();
+    let (json_type, format) = match value_type {
+        field_type::MESSAGE => {
+            let target = type_name.unwrap_or_default();
+            schema.insert(
+                val_s("$ref"),
+                val_s(&format!("#/components/schemas/{target}")),
+            );
+            return Value::Mapping(schema);
+        }
+        field_type::DOUBLE => ("number", Some("double")),
+        field_type::FLOAT => ("number", Some("float")),
+        field_type::INT32 | field_type::SINT32 | field_type::SFIXED32 => ("integer", Some("int32")),
+        field_type::UINT32 | field_type::FIXED32 => ("integer", Some("uint32")),
+        field_type::INT64 | field_type::SINT64 | field_type::SFIXED64 => ("string", Some("int64")),
+        field_type::UINT64 | field_type::FIXED64 => ("string", Some("uint64")),
+        field_type::BOOL => ("boolean", None),
+        field_type::BYTES => {
+            schema.insert(val_s("contentEncoding"), val_s("base64"));
+            ("string", None)
+        }
+        _ => ("string", None),
+    };
+    schema.insert(val_s("type"), val_s(json_type));
+    if let Some(f) = format {
+        schema.insert(val_s("format"), val_s(f));
+    }
+    Value::Mapping(schema)
+}
+
+/// Rewrite proto `oneof` groups as mutually exclusive `oneOf` alternatives.
+///
+/// Each member property moves out of the schema's `properties` into its own
+/// alternative that requires it, so Swagger UI renders one input per member.
+/// A discovered discriminator becomes `discriminator.propertyName`. When a
+/// message has several oneofs, the first uses the schema's own `oneOf` and
+/// the rest are appended to `allOf`, as one keyword can express one choice.
+pub fn apply_oneof_schemas(doc: &mut Value, oneofs: &[OneofInfo]) {
+    let Some(schemas) = schemas_mut(doc) else {
+        return;
+    };
+
+    for oneof in oneofs {
+        let Some(schema) = schemas
+            .get_mut(oneof.schema.as_str())
+            .and_then(Value::as_mapping_mut)
+        else {
+            continue;
+        };
+        let Some(props) = schema.get_mut("properties").and_then(Value::as_mapping_mut) else {
+            continue;
+        };
+
+        let alternatives: Vec<Value> = oneof
+            .fields
+            .iter()
+            .filter_map(|field| {
+                let prop = props.remove(field.as_str())?;
+                let mut alt = serde_yaml_ng::Mapping::new();
+                alt.insert(val_s("title"), val_s(field));
+                alt.insert(
+                    val_s("properties"),
+                    Value::Mapping([(val_s(field), prop)].into_iter().collect()),
+                );
+                alt.insert(val_s("required"), Value::Sequence(vec![val_s(field)]));
+                Some(Value::Mapping(alt))
+            })
+            .collect();
+        if alternatives.is_empty() {
+            continue;
+        }
+
+        if let Some(required) = schema.get_mut("required").and_then(Value::as_sequence_mut) {
+            required.retain(|r| {
+                !r.as_str()
+                    .is_some_and(|r| oneof.fields.iter().any(|f| f == r))
+            });
+        }
+
+        let mut group = serde_yaml_ng::Mapping::new();
+        group.insert(val_s("oneOf"), Value::Sequence(alternatives));
+        if let Some(prop) = &oneof.discriminator {
+            let mut discriminator = serde_yaml_ng::Mapping::new();
+            discriminator.insert(val_s("propertyName"), val_s(prop));
+            group.insert(val_s("discriminator"), Value::Mapping(discriminator));
+        }
+
+        if schema.contains_key("oneOf") {
+            let all_of = schema
+                .entry(val_s("allOf"))
+                .or_insert_with(|| Value::Sequence(Vec::new()));
+            if let Some(all_of) = all_of.as_sequence_mut() {
+                all_of.push(Value::Mapping(group));
+            }
+        } else {
+            schema.extend(group);
+        }
+    }
+}
+
+/// JSON type of a wrapper's value, plus an optional `format`/`contentEncoding` keyword.
+fn wrapper_json_type(
+    wrapper: &str,
+) -> Option<(&'static str, Option<(&'static str, &'static str)>)> {
+    Some(match wrapper {
+        "DoubleValue" => ("number", Some(("format", "double"))),
+        "FloatValue" => ("number", Some(("format", "float"))),
+        "Int64Value" => ("string", Some(("format", "int64"))),
+        "UInt64Value" => ("string", Some(("format", "uint64"))),
+        "Int32Value" => ("integer", Some(("format", "int32"))),
+        "UInt32Value" => ("integer", Some(("format", "uint32"))),
+        "BoolValue" => ("boolean", None),
+        "StringValue" => ("string", None),
+        "BytesValue" => ("string", Some(("contentEncoding", "base64"))),
+        _ => return None,
+    })
+}
+
 /// Normalize a path for matching by stripping `.value` suffixes from template
 /// variables, removing underscores, and lowercasing.
 ///
@@ -708,7 +1451,14 @@ fn normalize_name_for_match(name: &str) -> String {
 }
 
 /// Enrich path parameters with constraints from proto field definitions.
-pub fn enrich_path_params(doc: &mut Value, path_params: &[PathParamInfo]) {
+///
+/// With `uuid_length_bounds`, every `format: uuid` path parameter also gets
+/// `minLength`/`maxLength` of 36 for validators that ignore `format`.
+pub fn enrich_path_params(
+    doc: &mut Value,
+    path_params: &[PathParamInfo],
+    uuid_length_bounds: bool,
+) {
     for_each_operation(doc, |path, _method, op_map| {
         let Some(params) = op_map
             .get_mut("parameters")
@@ -763,6 +1513,9 @@ pub fn enrich_path_params(doc: &mut Value, path_params: &[PathParamInfo]) {
                 schema.insert(val_s("format"), val_s("uuid"));
                 schema.insert(val_s("pattern"), val_s(UUID_PATTERN));
                 schema.insert(val_s("example"), val_s(UUID_EXAMPLE));
+                if uuid_length_bounds {
+                    insert_uuid_length_bounds(&mut schema);
+                }
                 p.insert(val_s("schema"), Value::Mapping(schema));
                 p.insert(val_s("description"), val_s("Resource UUID"));
                 continue;
@@ -792,11 +1545,22 @@ pub fn enrich_path_params(doc: &mut Value, path_params: &[PathParamInfo]) {
                 if let Some(enum_vals) = schema.get_mut("enum").and_then(Value::as_sequence_mut) {
                     enum_vals.retain(|v| v.as_str().is_none_or(|s| !s.ends_with("_UNSPECIFIED")));
                 }
+                let is_uuid = schema.get("format").and_then(Value::as_str) == Some("uuid");
+                if uuid_length_bounds && is_uuid {
+                    insert_uuid_length_bounds(schema);
+                }
             }
         }
     });
 }
 
+/// Pin a UUID string schema to the 36-character hyphenated form.
+fn insert_uuid_length_bounds(schema: &mut serde_yaml_ng::Mapping) {
+    let len = val_n(UUID_EXAMPLE.len() as u64);
+    schema.insert(val_s("minLength"), len.clone());
+    schema.insert(val_s("maxLength"), len);
+}
+
 #[cfg(test)]
 mod tests {
     use crate::discover::FieldConstraint;
@@ -894,11 +1658,15 @@ components:
                     max: Some(100),
                     signed_min: None,
                     signed_max: None,
+                    min_items: None,
+                    max_items: None,
+                    format: None,
                     pattern: None,
                     enum_values: Vec::new(),
                     required: true,
                     is_uuid: false,
                     is_numeric: false,
+                    proto_type: field_type::STRING,
                 },
                 FieldConstraint {
                     field: "email".to_string(),
@@ -906,11 +1674,15 @@ components:
                     max: Some(255),
                     signed_min: None,
                     signed_max: None,
+                    min_items: None,
+                    max_items: None,
+                    format: None,
                     pattern: None,
                     enum_values: Vec::new(),
                     required: true,
                     is_uuid: false,
                     is_numeric: false,
+                    proto_type: field_type::STRING,
                 },
             ],
         }];
@@ -929,6 +1701,154 @@ components:
         assert!(required.contains(&val_s("email")));
     }
 
+    #[test]
+    fn int64_constraints_render_as_string() {
+        let yaml = r"
+components:
+  schemas:
+    test.v1.Request:
+      type: object
+      properties:
+        size:
+          type: integer
+          format: int64
+        count:
+          type: integer
+          format: int32
+        offset:
+          type: integer
+          format: uint64
+";
+        let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        let numeric = |field: &str, proto_type: i32| FieldConstraint {
+            field: field.to_string(),
+            min: Some(1),
+            max: Some(1000),
+            signed_min: None,
+            signed_max: None,
+            min_items: None,
+            max_items: None,
+            format: None,
+            pattern: None,
+            enum_values: Vec::new(),
+            required: false,
+            is_uuid: false,
+            is_numeric: true,
+            proto_type,
+        };
+        let constraints = vec![SchemaConstraints {
+            schema: "test.v1.Request".to_string(),
+            fields: vec![
+                numeric("size", field_type::UINT64),
+                numeric("count", field_type::UINT32),
+                // Beyond the JSON-safe integer range
+                FieldConstraint {
+                    max: Some(u64::MAX),
+                    ..numeric("offset", field_type::FIXED64)
+                },
+            ],
+        }];
+
+        inject_validation_constraints(&mut doc, &constraints);
+
+        let props = &doc["components"]["schemas"]["test.v1.Request"]["properties"];
+        assert_eq!(props["size"]["type"].as_str(), Some("string"));
+        assert_eq!(props["size"]["format"].as_str(), Some("uint64"));
+        assert!(props["size"].get("maximum").is_none());
+        assert_eq!(props["count"]["type"].as_str(), Some("integer"));
+        assert!(props["count"].get("format").is_none());
+        assert_eq!(props["count"]["maximum"].as_u64(), Some(1000));
+        assert_eq!(props["offset"]["type"].as_str(), Some("string"));
+        assert_eq!(props["offset"]["format"].as_str(), Some("uint64"));
+        assert!(props["offset"].get("maximum").is_none());
+    }
+
+    #[test]
+    fn well_known_string_formats_injected() {
+        let yaml = r"
+components:
+  schemas:
+    test.v1.Contact:
+      type: object
+      properties:
+        email:
+          type: string
+        website:
+          type: string
+";
+        let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        let with_format = |field: &str, format: &str| FieldConstraint {
+            field: field.to_string(),
+            min: None,
+            max: None,
+            signed_min: None,
+            signed_max: None,
+            min_items: None,
+            max_items: None,
+            format: Some(format.to_string()),
+            pattern: None,
+            enum_values: Vec::new(),
+            required: false,
+            is_uuid: false,
+            is_numeric: false,
+            proto_type: field_type::STRING,
+        };
+        let constraints = vec![SchemaConstraints {
+            schema: "test.v1.Contact".to_string(),
+            fields: vec![with_format("email", "email"), with_format("website", "uri")],
+        }];
+
+        inject_validation_constraints(&mut doc, &constraints);
+
+        let props = &doc["components"]["schemas"]["test.v1.Contact"]["properties"];
+        assert_eq!(props["email"]["format"].as_str(), Some("email"));
+        assert_eq!(props["website"]["format"].as_str(), Some("uri"));
+        assert_eq!(props["website"]["type"].as_str(), Some("string"));
+    }
+
+    #[test]
+    fn repeated_item_bounds_injected() {
+        let yaml = r"
+components:
+  schemas:
+    test.v1.User:
+      type: object
+      properties:
+        roles:
+          type: array
+          items:
+            type: string
+";
+        let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        let constraints = vec![SchemaConstraints {
+            schema: "test.v1.User".to_string(),
+            fields: vec![FieldConstraint {
+                field: "roles".to_string(),
+                min: None,
+                max: None,
+                signed_min: None,
+                signed_max: None,
+                min_items: Some(1),
+                max_items: Some(10),
+                format: None,
+                pattern: None,
+                enum_values: Vec::new(),
+                required: false,
+                is_uuid: false,
+                is_numeric: false,
+                proto_type: field_type::STRING,
+            }],
+        }];
+
+        inject_validation_constraints(&mut doc, &constraints);
+
+        let roles = &doc["components"]["schemas"]["test.v1.User"]["properties"]["roles"];
+        assert_eq!(roles["minItems"].as_u64(), Some(1));
+        assert_eq!(roles["maxItems"].as_u64(), Some(10));
+        assert!(roles.get("minLength").is_none());
+        assert_eq!(roles["items"]["type"].as_str(), Some("string"));
+    }
+
     #[test]
     fn path_fields_stripped_from_body() {
         let yaml = r"
@@ -954,7 +1874,7 @@ components:
           type: string
 ";
         let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
-        strip_path_fields_from_body(&mut doc);
+        strip_path_fields_from_body(&mut doc, &[]);
 
         // The operation's schema should be inlined with itemId stripped
         let inlined_schema =
@@ -1019,7 +1939,7 @@ components:
           type: string
 ";
         let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
-        strip_path_fields_from_body(&mut doc);
+        strip_path_fields_from_body(&mut doc, &[]);
 
         // PUT operation should have inlined schema with itemId stripped
         let put_schema =
@@ -1056,6 +1976,98 @@ components:
         assert!(component_props.contains_key("name"));
     }
 
+    #[test]
+    fn repeated_body_field_not_stripped_by_path_param() {
+        let yaml = r"
+paths:
+  /v1/tags/{tags}:
+    post:
+      parameters:
+        - name: tags
+          in: path
+      requestBody:
+        content:
+          application/json:
+            schema:
+              $ref: '#/components/schemas/test.v1.TagRequest'
+components:
+  schemas:
+    test.v1.TagRequest:
+      type: object
+      properties:
+        tags:
+          type: array
+          items:
+            type: string
+        name:
+          type: string
+";
+        let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        let repeated = vec![RepeatedField {
+            schema: "test.v1.TagRequest".to_string(),
+            field: "tags".to_string(),
+        }];
+        strip_path_fields_from_body(&mut doc, &repeated);
+
+        // Nothing left to strip → operation keeps its $ref untouched
+        let schema = doc["paths"]["/v1/tags/{tags}"]["post"]["requestBody"]["content"]
+            ["application/json"]["schema"]
+            .as_mapping()
+            .unwrap();
+        assert!(
+            schema.contains_key("$ref"),
+            "repeated field must not be stripped from the body"
+        );
+    }
+
+    #[test]
+    fn repeated_field_restored_to_array() {
+        let yaml = r"
+components:
+  schemas:
+    test.v1.BatchRequest:
+      type: object
+      properties:
+        itemIds:
+          type: string
+          format: uuid
+          example: 550e8400-e29b-41d4-a716-446655440000
+          description: Items to fetch
+        names:
+          type: array
+          items:
+            type: string
+";
+        let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        let repeated = vec![
+            RepeatedField {
+                schema: "test.v1.BatchRequest".to_string(),
+                field: "itemIds".to_string(),
+            },
+            RepeatedField {
+                schema: "test.v1.BatchRequest".to_string(),
+                field: "names".to_string(),
+            },
+        ];
+        enforce_repeated_arrays(&mut doc, &repeated);
+
+        let props = &doc["components"]["schemas"]["test.v1.BatchRequest"]["properties"];
+        assert_eq!(props["itemIds"]["type"].as_str().unwrap(), "array");
+        assert_eq!(
+            props["itemIds"]["items"]["format"].as_str().unwrap(),
+            "uuid"
+        );
+        assert_eq!(
+            props["itemIds"]["description"].as_str().unwrap(),
+            "Items to fetch"
+        );
+        assert!(props["itemIds"]["example"].is_sequence());
+        assert!(props["itemIds"]["items"].get("description").is_none());
+
+        // Already an array → unchanged
+        assert_eq!(props["names"]["items"]["type"].as_str().unwrap(), "string");
+    }
+
     #[test]
     fn uuid_path_template_flattened() {
         let yaml = r"
@@ -1130,7 +2142,7 @@ paths:
 
         // Phase 10: enrich should still find and apply UUID metadata
         // despite path and name case differences
-        enrich_path_params(&mut doc, &path_params);
+        enrich_path_params(&mut doc, &path_params, false);
 
         let param = doc["paths"]["/v1/users/{user_id}"]["get"]["parameters"][0]
             .as_mapping()
@@ -1154,6 +2166,57 @@ paths:
         );
     }
 
+    #[test]
+    fn uuid_path_param_length_bounds() {
+        use crate::discover::{PathParamConstraint, PathParamInfo};
+
+        let yaml = r"
+paths:
+  /v1/users/{userId}:
+    get:
+      parameters:
+        - name: userId
+          in: path
+          schema:
+            type: string
+  /v1/devices/{deviceId}:
+    get:
+      parameters:
+        - name: deviceId
+          in: path
+          schema:
+            type: string
+            format: uuid
+";
+        let path_params = vec![PathParamInfo {
+            path: "/v1/users/{userId.value}".to_string(),
+            params: vec![PathParamConstraint {
+                name: "userId.value".to_string(),
+                description: None,
+                is_uuid: true,
+                min: None,
+                max: None,
+            }],
+        }];
+        let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        let untouched = doc.clone();
+        enrich_path_params(&mut doc, &path_params, true);
+
+        let wrapper = &doc["paths"]["/v1/users/{userId}"]["get"]["parameters"][0]["schema"];
+        assert_eq!(wrapper["pattern"].as_str(), Some(UUID_PATTERN));
+        assert_eq!(wrapper["minLength"].as_u64(), Some(36));
+        assert_eq!(wrapper["maxLength"].as_u64(), Some(36));
+
+        let plain = &doc["paths"]["/v1/devices/{deviceId}"]["get"]["parameters"][0]["schema"];
+        assert_eq!(plain["minLength"].as_u64(), Some(36));
+        assert_eq!(plain["maxLength"].as_u64(), Some(36));
+
+        let mut doc = untouched;
+        enrich_path_params(&mut doc, &path_params, false);
+        let wrapper = &doc["paths"]["/v1/users/{userId}"]["get"]["parameters"][0]["schema"];
+        assert!(wrapper.get("minLength").is_none());
+    }
+
     #[test]
     fn field_access_annotation_conventions() {
         let yaml = r"
@@ -1176,7 +2239,7 @@ components:
           type: string
 ";
         let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
-        annotate_field_access(&mut doc, &[], &[]);
+        annotate_field_access(&mut doc, &[], &[], &[]);
 
         let props = &doc["components"]["schemas"]["test.v1.User"]["properties"];
         assert!(props["password"]["writeOnly"].as_bool().unwrap());
@@ -1213,7 +2276,12 @@ components:
           type: string
 ";
         let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
-        annotate_field_access(&mut doc, &["apiKey".to_string()], &["lastSync".to_string()]);
+        annotate_field_access(
+            &mut doc,
+            &["apiKey".to_string()],
+            &["lastSync".to_string()],
+            &[],
+        );
 
         let props = &doc["components"]["schemas"]["test.v1.Config"]["properties"];
         assert!(props["apiKey"]["writeOnly"].as_bool().unwrap());
@@ -1244,7 +2312,7 @@ components:
           type: string
 ";
         let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
-        annotate_field_access(&mut doc, &[], &[]);
+        annotate_field_access(&mut doc, &[], &[], &[]);
 
         // Response schema: `secret` must NOT be writeOnly (client must read it)
         let response_props =
@@ -1265,6 +2333,44 @@ components:
         assert!(request_props["password"]["writeOnly"].as_bool().unwrap());
     }
 
+    #[test]
+    fn force_write_only_overrides_response_skip() {
+        let yaml = r"
+components:
+  schemas:
+    test.v1.SetupMfaResponse:
+      type: object
+      properties:
+        secret:
+          type: string
+        recoveryCode:
+          type: string
+    test.v1.RotateKeyResponse:
+      type: object
+      properties:
+        secret:
+          type: string
+";
+        let mut doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        annotate_field_access(
+            &mut doc,
+            &[],
+            &[],
+            &[
+                "test.v1.SetupMfaResponse.recoveryCode".to_string(),
+                "test.v1.RotateKeyResponse.secret".to_string(),
+            ],
+        );
+
+        let schemas = &doc["components"]["schemas"];
+        let mfa = &schemas["test.v1.SetupMfaResponse"]["properties"];
+        assert!(mfa["recoveryCode"]["writeOnly"].as_bool().unwrap());
+        // Not listed: the response-schema skip still applies.
+        assert!(mfa["secret"].get("writeOnly").is_none());
+        let rotate = &schemas["test.v1.RotateKeyResponse"]["properties"];
+        assert!(rotate["secret"]["writeOnly"].as_bool().unwrap());
+    }
+
     #[test]
     fn duration_fields_annotated() {
         let yaml = r"
@@ -0,0 +1,222 @@
+//! Structural checks on the patched spec.
+//!
+//! Catches mistakes that are valid YAML but not a usable `OpenAPI` 3.1
+//! document, so regressions in the transforms surface as errors rather than
+//! as broken client generation downstream.
+
+use serde_yaml_ng::Value;
+
+use crate::error;
+use crate::json::escape_pointer;
+
+use super::helpers::{HTTP_METHODS, is_unresolved_ref};
+
+/// Check `doc` for dangling local `$ref`s, empty `required` arrays, and
+/// operations without `responses`.
+///
+/// Returns [`Error::InvalidSpec`](error::Error::InvalidSpec) for the first
+/// problem found, in document order, with the JSON pointer of the node.
+/// `example`/`examples` values are not checked.
+pub fn validate_output(doc: &Value) -> error::Result<()> {
+    if let Some(paths) = doc.get("paths").and_then(Value::as_mapping) {
+        for (path, item) in paths {
+            let (Some(path), Some(item)) = (path.as_str(), item.as_mapping()) else {
+                continue;
+            };
+            for (method, op) in item {
+                let Some(method) = method.as_str().filter(|m| HTTP_METHODS.contains(m)) else {
+                    continue;
+                };
+                if op.get("responses").is_none() {
+                    return Err(invalid(
+                        format!("/paths/{}/{method}", escape_pointer(path)),
+                        "operation has no `responses`",
+                    ));
+                }
+            }
+        }
+    }
+
+    check_node(doc, doc, &mut String::new())
+}
+
+/// Recursively check `node` (at JSON pointer `pointer`) against `doc`,
+/// skipping example payloads.
+fn check_node(doc: &Value, node: &Value, pointer: &mut String) -> error::Result<()> {
+    match node {
+        Value::Mapping(map) => {
+            for (key, value) in map {
+                let Some(key) = key.as_str() else {
+                    continue;
+                };
+                // Example payloads are user data, not schema; a property
+                // named `example` is still a schema.
+                if matches!(key, "example" | "examples") && !pointer.ends_with("/properties") {
+                    continue;
+                }
+                if key == "$ref" {
+                    if let Some(target) = value.as_str().filter(|t| is_unresolved_ref(doc, t)) {
+                        return Err(invalid(
+                            pointer.clone(),
+                            format!("dangling $ref `{target}`"),
+                        ));
+                    }
+                } else if key == "required" && value.as_sequence().is_some_and(Vec::is_empty) {
+                    return Err(invalid(
+                        format!("{pointer}/required"),
+                        "empty `required` array",
+                    ));
+                }
+
+                let len = pointer.len();
+                pointer.push('/');
+                pointer.push_str(&escape_pointer(key));
+                check_node(doc, value, pointer)?;
+                pointer.truncate(len);
+            }
+        }
+        Value::Sequence(seq) => {
+            for (index, item) in seq.iter().enumerate() {
+                let len = pointer.len();
+                pointer.push('/');
+                pointer.push_str(&index.to_string());
+                check_node(doc, item, pointer)?;
+                pointer.truncate(len);
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn invalid(path: String, reason: impl Into<String>) -> error::Error {
+    error::Error::InvalidSpec {
+        path,
+        reason: reason.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(yaml: &str) -> Option<(String, String)> {
+        let doc: Value = serde_yaml_ng::from_str(yaml).unwrap();
+        match validate_output(&doc) {
+            Ok(()) => None,
+            Err(error::Error::InvalidSpec { path, reason }) => Some((path, reason)),
+            Err(other) => panic!("unexpected error: {other}"),
+        }
+    }
+
+    #[test]
+    fn valid_spec_passes() {
+        let yaml = r"
+paths:
+  /v1/items:
+    get:
+      parameters:
+        - name: id
+          in: query
+          required: true
+      responses:
+        '200':
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/Item'
+components:
+  schemas:
+    Item:
+      type: object
+      required: [id]
+";
+        assert_eq!(check(yaml), None);
+    }
+
+    #[test]
+    fn dangling_ref_reported_with_path() {
+        let yaml = r"
+paths:
+  /v1/items:
+    get:
+      responses:
+        '200':
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/Missing'
+";
+        assert_eq!(
+            check(yaml),
+            Some((
+                "/paths/~1v1~1items/get/responses/200/content/application~1json/schema".to_string(),
+                "dangling $ref `#/components/schemas/Missing`".to_string(),
+            ))
+        );
+    }
+
+    #[test]
+    fn empty_required_array_reported() {
+        let yaml = r"
+components:
+  schemas:
+    Item:
+      type: object
+      required: []
+";
+        assert_eq!(
+            check(yaml),
+            Some((
+                "/components/schemas/Item/required".to_string(),
+                "empty `required` array".to_string(),
+            ))
+        );
+    }
+
+    #[test]
+    fn empty_required_in_examples_ignored() {
+        let yaml = r"
+components:
+  schemas:
+    Item:
+      type: object
+      required: [filter]
+      properties:
+        filter:
+          type: object
+          example:
+            required: []
+        example:
+          type: object
+          required: []
+      examples:
+        - required: []
+";
+        assert_eq!(
+            check(yaml),
+            Some((
+                "/components/schemas/Item/properties/example/required".to_string(),
+                "empty `required` array".to_string(),
+            ))
+        );
+    }
+
+    #[test]
+    fn operation_without_responses_reported() {
+        let yaml = r"
+paths:
+  /v1/items:
+    parameters: []
+    delete:
+      operationId: ItemService_DeleteItem
+";
+        assert_eq!(
+            check(yaml),
+            Some((
+                "/paths/~1v1~1items/delete".to_string(),
+                "operation has no `responses`".to_string(),
+            ))
+        );
+    }
+}
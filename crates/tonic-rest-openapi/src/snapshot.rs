@@ -0,0 +1,290 @@
+//! Serializable view of discovered proto metadata for external tooling.
+//!
+//! [`ProtoMetadata`] keeps its fields crate-private so transforms can evolve
+//! freely. [`MetadataSnapshot`] is the stable, serde-backed shape exported by
+//! [`ProtoMetadata::snapshot`] and `tonic-rest-openapi discover --format json`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::discover::ProtoMetadata;
+use crate::error;
+
+/// Stable, serializable snapshot of [`ProtoMetadata`].
+///
+/// ```json
+/// {
+///   "operations": [{ "method_name": "GetUser", "operation_id": "UserService_GetUser" }],
+///   "streaming_ops": [{ "method": "get", "path": "/v1/events" }],
+///   "field_constraints": [{ "schema": "users.v1.User", "fields": [...] }],
+///   "enum_rewrites": [{ "schema": "...", "field": "status", "values": [...], "raw_values": [...] }],
+///   "redirect_paths": [],
+///   "uuid_schema": "core.v1.UUID",
+///   "unbound_path_params": []
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MetadataSnapshot {
+    /// Every HTTP-annotated RPC and its gnostic operation ID.
+    pub operations: Vec<OperationSnapshot>,
+    /// Server-streaming endpoints.
+    pub streaming_ops: Vec<StreamingOpSnapshot>,
+    /// `validate.rules` constraints, grouped by schema.
+    pub field_constraints: Vec<SchemaConstraintsSnapshot>,
+    /// Prefix-stripped enum fields and their wire values.
+    pub enum_rewrites: Vec<EnumRewriteSnapshot>,
+    /// HTTP paths of redirect endpoints.
+    pub redirect_paths: Vec<String>,
+    /// gnostic schema name of the UUID wrapper type, if detected.
+    pub uuid_schema: Option<String>,
+    /// Path template variables with no backing request field.
+    pub unbound_path_params: Vec<UnboundPathParamSnapshot>,
+}
+
+/// An RPC operation ID entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OperationSnapshot {
+    /// Short proto method name (e.g., `GetUser`).
+    pub method_name: String,
+    /// gnostic operation ID (e.g., `UserService_GetUser`).
+    pub operation_id: String,
+}
+
+/// A server-streaming endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StreamingOpSnapshot {
+    /// Lowercase HTTP method (e.g., `get`).
+    pub method: String,
+    /// URL path template.
+    pub path: String,
+}
+
+/// Validation constraints of one schema.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaConstraintsSnapshot {
+    /// Schema name in gnostic format.
+    pub schema: String,
+    /// Per-field constraints.
+    pub fields: Vec<FieldConstraintSnapshot>,
+}
+
+/// Validation constraints of one field.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldConstraintSnapshot {
+    /// JSON property name.
+    pub field: String,
+    /// Whether the field is numeric; decides how `min`/`max` are read.
+    pub is_numeric: bool,
+    /// Proto field type (a `google.protobuf.FieldDescriptorProto.Type` value,
+    /// e.g. `9` for `string`, `4` for `uint64`).
+    pub proto_type: i32,
+    /// `minLength` for strings, `minimum` for unsigned integers.
+    pub min: Option<u64>,
+    /// `maxLength` for strings, `maximum` for unsigned integers.
+    pub max: Option<u64>,
+    /// `minimum` for signed integers.
+    pub signed_min: Option<i64>,
+    /// `maximum` for signed integers.
+    pub signed_max: Option<i64>,
+    /// `minItems` for repeated fields.
+    pub min_items: Option<u64>,
+    /// `maxItems` for repeated fields.
+    pub max_items: Option<u64>,
+    /// JSON Schema `format` from a well-known string rule.
+    pub format: Option<String>,
+    /// Regex pattern.
+    pub pattern: Option<String>,
+    /// Allowed string values.
+    pub enum_values: Vec<String>,
+    /// Whether the field is required.
+    pub required: bool,
+    /// Whether the field must be a UUID.
+    pub is_uuid: bool,
+}
+
+/// A prefix-stripped enum field.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnumRewriteSnapshot {
+    /// Schema name in gnostic format.
+    pub schema: String,
+    /// JSON property name.
+    pub field: String,
+    /// Values as they appear on the wire.
+    pub values: Vec<String>,
+    /// Original proto value names, parallel to `values`.
+    pub raw_values: Vec<String>,
+}
+
+/// A path template variable with no backing request field.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnboundPathParamSnapshot {
+    /// gnostic operation ID.
+    pub operation_id: String,
+    /// HTTP path template.
+    pub path: String,
+    /// Template variable name.
+    pub param: String,
+    /// Fully-qualified input message type.
+    pub input_type: String,
+}
+
+impl ProtoMetadata {
+    /// Copy the tooling-relevant parts of this metadata into a
+    /// [`MetadataSnapshot`].
+    #[must_use]
+    pub fn snapshot(&self) -> MetadataSnapshot {
+        MetadataSnapshot {
+            operations: self
+                .operation_ids
+                .iter()
+                .map(|e| OperationSnapshot {
+                    method_name: e.method_name.clone(),
+                    operation_id: e.operation_id.clone(),
+                })
+                .collect(),
+            streaming_ops: self
+                .streaming_ops
+                .iter()
+                .map(|op| StreamingOpSnapshot {
+                    method: op.method.clone(),
+                    path: op.path.clone(),
+                })
+                .collect(),
+            field_constraints: self
+                .field_constraints
+                .iter()
+                .map(|sc| SchemaConstraintsSnapshot {
+                    schema: sc.schema.clone(),
+                    fields: sc
+                        .fields
+                        .iter()
+                        .map(|f| FieldConstraintSnapshot {
+                            field: f.field.clone(),
+                            is_numeric: f.is_numeric,
+                            proto_type: f.proto_type,
+                            min: f.min,
+                            max: f.max,
+                            signed_min: f.signed_min,
+                            signed_max: f.signed_max,
+                            min_items: f.min_items,
+                            max_items: f.max_items,
+                            format: f.format.clone(),
+                            pattern: f.pattern.clone(),
+                            enum_values: f.enum_values.clone(),
+                            required: f.required,
+                            is_uuid: f.is_uuid,
+                        })
+                        .collect(),
+                })
+                .collect(),
+            enum_rewrites: self
+                .enum_rewrites
+                .iter()
+                .map(|rw| EnumRewriteSnapshot {
+                    schema: rw.schema.clone(),
+                    field: rw.field.clone(),
+                    values: rw.values.clone(),
+                    raw_values: rw.raw_values.clone(),
+                })
+                .collect(),
+            redirect_paths: self.redirect_paths.clone(),
+            uuid_schema: self.uuid_schema.clone(),
+            unbound_path_params: self
+                .unbound_path_params
+                .iter()
+                .map(|p| UnboundPathParamSnapshot {
+                    operation_id: p.operation_id.clone(),
+                    path: p.path.clone(),
+                    param: p.param.clone(),
+                    input_type: p.input_type.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Render [`snapshot`](Self::snapshot) as pretty-printed JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`](crate::Error::Json) if serialization fails.
+    pub fn to_json(&self) -> error::Result<String> {
+        Ok(serde_json::to_string_pretty(&self.snapshot())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+
+    use super::*;
+    use crate::descriptor::{
+        DescriptorProto, FieldDescriptorProto, FieldOptions, FieldRules, FileDescriptorProto,
+        FileDescriptorSet, HttpPattern, HttpRule, MethodDescriptorProto, MethodOptions,
+        ServiceDescriptorProto, StringRules, field_type,
+    };
+
+    #[test]
+    fn snapshot_round_trips_through_json() {
+        let fdset = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("users.proto".to_string()),
+                package: Some("users.v1".to_string()),
+                message_type: vec![DescriptorProto {
+                    name: Some("GetUserRequest".to_string()),
+                    field: vec![FieldDescriptorProto {
+                        name: Some("user_id".to_string()),
+                        label: None,
+                        proto3_optional: None,
+                        default_value: None,
+                        json_name: None,
+                        oneof_index: None,
+                        r#type: Some(field_type::STRING),
+                        type_name: None,
+                        options: Some(FieldOptions {
+                            rules: Some(FieldRules {
+                                string: Some(StringRules {
+                                    max_len: Some(64),
+                                    ..Default::default()
+                                }),
+                                ..Default::default()
+                            }),
+                        }),
+                    }],
+                    oneof_decl: vec![],
+                    options: None,
+                    nested_type: vec![],
+                }],
+                enum_type: vec![],
+                service: vec![ServiceDescriptorProto {
+                    name: Some("UserService".to_string()),
+                    method: vec![MethodDescriptorProto {
+                        name: Some("GetUser".to_string()),
+                        input_type: Some(".users.v1.GetUserRequest".to_string()),
+                        output_type: Some(".users.v1.GetUserRequest".to_string()),
+                        options: Some(MethodOptions {
+                            http: Some(HttpRule {
+                                pattern: Some(HttpPattern::Get("/v1/users/{user_id}".to_string())),
+                                ..Default::default()
+                            }),
+                        }),
+                        client_streaming: None,
+                        server_streaming: None,
+                    }],
+                    options: None,
+                }],
+                source_code_info: None,
+            }],
+        };
+        let metadata = crate::discover(&fdset.encode_to_vec()).unwrap();
+
+        let json = metadata.to_json().unwrap();
+        let parsed: MetadataSnapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, metadata.snapshot());
+
+        assert_eq!(parsed.operations[0].operation_id, "UserService_GetUser");
+        let user_id = &parsed.field_constraints[0].fields[0];
+        assert_eq!(user_id.field, "userId");
+        assert_eq!(user_id.max, Some(64));
+        assert!(!user_id.is_numeric);
+        assert_eq!(user_id.proto_type, field_type::STRING);
+    }
+}
@@ -3,12 +3,17 @@
 //! Each test provides a minimal input YAML and verifies the output
 //! after applying [`tonic_rest_openapi::patch`] with specific config.
 
+use std::collections::{BTreeMap, HashMap};
+
 use pretty_assertions::assert_eq;
 use serde_yaml_ng::Value;
 
 use tonic_rest_openapi::{
-    ContactInfo, EnumRewrite, ExternalDocsInfo, FieldConstraint, InfoOverrides, LicenseInfo,
-    OperationEntry, PatchConfig, ProtoMetadata, SchemaConstraints, ServerEntry, StreamingOp,
+    Badge, BytesField, BytesParam, CallbackDef, CodeSample, ContactInfo, EnumCase, EnumRewrite,
+    ExternalDocsInfo, FieldConstraint, FieldDefault, FormUrlEncodedEndpoint, InfoOverrides,
+    LicenseInfo, MethodSource, OneofInfo, OperationEntry, OperationExample, OptionalField,
+    PaginatedResponse, PatchConfig, ProjectConfig, ProtoMetadata, SanitizeMode, SchemaConstraints,
+    ServerEntry, StreamingOp,
 };
 
 /// Build minimal valid metadata with defaults.
@@ -162,11 +167,15 @@ components:
                 max: Some(255),
                 signed_min: None,
                 signed_max: None,
+                min_items: None,
+                max_items: None,
+                format: None,
                 pattern: Some(r"^[^@\s]+@[^@\s]+$".to_string()),
                 enum_values: Vec::new(),
                 required: true,
                 is_uuid: false,
                 is_numeric: false,
+                proto_type: tonic_rest_core::descriptor::field_type::STRING,
             },
             FieldConstraint {
                 field: "password".to_string(),
@@ -174,11 +183,15 @@ components:
                 max: Some(128),
                 signed_min: None,
                 signed_max: None,
+                min_items: None,
+                max_items: None,
+                format: None,
                 pattern: None,
                 enum_values: Vec::new(),
                 required: true,
                 is_uuid: false,
                 is_numeric: false,
+                proto_type: tonic_rest_core::descriptor::field_type::STRING,
             },
         ],
     }]);
@@ -324,6 +337,12 @@ components:
             "active".to_string(),
             "suspended".to_string(),
         ],
+        raw_values: vec![
+            "USER_STATUS_UNSPECIFIED".to_string(),
+            "USER_STATUS_ACTIVE".to_string(),
+            "USER_STATUS_SUSPENDED".to_string(),
+        ],
+        descriptions: vec![String::new(); 3],
     }]);
     metadata.set_enum_value_map(
         [
@@ -620,10 +639,12 @@ paths: {}
             ServerEntry {
                 url: "https://api.example.com".to_string(),
                 description: Some("Production".to_string()),
+                variables: BTreeMap::new(),
             },
             ServerEntry {
                 url: "https://staging.example.com".to_string(),
                 description: None,
+                variables: BTreeMap::new(),
             },
         ]);
 
@@ -642,6 +663,149 @@ paths: {}
     );
 }
 
+#[test]
+fn default_host_seeds_servers() {
+    let input = r"
+openapi: 3.1.0
+info:
+  title: Test
+  version: 0.1.0
+paths: {}
+";
+
+    let mut metadata = empty_metadata();
+    metadata.set_default_hosts(vec!["api.example.com".to_string()]);
+
+    let result = run_patch(input, &PatchConfig::new(&metadata));
+    let servers = result["servers"].as_sequence().unwrap();
+    assert_eq!(servers.len(), 1);
+    assert_eq!(servers[0]["url"], "https://api.example.com");
+
+    // Explicit servers replace the inferred one.
+    let project: ProjectConfig =
+        serde_yaml_ng::from_str("servers:\n  - url: https://override.example.com\n").unwrap();
+    let config = PatchConfig::new(&metadata).with_project_config(&project);
+    let result = run_patch(input, &config);
+    let servers = result["servers"].as_sequence().unwrap();
+    assert_eq!(servers.len(), 1);
+    assert_eq!(servers[0]["url"], "https://override.example.com");
+}
+
+#[test]
+fn server_variables_from_project_config() {
+    let input = r"
+openapi: 3.0.3
+info:
+  title: Test
+  version: 0.1.0
+paths: {}
+";
+
+    let project: ProjectConfig = serde_yaml_ng::from_str(
+        r#"
+servers:
+  - url: "https://{region}.api.example.com"
+    description: Production
+    variables:
+      region:
+        enum: [us, eu]
+        default: us
+        description: Deployment region
+"#,
+    )
+    .unwrap();
+
+    let metadata = empty_metadata();
+    let config = PatchConfig::new(&metadata)
+        .with_project_config(&project)
+        .upgrade_to_3_1(false)
+        .annotate_sse(false)
+        .inject_validation(false)
+        .add_security(false)
+        .inline_request_bodies(false)
+        .flatten_uuid_refs(false);
+
+    let result = run_patch(input, &config);
+
+    let server = &result["servers"][0];
+    assert_eq!(
+        server["url"].as_str().unwrap(),
+        "https://{region}.api.example.com"
+    );
+    let region = &server["variables"]["region"];
+    assert_eq!(region["default"].as_str().unwrap(), "us");
+    assert_eq!(region["description"].as_str().unwrap(), "Deployment region");
+    let values: Vec<&str> = region["enum"]
+        .as_sequence()
+        .unwrap()
+        .iter()
+        .map(|v| v.as_str().unwrap())
+        .collect();
+    assert_eq!(values, ["us", "eu"]);
+}
+
+#[test]
+fn server_variable_default_outside_enum_rejected() {
+    let project: ProjectConfig = serde_yaml_ng::from_str(
+        r#"
+servers:
+  - url: "https://{region}.api.example.com"
+    variables:
+      region:
+        enum: [us, eu]
+        default: apac
+"#,
+    )
+    .unwrap();
+
+    let metadata = empty_metadata();
+    let config = PatchConfig::new(&metadata).with_project_config(&project);
+
+    let err =
+        tonic_rest_openapi::patch("openapi: 3.0.3\ninfo: {}\npaths: {}\n", &config).unwrap_err();
+    assert!(
+        matches!(err, tonic_rest_openapi::Error::InvalidServerVariable { ref variable, .. } if variable == "region"),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+fn oauth_flow_missing_token_url_rejected() {
+    let project: ProjectConfig = serde_yaml_ng::from_str(
+        r"
+security_schemes:
+  oauth:
+    type: oauth2
+    flows:
+      clientCredentials:
+        scopes:
+          users.read: Read users
+",
+    )
+    .unwrap();
+
+    let metadata = empty_metadata();
+    let config = PatchConfig::new(&metadata).with_project_config(&project);
+
+    let input = r"
+openapi: 3.0.3
+info:
+  title: Test
+  version: 0.1.0
+paths: {}
+";
+
+    let err = tonic_rest_openapi::patch(input, &config).unwrap_err();
+    assert!(
+        matches!(
+            &err,
+            tonic_rest_openapi::Error::InvalidSecurityScheme { name, reason }
+                if name == "oauth" && reason.contains("tokenUrl")
+        ),
+        "unexpected error: {err}",
+    );
+}
+
 #[test]
 fn info_enrichment_pipeline() {
     let input = r"
@@ -675,6 +839,7 @@ paths: {}
                 description: Some("Full documentation".to_string()),
             }),
             terms_of_service: Some("https://example.com/tos".to_string()),
+            ..InfoOverrides::default()
         });
 
     let result = run_patch(input, &config);
@@ -870,6 +1035,124 @@ components:
     assert!(!login_responses.contains_key("201"));
 }
 
+#[test]
+fn create_response_overrides_pipeline() {
+    let input = r"
+openapi: 3.0.3
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/settings:
+    post:
+      operationId: SettingsService_CreateOrUpdateSettings
+      responses:
+        '200':
+          description: OK
+  /v1/users:import:
+    post:
+      operationId: UserService_ImportUsers
+      responses:
+        '200':
+          description: OK
+components:
+  schemas: {}
+";
+
+    let mut metadata = empty_metadata();
+    metadata.set_operation_ids(vec![
+        OperationEntry {
+            method_name: "CreateOrUpdateSettings".to_string(),
+            operation_id: "SettingsService_CreateOrUpdateSettings".to_string(),
+        },
+        OperationEntry {
+            method_name: "ImportUsers".to_string(),
+            operation_id: "UserService_ImportUsers".to_string(),
+        },
+    ]);
+
+    let config = PatchConfig::new(&metadata)
+        .upgrade_to_3_1(false)
+        .annotate_sse(false)
+        .inject_validation(false)
+        .add_security(false)
+        .inline_request_bodies(false)
+        .flatten_uuid_refs(false)
+        .created_methods(&["ImportUsers"])
+        .not_created_methods(&["CreateOrUpdateSettings"]);
+
+    let result = run_patch(input, &config);
+
+    // Excluded upsert keeps 200 despite its `Create` prefix
+    let settings = result["paths"]["/v1/settings"]["post"]["responses"]
+        .as_mapping()
+        .unwrap();
+    assert!(settings.contains_key("200"));
+    assert!(!settings.contains_key("201"));
+
+    // Included method becomes 201 without matching the heuristic
+    let import = result["paths"]["/v1/users:import"]["post"]["responses"]
+        .as_mapping()
+        .unwrap();
+    assert!(import.contains_key("201"));
+    assert!(!import.contains_key("200"));
+}
+
+#[test]
+fn liveness_probe_drops_injected_errors_pipeline() {
+    let input = r"
+openapi: 3.0.3
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /health/live:
+    get:
+      operationId: HealthService_Live
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                type: object
+        default:
+          description: Default error response
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/Status'
+components:
+  schemas:
+    Status:
+      type: object
+";
+
+    let metadata = empty_metadata();
+    let config = PatchConfig::new(&metadata)
+        .upgrade_to_3_1(false)
+        .annotate_sse(false)
+        .inject_validation(false)
+        .add_security(false)
+        .inline_request_bodies(false)
+        .flatten_uuid_refs(false)
+        .explicit_error_responses(true)
+        .rate_limit_headers(true)
+        .liveness_path("/health/live");
+
+    let result = run_patch(input, &config);
+
+    let responses = result["paths"]["/health/live"]["get"]["responses"]
+        .as_mapping()
+        .unwrap();
+    let codes: Vec<_> = responses.keys().filter_map(Value::as_str).collect();
+    assert_eq!(codes, vec!["200"]);
+    assert_eq!(
+        responses["200"]["content"]["text/plain"]["schema"]["type"].as_str(),
+        Some("string")
+    );
+}
+
 #[test]
 fn unspecified_stripped_from_schemas_pipeline() {
     let input = r"
@@ -1141,3 +1424,1956 @@ paths:
     assert_eq!(param["required"].as_bool().unwrap(), false);
     assert_eq!(param["schema"]["type"].as_str().unwrap(), "string");
 }
+
+#[test]
+fn code_samples_pipeline() {
+    let input = r"
+openapi: 3.0.3
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/users/{userId.value}:
+    get:
+      operationId: UserService_GetUser
+      parameters:
+        - name: userId.value
+          in: path
+          required: true
+          schema:
+            type: string
+      responses:
+        '200':
+          description: OK
+";
+
+    let mut metadata = empty_metadata();
+    metadata.set_operation_ids(vec![OperationEntry {
+        method_name: "GetUser".to_string(),
+        operation_id: "UserService_GetUser".to_string(),
+    }]);
+
+    let templates = HashMap::from([(
+        "GetUser".to_string(),
+        vec![CodeSample {
+            lang: "Shell".to_string(),
+            label: Some("curl".to_string()),
+            source: "curl -X {method} https://api.example.com{path}".to_string(),
+        }],
+    )]);
+
+    let config = PatchConfig::new(&metadata)
+        .add_security(false)
+        .code_sample_templates(templates);
+
+    let result = run_patch(input, &config);
+
+    // Rendered against the flattened path template
+    let sample = &result["paths"]["/v1/users/{userId}"]["get"]["x-codeSamples"][0];
+    assert_eq!(sample["lang"].as_str().unwrap(), "Shell");
+    assert_eq!(sample["label"].as_str().unwrap(), "curl");
+    assert_eq!(
+        sample["source"].as_str().unwrap(),
+        "curl -X GET https://api.example.com/v1/users/{userId}"
+    );
+}
+
+#[test]
+fn code_samples_unknown_method_errors() {
+    let input = r"
+openapi: 3.0.3
+info:
+  title: Test
+  version: 0.1.0
+paths: {}
+";
+
+    let metadata = empty_metadata();
+    let templates = HashMap::from([(
+        "Missing".to_string(),
+        vec![CodeSample {
+            lang: "Shell".to_string(),
+            label: None,
+            source: "curl {path}".to_string(),
+        }],
+    )]);
+    let config = PatchConfig::new(&metadata).code_sample_templates(templates);
+
+    assert!(tonic_rest_openapi::patch(input, &config).is_err());
+}
+
+#[test]
+fn operation_security_pipeline() {
+    let input = r"
+openapi: 3.0.3
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/admin/keys:
+    post:
+      operationId: AdminService_RotateKeys
+      responses:
+        '200':
+          description: OK
+  /v1/users:
+    get:
+      operationId: UserService_ListUsers
+      responses:
+        '200':
+          description: OK
+components:
+  schemas: {}
+  securitySchemes:
+    mtls:
+      type: mutualTLS
+";
+
+    let mut metadata = empty_metadata();
+    metadata.set_operation_ids(vec![
+        OperationEntry {
+            method_name: "RotateKeys".to_string(),
+            operation_id: "AdminService_RotateKeys".to_string(),
+        },
+        OperationEntry {
+            method_name: "ListUsers".to_string(),
+            operation_id: "UserService_ListUsers".to_string(),
+        },
+    ]);
+
+    let config = PatchConfig::new(&metadata).operation_security(HashMap::from([(
+        "RotateKeys".to_string(),
+        vec!["mtls".to_string()],
+    )]));
+
+    let result = run_patch(input, &config);
+
+    // mTLS replaces Bearer on the private admin operation
+    let admin = result["paths"]["/v1/admin/keys"]["post"]["security"]
+        .as_sequence()
+        .unwrap();
+    assert_eq!(admin.len(), 1);
+    assert!(admin[0].as_mapping().unwrap().contains_key("mtls"));
+
+    // Other operations keep the global Bearer requirement
+    assert!(
+        result["paths"]["/v1/users"]["get"]
+            .get("security")
+            .is_none()
+    );
+    assert!(
+        result["security"][0]
+            .as_mapping()
+            .unwrap()
+            .contains_key("bearerAuth")
+    );
+    assert!(
+        result["components"]["securitySchemes"]
+            .as_mapping()
+            .unwrap()
+            .contains_key("mtls")
+    );
+}
+
+#[test]
+fn proto_source_pipeline() {
+    let input = r"
+openapi: 3.0.3
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/users/{userId}:
+    get:
+      operationId: UserService_GetUser
+      responses:
+        '200':
+          description: OK
+";
+
+    let mut metadata = empty_metadata();
+    metadata.set_method_sources(vec![MethodSource {
+        operation_id: "UserService_GetUser".to_string(),
+        file: "users/v1/users.proto".to_string(),
+        service: "users.v1.UserService".to_string(),
+        method: "GetUser".to_string(),
+        line: Some(27),
+    }]);
+    let config = PatchConfig::new(&metadata).annotate_proto_source(true);
+
+    let result = run_patch(input, &config);
+
+    let source = &result["paths"]["/v1/users/{userId}"]["get"]["x-proto-source"];
+    assert_eq!(source["file"].as_str().unwrap(), "users/v1/users.proto");
+    assert_eq!(source["service"].as_str().unwrap(), "users.v1.UserService");
+    assert_eq!(source["method"].as_str().unwrap(), "GetUser");
+    assert_eq!(source["line"].as_u64(), Some(27));
+
+    let config = PatchConfig::new(&metadata);
+    let result = run_patch(input, &config);
+    assert!(
+        result["paths"]["/v1/users/{userId}"]["get"]
+            .get("x-proto-source")
+            .is_none()
+    );
+}
+
+#[test]
+fn pagination_pipeline() {
+    let input = r"
+openapi: 3.0.3
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/users:
+    get:
+      operationId: UserService_ListUsers
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/users.v1.ListUsersResponse'
+components:
+  schemas:
+    users.v1.ListUsersResponse:
+      type: object
+      properties:
+        users:
+          type: array
+          items:
+            $ref: '#/components/schemas/users.v1.User'
+        nextPageToken:
+          type: string
+    users.v1.User:
+      type: object
+      properties:
+        name:
+          type: string
+";
+
+    let mut metadata = empty_metadata();
+    metadata.set_paginated_responses(vec![PaginatedResponse {
+        schema: "users.v1.ListUsersResponse".to_string(),
+        items_field: "users".to_string(),
+        token_field: "nextPageToken".to_string(),
+        request_token_field: Some("pageToken".to_string()),
+        max_page_size: None,
+    }]);
+    let config = PatchConfig::new(&metadata);
+
+    let result = run_patch(input, &config);
+
+    let schema = &result["components"]["schemas"]["users.v1.ListUsersResponse"];
+    assert_eq!(
+        schema["x-pagination"]["token_field"].as_str().unwrap(),
+        "nextPageToken"
+    );
+    assert_eq!(
+        schema["x-pagination"]["items_field"].as_str().unwrap(),
+        "users"
+    );
+    assert_eq!(
+        schema["properties"]["nextPageToken"]["readOnly"].as_bool(),
+        Some(true)
+    );
+
+    // Disabled toggle leaves the schema untouched
+    let config = PatchConfig::new(&metadata).skip_pagination_annotation();
+    let result = run_patch(input, &config);
+    let schema = &result["components"]["schemas"]["users.v1.ListUsersResponse"];
+    assert!(schema.get("x-pagination").is_none());
+}
+
+#[test]
+fn pagination_link_header_on_list_operations_only() {
+    let input = r"
+openapi: 3.1.0
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/users:
+    get:
+      operationId: UserService_ListUsers
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/users.v1.ListUsersResponse'
+  /v1/users/{userId}:
+    get:
+      operationId: UserService_GetUser
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/users.v1.User'
+components:
+  schemas:
+    users.v1.ListUsersResponse:
+      type: object
+      properties:
+        users:
+          type: array
+          items:
+            $ref: '#/components/schemas/users.v1.User'
+        nextPageToken:
+          type: string
+    users.v1.User:
+      type: object
+      properties:
+        name:
+          type: string
+";
+    let mut metadata = empty_metadata();
+    metadata.set_paginated_responses(vec![PaginatedResponse {
+        schema: "users.v1.ListUsersResponse".to_string(),
+        items_field: "users".to_string(),
+        token_field: "nextPageToken".to_string(),
+        request_token_field: Some("pageToken".to_string()),
+        max_page_size: None,
+    }]);
+    let config = PatchConfig::new(&metadata).pagination_link_header(true);
+    let result = run_patch(input, &config);
+
+    let list = &result["paths"]["/v1/users"]["get"]["responses"]["200"];
+    let link = &list["headers"]["Link"];
+    assert_eq!(link["schema"]["type"], "string");
+    assert!(link["description"].as_str().unwrap().contains("RFC 8288"));
+
+    let get = &result["paths"]["/v1/users/{userId}"]["get"]["responses"]["200"];
+    assert!(get.get("headers").is_none(), "non-list operation: {get:?}");
+
+    // Off by default
+    let result = run_patch(input, &PatchConfig::new(&metadata));
+    assert!(
+        result["paths"]["/v1/users"]["get"]["responses"]["200"]
+            .get("headers")
+            .is_none()
+    );
+}
+
+#[test]
+#[expect(clippy::too_many_lines)]
+fn page_size_bound_becomes_max_items() {
+    use prost::Message as _;
+    use tonic_rest_core::descriptor::{
+        DescriptorProto, FieldDescriptorProto, FieldOptions, FieldRules, FileDescriptorProto,
+        FileDescriptorSet, Int32Rules, MethodDescriptorProto, ServiceDescriptorProto, field_label,
+        field_type,
+    };
+
+    let field = |name: &str, ty: i32| FieldDescriptorProto {
+        name: Some(name.to_string()),
+        label: None,
+        proto3_optional: None,
+        default_value: None,
+        json_name: None,
+        oneof_index: None,
+        r#type: Some(ty),
+        type_name: None,
+        options: None,
+    };
+    let message = |name: &str, field: Vec<FieldDescriptorProto>| DescriptorProto {
+        name: Some(name.to_string()),
+        field,
+        oneof_decl: vec![],
+        options: None,
+        nested_type: vec![],
+    };
+    let fdset = FileDescriptorSet {
+        file: vec![FileDescriptorProto {
+            name: Some("users.proto".to_string()),
+            package: Some("users.v1".to_string()),
+            message_type: vec![
+                message(
+                    "ListUsersRequest",
+                    vec![FieldDescriptorProto {
+                        options: Some(FieldOptions {
+                            rules: Some(FieldRules {
+                                int32: Some(Int32Rules {
+                                    gte: Some(1),
+                                    lte: Some(100),
+                                    ..Default::default()
+                                }),
+                                ..Default::default()
+                            }),
+                        }),
+                        ..field("page_size", field_type::INT32)
+                    }],
+                ),
+                message(
+                    "ListUsersResponse",
+                    vec![
+                        FieldDescriptorProto {
+                            label: Some(field_label::REPEATED),
+                            ..field("names", field_type::STRING)
+                        },
+                        field("next_page_token", field_type::STRING),
+                    ],
+                ),
+            ],
+            enum_type: vec![],
+            service: vec![ServiceDescriptorProto {
+                name: Some("UserService".to_string()),
+                method: vec![MethodDescriptorProto {
+                    name: Some("ListUsers".to_string()),
+                    input_type: Some(".users.v1.ListUsersRequest".to_string()),
+                    output_type: Some(".users.v1.ListUsersResponse".to_string()),
+                    options: None,
+                    client_streaming: None,
+                    server_streaming: None,
+                }],
+                options: None,
+            }],
+            source_code_info: None,
+        }],
+    };
+    let metadata = tonic_rest_openapi::discover(&fdset.encode_to_vec()).unwrap();
+
+    let input = r"
+openapi: 3.1.0
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/users:
+    get:
+      operationId: UserService_ListUsers
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/users.v1.ListUsersResponse'
+components:
+  schemas:
+    users.v1.ListUsersResponse:
+      type: object
+      properties:
+        names:
+          type: array
+          items:
+            type: string
+        nextPageToken:
+          type: string
+";
+    let config = PatchConfig::new(&metadata).page_max_items(true);
+    let result = run_patch(input, &config);
+    let names =
+        &result["components"]["schemas"]["users.v1.ListUsersResponse"]["properties"]["names"];
+    assert_eq!(names["maxItems"].as_u64(), Some(100));
+
+    // Off by default
+    let result = run_patch(input, &PatchConfig::new(&metadata));
+    let names =
+        &result["components"]["schemas"]["users.v1.ListUsersResponse"]["properties"]["names"];
+    assert!(names.get("maxItems").is_none());
+}
+
+// ── Strict $ref checking ──────────────────────────────────────────────────
+
+const DANGLING_REF_SPEC: &str = r"
+openapi: 3.0.3
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/items:
+    get:
+      operationId: ItemService_GetItem
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/Missing'
+components:
+  schemas:
+    Item:
+      type: object
+      properties:
+        id:
+          type: string
+";
+
+#[test]
+fn strict_refs_rejects_dangling_ref() {
+    let metadata = empty_metadata();
+    let config = PatchConfig::new(&metadata).strict_refs(true);
+
+    let err = tonic_rest_openapi::patch(DANGLING_REF_SPEC, &config).unwrap_err();
+    match err {
+        tonic_rest_openapi::Error::UnresolvedRefs { refs } => {
+            assert_eq!(refs, vec!["#/components/schemas/Missing".to_string()]);
+        }
+        other => panic!("expected UnresolvedRefs, got {other:?}"),
+    }
+}
+
+#[test]
+fn dangling_ref_allowed_without_strict_mode() {
+    let metadata = empty_metadata();
+    let config = PatchConfig::new(&metadata);
+
+    let result = run_patch(DANGLING_REF_SPEC, &config);
+    let schema = &result["paths"]["/v1/items"]["get"]["responses"]["200"]["content"]["application/json"]
+        ["schema"];
+    assert_eq!(
+        schema["$ref"].as_str().unwrap(),
+        "#/components/schemas/Missing"
+    );
+}
+
+#[test]
+fn validate_output_rejects_dangling_ref() {
+    let metadata = empty_metadata();
+    let config = PatchConfig::new(&metadata).validate_output(true);
+
+    let err = tonic_rest_openapi::patch(DANGLING_REF_SPEC, &config).unwrap_err();
+    match err {
+        tonic_rest_openapi::Error::InvalidSpec { path, reason } => {
+            assert_eq!(
+                path,
+                "/paths/~1v1~1items/get/responses/200/content/application~1json/schema"
+            );
+            assert_eq!(reason, "dangling $ref `#/components/schemas/Missing`");
+        }
+        other => panic!("expected InvalidSpec, got {other:?}"),
+    }
+}
+
+#[test]
+fn validate_output_rejects_empty_required() {
+    let input = r"
+openapi: 3.1.0
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/items:
+    get:
+      operationId: ItemService_GetItem
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/items.v1.Item'
+components:
+  schemas:
+    items.v1.Item:
+      type: object
+      required: []
+      properties:
+        id:
+          type: string
+";
+    let metadata = empty_metadata();
+    let err = tonic_rest_openapi::patch(input, &PatchConfig::new(&metadata).validate_output(true))
+        .unwrap_err();
+    match err {
+        tonic_rest_openapi::Error::InvalidSpec { path, reason } => {
+            assert_eq!(path, "/components/schemas/items.v1.Item/required");
+            assert_eq!(reason, "empty `required` array");
+        }
+        other => panic!("expected InvalidSpec, got {other:?}"),
+    }
+
+    // Off by default
+    tonic_rest_openapi::patch(input, &PatchConfig::new(&metadata)).unwrap();
+}
+
+#[test]
+fn bytes_encoding_from_discovered_metadata() {
+    use prost::Message as _;
+    use tonic_rest_core::descriptor::{
+        DescriptorProto, FieldDescriptorProto, FileDescriptorProto, FileDescriptorSet, field_type,
+    };
+
+    let field = |name: &str, ty: i32| FieldDescriptorProto {
+        name: Some(name.to_string()),
+        label: None,
+        proto3_optional: None,
+        default_value: None,
+        json_name: None,
+        oneof_index: None,
+        r#type: Some(ty),
+        type_name: None,
+        options: None,
+    };
+    let fdset = FileDescriptorSet {
+        file: vec![FileDescriptorProto {
+            name: Some("files.proto".to_string()),
+            package: Some("files.v1".to_string()),
+            message_type: vec![DescriptorProto {
+                name: Some("File".to_string()),
+                field: vec![
+                    field("name", field_type::STRING),
+                    field("content", field_type::BYTES),
+                ],
+                oneof_decl: vec![],
+                options: None,
+                nested_type: vec![],
+            }],
+            enum_type: vec![],
+            service: vec![],
+            source_code_info: None,
+        }],
+    };
+    let metadata = tonic_rest_openapi::discover(&fdset.encode_to_vec()).unwrap();
+
+    let input = r"
+openapi: 3.1.0
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/files/{name}:
+    get:
+      operationId: FileService_GetFile
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/files.v1.File'
+components:
+  schemas:
+    files.v1.File:
+      type: object
+      properties:
+        name:
+          type: string
+        content:
+          type: string
+          format: bytes
+";
+    let config = PatchConfig::new(&metadata).bytes_content_media_type("application/pdf");
+    let result = run_patch(input, &config);
+
+    let props = &result["components"]["schemas"]["files.v1.File"]["properties"];
+    assert_eq!(props["content"]["contentEncoding"].as_str(), Some("base64"));
+    assert_eq!(
+        props["content"]["contentMediaType"].as_str(),
+        Some("application/pdf")
+    );
+    assert!(props["name"].get("contentEncoding").is_none());
+}
+
+#[test]
+fn wrapper_fields_become_nullable_scalars() {
+    use prost::Message as _;
+    use tonic_rest_core::descriptor::{
+        DescriptorProto, FieldDescriptorProto, FileDescriptorProto, FileDescriptorSet, field_type,
+    };
+
+    let fdset = FileDescriptorSet {
+        file: vec![FileDescriptorProto {
+            name: Some("items.proto".to_string()),
+            package: Some("items.v1".to_string()),
+            message_type: vec![DescriptorProto {
+                name: Some("Item".to_string()),
+                field: vec![FieldDescriptorProto {
+                    name: Some("archived".to_string()),
+                    label: None,
+                    proto3_optional: None,
+                    default_value: None,
+                    json_name: None,
+                    oneof_index: None,
+                    r#type: Some(field_type::MESSAGE),
+                    type_name: Some(".google.protobuf.BoolValue".to_string()),
+                    options: None,
+                }],
+                oneof_decl: vec![],
+                options: None,
+                nested_type: vec![],
+            }],
+            enum_type: vec![],
+            service: vec![],
+            source_code_info: None,
+        }],
+    };
+    let metadata = tonic_rest_openapi::discover(&fdset.encode_to_vec()).unwrap();
+
+    let input = r"
+openapi: 3.1.0
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/items/{id}:
+    get:
+      operationId: ItemService_GetItem
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/items.v1.Item'
+components:
+  schemas:
+    items.v1.Item:
+      type: object
+      properties:
+        archived:
+          $ref: '#/components/schemas/google.protobuf.BoolValue'
+    google.protobuf.BoolValue:
+      type: object
+      properties:
+        value:
+          type: boolean
+      description: Wrapper message for `bool`.
+";
+    let result = run_patch(input, &PatchConfig::new(&metadata));
+
+    let schemas = &result["components"]["schemas"];
+    let archived = &schemas["items.v1.Item"]["properties"]["archived"];
+    assert!(
+        archived.get("$ref").is_none(),
+        "ref not inlined: {archived:?}"
+    );
+    let types: Vec<&str> = archived["type"]
+        .as_sequence()
+        .unwrap()
+        .iter()
+        .filter_map(|v| v.as_str())
+        .collect();
+    assert_eq!(types, ["boolean", "null"]);
+    assert!(schemas.get("google.protobuf.BoolValue").is_none());
+}
+
+#[test]
+fn proto3_optional_fields_become_nullable() {
+    let mut metadata = empty_metadata();
+    metadata.set_optional_fields(vec![
+        OptionalField {
+            schema: "users.v1.User".to_string(),
+            field: "nickName".to_string(),
+        },
+        OptionalField {
+            schema: "users.v1.User".to_string(),
+            field: "tier".to_string(),
+        },
+    ]);
+
+    let input = r"
+openapi: 3.0.3
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/users/{id}:
+    get:
+      operationId: UserService_GetUser
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/users.v1.User'
+components:
+  schemas:
+    users.v1.User:
+      type: object
+      properties:
+        name:
+          type: string
+        nickName:
+          type: string
+        tier:
+          type: string
+          enum: [FREE, PRO]
+";
+    let result = run_patch(input, &PatchConfig::new(&metadata));
+
+    let props = &result["components"]["schemas"]["users.v1.User"]["properties"];
+    let types: Vec<&str> = props["nickName"]["type"]
+        .as_sequence()
+        .unwrap()
+        .iter()
+        .filter_map(|v| v.as_str())
+        .collect();
+    assert_eq!(types, ["string", "null"]);
+    assert_eq!(props["name"]["type"].as_str(), Some("string"));
+    let tier_enum = props["tier"]["enum"].as_sequence().unwrap();
+    assert_eq!(tier_enum.last(), Some(&Value::Null));
+
+    // 3.0 output keeps the 3.0 `nullable` keyword instead.
+    let result = run_patch(input, &PatchConfig::new(&metadata).upgrade_to_3_1(false));
+    let nick = &result["components"]["schemas"]["users.v1.User"]["properties"]["nickName"];
+    assert_eq!(nick["type"].as_str(), Some("string"));
+    assert_eq!(nick["nullable"], Value::Bool(true));
+}
+
+#[test]
+fn oneof_members_become_one_of_alternatives() {
+    let mut metadata = empty_metadata();
+    metadata.set_oneofs(vec![OneofInfo {
+        schema: "auth.v1.LoginRequest".to_string(),
+        name: "credential".to_string(),
+        fields: vec!["password".to_string(), "token".to_string()],
+        discriminator: Some("kind".to_string()),
+    }]);
+
+    let input = r"
+openapi: 3.1.0
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/login:
+    get:
+      operationId: AuthService_GetLogin
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/auth.v1.LoginRequest'
+components:
+  schemas:
+    auth.v1.LoginRequest:
+      type: object
+      properties:
+        email:
+          type: string
+        kind:
+          type: string
+        password:
+          type: string
+        token:
+          type: string
+";
+    let result = run_patch(input, &PatchConfig::new(&metadata));
+
+    let schema = &result["components"]["schemas"]["auth.v1.LoginRequest"];
+    let props = schema["properties"].as_mapping().unwrap();
+    assert!(props.contains_key("email"));
+    assert!(!props.contains_key("password"), "member left in properties");
+
+    let alternatives = schema["oneOf"].as_sequence().unwrap();
+    assert_eq!(alternatives.len(), 2);
+    assert_eq!(alternatives[0]["required"][0].as_str(), Some("password"));
+    assert_eq!(
+        alternatives[1]["properties"]["token"]["type"].as_str(),
+        Some("string")
+    );
+    assert_eq!(
+        schema["discriminator"]["propertyName"].as_str(),
+        Some("kind")
+    );
+}
+
+#[test]
+fn map_field_becomes_additional_properties() {
+    use prost::Message as _;
+    use tonic_rest_core::descriptor::{
+        DescriptorProto, FieldDescriptorProto, FileDescriptorProto, FileDescriptorSet,
+        MessageOptions, field_label, field_type,
+    };
+
+    let field = |name: &str, ty: i32| FieldDescriptorProto {
+        name: Some(name.to_string()),
+        label: None,
+        proto3_optional: None,
+        default_value: None,
+        json_name: None,
+        oneof_index: None,
+        r#type: Some(ty),
+        type_name: None,
+        options: None,
+    };
+    let fdset = FileDescriptorSet {
+        file: vec![FileDescriptorProto {
+            name: Some("items.proto".to_string()),
+            package: Some("items.v1".to_string()),
+            message_type: vec![DescriptorProto {
+                name: Some("Item".to_string()),
+                field: vec![FieldDescriptorProto {
+                    label: Some(field_label::REPEATED),
+                    type_name: Some(".items.v1.Item.LabelsEntry".to_string()),
+                    ..field("labels", field_type::MESSAGE)
+                }],
+                oneof_decl: vec![],
+                options: None,
+                nested_type: vec![DescriptorProto {
+                    name: Some("LabelsEntry".to_string()),
+                    field: vec![
+                        field("key", field_type::STRING),
+                        field("value", field_type::STRING),
+                    ],
+                    oneof_decl: vec![],
+                    options: Some(MessageOptions {
+                        map_entry: Some(true),
+                    }),
+                    nested_type: vec![],
+                }],
+            }],
+            enum_type: vec![],
+            service: vec![],
+            source_code_info: None,
+        }],
+    };
+    let metadata = tonic_rest_openapi::discover(&fdset.encode_to_vec()).unwrap();
+
+    let input = r"
+openapi: 3.1.0
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/items/{id}:
+    get:
+      operationId: ItemService_GetItem
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/items.v1.Item'
+components:
+  schemas:
+    items.v1.Item:
+      type: object
+      properties:
+        labels:
+          type: array
+          items:
+            $ref: '#/components/schemas/items.v1.Item.LabelsEntry'
+          description: Free-form labels.
+    items.v1.Item.LabelsEntry:
+      type: object
+      properties:
+        key:
+          type: string
+        value:
+          type: string
+";
+    let result = run_patch(input, &PatchConfig::new(&metadata));
+
+    let schemas = &result["components"]["schemas"];
+    let labels = &schemas["items.v1.Item"]["properties"]["labels"];
+    assert_eq!(labels["type"].as_str(), Some("object"));
+    assert_eq!(
+        labels["additionalProperties"]["type"].as_str(),
+        Some("string")
+    );
+    assert_eq!(labels["description"].as_str(), Some("Free-form labels."));
+    assert!(labels.get("items").is_none());
+    assert!(schemas.get("items.v1.Item.LabelsEntry").is_none());
+}
+
+#[test]
+fn sanitize_schema_names_rewrites_refs() {
+    let input = r"
+openapi: 3.1.0
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/login:
+    get:
+      operationId: AuthService_Login
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/auth.v1.LoginResponse'
+components:
+  schemas:
+    auth.v1.LoginResponse:
+      type: object
+      properties:
+        user:
+          $ref: '#/components/schemas/auth.v1.User'
+    auth.v1.User:
+      type: object
+      properties:
+        id:
+          type: string
+";
+    let metadata = empty_metadata();
+    let config = PatchConfig::new(&metadata).sanitize_schema_names(SanitizeMode::Underscore);
+    let result = run_patch(input, &config);
+
+    let schemas = result["components"]["schemas"].as_mapping().unwrap();
+    let names: Vec<&str> = schemas.keys().filter_map(Value::as_str).collect();
+    assert_eq!(names, ["auth_v1_LoginResponse", "auth_v1_User"]);
+    assert_eq!(
+        schemas["auth_v1_LoginResponse"]["properties"]["user"]["$ref"].as_str(),
+        Some("#/components/schemas/auth_v1_User")
+    );
+    let response = &result["paths"]["/v1/login"]["get"]["responses"]["200"];
+    assert_eq!(
+        response["content"]["application/json"]["schema"]["$ref"].as_str(),
+        Some("#/components/schemas/auth_v1_LoginResponse")
+    );
+}
+
+#[test]
+fn sanitize_schema_names_reports_collisions() {
+    let input = r"
+openapi: 3.1.0
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/a:
+    get:
+      operationId: A_Get
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                type: object
+                properties:
+                  a:
+                    $ref: '#/components/schemas/a.v1.Item'
+                  b:
+                    $ref: '#/components/schemas/a_v1.Item'
+components:
+  schemas:
+    a.v1.Item:
+      type: object
+    a_v1.Item:
+      type: object
+";
+    let metadata = empty_metadata();
+    let config = PatchConfig::new(&metadata).sanitize_schema_names(SanitizeMode::Underscore);
+    let err = tonic_rest_openapi::patch(input, &config).unwrap_err();
+    match err {
+        tonic_rest_openapi::Error::SchemaNameCollision { name, schemas } => {
+            assert_eq!(name, "a_v1_Item");
+            assert_eq!(schemas, ["a.v1.Item", "a_v1.Item"]);
+        }
+        other => panic!("expected SchemaNameCollision, got {other:?}"),
+    }
+}
+
+#[test]
+fn field_comments_become_property_descriptions() {
+    let mut metadata = empty_metadata();
+    metadata.set_field_descriptions(HashMap::from([(
+        "items.v1.Item".to_string(),
+        HashMap::from([
+            ("displayName".to_string(), "Shown in the UI.".to_string()),
+            ("id".to_string(), "Ignored: already described.".to_string()),
+        ]),
+    )]));
+
+    let input = r"
+openapi: 3.1.0
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/items/{id}:
+    get:
+      operationId: ItemService_GetItem
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/items.v1.Item'
+components:
+  schemas:
+    items.v1.Item:
+      type: object
+      properties:
+        id:
+          type: string
+          description: Item identifier
+        displayName:
+          type: string
+";
+    let result = run_patch(input, &PatchConfig::new(&metadata));
+
+    let props = &result["components"]["schemas"]["items.v1.Item"]["properties"];
+    assert_eq!(props["displayName"]["description"], "Shown in the UI.");
+    assert_eq!(props["id"]["description"], "Item identifier");
+}
+
+#[test]
+fn proto_defaults_become_schema_defaults() {
+    let default = |field: &str, value: &str, proto_type: i32| FieldDefault {
+        schema: "jobs.v1.JobOptions".to_string(),
+        field: field.to_string(),
+        value: value.to_string(),
+        proto_type,
+    };
+    let mut metadata = empty_metadata();
+    metadata.set_field_defaults(vec![
+        default("notify", "true", 8),
+        default("retries", "3", 5),
+        default("timeoutMs", "30000", 3),
+        default("priority", "JOB_PRIORITY_LOW", 14),
+    ]);
+    metadata.set_enum_value_map([("JOB_PRIORITY_LOW".to_string(), "low".to_string())].into());
+
+    let input = r"
+openapi: 3.1.0
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/jobs/defaults:
+    get:
+      operationId: JobService_GetDefaults
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/jobs.v1.JobOptions'
+components:
+  schemas:
+    jobs.v1.JobOptions:
+      type: object
+      properties:
+        notify:
+          type: boolean
+        retries:
+          type: integer
+          format: int32
+        timeoutMs:
+          type: string
+        priority:
+          type: string
+";
+    let result = run_patch(input, &PatchConfig::new(&metadata));
+
+    let props = &result["components"]["schemas"]["jobs.v1.JobOptions"]["properties"];
+    assert_eq!(props["notify"]["default"], Value::Bool(true));
+    assert_eq!(props["retries"]["default"].as_i64(), Some(3));
+    assert_eq!(props["timeoutMs"]["default"], "30000");
+    assert_eq!(props["priority"]["default"], "low");
+}
+
+#[test]
+fn error_response_codes_reference_error_schema() {
+    fn error_ref(response: &Value) -> Option<&str> {
+        response["content"]["application/json"]["schema"]["$ref"].as_str()
+    }
+
+    let input = r"
+openapi: 3.1.0
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/items/{id}:
+    get:
+      operationId: ItemService_GetItem
+      responses:
+        '200':
+          description: OK
+        '404':
+          description: Item not found
+        default:
+          description: Error
+    put:
+      operationId: ItemService_UpdateItem
+      responses:
+        '200':
+          description: OK
+        409:
+          description: Version conflict
+";
+    let metadata = empty_metadata();
+    let config = PatchConfig::new(&metadata).error_response_codes(&[404, 409]);
+    let result = run_patch(input, &config);
+
+    let get = &result["paths"]["/v1/items/{id}"]["get"]["responses"];
+    let put = &result["paths"]["/v1/items/{id}"]["put"]["responses"];
+    let expected = Some(tonic_rest_openapi::DEFAULT_ERROR_SCHEMA_REF);
+    assert_eq!(error_ref(&get["404"]), expected);
+    assert_eq!(error_ref(&get["default"]), expected);
+    // Unquoted YAML key: a numeric mapping key, not a string.
+    assert_eq!(error_ref(&put[409]), expected);
+    assert_eq!(error_ref(&get["200"]), None);
+    assert_eq!(get["404"]["description"], "Item not found");
+    assert!(put.get("404").is_none(), "undeclared codes are not added");
+}
+
+#[test]
+fn default_error_response_expanded_into_explicit_codes() {
+    let input = r"
+openapi: 3.1.0
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/items/{id}:
+    get:
+      operationId: ItemService_GetItem
+      responses:
+        '200':
+          description: OK
+        '404':
+          description: Item not found
+        default:
+          description: Error
+";
+    let metadata = empty_metadata();
+    let config = PatchConfig::new(&metadata)
+        .error_response_codes(&[400, 401, 404, 500])
+        .explicit_error_responses(true);
+    let result = run_patch(input, &config);
+
+    let responses = result["paths"]["/v1/items/{id}"]["get"]["responses"]
+        .as_mapping()
+        .unwrap();
+    let keys: Vec<&str> = responses.keys().filter_map(Value::as_str).collect();
+    assert_eq!(keys, ["200", "404", "400", "401", "500"]);
+    for code in ["400", "401", "404", "500"] {
+        assert_eq!(
+            responses[code]["content"]["application/json"]["schema"]["$ref"].as_str(),
+            Some(tonic_rest_openapi::DEFAULT_ERROR_SCHEMA_REF),
+            "{code} should reference the error schema"
+        );
+    }
+    assert_eq!(responses["404"]["description"], "Item not found");
+    assert_eq!(responses["401"]["description"], "Unauthorized");
+}
+
+#[test]
+fn rate_limit_headers_and_429_documented() {
+    let input = r"
+openapi: 3.1.0
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/items/{id}:
+    get:
+      operationId: ItemService_GetItem
+      responses:
+        '200':
+          description: OK
+        '404':
+          description: Item not found
+";
+    let metadata = empty_metadata();
+    let config = PatchConfig::new(&metadata).rate_limit_headers(true);
+    let result = run_patch(input, &config);
+
+    let responses = &result["paths"]["/v1/items/{id}"]["get"]["responses"];
+    let too_many = &responses["429"];
+    assert_eq!(too_many["description"], "Too Many Requests");
+    assert_eq!(
+        too_many["content"]["application/json"]["schema"]["$ref"].as_str(),
+        Some(tonic_rest_openapi::DEFAULT_ERROR_SCHEMA_REF)
+    );
+    for code in ["200", "429"] {
+        let headers = &responses[code]["headers"];
+        for name in [
+            "X-RateLimit-Limit",
+            "X-RateLimit-Remaining",
+            "X-RateLimit-Reset",
+        ] {
+            assert_eq!(
+                headers[name]["schema"]["type"], "integer",
+                "{code} is missing {name}"
+            );
+        }
+    }
+    assert!(responses["404"].get("headers").is_none());
+}
+
+#[test]
+fn bytes_fields_and_params_use_format_byte() {
+    let mut metadata = empty_metadata();
+    // message Avatar { bytes photo = 1; }
+    metadata.set_bytes_fields(vec![BytesField {
+        schema: "users.v1.Avatar".to_string(),
+        field: "photo".to_string(),
+    }]);
+    metadata.set_bytes_params(vec![BytesParam {
+        method: "get".to_string(),
+        path: "/v1/users/{userId}/avatar".to_string(),
+        name: "etag".to_string(),
+    }]);
+
+    let input = r"
+openapi: 3.1.0
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/users/{userId}/avatar:
+    get:
+      operationId: UserService_GetAvatar
+      parameters:
+        - name: userId
+          in: path
+          required: true
+          schema:
+            type: string
+        - name: etag
+          in: query
+          schema:
+            type: string
+            format: bytes
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/users.v1.Avatar'
+components:
+  schemas:
+    users.v1.Avatar:
+      type: object
+      properties:
+        photo:
+          type: string
+          format: bytes
+";
+    let result = run_patch(input, &PatchConfig::new(&metadata));
+
+    let photo = &result["components"]["schemas"]["users.v1.Avatar"]["properties"]["photo"];
+    assert_eq!(photo["type"], "string");
+    assert_eq!(photo["format"], "byte");
+    assert_eq!(photo["contentEncoding"], "base64");
+    assert!(photo["example"].is_string());
+
+    let params = &result["paths"]["/v1/users/{userId}/avatar"]["get"]["parameters"];
+    assert_eq!(params[0]["name"], "userId");
+    assert!(params[0]["schema"].get("format").is_none());
+    assert_eq!(params[1]["name"], "etag");
+    assert_eq!(params[1]["schema"]["format"], "byte");
+}
+
+#[test]
+fn info_version_from_env_with_fallback() {
+    let input = r"
+openapi: 3.1.0
+info:
+  title: Test
+  version: 0.0.1
+paths: {}
+";
+    let metadata = empty_metadata();
+    let with_env = |version_env: &str| {
+        let config = PatchConfig::new(&metadata).info(InfoOverrides {
+            version_env: Some(version_env.to_string()),
+            version: Some("0.0.0-dev".to_string()),
+            ..InfoOverrides::default()
+        });
+        run_patch(input, &config)["info"]["version"].clone()
+    };
+
+    // Cargo sets `CARGO_PKG_VERSION` for the test process, as CI would `BUILD_VERSION`.
+    assert_eq!(with_env("CARGO_PKG_VERSION"), env!("CARGO_PKG_VERSION"));
+    assert_eq!(with_env("TONIC_REST_TEST_UNSET_VERSION"), "0.0.0-dev");
+
+    let untouched = run_patch(input, &PatchConfig::new(&metadata));
+    assert_eq!(untouched["info"]["version"], "0.0.1");
+}
+
+#[test]
+fn header_fields_become_header_parameters() {
+    let input = r"
+openapi: 3.0.3
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/items:
+    get:
+      operationId: ItemService_ListItems
+      parameters:
+        - name: clientVersion
+          in: query
+          schema:
+            type: string
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/items.v1.CreateItemRequest'
+    post:
+      operationId: ItemService_CreateItem
+      requestBody:
+        content:
+          application/json:
+            schema:
+              $ref: '#/components/schemas/items.v1.CreateItemRequest'
+      responses:
+        '200':
+          description: OK
+components:
+  schemas:
+    items.v1.CreateItemRequest:
+      type: object
+      required: [clientVersion]
+      properties:
+        name:
+          type: string
+        clientVersion:
+          type: string
+          description: Version of the calling client.
+";
+    let metadata = empty_metadata();
+    let config = PatchConfig::new(&metadata).header_fields(&["client_version"]);
+    let result = run_patch(input, &config);
+
+    let get_param = &result["paths"]["/v1/items"]["get"]["parameters"][0];
+    assert_eq!(get_param["name"], "client-version");
+    assert_eq!(get_param["in"], "header");
+
+    let post = &result["paths"]["/v1/items"]["post"];
+    let header = &post["parameters"][0];
+    assert_eq!(header["name"], "client-version");
+    assert_eq!(header["in"], "header");
+    assert_eq!(header["required"], true);
+    assert_eq!(header["description"], "Version of the calling client.");
+    assert_eq!(header["schema"]["type"], "string");
+
+    let body = &post["requestBody"]["content"]["application/json"]["schema"];
+    assert!(body["properties"].get("name").is_some());
+    assert!(body["properties"].get("clientVersion").is_none());
+    assert!(body.get("required").is_none());
+
+    // The shared component keeps the field for the response.
+    let component = &result["components"]["schemas"]["items.v1.CreateItemRequest"];
+    assert!(component["properties"].get("clientVersion").is_some());
+}
+
+#[test]
+fn form_urlencoded_endpoints_switch_request_media_type() {
+    let input = r"
+openapi: 3.0.3
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /oauth/token:
+    post:
+      operationId: AuthService_Token
+      requestBody:
+        content:
+          application/json:
+            schema:
+              $ref: '#/components/schemas/auth.v1.TokenRequest'
+      responses:
+        '200':
+          description: OK
+  /v1/login:
+    post:
+      operationId: AuthService_Login
+      requestBody:
+        content:
+          application/json:
+            schema:
+              $ref: '#/components/schemas/auth.v1.TokenRequest'
+      responses:
+        '200':
+          description: OK
+components:
+  schemas:
+    auth.v1.TokenRequest:
+      type: object
+      properties:
+        grantType:
+          type: string
+";
+    let metadata = empty_metadata();
+    let config = PatchConfig::new(&metadata).form_urlencoded_endpoints(&[FormUrlEncodedEndpoint {
+        path: "/oauth/token".to_string(),
+        method: "POST".to_string(),
+    }]);
+    let result = run_patch(input, &config);
+
+    let token = &result["paths"]["/oauth/token"]["post"]["requestBody"]["content"];
+    assert!(token.get("application/json").is_none());
+    let form = &token["application/x-www-form-urlencoded"]["schema"];
+    assert_eq!(form["type"], "object");
+    assert_eq!(form["properties"]["grantType"]["type"], "string");
+
+    let login = &result["paths"]["/v1/login"]["post"]["requestBody"]["content"];
+    assert!(login.get("application/json").is_some());
+    assert!(login.get("application/x-www-form-urlencoded").is_none());
+}
+
+#[test]
+fn callbacks_pipeline() {
+    let input = r"
+openapi: 3.0.3
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/subscriptions:
+    post:
+      operationId: WebhookService_CreateSubscription
+      responses:
+        '200':
+          description: OK
+components:
+  schemas:
+    webhooks.v1.Delivery:
+      type: object
+      properties:
+        eventId:
+          type: string
+";
+    let mut metadata = empty_metadata();
+    metadata.set_operation_ids(vec![OperationEntry {
+        method_name: "CreateSubscription".to_string(),
+        operation_id: "WebhookService_CreateSubscription".to_string(),
+    }]);
+
+    let config = PatchConfig::new(&metadata)
+        .add_security(false)
+        .callbacks(HashMap::from([(
+            "CreateSubscription".to_string(),
+            vec![CallbackDef {
+                event: "onDelivery".to_string(),
+                url: "{$request.body#/callbackUrl}".to_string(),
+                request_schema_ref: "#/components/schemas/webhooks.v1.Delivery".to_string(),
+            }],
+        )]));
+    let result = run_patch(input, &config);
+
+    let post = &result["paths"]["/v1/subscriptions"]["post"]["callbacks"]["onDelivery"]["{$request.body#/callbackUrl}"]
+        ["post"];
+    assert_eq!(
+        post["requestBody"]["content"]["application/json"]["schema"]["$ref"],
+        "#/components/schemas/webhooks.v1.Delivery"
+    );
+    assert_eq!(post["requestBody"]["required"], true);
+    assert_eq!(post["responses"]["200"]["description"], "Callback received");
+
+    // Referenced only by the callback, yet kept by orphan removal
+    assert!(
+        result["components"]["schemas"]
+            .get("webhooks.v1.Delivery")
+            .is_some()
+    );
+}
+
+#[test]
+fn enum_case_policies() {
+    let input = r"
+openapi: 3.0.3
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/health:
+    get:
+      operationId: HealthService_Check
+      parameters:
+        - name: status
+          in: query
+          schema:
+            type: string
+            enum:
+              - HEALTH_STATUS_UNSPECIFIED
+              - HEALTH_STATUS_PARTIALLY_HEALTHY
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/health.v1.Health'
+components:
+  schemas:
+    health.v1.Health:
+      type: object
+      properties:
+        status:
+          type: string
+          enum:
+            - HEALTH_STATUS_UNSPECIFIED
+            - HEALTH_STATUS_PARTIALLY_HEALTHY
+";
+
+    let mut metadata = empty_metadata();
+    metadata.set_enum_rewrites(vec![EnumRewrite {
+        schema: "health.v1.Health".to_string(),
+        field: "status".to_string(),
+        values: vec!["unspecified".to_string(), "partially_healthy".to_string()],
+        raw_values: vec![
+            "HEALTH_STATUS_UNSPECIFIED".to_string(),
+            "HEALTH_STATUS_PARTIALLY_HEALTHY".to_string(),
+        ],
+        descriptions: vec![String::new(); 2],
+    }]);
+    metadata.set_enum_value_map(
+        [
+            (
+                "HEALTH_STATUS_UNSPECIFIED".to_string(),
+                "unspecified".to_string(),
+            ),
+            (
+                "HEALTH_STATUS_PARTIALLY_HEALTHY".to_string(),
+                "partially_healthy".to_string(),
+            ),
+        ]
+        .into(),
+    );
+
+    for (case, expected) in [
+        (EnumCase::Lower, "partially_healthy"),
+        (EnumCase::Upper, "PARTIALLY_HEALTHY"),
+        (EnumCase::Camel, "partiallyHealthy"),
+        (EnumCase::Original, "HEALTH_STATUS_PARTIALLY_HEALTHY"),
+    ] {
+        let config = PatchConfig::new(&metadata)
+            .add_security(false)
+            .enum_case(case);
+        let result = run_patch(input, &config);
+
+        let expected = vec![Value::String(expected.to_string())];
+        let schema_enum =
+            &result["components"]["schemas"]["health.v1.Health"]["properties"]["status"]["enum"];
+        assert_eq!(schema_enum.as_sequence().unwrap(), &expected, "{case:?}");
+        let param_enum = &result["paths"]["/v1/health"]["get"]["parameters"][0]["schema"]["enum"];
+        assert_eq!(param_enum.as_sequence().unwrap(), &expected, "{case:?}");
+    }
+}
+
+#[test]
+fn enum_varnames_stay_aligned_with_enum() {
+    let input = r"
+openapi: 3.0.3
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/jobs/{id}:
+    get:
+      operationId: JobService_GetJob
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/jobs.v1.Job'
+components:
+  schemas:
+    jobs.v1.Job:
+      type: object
+      properties:
+        state:
+          type: string
+          enum:
+            - JOB_STATE_UNSPECIFIED
+            - JOB_STATE_QUEUED
+            - JOB_STATE_DONE
+        history:
+          type: array
+          items:
+            type: string
+            enum:
+              - JOB_STATE_UNSPECIFIED
+              - JOB_STATE_QUEUED
+              - JOB_STATE_DONE
+";
+
+    let rewrite = |field: &str| EnumRewrite {
+        schema: "jobs.v1.Job".to_string(),
+        field: field.to_string(),
+        values: vec![
+            "unspecified".to_string(),
+            "queued".to_string(),
+            "done".to_string(),
+        ],
+        raw_values: vec![
+            "JOB_STATE_UNSPECIFIED".to_string(),
+            "JOB_STATE_QUEUED".to_string(),
+            "JOB_STATE_DONE".to_string(),
+        ],
+        descriptions: vec![
+            String::new(),
+            "Waiting for a worker.".to_string(),
+            String::new(),
+        ],
+    };
+    let mut metadata = empty_metadata();
+    metadata.set_enum_rewrites(vec![rewrite("state"), rewrite("history")]);
+
+    let config = PatchConfig::new(&metadata)
+        .add_security(false)
+        .enum_varnames(true);
+    let result = run_patch(input, &config);
+
+    let props = &result["components"]["schemas"]["jobs.v1.Job"]["properties"];
+    for schema in [&props["state"], &props["history"]["items"]] {
+        let strings = |key: &str| -> Vec<&str> {
+            schema[key]
+                .as_sequence()
+                .unwrap_or_else(|| panic!("missing {key}"))
+                .iter()
+                .map(|v| v.as_str().unwrap())
+                .collect()
+        };
+        assert_eq!(strings("enum"), ["queued", "done"]);
+        assert_eq!(
+            strings("x-enum-varnames"),
+            ["JOB_STATE_QUEUED", "JOB_STATE_DONE"]
+        );
+        assert_eq!(
+            strings("x-enum-descriptions"),
+            ["Waiting for a worker.", ""]
+        );
+    }
+
+    // Off by default.
+    let result = run_patch(input, &PatchConfig::new(&metadata).add_security(false));
+    let state = &result["components"]["schemas"]["jobs.v1.Job"]["properties"]["state"];
+    assert!(state.get("x-enum-varnames").is_none());
+}
+
+#[test]
+fn operation_badges_pipeline() {
+    let input = r"
+openapi: 3.0.3
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/admin/keys:
+    post:
+      operationId: AdminService_RotateKeys
+      responses:
+        '200':
+          description: OK
+  /v1/users:
+    get:
+      operationId: UserService_ListUsers
+      responses:
+        '200':
+          description: OK
+";
+    let mut metadata = empty_metadata();
+    metadata.set_operation_ids(vec![OperationEntry {
+        method_name: "RotateKeys".to_string(),
+        operation_id: "AdminService_RotateKeys".to_string(),
+    }]);
+
+    let config = PatchConfig::new(&metadata)
+        .add_security(false)
+        .operation_badges(HashMap::from([(
+            "RotateKeys".to_string(),
+            vec![Badge {
+                name: "Admin-only".to_string(),
+                color: Some("#d9534f".to_string()),
+            }],
+        )]));
+    let result = run_patch(input, &config);
+
+    let badges = result["paths"]["/v1/admin/keys"]["post"]["x-badges"]
+        .as_sequence()
+        .unwrap();
+    assert_eq!(badges.len(), 1);
+    assert_eq!(badges[0]["name"], "Admin-only");
+    assert_eq!(badges[0]["color"], "#d9534f");
+
+    assert!(
+        result["paths"]["/v1/users"]["get"]
+            .get("x-badges")
+            .is_none()
+    );
+}
+
+#[test]
+fn body_level_example_composed_from_properties() {
+    let input = r"
+openapi: 3.1.0
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/invites:
+    post:
+      operationId: InviteService_CreateInvite
+      requestBody:
+        content:
+          application/json:
+            schema:
+              $ref: '#/components/schemas/invites.v1.CreateInviteRequest'
+      responses:
+        '200':
+          description: OK
+components:
+  schemas:
+    invites.v1.CreateInviteRequest:
+      type: object
+      properties:
+        email:
+          type: string
+          example: ada@example.com
+        seats:
+          type: integer
+          format: int32
+          example: 3
+";
+    let metadata = empty_metadata();
+
+    let body = |result: &Value| {
+        result["paths"]["/v1/invites"]["post"]["requestBody"]["content"]["application/json"].clone()
+    };
+
+    let result = run_patch(
+        input,
+        &PatchConfig::new(&metadata)
+            .add_security(false)
+            .body_level_examples(true),
+    );
+    let media_type = body(&result);
+    assert_eq!(media_type["example"]["email"], "ada@example.com");
+    assert_eq!(media_type["example"]["seats"].as_i64(), Some(3));
+    // Per-property examples are kept.
+    assert_eq!(
+        media_type["schema"]["properties"]["email"]["example"],
+        "ada@example.com"
+    );
+
+    let result = run_patch(input, &PatchConfig::new(&metadata).add_security(false));
+    assert!(body(&result).get("example").is_none());
+}
+
+#[test]
+fn operation_examples_survive_inlining() {
+    let input = r"
+openapi: 3.0.3
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/auth/login:
+    post:
+      operationId: AuthService_Login
+      requestBody:
+        content:
+          application/json:
+            schema:
+              $ref: '#/components/schemas/auth.v1.LoginRequest'
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/auth.v1.LoginResponse'
+components:
+  schemas:
+    auth.v1.LoginRequest:
+      type: object
+      properties:
+        email:
+          type: string
+        password:
+          type: string
+    auth.v1.LoginResponse:
+      type: object
+      properties:
+        accessToken:
+          type: string
+";
+    let mut metadata = empty_metadata();
+    metadata.set_operation_ids(vec![OperationEntry {
+        method_name: "Login".to_string(),
+        operation_id: "AuthService_Login".to_string(),
+    }]);
+
+    let request: Value =
+        serde_yaml_ng::from_str("email: jane@example.com\npassword: hunter2").unwrap();
+    let config = PatchConfig::new(&metadata)
+        .add_security(false)
+        .operation_examples(HashMap::from([(
+            "AuthService_Login".to_string(),
+            OperationExample {
+                request: Some(request.clone()),
+                response: None,
+            },
+        )]));
+    let result = run_patch(input, &config);
+
+    let media_type =
+        &result["paths"]["/v1/auth/login"]["post"]["requestBody"]["content"]["application/json"];
+    // Inlined, with the pinned example on the media type
+    assert!(media_type["schema"].get("$ref").is_none());
+    assert_eq!(media_type["example"], request);
+
+    let response = &result["paths"]["/v1/auth/login"]["post"]["responses"]["200"]["content"]["application/json"];
+    assert!(response.get("example").is_none());
+}
+
+#[test]
+fn patch_with_report_lists_removed_formats() {
+    let input = r"
+openapi: 3.1.0
+info:
+  title: Test
+  version: 0.1.0
+paths:
+  /v1/items:
+    get:
+      operationId: ItemService_GetItem
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/items.v1.Item'
+components:
+  schemas:
+    items.v1.Item:
+      type: object
+      properties:
+        code:
+          type: string
+          format: custom
+        total:
+          type: string
+          format: int64
+";
+    let metadata = empty_metadata();
+    let config = PatchConfig::new(&metadata).normalize_formats(&[]);
+
+    let (output, report) = tonic_rest_openapi::patch_with_report(input, &config).unwrap();
+    assert_eq!(
+        report.removed_formats,
+        vec![(
+            "#/components/schemas/items.v1.Item/properties/code".to_string(),
+            "custom".to_string(),
+        )],
+    );
+    let result: Value = serde_yaml_ng::from_str(&output).unwrap();
+    let props = &result["components"]["schemas"]["items.v1.Item"]["properties"];
+    assert!(props["code"].get("format").is_none());
+    assert_eq!(props["total"]["format"].as_str(), Some("int64"));
+}
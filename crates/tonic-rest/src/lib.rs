@@ -9,8 +9,14 @@
 //!
 //! - [`RestError`] — Converts [`tonic::Status`] to HTTP JSON error responses
 //! - [`build_tonic_request`] — Bridges Axum requests to [`tonic::Request`]
+//! - [`build_tonic_request_stream`] — Bridges NDJSON bodies to client-streaming requests
+//! - [`apply_response_metadata`] — Copies allowlisted response metadata to HTTP headers
+//! - [`json_not_found_fallback`] — Router fallback answering unmatched routes with a JSON 404
+//! - [`sse_data_event`] — Formats streamed messages as numbered, optionally named SSE events
+//! - [`sse_first_event_id`] — Resumes SSE event numbering after `Last-Event-ID`
 //! - [`sse_error_event`] — Formats gRPC errors as SSE events
 //! - [`grpc_to_http_status`] — Maps gRPC status codes to HTTP status codes
+//! - [`StatusMap`] — Overridable gRPC → HTTP mapping table
 //! - [`grpc_code_name`] — Returns canonical `SCREAMING_SNAKE_CASE` name for a gRPC code
 //!
 //! # Usage
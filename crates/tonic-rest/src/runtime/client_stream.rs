@@ -0,0 +1,238 @@
+//! Client-streaming request bodies — NDJSON → [`tonic::Streaming`].
+//!
+//! Tonic service traits take client-streaming input as
+//! `tonic::Request<tonic::Streaming<T>>`, which can only be built from a
+//! gRPC-framed HTTP body. These helpers parse newline-delimited JSON, re-encode
+//! each message as a gRPC frame, and hand the framed body to tonic's decoder so
+//! the service sees the same stream type as over gRPC.
+
+use std::marker::PhantomData;
+
+use axum::body::{Body, Bytes};
+use axum::http::HeaderMap;
+use futures::stream::{Stream, StreamExt};
+use prost::Message;
+use serde::de::DeserializeOwned;
+use tonic::codec::{DecodeBuf, Decoder, Streaming};
+use tonic::{Request, Status};
+
+use super::request::build_tonic_request;
+
+/// Default cap on one NDJSON line (1 MiB), applied by [`ndjson_streaming`].
+///
+/// The raw body is not covered by axum's `DefaultBodyLimit`, so without a cap
+/// a body with no newline would be buffered whole.
+pub const DEFAULT_MAX_NDJSON_LINE_BYTES: usize = 1024 * 1024;
+
+/// Build a client-streaming [`tonic::Request`] from an NDJSON request body.
+///
+/// Each non-empty line of `body` is deserialized as one `T`; malformed lines
+/// surface as `INVALID_ARGUMENT` items in the stream, and a line longer than
+/// [`DEFAULT_MAX_NDJSON_LINE_BYTES`] ends it with `RESOURCE_EXHAUSTED`.
+/// Headers and the optional extension are forwarded exactly as in
+/// [`build_tonic_request`].
+///
+/// # Examples
+///
+/// ```ignore
+/// async fn upload(
+///     State(service): State<Arc<S>>,
+///     headers: HeaderMap,
+///     body: axum::body::Body,
+/// ) -> Result<Json<UploadResponse>, RestError> {
+///     let req = tonic_rest::build_tonic_request_stream::<UploadChunk, ()>(body, &headers, None);
+///     let response = service.upload(req).await?;
+///     Ok(Json(response.into_inner()))
+/// }
+/// ```
+pub fn build_tonic_request_stream<T, E>(
+    body: Body,
+    headers: &HeaderMap,
+    extension: Option<E>,
+) -> Request<Streaming<T>>
+where
+    T: Message + DeserializeOwned + Default + Send + 'static,
+    E: Clone + Send + Sync + 'static,
+{
+    build_tonic_request(ndjson_streaming(body), headers, extension)
+}
+
+/// Convert an NDJSON request body into a [`tonic::Streaming`] of messages.
+///
+/// Lower-level building block of [`build_tonic_request_stream`], for handlers
+/// that forward a custom header set via
+/// [`build_tonic_request_with_headers`](crate::build_tonic_request_with_headers).
+/// Lines are capped at [`DEFAULT_MAX_NDJSON_LINE_BYTES`].
+#[must_use]
+pub fn ndjson_streaming<T>(body: Body) -> Streaming<T>
+where
+    T: Message + DeserializeOwned + Default + Send + 'static,
+{
+    ndjson_streaming_with_limit(body, DEFAULT_MAX_NDJSON_LINE_BYTES)
+}
+
+/// Like [`ndjson_streaming`], with a custom cap on the bytes of one line.
+///
+/// A line longer than `max_line_bytes` yields `RESOURCE_EXHAUSTED` and ends
+/// the stream without buffering the rest of the body.
+#[must_use]
+pub fn ndjson_streaming_with_limit<T>(body: Body, max_line_bytes: usize) -> Streaming<T>
+where
+    T: Message + DeserializeOwned + Default + Send + 'static,
+{
+    let frames =
+        ndjson_messages::<T>(body, max_line_bytes).map(|msg| msg.and_then(|m| encode_frame(&m)));
+    Streaming::new_request(
+        ProstDecoder::<T>(PhantomData),
+        Body::from_stream(frames),
+        None,
+        None,
+    )
+}
+
+/// Split a body into lines and deserialize each non-blank line.
+fn ndjson_messages<T>(
+    body: Body,
+    max_line_bytes: usize,
+) -> impl Stream<Item = Result<T, Status>> + Send
+where
+    T: DeserializeOwned + Send,
+{
+    let state = (body.into_data_stream(), Vec::<u8>::new(), false);
+    futures::stream::unfold(state, move |(mut chunks, mut buf, mut done)| async move {
+        loop {
+            let newline = buf.iter().position(|&b| b == b'\n');
+            if newline.unwrap_or(buf.len()) > max_line_bytes {
+                let status = Status::resource_exhausted(format!(
+                    "NDJSON line exceeds the {max_line_bytes}-byte limit"
+                ));
+                return Some((Err(status), (chunks, Vec::new(), true)));
+            }
+            if let Some(pos) = newline {
+                let line: Vec<u8> = buf.drain(..=pos).collect();
+                if line.trim_ascii().is_empty() {
+                    continue;
+                }
+                return Some((parse_line(&line), (chunks, buf, done)));
+            }
+            if done {
+                if buf.trim_ascii().is_empty() {
+                    return None;
+                }
+                let line = std::mem::take(&mut buf);
+                return Some((parse_line(&line), (chunks, buf, done)));
+            }
+            match chunks.next().await {
+                Some(Ok(bytes)) => buf.extend_from_slice(&bytes),
+                Some(Err(e)) => {
+                    let status =
+                        Status::invalid_argument(format!("failed to read request body: {e}"));
+                    return Some((Err(status), (chunks, Vec::new(), true)));
+                }
+                None => done = true,
+            }
+        }
+    })
+}
+
+fn parse_line<T: DeserializeOwned>(line: &[u8]) -> Result<T, Status> {
+    serde_json::from_slice(line)
+        .map_err(|e| Status::invalid_argument(format!("invalid NDJSON message: {e}")))
+}
+
+/// Encode a message as an uncompressed gRPC length-prefixed frame.
+fn encode_frame<T: Message>(msg: &T) -> Result<Bytes, Status> {
+    let len = msg.encoded_len();
+    let prefix = u32::try_from(len).map_err(|_| Status::resource_exhausted("message too large"))?;
+    let mut buf = Vec::with_capacity(5 + len);
+    buf.push(0);
+    buf.extend_from_slice(&prefix.to_be_bytes());
+    msg.encode(&mut buf)
+        .map_err(|e| Status::internal(e.to_string()))?;
+    Ok(Bytes::from(buf))
+}
+
+/// Decodes the frames produced by [`encode_frame`].
+struct ProstDecoder<T>(PhantomData<fn() -> T>);
+
+impl<T: Message + Default> Decoder for ProstDecoder<T> {
+    type Item = T;
+    type Error = Status;
+
+    fn decode(&mut self, src: &mut DecodeBuf<'_>) -> Result<Option<T>, Status> {
+        T::decode(src)
+            .map(Some)
+            .map_err(|e| Status::internal(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, PartialEq, Message, serde::Deserialize)]
+    struct Chunk {
+        #[prost(string, tag = "1")]
+        data: String,
+    }
+
+    async fn collect(body: &'static str) -> Vec<Result<Chunk, Status>> {
+        let mut stream = ndjson_streaming::<Chunk>(Body::from(body));
+        let mut out = Vec::new();
+        loop {
+            match stream.message().await {
+                Ok(Some(chunk)) => out.push(Ok(chunk)),
+                Ok(None) => break,
+                Err(status) => {
+                    out.push(Err(status));
+                    break;
+                }
+            }
+        }
+        out
+    }
+
+    #[tokio::test]
+    async fn decodes_each_line() {
+        let items = collect("{\"data\":\"a\"}\n\n{\"data\":\"b\"}").await;
+        let data: Vec<String> = items.into_iter().map(|c| c.unwrap().data).collect();
+        assert_eq!(data, ["a", "b"]);
+    }
+
+    #[tokio::test]
+    async fn empty_body_yields_no_messages() {
+        assert!(collect("").await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn malformed_line_is_invalid_argument() {
+        let items = collect("{\"data\":\"a\"}\nnot json\n").await;
+        assert_eq!(items[0].as_ref().unwrap().data, "a");
+        let err = items[1].as_ref().unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn overlong_line_is_resource_exhausted() {
+        let body = Body::from("{\"data\":\"aaaaaaaaaaaaaaaaaaaa\"}\n{\"data\":\"b\"}\n");
+        let mut stream = ndjson_streaming_with_limit::<Chunk>(body, 16);
+        let err = stream.message().await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::ResourceExhausted);
+    }
+
+    #[tokio::test]
+    async fn unterminated_body_stops_at_limit() {
+        let body = Body::from("x".repeat(64));
+        let mut stream = ndjson_streaming_with_limit::<Chunk>(body, 16);
+        let err = stream.message().await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::ResourceExhausted);
+    }
+
+    #[tokio::test]
+    async fn forwards_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("authorization", "Bearer t".parse().unwrap());
+        let req = build_tonic_request_stream::<Chunk, ()>(Body::empty(), &headers, None);
+        assert_eq!(req.metadata().get("authorization").unwrap(), "Bearer t");
+    }
+}
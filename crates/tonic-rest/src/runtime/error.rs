@@ -1,9 +1,11 @@
 //! REST error wrapper — converts [`tonic::Status`] to HTTP error responses.
 
+use std::borrow::Cow;
+
 use axum::extract::Json;
 use axum::response::IntoResponse;
 
-use super::status_map::{grpc_code_name, grpc_to_http_status};
+use super::status_map::{StatusMap, grpc_code_name};
 
 /// REST error wrapper — converts [`tonic::Status`] to an HTTP error response.
 ///
@@ -45,6 +47,37 @@ use super::status_map::{grpc_code_name, grpc_to_http_status};
 /// let err: RestError = tonic::Status::not_found("gone").into();
 /// ```
 ///
+/// # Request Context
+///
+/// Generated handlers attach the originating HTTP method and route template
+/// via [`RestError::with_context`]. The context shows up in the `Debug`
+/// output (and the [`method`](Self::method) / [`path`](Self::path)
+/// accessors) for centralized logging, but is never sent to the client.
+///
+/// # Problem Details
+///
+/// [`RestError::problem_json`] switches the body to an
+/// [RFC 7807](https://www.rfc-editor.org/rfc/rfc7807) problem document served as
+/// `application/problem+json`. `title` is the canonical gRPC code name and
+/// `instance` is the route template from [`with_context`](Self::with_context),
+/// omitted when no context is attached. Selected ASCII metadata keys from the
+/// status are copied into a nested `extensions` object, so they can never
+/// shadow the reserved members:
+///
+/// ```json
+/// {
+///   "type": "about:blank", "title": "NOT_FOUND", "status": 404, "detail": "...",
+///   "instance": "/v1/items/{item_id}",
+///   "extensions": { "x-request-id": "abc123" }
+/// }
+/// ```
+///
+/// # Retry-After
+///
+/// For `RESOURCE_EXHAUSTED` and `UNAVAILABLE`, a `retry-after` or
+/// `grpc-retry-delay` metadata entry on the status is surfaced as the
+/// `Retry-After` response header.
+///
 /// # Examples
 ///
 /// Convert a tonic status to an Axum-compatible HTTP response:
@@ -58,17 +91,70 @@ use super::status_map::{grpc_code_name, grpc_to_http_status};
 /// assert_eq!(response.status(), axum::http::StatusCode::NOT_FOUND);
 /// ```
 #[derive(Debug, Clone)]
-pub struct RestError(tonic::Status);
+pub struct RestError {
+    status: tonic::Status,
+    context: Option<RequestContext>,
+    problem: Option<ProblemOptions>,
+    status_map: Option<StatusMap>,
+}
+
+/// Originating request of a [`RestError`], attached with
+/// [`RestError::with_context`].
+///
+/// Never part of the response body. When the error becomes a response, a copy
+/// is stored in the response extensions so logging middleware can read it:
+///
+/// ```
+/// use axum::response::IntoResponse;
+/// use tonic_rest::{RequestContext, RestError};
+///
+/// let response = RestError::new(tonic::Status::not_found("gone"))
+///     .with_context("GET", "/v1/items/{item_id}")
+///     .into_response();
+/// let context = response.extensions().get::<RequestContext>().unwrap();
+/// assert_eq!(context.method(), "GET");
+/// assert_eq!(context.path(), "/v1/items/{item_id}");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestContext {
+    method: Cow<'static, str>,
+    path: Cow<'static, str>,
+}
+
+impl RequestContext {
+    /// HTTP method of the originating request (e.g. `GET`).
+    #[must_use]
+    pub fn method(&self) -> &str {
+        &self.method
+    }
+
+    /// Route template of the originating request (e.g. `/v1/items/{item_id}`).
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+/// Rendering options for the RFC 7807 body, set via [`RestError::problem_json`].
+#[derive(Debug, Clone)]
+struct ProblemOptions {
+    metadata_keys: Vec<Cow<'static, str>>,
+}
 
 impl std::fmt::Display for RestError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}: {}", grpc_code_name(self.0.code()), self.0.message())
+        write!(
+            f,
+            "{}: {}",
+            grpc_code_name(self.status.code()),
+            self.status.message()
+        )
     }
 }
 
 impl std::error::Error for RestError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        Some(&self.0)
+        Some(&self.status)
     }
 }
 
@@ -76,42 +162,214 @@ impl RestError {
     /// Create a new `RestError` from a [`tonic::Status`].
     #[must_use]
     pub const fn new(status: tonic::Status) -> Self {
-        Self(status)
+        Self {
+            status,
+            context: None,
+            problem: None,
+            status_map: None,
+        }
+    }
+
+    /// Attach the originating HTTP method and path for logging.
+    ///
+    /// The context is visible in `Debug` output and through [`method`](Self::method)
+    /// / [`path`](Self::path), but is not included in the client-facing JSON body.
+    ///
+    /// ```
+    /// # use tonic_rest::RestError;
+    /// let err = RestError::new(tonic::Status::not_found("gone"))
+    ///     .with_context("GET", "/v1/items/{item_id}");
+    /// assert_eq!(err.method(), Some("GET"));
+    /// assert_eq!(err.path(), Some("/v1/items/{item_id}"));
+    /// ```
+    #[must_use]
+    pub fn with_context(
+        mut self,
+        method: impl Into<Cow<'static, str>>,
+        path: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.context = Some(RequestContext {
+            method: method.into(),
+            path: path.into(),
+        });
+        self
+    }
+
+    /// Render this error as an RFC 7807 `application/problem+json` document.
+    ///
+    /// `metadata_keys` is an allowlist of gRPC metadata keys to copy from the
+    /// status into the `extensions` object. Keys that are absent, binary
+    /// (`-bin`), or not valid ASCII are skipped; everything else in the status
+    /// metadata stays out of the response.
+    ///
+    /// ```
+    /// # use tonic_rest::RestError;
+    /// let err = RestError::new(tonic::Status::not_found("gone"))
+    ///     .problem_json(["x-request-id"]);
+    /// ```
+    #[must_use]
+    pub fn problem_json<I, K>(mut self, metadata_keys: I) -> Self
+    where
+        I: IntoIterator<Item = K>,
+        K: Into<Cow<'static, str>>,
+    {
+        self.problem = Some(ProblemOptions {
+            metadata_keys: metadata_keys.into_iter().map(Into::into).collect(),
+        });
+        self
+    }
+
+    /// Map the gRPC code to HTTP with `map` instead of the
+    /// [installed](StatusMap::install) or default mapping.
+    ///
+    /// ```
+    /// # use tonic_rest::{RestError, StatusMap};
+    /// use axum::http::StatusCode;
+    /// use axum::response::IntoResponse;
+    ///
+    /// let map = StatusMap::DEFAULT.with(tonic::Code::Aborted, StatusCode::LOCKED);
+    /// let response = RestError::new(tonic::Status::aborted("retry"))
+    ///     .with_status_map(map)
+    ///     .into_response();
+    /// assert_eq!(response.status(), StatusCode::LOCKED);
+    /// ```
+    #[must_use]
+    pub const fn with_status_map(mut self, map: StatusMap) -> Self {
+        self.status_map = Some(map);
+        self
+    }
+
+    /// HTTP method of the originating request, if attached via [`with_context`](Self::with_context).
+    #[must_use]
+    pub fn method(&self) -> Option<&str> {
+        self.context.as_ref().map(|c| c.method.as_ref())
+    }
+
+    /// Path of the originating request, if attached via [`with_context`](Self::with_context).
+    #[must_use]
+    pub fn path(&self) -> Option<&str> {
+        self.context.as_ref().map(|c| c.path.as_ref())
     }
 
     /// Returns a reference to the underlying [`tonic::Status`].
     #[must_use]
     pub const fn status(&self) -> &tonic::Status {
-        &self.0
+        &self.status
     }
 
     /// Consumes the `RestError` and returns the underlying [`tonic::Status`].
     #[must_use]
     pub fn into_status(self) -> tonic::Status {
-        self.0
+        self.status
     }
 }
 
 impl From<tonic::Status> for RestError {
     fn from(status: tonic::Status) -> Self {
-        Self(status)
+        Self::new(status)
     }
 }
 
 impl IntoResponse for RestError {
     fn into_response(self) -> axum::response::Response {
-        let http_status = grpc_to_http_status(self.0.code());
-
-        let body = serde_json::json!({
-            "error": {
-                "code": http_status.as_u16(),
-                "message": self.0.message(),
-                "status": grpc_code_name(self.0.code()),
-            }
-        });
+        let http_status = self
+            .status_map
+            .unwrap_or_else(StatusMap::current)
+            .get(self.status.code());
+
+        let mut response = if let Some(problem) = &self.problem {
+            problem_response(&self, http_status, problem)
+        } else {
+            let body = serde_json::json!({
+                "error": {
+                    "code": http_status.as_u16(),
+                    "message": self.status.message(),
+                    "status": grpc_code_name(self.status.code()),
+                }
+            });
+            (http_status, Json(body)).into_response()
+        };
 
-        (http_status, Json(body)).into_response()
+        if let Some(retry_after) = retry_after(&self.status) {
+            response
+                .headers_mut()
+                .insert(axum::http::header::RETRY_AFTER, retry_after);
+        }
+        if let Some(context) = self.context {
+            response.extensions_mut().insert(context);
+        }
+
+        response
+    }
+}
+
+/// `Retry-After` value for `RESOURCE_EXHAUSTED` (429) and `UNAVAILABLE` (503).
+///
+/// Taken from the `retry-after` status metadata verbatim, or derived from
+/// `grpc-retry-delay` (seconds, optionally suffixed with `s`, rounded up).
+/// `None` for other codes or when neither key carries a usable value.
+fn retry_after(status: &tonic::Status) -> Option<axum::http::HeaderValue> {
+    if !matches!(
+        status.code(),
+        tonic::Code::ResourceExhausted | tonic::Code::Unavailable
+    ) {
+        return None;
+    }
+
+    let metadata = status.metadata();
+    if let Some(value) = metadata.get("retry-after").and_then(|v| v.to_str().ok()) {
+        return axum::http::HeaderValue::from_str(value.trim()).ok();
+    }
+
+    let delay = metadata.get("grpc-retry-delay")?.to_str().ok()?.trim();
+    let secs: f64 = delay.strip_suffix('s').unwrap_or(delay).parse().ok()?;
+    if !secs.is_finite() || secs < 0.0 {
+        return None;
+    }
+    #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    // checked non-negative above
+    let secs = secs.ceil() as u64;
+    Some(axum::http::HeaderValue::from(secs))
+}
+
+/// Build the `application/problem+json` response for [`RestError::problem_json`].
+fn problem_response(
+    err: &RestError,
+    http_status: axum::http::StatusCode,
+    problem: &ProblemOptions,
+) -> axum::response::Response {
+    let status = &err.status;
+    let mut body = serde_json::json!({
+        "type": "about:blank",
+        "title": grpc_code_name(status.code()),
+        "status": http_status.as_u16(),
+        "detail": status.message(),
+    });
+    if let Some(path) = err.path() {
+        body["instance"] = serde_json::Value::from(path);
     }
+
+    let extensions: serde_json::Map<String, serde_json::Value> = problem
+        .metadata_keys
+        .iter()
+        .filter_map(|key| {
+            let value = status.metadata().get(key.as_ref())?.to_str().ok()?;
+            Some((key.to_string(), serde_json::Value::from(value)))
+        })
+        .collect();
+    if !extensions.is_empty() {
+        body["extensions"] = serde_json::Value::Object(extensions);
+    }
+
+    (
+        http_status,
+        [(
+            axum::http::header::CONTENT_TYPE,
+            axum::http::HeaderValue::from_static("application/problem+json"),
+        )],
+        body.to_string(),
+    )
+        .into_response()
 }
 
 #[cfg(test)]
@@ -207,6 +465,54 @@ mod tests {
         assert!(debug.contains("RestError"), "missing type name: {debug}");
     }
 
+    #[tokio::test]
+    async fn context_in_debug_but_not_body() {
+        let err = RestError::new(tonic::Status::not_found("gone"))
+            .with_context("DELETE", "/v1/items/{item_id}");
+
+        let debug = format!("{err:?}");
+        assert!(debug.contains("DELETE"), "missing method: {debug}");
+        assert!(
+            debug.contains("/v1/items/{item_id}"),
+            "missing path: {debug}"
+        );
+
+        // Display stays the canonical `CODE: message` form
+        assert_eq!(err.to_string(), "NOT_FOUND: gone");
+
+        let response = err.into_response();
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "error": { "code": 404, "message": "gone", "status": "NOT_FOUND" }
+            })
+        );
+    }
+
+    #[test]
+    fn context_absent_by_default() {
+        let err = RestError::new(tonic::Status::internal("boom"));
+        assert!(err.method().is_none());
+        assert!(err.path().is_none());
+
+        let response = err.into_response();
+        assert!(response.extensions().get::<RequestContext>().is_none());
+    }
+
+    #[test]
+    fn context_copied_into_response_extensions() {
+        let response = RestError::new(tonic::Status::not_found("gone"))
+            .with_context("DELETE", "/v1/items/{item_id}")
+            .problem_json(["x-request-id"])
+            .into_response();
+
+        let context = response.extensions().get::<RequestContext>().unwrap();
+        assert_eq!(context.method(), "DELETE");
+        assert_eq!(context.path(), "/v1/items/{item_id}");
+    }
+
     #[test]
     fn error_source_is_tonic_status() {
         use std::error::Error;
@@ -247,4 +553,134 @@ mod tests {
         assert_eq!(status.code(), tonic::Code::PermissionDenied);
         assert_eq!(status.message(), "nope");
     }
+
+    #[tokio::test]
+    async fn problem_json_copies_only_allowlisted_metadata() {
+        let mut status = tonic::Status::not_found("gone");
+        status
+            .metadata_mut()
+            .insert("x-request-id", "abc123".parse().unwrap());
+        status
+            .metadata_mut()
+            .insert("x-internal-trace", "secret".parse().unwrap());
+
+        let response = RestError::new(status)
+            .problem_json(["x-request-id", "x-missing"])
+            .into_response();
+        assert_eq!(response.status(), axum::http::StatusCode::NOT_FOUND);
+        assert_eq!(
+            response.headers()["content-type"],
+            "application/problem+json"
+        );
+
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "type": "about:blank",
+                "title": "NOT_FOUND",
+                "status": 404,
+                "detail": "gone",
+                "extensions": { "x-request-id": "abc123" }
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn problem_json_omits_empty_extensions() {
+        let response = RestError::new(tonic::Status::internal("boom"))
+            .problem_json(Vec::<&'static str>::new())
+            .into_response();
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert!(json.get("extensions").is_none(), "unexpected: {json}");
+        assert_eq!(json["status"], 500);
+    }
+
+    #[tokio::test]
+    async fn problem_json_body_shape() {
+        let response = RestError::new(tonic::Status::invalid_argument("bad email"))
+            .with_context("POST", "/v1/users")
+            .problem_json(Vec::<&'static str>::new())
+            .into_response();
+        assert_eq!(response.status(), axum::http::StatusCode::BAD_REQUEST);
+        assert_eq!(
+            response.headers()["content-type"],
+            "application/problem+json"
+        );
+
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "type": "about:blank",
+                "title": "INVALID_ARGUMENT",
+                "status": 400,
+                "detail": "bad email",
+                "instance": "/v1/users"
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn retry_after_from_metadata() {
+        let mut status = tonic::Status::resource_exhausted("slow down");
+        status
+            .metadata_mut()
+            .insert("retry-after", "30".parse().unwrap());
+
+        let response = RestError::new(status).into_response();
+        assert_eq!(response.status(), axum::http::StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()["retry-after"], "30");
+    }
+
+    #[test]
+    fn retry_after_from_grpc_retry_delay() {
+        let mut status = tonic::Status::unavailable("draining");
+        status
+            .metadata_mut()
+            .insert("grpc-retry-delay", "1.5s".parse().unwrap());
+
+        let response = RestError::new(status).into_response();
+        assert_eq!(response.headers()["retry-after"], "2");
+    }
+
+    #[test]
+    fn retry_after_absent_without_metadata_or_for_other_codes() {
+        let response = RestError::new(tonic::Status::unavailable("down")).into_response();
+        assert!(response.headers().get("retry-after").is_none());
+
+        let mut status = tonic::Status::internal("boom");
+        status
+            .metadata_mut()
+            .insert("retry-after", "30".parse().unwrap());
+        let response = RestError::new(status).into_response();
+        assert!(response.headers().get("retry-after").is_none());
+    }
+
+    #[tokio::test]
+    async fn status_map_overrides_one_code() {
+        let map = StatusMap::DEFAULT.with(
+            tonic::Code::FailedPrecondition,
+            axum::http::StatusCode::UNPROCESSABLE_ENTITY,
+        );
+
+        let response = RestError::new(tonic::Status::failed_precondition("stale"))
+            .with_status_map(map)
+            .into_response();
+        assert_eq!(
+            response.status(),
+            axum::http::StatusCode::UNPROCESSABLE_ENTITY
+        );
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["error"]["code"], 422);
+
+        let response = RestError::new(tonic::Status::not_found("gone"))
+            .with_status_map(map)
+            .into_response();
+        assert_eq!(response.status(), axum::http::StatusCode::NOT_FOUND);
+    }
 }
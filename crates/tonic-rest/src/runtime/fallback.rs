@@ -0,0 +1,33 @@
+//! Router fallback — answers unmatched routes with the JSON error model.
+
+use std::future::{Ready, ready};
+
+use axum::http::{Method, Uri};
+
+use super::RestError;
+
+/// Build an Axum fallback handler that answers unmatched routes with a JSON 404.
+///
+/// Axum's default fallback replies with an empty plain-text 404; this one
+/// returns a [`RestError`] with `NOT_FOUND`, so clients get the same JSON
+/// error body as from generated handlers. The request method and path are
+/// attached with [`RestError::with_context`] for server-side logging.
+///
+/// Register it once on the outermost router, after merging the generated
+/// routers: Axum panics when merging two routers that both set a fallback.
+///
+/// # Example
+///
+/// ```ignore
+/// let app = all_rest_routes(auth_service, user_service)
+///     .merge(health_routes())
+///     .fallback(tonic_rest::json_not_found_fallback());
+/// ```
+pub fn json_not_found_fallback()
+-> impl Fn(Method, Uri) -> Ready<RestError> + Clone + Send + Sync + 'static {
+    |method: Method, uri: Uri| {
+        let path = uri.path().to_string();
+        let status = tonic::Status::not_found(format!("no route for {method} {path}"));
+        ready(RestError::new(status).with_context(method.to_string(), path))
+    }
+}
@@ -3,19 +3,45 @@
 //! This module provides the shared types that generated Axum handlers reference:
 //! - [`RestError`] — Error type that converts [`tonic::Status`] to HTTP responses
 //! - [`build_tonic_request`] — Bridges Axum requests to [`tonic::Request`]
+//! - [`build_tonic_request_stream`] — Bridges NDJSON bodies to client-streaming requests
+//! - [`apply_response_metadata`] — Copies allowlisted response metadata to HTTP headers
+//! - [`json_not_found_fallback`] — Router fallback answering unmatched routes with a JSON 404
+//! - [`sse_data_event`] — Formats streamed messages as numbered, optionally named SSE events
+//! - [`sse_first_event_id`] — Resumes SSE event numbering after `Last-Event-ID`
 //! - [`sse_error_event`] — Formats gRPC errors as SSE events
+//! - [`ndjson_response`] — Streams messages as `application/x-ndjson` lines
 //! - [`grpc_to_http_status`] — Maps gRPC status codes to HTTP status codes
+//! - [`StatusMap`] — Overridable gRPC → HTTP mapping table
 //! - [`grpc_code_name`] — Returns the canonical `SCREAMING_SNAKE_CASE` name for a gRPC code
 
+#[cfg(feature = "serde")]
+mod client_stream;
 mod error;
+mod fallback;
+#[cfg(feature = "serde")]
+mod ndjson;
 mod request;
+mod response;
 mod sse;
 mod status_map;
 
-pub use error::RestError;
+#[cfg(feature = "serde")]
+pub use client_stream::{
+    DEFAULT_MAX_NDJSON_LINE_BYTES, build_tonic_request_stream, ndjson_streaming,
+    ndjson_streaming_with_limit,
+};
+pub use error::{RequestContext, RestError};
+pub use fallback::json_not_found_fallback;
+#[cfg(feature = "serde")]
+pub use ndjson::{NDJSON_CONTENT_TYPE, ndjson_response};
 pub use request::{
-    CLOUDFLARE_HEADERS, FORWARDED_HEADERS, build_tonic_request, build_tonic_request_simple,
-    build_tonic_request_with_headers, cloudflare_header_names, forwarded_header_names,
+    CLOUDFLARE_HEADERS, DEFAULT_MAX_FORWARDED_HEADER_BYTES, FORWARDED_HEADERS, HeaderFilter,
+    build_tonic_request, build_tonic_request_checked, build_tonic_request_simple,
+    build_tonic_request_with_headers, build_tonic_request_with_resume, check_forwarded_headers,
+    cloudflare_header_names, forwarded_header_names,
 };
-pub use sse::sse_error_event;
-pub use status_map::{grpc_code_name, grpc_to_http_status};
+pub use response::apply_response_metadata;
+#[cfg(feature = "serde")]
+pub use sse::sse_data_event;
+pub use sse::{sse_error_event, sse_first_event_id};
+pub use status_map::{StatusMap, grpc_code_name, grpc_to_http_status};
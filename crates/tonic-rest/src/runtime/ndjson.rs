@@ -0,0 +1,131 @@
+//! Server-streaming responses as newline-delimited JSON.
+//!
+//! An alternative to SSE for clients that read a plain chunked body: each
+//! streamed message is written as one compact JSON object followed by `\n`.
+
+use axum::body::{Body, Bytes};
+use axum::http::header::CONTENT_TYPE;
+use axum::response::{IntoResponse, Response};
+use futures::stream::{Stream, StreamExt};
+use serde::Serialize;
+use tonic::Status;
+
+use super::sse::stream_error_body;
+
+/// Media type of [`ndjson_response`] bodies.
+pub const NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+
+/// Build an `application/x-ndjson` response from a gRPC response stream.
+///
+/// Every `Ok` item becomes one line. The first `Err` is written as a trailing
+/// error line using the same `{"error": {...}}` shape as
+/// [`RestError`](crate::RestError), and ends the body:
+///
+/// ```text
+/// {"id":"a"}
+/// {"id":"b"}
+/// {"error":{"code":503,"status":"UNAVAILABLE","message":"..."}}
+/// ```
+///
+/// A message that fails to serialize is written as `{}`.
+///
+/// # Examples
+///
+/// ```
+/// use futures::stream;
+///
+/// let items = stream::iter([Ok::<_, tonic::Status>(serde_json::json!({ "n": 1 }))]);
+/// let response = tonic_rest::ndjson_response(items);
+/// assert_eq!(response.headers()["content-type"], tonic_rest::NDJSON_CONTENT_TYPE);
+/// ```
+pub fn ndjson_response<S, T>(stream: S) -> Response
+where
+    S: Stream<Item = Result<T, Status>> + Send + 'static,
+    T: Serialize + Send + 'static,
+{
+    let lines = ndjson_lines(stream).map(Ok::<_, std::convert::Infallible>);
+    (
+        [(CONTENT_TYPE, NDJSON_CONTENT_TYPE)],
+        Body::from_stream(lines),
+    )
+        .into_response()
+}
+
+/// Encode stream items as NDJSON lines, stopping after the first error.
+fn ndjson_lines<S, T>(stream: S) -> impl Stream<Item = Bytes>
+where
+    S: Stream<Item = Result<T, Status>>,
+    T: Serialize,
+{
+    stream.scan(false, |failed, item| {
+        if *failed {
+            return std::future::ready(None);
+        }
+        let mut line = match item {
+            Ok(message) => serde_json::to_vec(&message).unwrap_or_else(|_| b"{}".to_vec()),
+            Err(status) => {
+                *failed = true;
+                stream_error_body(&status).to_string().into_bytes()
+            }
+        };
+        line.push(b'\n');
+        std::future::ready(Some(Bytes::from(line)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures::stream;
+    use http_body_util::BodyExt;
+
+    async fn render<S, T>(items: S) -> (Response, String)
+    where
+        S: Stream<Item = Result<T, Status>> + Send + 'static,
+        T: Serialize + Send + 'static,
+    {
+        let response = ndjson_response(items);
+        let (parts, body) = response.into_parts();
+        let bytes = body.collect().await.unwrap().to_bytes();
+        let text = String::from_utf8(bytes.to_vec()).unwrap();
+        (Response::from_parts(parts, Body::empty()), text)
+    }
+
+    #[tokio::test]
+    async fn writes_one_line_per_message() {
+        let items = stream::iter([
+            Ok(serde_json::json!({ "id": "a" })),
+            Ok(serde_json::json!({ "id": "b" })),
+        ]);
+        let (response, text) = render(items).await;
+
+        assert_eq!(response.headers()["content-type"], NDJSON_CONTENT_TYPE);
+        assert_eq!(text, "{\"id\":\"a\"}\n{\"id\":\"b\"}\n");
+    }
+
+    #[tokio::test]
+    async fn error_becomes_trailing_line_and_ends_stream() {
+        let items = stream::iter([
+            Ok(serde_json::json!({ "id": "a" })),
+            Err(Status::unavailable("backend down")),
+            Ok(serde_json::json!({ "id": "never" })),
+        ]);
+        let (_, text) = render(items).await;
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert_eq!(lines.len(), 2, "stream must stop after the error: {text}");
+        let error: serde_json::Value = serde_json::from_str(lines[1]).unwrap();
+        assert_eq!(error["error"]["code"], 503);
+        assert_eq!(error["error"]["status"], "UNAVAILABLE");
+        assert_eq!(error["error"]["message"], "backend down");
+    }
+
+    #[tokio::test]
+    async fn empty_stream_has_empty_body() {
+        let items = stream::iter(Vec::<Result<serde_json::Value, Status>>::new());
+        let (_, text) = render(items).await;
+
+        assert!(text.is_empty());
+    }
+}
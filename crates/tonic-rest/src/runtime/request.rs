@@ -1,7 +1,10 @@
 //! Tonic request builder — bridges Axum HTTP requests to [`tonic::Request`].
 
-use axum::http::{HeaderMap, HeaderName};
+use axum::http::{HeaderMap, HeaderName, HeaderValue};
 use tonic::Request;
+use tonic::metadata::{Ascii, Binary, MetadataKey, MetadataValue};
+
+use super::error::RestError;
 
 /// HTTP headers forwarded from Axum to tonic metadata for client context.
 ///
@@ -29,6 +32,91 @@ pub const FORWARDED_HEADERS: &[&str] = &[
 /// ```
 pub const CLOUDFLARE_HEADERS: &[&str] = &["cf-connecting-ip"];
 
+/// Selects which HTTP headers are copied into tonic metadata.
+///
+/// Every function that takes forwarded headers accepts anything convertible
+/// into a `HeaderFilter`, so plain lists like [`FORWARDED_HEADERS`] keep
+/// working as [`HeaderFilter::Exact`].
+///
+/// # Examples
+///
+/// ```
+/// use axum::http::HeaderMap;
+/// use tonic_rest::{HeaderFilter, build_tonic_request_with_headers};
+///
+/// let mut headers = HeaderMap::new();
+/// headers.insert("x-tenant-id", "acme".parse().unwrap());
+/// headers.insert("cookie", "secret".parse().unwrap());
+///
+/// let req = build_tonic_request_with_headers::<_, ()>(
+///     "body", &headers, None, HeaderFilter::Prefixes(&["x-tenant-"])
+/// );
+/// assert_eq!(req.metadata().get("x-tenant-id").unwrap(), "acme");
+/// assert!(req.metadata().get("cookie").is_none());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub enum HeaderFilter<'a> {
+    /// Forward exactly these header names.
+    Exact(&'a [&'a str]),
+    /// Forward every header whose name starts with one of these prefixes.
+    ///
+    /// Header names are lowercase, so prefixes should be too.
+    Prefixes(&'a [&'a str]),
+    /// Forward every header the function returns `true` for.
+    Predicate(fn(&HeaderName) -> bool),
+}
+
+impl HeaderFilter<'_> {
+    /// Whether a header with this name is forwarded.
+    #[must_use]
+    pub fn matches(&self, name: &HeaderName) -> bool {
+        match self {
+            Self::Exact(names) => names.iter().any(|n| n.eq_ignore_ascii_case(name.as_str())),
+            Self::Prefixes(prefixes) => prefixes.iter().any(|p| name.as_str().starts_with(p)),
+            Self::Predicate(f) => f(name),
+        }
+    }
+
+    /// Call `f` with the name and first value of every selected header.
+    fn for_each(&self, headers: &HeaderMap, mut f: impl FnMut(&str, &HeaderValue)) {
+        if let Self::Exact(names) = self {
+            for &name in *names {
+                if let Some(val) = headers.get(name) {
+                    f(name, val);
+                }
+            }
+            return;
+        }
+        for name in headers.keys() {
+            if self.matches(name) {
+                f(name.as_str(), &headers[name]);
+            }
+        }
+    }
+}
+
+impl<'a> From<&'a [&'a str]> for HeaderFilter<'a> {
+    fn from(names: &'a [&'a str]) -> Self {
+        Self::Exact(names)
+    }
+}
+
+impl<'a, const N: usize> From<&'a [&'a str; N]> for HeaderFilter<'a> {
+    fn from(names: &'a [&'a str; N]) -> Self {
+        Self::Exact(names)
+    }
+}
+
+impl<'a> From<&'a Vec<&'a str>> for HeaderFilter<'a> {
+    fn from(names: &'a Vec<&'a str>) -> Self {
+        Self::Exact(names)
+    }
+}
+
+/// Default cap for [`build_tonic_request_checked`], matching the 8 KiB
+/// metadata limit most gRPC servers apply.
+pub const DEFAULT_MAX_FORWARDED_HEADER_BYTES: usize = 8 * 1024;
+
 /// Return [`FORWARDED_HEADERS`] as typed [`HeaderName`] values.
 ///
 /// Useful when working with `HeaderMap` APIs that require `HeaderName`
@@ -129,14 +217,15 @@ where
 /// Build a [`tonic::Request`] with a custom set of forwarded headers.
 ///
 /// Like [`build_tonic_request`] but lets you control which HTTP headers
-/// are copied to tonic metadata.
+/// are copied to tonic metadata, either by name list or by a
+/// [`HeaderFilter`] prefix or predicate rule.
 ///
 /// # Arguments
 ///
 /// * `body` — Request body (proto message)
 /// * `headers` — Incoming HTTP headers from Axum
 /// * `extension` — Optional extension value (e.g., auth info from middleware)
-/// * `forwarded_headers` — Header names to copy to tonic metadata
+/// * `forwarded_headers` — Header names (or a [`HeaderFilter`]) to copy to tonic metadata
 ///
 /// # Examples
 ///
@@ -152,11 +241,11 @@ where
 /// );
 /// assert_eq!(req.metadata().get("x-custom").unwrap(), "value");
 /// ```
-pub fn build_tonic_request_with_headers<T, E>(
+pub fn build_tonic_request_with_headers<'a, T, E>(
     body: T,
     headers: &HeaderMap,
     extension: Option<E>,
-    forwarded_headers: &[&str],
+    forwarded_headers: impl Into<HeaderFilter<'a>>,
 ) -> Request<T>
 where
     E: Clone + Send + Sync + 'static,
@@ -170,24 +259,187 @@ where
     }
 
     // Copy relevant HTTP headers to tonic metadata.
-    // Silently skip values that can't be parsed (non-ASCII, control chars)
-    // rather than panicking — malformed client headers shouldn't crash the server.
+    // Values that aren't visible ASCII (e.g. a UTF-8 `user-agent`) go to the
+    // `{name}-bin` key, which tonic base64-encodes on the wire; so do headers
+    // already named `*-bin`, which tonic won't accept as ASCII keys. Anything
+    // that still can't be represented is skipped rather than panicking —
+    // malformed client headers shouldn't crash the server.
     let metadata = req.metadata_mut();
-    for &name in forwarded_headers {
-        let Some(val) = headers.get(name).and_then(|v| v.to_str().ok()) else {
-            continue;
-        };
-        let Ok(key) = name.parse::<tonic::metadata::MetadataKey<tonic::metadata::Ascii>>() else {
-            continue;
+    forwarded_headers.into().for_each(headers, |name, val| {
+        let ascii = if forwards_as_binary(name, val) {
+            None
+        } else {
+            val.to_str()
+                .ok()
+                .and_then(|v| v.parse::<MetadataValue<Ascii>>().ok())
         };
-        if let Ok(parsed) = val.parse() {
-            metadata.insert(key, parsed);
+        if let Some(ascii) = ascii {
+            if let Ok(key) = name.parse::<MetadataKey<Ascii>>() {
+                metadata.insert(key, ascii);
+            }
+        } else if let Ok(key) = binary_key(name).parse::<MetadataKey<Binary>>() {
+            metadata.insert_bin(key, MetadataValue::from_bytes(val.as_bytes()));
         }
+    });
+
+    req
+}
+
+/// Build a [`tonic::Request`] after checking the forwarded headers' size.
+///
+/// Like [`build_tonic_request_with_headers`], but first sums the name and
+/// value lengths of every forwarded header present in `headers`, as they
+/// will appear in gRPC metadata: values forwarded under a `{name}-bin` key
+/// count at their padded base64 length. If the total exceeds `max_bytes`,
+/// returns an `INVALID_ARGUMENT` [`RestError`]
+/// instead of handing the upstream gRPC service metadata it would reject.
+/// [`DEFAULT_MAX_FORWARDED_HEADER_BYTES`] is a sensible default.
+///
+/// # Errors
+///
+/// Returns [`RestError`] (HTTP 400) when the forwarded headers are larger
+/// than `max_bytes`.
+///
+/// # Examples
+///
+/// ```
+/// use axum::http::HeaderMap;
+/// use tonic_rest::{FORWARDED_HEADERS, build_tonic_request_checked};
+///
+/// let mut headers = HeaderMap::new();
+/// headers.insert("user-agent", "x".repeat(64).parse().unwrap());
+///
+/// assert!(build_tonic_request_checked::<_, ()>("b", &headers, None, FORWARDED_HEADERS, 128).is_ok());
+/// assert!(build_tonic_request_checked::<_, ()>("b", &headers, None, FORWARDED_HEADERS, 32).is_err());
+/// ```
+pub fn build_tonic_request_checked<'a, T, E>(
+    body: T,
+    headers: &HeaderMap,
+    extension: Option<E>,
+    forwarded_headers: impl Into<HeaderFilter<'a>>,
+    max_bytes: usize,
+) -> Result<Request<T>, RestError>
+where
+    E: Clone + Send + Sync + 'static,
+{
+    let forwarded_headers = forwarded_headers.into();
+    check_forwarded_headers(headers, forwarded_headers, max_bytes)?;
+    Ok(build_tonic_request_with_headers(
+        body,
+        headers,
+        extension,
+        forwarded_headers,
+    ))
+}
+
+/// Check the forwarded headers' size without building a request.
+///
+/// The size check behind [`build_tonic_request_checked`], for callers that
+/// build the request another way (e.g. [`build_tonic_request_with_resume`]).
+///
+/// # Errors
+///
+/// Returns [`RestError`] (HTTP 400) when the forwarded headers are larger
+/// than `max_bytes`.
+///
+/// # Examples
+///
+/// ```
+/// use axum::http::HeaderMap;
+/// use tonic_rest::{FORWARDED_HEADERS, check_forwarded_headers};
+///
+/// let mut headers = HeaderMap::new();
+/// headers.insert("user-agent", "x".repeat(64).parse().unwrap());
+///
+/// assert!(check_forwarded_headers(&headers, FORWARDED_HEADERS, 128).is_ok());
+/// assert!(check_forwarded_headers(&headers, FORWARDED_HEADERS, 32).is_err());
+/// ```
+pub fn check_forwarded_headers<'a>(
+    headers: &HeaderMap,
+    forwarded_headers: impl Into<HeaderFilter<'a>>,
+    max_bytes: usize,
+) -> Result<(), RestError> {
+    let mut total = 0;
+    forwarded_headers
+        .into()
+        .for_each(headers, |name, val| total += metadata_len(name, val));
+    if total > max_bytes {
+        return Err(RestError::new(tonic::Status::invalid_argument(format!(
+            "forwarded headers are {total} bytes, exceeding the {max_bytes}-byte limit"
+        ))));
     }
+    Ok(())
+}
+
+/// Size of a forwarded header once copied into gRPC metadata.
+///
+/// Binary values go to a `*-bin` key and are base64-encoded on the wire,
+/// which [`build_tonic_request_with_headers`] mirrors.
+fn metadata_len(name: &str, val: &HeaderValue) -> usize {
+    if forwards_as_binary(name, val) {
+        binary_key(name).len() + val.len().div_ceil(3) * 4
+    } else {
+        name.len() + val.len()
+    }
+}
+
+/// Whether a forwarded header goes into binary (`*-bin`) metadata: its value
+/// isn't visible ASCII, or its name already carries the `-bin` suffix.
+fn forwards_as_binary(name: &str, val: &HeaderValue) -> bool {
+    name.ends_with("-bin") || val.to_str().is_err()
+}
+
+/// Binary metadata key for a forwarded header, adding `-bin` if missing.
+fn binary_key(name: &str) -> String {
+    if name.ends_with("-bin") {
+        name.to_string()
+    } else {
+        format!("{name}-bin")
+    }
+}
 
+/// Build a [`tonic::Request`] for a resumable SSE stream.
+///
+/// Like [`build_tonic_request_with_headers`], and additionally copies the
+/// `Last-Event-ID` header that browsers send when an `EventSource`
+/// reconnects into the `last-event-id` metadata key, so the service can
+/// resume after the last event the client received.
+///
+/// # Examples
+///
+/// ```
+/// use axum::http::HeaderMap;
+/// use tonic_rest::{FORWARDED_HEADERS, build_tonic_request_with_resume};
+///
+/// let mut headers = HeaderMap::new();
+/// headers.insert("last-event-id", "41".parse().unwrap());
+///
+/// let req = build_tonic_request_with_resume::<_, ()>("body", &headers, None, FORWARDED_HEADERS);
+/// assert_eq!(req.metadata().get("last-event-id").unwrap(), "41");
+/// ```
+pub fn build_tonic_request_with_resume<'a, T, E>(
+    body: T,
+    headers: &HeaderMap,
+    extension: Option<E>,
+    forwarded_headers: impl Into<HeaderFilter<'a>>,
+) -> Request<T>
+where
+    E: Clone + Send + Sync + 'static,
+{
+    let mut req = build_tonic_request_with_headers(body, headers, extension, forwarded_headers);
+    let last_event_id = headers
+        .get(LAST_EVENT_ID)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse().ok());
+    if let Some(id) = last_event_id {
+        req.metadata_mut().insert(LAST_EVENT_ID, id);
+    }
     req
 }
 
+/// SSE reconnect header, forwarded under the same name as gRPC metadata.
+const LAST_EVENT_ID: &str = "last-event-id";
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -225,6 +477,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn resume_forwards_last_event_id() {
+        let mut headers = HeaderMap::new();
+        headers.insert("last-event-id", "17".parse().unwrap());
+        headers.insert("authorization", "Bearer tok".parse().unwrap());
+
+        let req = build_tonic_request_with_resume::<_, ()>("b", &headers, None, FORWARDED_HEADERS);
+        assert_eq!(req.metadata().get("last-event-id").unwrap(), "17");
+        assert_eq!(req.metadata().get("authorization").unwrap(), "Bearer tok");
+
+        let req = build_tonic_request_with_resume::<_, ()>("b", &HeaderMap::new(), None, &[]);
+        assert!(req.metadata().get("last-event-id").is_none());
+    }
+
     #[test]
     fn no_auth_no_headers() {
         let headers = HeaderMap::new();
@@ -323,6 +589,146 @@ mod tests {
         assert!(req.metadata().is_empty());
     }
 
+    #[test]
+    fn non_ascii_header_forwarded_as_binary() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "user-agent",
+            axum::http::HeaderValue::from_bytes("Klient/1 (Zürich)".as_bytes()).unwrap(),
+        );
+        headers.insert("authorization", "Bearer tok".parse().unwrap());
+
+        let req = build_tonic_request::<_, ()>("b", &headers, None);
+        let meta = req.metadata();
+        assert!(meta.get("user-agent").is_none());
+        assert_eq!(
+            meta.get_bin("user-agent-bin").unwrap().to_bytes().unwrap(),
+            "Klient/1 (Zürich)".as_bytes(),
+        );
+        assert_eq!(meta.get("authorization").unwrap(), "Bearer tok");
+    }
+
+    #[test]
+    fn bin_named_header_forwarded_as_binary() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-trace-bin", "AQID".parse().unwrap());
+
+        let req = build_tonic_request_with_headers::<_, ()>("b", &headers, None, &["x-trace-bin"]);
+        assert_eq!(
+            req.metadata()
+                .get_bin("x-trace-bin")
+                .unwrap()
+                .to_bytes()
+                .unwrap(),
+            "AQID".as_bytes(),
+        );
+
+        // "x-trace-bin" (11) + base64 of 4 bytes (8)
+        assert!(check_forwarded_headers(&headers, &["x-trace-bin"], 18).is_err());
+        assert!(check_forwarded_headers(&headers, &["x-trace-bin"], 19).is_ok());
+    }
+
+    #[test]
+    fn checked_rejects_oversized_forwarded_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("authorization", "Bearer tok".parse().unwrap());
+        // Not forwarded, so it doesn't count toward the limit.
+        headers.insert("x-large", "z".repeat(1024).parse().unwrap());
+
+        // "authorization" (13) + "Bearer tok" (10)
+        let req = build_tonic_request_checked::<_, ()>("b", &headers, None, FORWARDED_HEADERS, 23)
+            .unwrap();
+        assert_eq!(req.metadata().get("authorization").unwrap(), "Bearer tok");
+
+        let err = build_tonic_request_checked::<_, ()>("b", &headers, None, FORWARDED_HEADERS, 22)
+            .unwrap_err();
+        assert_eq!(err.status().code(), tonic::Code::InvalidArgument);
+        assert!(err.status().message().contains("22-byte limit"));
+    }
+
+    #[test]
+    fn checked_counts_non_ascii_values() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "user-agent",
+            axum::http::HeaderValue::from_bytes(&[0xff; 100]).unwrap(),
+        );
+
+        // "user-agent-bin" (14) + base64 of 100 bytes (136)
+        assert!(
+            build_tonic_request_checked::<_, ()>("b", &headers, None, FORWARDED_HEADERS, 149)
+                .is_err()
+        );
+        assert!(
+            build_tonic_request_checked::<_, ()>("b", &headers, None, FORWARDED_HEADERS, 150)
+                .is_ok()
+        );
+        let req = build_tonic_request_checked::<_, ()>(
+            "b",
+            &headers,
+            None,
+            FORWARDED_HEADERS,
+            DEFAULT_MAX_FORWARDED_HEADER_BYTES,
+        )
+        .unwrap();
+        assert_eq!(
+            req.metadata()
+                .get_bin("user-agent-bin")
+                .unwrap()
+                .to_bytes()
+                .unwrap()
+                .len(),
+            100
+        );
+    }
+
+    #[test]
+    fn prefix_filter_forwards_matching_headers_only() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-tenant-id", "acme".parse().unwrap());
+        headers.insert("x-tenant-region", "eu-west-1".parse().unwrap());
+        headers.insert("x-tenancy", "shared".parse().unwrap());
+        headers.insert("authorization", "Bearer tok".parse().unwrap());
+
+        let req = build_tonic_request_with_headers::<_, ()>(
+            "b",
+            &headers,
+            None,
+            HeaderFilter::Prefixes(&["x-tenant-"]),
+        );
+        let meta = req.metadata();
+        assert_eq!(meta.get("x-tenant-id").unwrap(), "acme");
+        assert_eq!(meta.get("x-tenant-region").unwrap(), "eu-west-1");
+        assert!(meta.get("x-tenancy").is_none());
+        assert!(meta.get("authorization").is_none());
+        assert_eq!(meta.len(), 2);
+    }
+
+    #[test]
+    fn predicate_filter_and_exact_matching() {
+        fn tenant_or_auth(name: &HeaderName) -> bool {
+            name == "authorization" || name.as_str().starts_with("x-tenant-")
+        }
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-tenant-id", "acme".parse().unwrap());
+        headers.insert("authorization", "Bearer tok".parse().unwrap());
+        headers.insert("cookie", "secret".parse().unwrap());
+
+        let req = build_tonic_request_with_headers::<_, ()>(
+            "b",
+            &headers,
+            None,
+            HeaderFilter::Predicate(tenant_or_auth),
+        );
+        assert_eq!(req.metadata().len(), 2);
+        assert!(req.metadata().get("cookie").is_none());
+
+        let exact = HeaderFilter::from(FORWARDED_HEADERS);
+        assert!(exact.matches(&HeaderName::from_static("user-agent")));
+        assert!(!exact.matches(&HeaderName::from_static("cookie")));
+    }
+
     #[test]
     fn auth_and_headers_combined() {
         #[derive(Clone, Debug, PartialEq)]
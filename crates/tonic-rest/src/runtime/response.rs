@@ -0,0 +1,80 @@
+//! Response metadata bridging — copies [`tonic::Response`] metadata to HTTP headers.
+
+use axum::http::{HeaderMap, HeaderName, HeaderValue};
+use tonic::metadata::MetadataMap;
+
+/// Copy allowlisted gRPC response metadata into HTTP response headers.
+///
+/// The response-side counterpart of [`build_tonic_request`](crate::build_tonic_request).
+/// Only keys listed in `keys` are copied; absent keys, binary (`-bin`) keys,
+/// and values that are not valid header values are skipped. Existing headers
+/// with the same name are replaced.
+///
+/// Generated handlers call this with the keys configured through
+/// `RestCodegenConfig::response_headers` in `tonic-rest-build`.
+///
+/// # Example
+///
+/// ```
+/// use axum::http::HeaderMap;
+/// use tonic_rest::apply_response_metadata;
+///
+/// let mut response = tonic::Response::new(());
+/// response.metadata_mut().insert("x-request-id", "abc123".parse().unwrap());
+/// response.metadata_mut().insert("x-internal", "secret".parse().unwrap());
+///
+/// let mut headers = HeaderMap::new();
+/// apply_response_metadata(&mut headers, response.metadata(), &["x-request-id", "etag"]);
+/// assert_eq!(headers["x-request-id"], "abc123");
+/// assert!(headers.get("x-internal").is_none());
+/// ```
+pub fn apply_response_metadata(headers: &mut HeaderMap, metadata: &MetadataMap, keys: &[&str]) {
+    for &key in keys {
+        let Some(value) = metadata.get(key) else {
+            continue;
+        };
+        let (Ok(name), Ok(value)) = (
+            HeaderName::from_bytes(key.as_bytes()),
+            HeaderValue::from_bytes(value.as_bytes()),
+        ) else {
+            continue;
+        };
+        headers.insert(name, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn copies_only_listed_keys() {
+        let mut metadata = MetadataMap::new();
+        metadata.insert("etag", "\"v2\"".parse().unwrap());
+        metadata.insert("x-request-id", "abc123".parse().unwrap());
+        metadata.insert("x-internal", "secret".parse().unwrap());
+
+        let mut headers = HeaderMap::new();
+        apply_response_metadata(
+            &mut headers,
+            &metadata,
+            &["etag", "x-request-id", "x-missing"],
+        );
+
+        assert_eq!(headers.len(), 2);
+        assert_eq!(headers["etag"], "\"v2\"");
+        assert_eq!(headers["x-request-id"], "abc123");
+    }
+
+    #[test]
+    fn replaces_existing_header() {
+        let mut metadata = MetadataMap::new();
+        metadata.insert("etag", "\"new\"".parse().unwrap());
+
+        let mut headers = HeaderMap::new();
+        headers.insert("etag", HeaderValue::from_static("\"old\""));
+        apply_response_metadata(&mut headers, &metadata, &["etag"]);
+
+        assert_eq!(headers["etag"], "\"new\"");
+    }
+}
@@ -1,8 +1,71 @@
-//! SSE error event formatting.
+//! SSE event formatting for streamed messages and errors.
 
+use axum::http::HeaderMap;
 use axum::response::sse::Event;
 
-use super::status_map::grpc_to_http_status;
+use super::status_map::StatusMap;
+
+/// Build an SSE data event carrying one streamed message.
+///
+/// The event has an `id:` so a reconnecting client can report the last
+/// message it saw via `Last-Event-ID`. With a `name`, it also gets an
+/// `event:` line for client-side routing with `addEventListener`; without
+/// one it stays a plain message delivered to `EventSource.onmessage`:
+///
+/// ```text
+/// event: Item
+/// id: 3
+/// data: {"id":"abc"}
+/// ```
+///
+/// If `payload` fails to serialize, the event carries `{}` as its data.
+///
+/// # Examples
+///
+/// ```
+/// use tonic_rest::sse_data_event;
+///
+/// let event = sse_data_event(Some("Tick"), 0, &serde_json::json!({ "n": 1 }));
+/// ```
+#[cfg(feature = "serde")]
+pub fn sse_data_event<T: serde::Serialize>(name: Option<&str>, id: u64, payload: &T) -> Event {
+    let base = || {
+        let event = Event::default().id(id.to_string());
+        match name {
+            Some(name) => event.event(name),
+            None => event,
+        }
+    };
+    base()
+        .json_data(payload)
+        .unwrap_or_else(|_| base().data("{}"))
+}
+
+/// First event `id:` for an SSE stream: one past the client's `Last-Event-ID`.
+///
+/// A fresh connection starts at `0`; a reconnecting `EventSource` continues
+/// the numbering after the last id it received, so ids stay monotonic across
+/// reconnects. An unparsable header counts as a fresh connection.
+///
+/// # Examples
+///
+/// ```
+/// use axum::http::HeaderMap;
+/// use tonic_rest::sse_first_event_id;
+///
+/// let mut headers = HeaderMap::new();
+/// assert_eq!(sse_first_event_id(&headers), 0);
+/// headers.insert("last-event-id", "41".parse().unwrap());
+/// assert_eq!(sse_first_event_id(&headers), 42);
+/// ```
+#[must_use]
+pub fn sse_first_event_id(headers: &HeaderMap) -> u64 {
+    headers
+        .get("last-event-id")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .map_or(0, |id| id.saturating_add(1))
+}
 
 /// Build a structured SSE error event from a [`tonic::Status`].
 ///
@@ -18,6 +81,7 @@ use super::status_map::grpc_to_http_status;
 /// [`RestError::into_response`](crate::RestError), ensuring a consistent error
 /// shape across both HTTP JSON and SSE transports. The SSE event type field
 /// (`event: error`) provides additional context for SSE-specific handling.
+/// The HTTP `code` follows the [installed](StatusMap::install) status map.
 ///
 /// # Examples
 ///
@@ -28,18 +92,22 @@ use super::status_map::grpc_to_http_status;
 /// // The event will have `event: error` type and JSON data with code 401
 /// ```
 pub fn sse_error_event(status: &tonic::Status) -> Event {
-    let http_code = grpc_to_http_status(status.code());
-    let body = serde_json::json!({
+    Event::default()
+        .event("error")
+        .json_data(stream_error_body(status))
+        .unwrap_or_else(|_| Event::default().event("error").data(status.message()))
+}
+
+/// The `{"error": {...}}` object reported in-band when a response stream fails.
+pub(super) fn stream_error_body(status: &tonic::Status) -> serde_json::Value {
+    let http_code = StatusMap::current().get(status.code());
+    serde_json::json!({
         "error": {
             "code": http_code.as_u16(),
             "status": super::status_map::grpc_code_name(status.code()),
             "message": status.message(),
         }
-    });
-    Event::default()
-        .event("error")
-        .json_data(&body)
-        .unwrap_or_else(|_| Event::default().event("error").data(status.message()))
+    })
 }
 
 #[cfg(test)]
@@ -118,6 +186,42 @@ mod tests {
         assert!(text.contains("\"error\":"), "missing error wrapper: {text}",);
     }
 
+    #[cfg(feature = "serde")]
+    #[tokio::test]
+    async fn data_event_has_name_and_id() {
+        let event = sse_data_event(Some("Item"), 7, &serde_json::json!({ "id": "abc" }));
+        let text = render_event(event).await;
+
+        assert!(text.contains("event: Item\n"), "missing event name: {text}");
+        assert!(text.contains("id: 7\n"), "missing id: {text}");
+        assert!(
+            text.contains("data: {\"id\":\"abc\"}"),
+            "missing payload: {text}",
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[tokio::test]
+    async fn unnamed_data_event_has_no_event_line() {
+        let event = sse_data_event(None, 0, &serde_json::json!({ "id": "abc" }));
+        let text = render_event(event).await;
+
+        assert!(!text.contains("event:"), "unexpected event name: {text}");
+        assert!(text.contains("id: 0\n"), "missing id: {text}");
+    }
+
+    #[test]
+    fn first_event_id_follows_last_event_id() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(sse_first_event_id(&headers), 0);
+
+        headers.insert("last-event-id", "41".parse().unwrap());
+        assert_eq!(sse_first_event_id(&headers), 42);
+
+        headers.insert("last-event-id", "not-a-number".parse().unwrap());
+        assert_eq!(sse_first_event_id(&headers), 0);
+    }
+
     /// Verify that the SSE response has the correct content-type header.
     #[tokio::test]
     async fn sse_content_type() {
@@ -1,5 +1,7 @@
 //! gRPC → HTTP status code mapping.
 
+use std::sync::OnceLock;
+
 use axum::http::StatusCode;
 
 /// Return the canonical `SCREAMING_SNAKE_CASE` name for a gRPC status code.
@@ -40,7 +42,9 @@ pub const fn grpc_code_name(code: tonic::Code) -> &'static str {
 
 /// Map gRPC status codes to HTTP status codes.
 ///
-/// Follows the [canonical mapping](https://grpc.github.io/grpc/core/md_doc_statuscodes.html).
+/// Follows the [canonical mapping](https://grpc.github.io/grpc/core/md_doc_statuscodes.html)
+/// via [`StatusMap::DEFAULT`]. Project-specific overrides installed with
+/// [`StatusMap::install`] do not affect this function.
 ///
 /// # Examples
 ///
@@ -52,6 +56,11 @@ pub const fn grpc_code_name(code: tonic::Code) -> &'static str {
 /// ```
 #[must_use]
 pub const fn grpc_to_http_status(code: tonic::Code) -> StatusCode {
+    StatusMap::DEFAULT.get(code)
+}
+
+/// Canonical mapping backing [`StatusMap::DEFAULT`].
+const fn canonical_http_status(code: tonic::Code) -> StatusCode {
     match code {
         tonic::Code::Ok => StatusCode::OK,
         tonic::Code::Cancelled => StatusCode::REQUEST_TIMEOUT,
@@ -71,6 +80,97 @@ pub const fn grpc_to_http_status(code: tonic::Code) -> StatusCode {
     }
 }
 
+/// A gRPC → HTTP status code mapping table.
+///
+/// [`StatusMap::DEFAULT`] is the canonical mapping used by
+/// [`grpc_to_http_status`]. Teams with different conventions can override
+/// individual codes and either attach the map to a single error via
+/// [`RestError::with_status_map`](crate::RestError::with_status_map) or make
+/// it the process-wide mapping for every [`RestError`](crate::RestError) and
+/// [`sse_error_event`](crate::sse_error_event) with [`install`](Self::install).
+///
+/// ```
+/// use axum::http::StatusCode;
+/// use tonic_rest::StatusMap;
+///
+/// let map = StatusMap::DEFAULT
+///     .with(tonic::Code::FailedPrecondition, StatusCode::UNPROCESSABLE_ENTITY);
+/// assert_eq!(map.get(tonic::Code::FailedPrecondition), StatusCode::UNPROCESSABLE_ENTITY);
+/// assert_eq!(map.get(tonic::Code::NotFound), StatusCode::NOT_FOUND);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatusMap {
+    /// HTTP status per gRPC code, indexed by the code's numeric value.
+    codes: [StatusCode; 17],
+}
+
+/// Process-wide mapping set via [`StatusMap::install`].
+static INSTALLED: OnceLock<StatusMap> = OnceLock::new();
+
+impl StatusMap {
+    /// The canonical gRPC → HTTP mapping.
+    pub const DEFAULT: Self = Self {
+        codes: [
+            canonical_http_status(tonic::Code::Ok),
+            canonical_http_status(tonic::Code::Cancelled),
+            canonical_http_status(tonic::Code::Unknown),
+            canonical_http_status(tonic::Code::InvalidArgument),
+            canonical_http_status(tonic::Code::DeadlineExceeded),
+            canonical_http_status(tonic::Code::NotFound),
+            canonical_http_status(tonic::Code::AlreadyExists),
+            canonical_http_status(tonic::Code::PermissionDenied),
+            canonical_http_status(tonic::Code::ResourceExhausted),
+            canonical_http_status(tonic::Code::FailedPrecondition),
+            canonical_http_status(tonic::Code::Aborted),
+            canonical_http_status(tonic::Code::OutOfRange),
+            canonical_http_status(tonic::Code::Unimplemented),
+            canonical_http_status(tonic::Code::Internal),
+            canonical_http_status(tonic::Code::Unavailable),
+            canonical_http_status(tonic::Code::DataLoss),
+            canonical_http_status(tonic::Code::Unauthenticated),
+        ],
+    };
+
+    /// Return a copy of this map with `code` mapped to `http`.
+    #[must_use]
+    pub const fn with(mut self, code: tonic::Code, http: StatusCode) -> Self {
+        self.codes[code as usize] = http;
+        self
+    }
+
+    /// HTTP status for a gRPC code.
+    #[must_use]
+    pub const fn get(&self, code: tonic::Code) -> StatusCode {
+        self.codes[code as usize]
+    }
+
+    /// Make this the process-wide mapping for [`RestError`](crate::RestError)
+    /// responses and SSE error events.
+    ///
+    /// Call once at startup, before serving requests. Errors with their own
+    /// map ([`RestError::with_status_map`](crate::RestError::with_status_map))
+    /// keep using it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(self)` if a mapping was already installed.
+    pub fn install(self) -> Result<(), Self> {
+        INSTALLED.set(self)
+    }
+
+    /// The installed process-wide mapping, or [`DEFAULT`](Self::DEFAULT).
+    #[must_use]
+    pub fn current() -> Self {
+        INSTALLED.get().copied().unwrap_or(Self::DEFAULT)
+    }
+}
+
+impl Default for StatusMap {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -168,4 +268,22 @@ mod tests {
             grpc_to_http_status(Code::Unknown),
         );
     }
+
+    #[test]
+    fn status_map_override_and_default() {
+        let map = StatusMap::DEFAULT
+            .with(Code::FailedPrecondition, StatusCode::UNPROCESSABLE_ENTITY)
+            .with(Code::Aborted, StatusCode::CONFLICT);
+
+        assert_eq!(
+            map.get(Code::FailedPrecondition),
+            StatusCode::UNPROCESSABLE_ENTITY
+        );
+        assert_eq!(map.get(Code::NotFound), StatusCode::NOT_FOUND);
+        // The free function keeps the canonical mapping.
+        assert_eq!(
+            grpc_to_http_status(Code::FailedPrecondition),
+            StatusCode::PRECONDITION_FAILED
+        );
+    }
 }
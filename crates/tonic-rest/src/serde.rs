@@ -6,6 +6,7 @@
 //! - **Timestamp** → RFC 3339 string (`"2025-01-15T09:30:00Z"`)
 //! - **Duration**  → seconds string with `s` suffix (`"300s"`)
 //! - **`FieldMask`** → comma-separated camelCase paths (`"name,email,role"`)
+//! - **Value** / **Struct** → arbitrary JSON value / JSON object
 //!
 //! ## Proto enums
 //!
@@ -26,6 +27,7 @@
 //! // In lib.rs:
 //! pub mod serde_wkt {
 //!     pub use tonic_rest::serde::{opt_timestamp, opt_duration, opt_field_mask};
+//!     pub use tonic_rest::serde::{vec_timestamp, vec_duration, vec_field_mask};
 //!     tonic_rest::define_enum_serde!(user_role, crate::core::UserRole);
 //! }
 //! ```
@@ -473,6 +475,664 @@ pub mod field_mask {
     }
 }
 
+/// Serde adapter for `Vec<prost_types::Timestamp>` ↔ array of RFC 3339 strings.
+///
+/// Used for `repeated google.protobuf.Timestamp` fields. Each element uses the
+/// [`timestamp`] encoding.
+///
+/// # Errors
+///
+/// Serialization fails if any timestamp has negative nanos or is out of range.
+/// Deserialization fails if any element is not a valid RFC 3339 datetime.
+pub mod vec_timestamp {
+    use prost_types::Timestamp;
+    use serde::{self, Deserialize, Deserializer, Serialize, Serializer};
+
+    struct Item<'a>(&'a Timestamp);
+
+    impl Serialize for Item<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::timestamp::serialize(self.0, serializer)
+        }
+    }
+
+    /// Serialize a list of `Timestamp`s as an array of RFC 3339 strings.
+    ///
+    /// # Errors
+    ///
+    /// Returns `S::Error` if any timestamp is out of range or has negative nanos.
+    pub fn serialize<S>(value: &[Timestamp], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(value.iter().map(Item))
+    }
+
+    /// Deserialize a list of `Timestamp`s from an array of RFC 3339 strings.
+    ///
+    /// # Errors
+    ///
+    /// Returns `D::Error` if any element is not a valid RFC 3339 datetime.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<Timestamp>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|s| super::timestamp::deserialize_str(s))
+            .collect::<Result<_, _>>()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// Serde adapter for `Vec<prost_types::Duration>` ↔ array of seconds strings.
+///
+/// Used for `repeated google.protobuf.Duration` fields. Each element uses the
+/// [`duration`] encoding (`["300s", "1.500s"]`).
+///
+/// # Errors
+///
+/// Deserialization fails if any element cannot be parsed as a duration.
+pub mod vec_duration {
+    use prost_types::Duration;
+    use serde::{self, Deserialize, Deserializer, Serialize, Serializer};
+
+    struct Item<'a>(&'a Duration);
+
+    impl Serialize for Item<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::duration::serialize(self.0, serializer)
+        }
+    }
+
+    /// Serialize a list of `Duration`s as an array of seconds strings.
+    ///
+    /// # Errors
+    ///
+    /// Returns `S::Error` if serialization fails.
+    pub fn serialize<S>(value: &[Duration], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(value.iter().map(Item))
+    }
+
+    /// Deserialize a list of `Duration`s from an array of seconds strings.
+    ///
+    /// # Errors
+    ///
+    /// Returns `D::Error` if any element is not a valid duration.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|s| super::duration::deserialize_str(s))
+            .collect::<Result<_, _>>()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// Serde adapter for `Vec<prost_types::FieldMask>` ↔ array of comma-separated paths strings.
+///
+/// Used for `repeated google.protobuf.FieldMask` fields. Each element uses the
+/// [`field_mask`] encoding.
+///
+/// # Errors
+///
+/// Deserialization fails if the input is not an array of strings.
+/// Serialization is infallible.
+pub mod vec_field_mask {
+    use prost_types::FieldMask;
+    use serde::{self, Deserialize, Deserializer, Serialize, Serializer};
+
+    struct Item<'a>(&'a FieldMask);
+
+    impl Serialize for Item<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::field_mask::serialize(self.0, serializer)
+        }
+    }
+
+    /// Serialize a list of `FieldMask`s as an array of comma-separated paths strings.
+    ///
+    /// # Errors
+    ///
+    /// Returns `S::Error` if serialization fails.
+    pub fn serialize<S>(value: &[FieldMask], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(value.iter().map(Item))
+    }
+
+    /// Deserialize a list of `FieldMask`s from an array of comma-separated paths strings.
+    ///
+    /// # Errors
+    ///
+    /// Returns `D::Error` if the input is not an array of strings.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<FieldMask>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|s| super::field_mask::from_str(s))
+            .collect())
+    }
+}
+
+/// Serde adapter for `prost_types::Value` ↔ arbitrary JSON.
+///
+/// Follows the canonical protobuf JSON mapping: `NullValue` → `null`, numbers,
+/// strings and booleans map to their JSON counterparts, `StructValue` → object
+/// and `ListValue` → array. Integral numbers within ±2^53 are written without
+/// a fractional part (`1`, not `1.0`).
+///
+/// # Errors
+///
+/// Serialization fails for `NaN` or infinite numbers, which JSON cannot represent.
+pub mod value {
+    use std::collections::BTreeMap;
+    use std::fmt;
+
+    use prost_types::value::Kind;
+    use prost_types::{ListValue, Struct, Value};
+    use serde::de::{MapAccess, SeqAccess, Visitor};
+    use serde::{self, Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Largest integer magnitude an `f64` represents exactly (2^53).
+    const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_992.0;
+
+    /// Borrowed `Value` with a JSON `Serialize` impl.
+    pub(crate) struct Json<'a>(pub(crate) &'a Value);
+
+    impl Serialize for Json<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match &self.0.kind {
+                None | Some(Kind::NullValue(_)) => serializer.serialize_unit(),
+                Some(Kind::NumberValue(n)) => serialize_number(*n, serializer),
+                Some(Kind::StringValue(s)) => serializer.serialize_str(s),
+                Some(Kind::BoolValue(b)) => serializer.serialize_bool(*b),
+                Some(Kind::StructValue(s)) => serialize_struct(s, serializer),
+                Some(Kind::ListValue(l)) => serializer.collect_seq(l.values.iter().map(Json)),
+            }
+        }
+    }
+
+    #[expect(clippy::cast_possible_truncation)] // guarded: integral and within ±2^53
+    fn serialize_number<S: Serializer>(n: f64, serializer: S) -> Result<S::Ok, S::Error> {
+        if !n.is_finite() {
+            return Err(serde::ser::Error::custom(
+                "google.protobuf.Value number must be finite",
+            ));
+        }
+        if n.fract() == 0.0 && n.abs() <= MAX_SAFE_INTEGER {
+            serializer.serialize_i64(n as i64)
+        } else {
+            serializer.serialize_f64(n)
+        }
+    }
+
+    pub(crate) fn serialize_struct<S: Serializer>(
+        value: &Struct,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(value.fields.iter().map(|(k, v)| (k, Json(v))))
+    }
+
+    /// Owned `Value` with a JSON `Deserialize` impl.
+    pub(crate) struct Owned(pub(crate) Value);
+
+    impl<'de> Deserialize<'de> for Owned {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_any(ValueVisitor).map(Owned)
+        }
+    }
+
+    struct ValueVisitor;
+
+    const fn of(kind: Kind) -> Value {
+        Value { kind: Some(kind) }
+    }
+
+    impl<'de> Visitor<'de> for ValueVisitor {
+        type Value = Value;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("any JSON value")
+        }
+
+        fn visit_unit<E>(self) -> Result<Value, E> {
+            Ok(of(Kind::NullValue(0)))
+        }
+
+        fn visit_none<E>(self) -> Result<Value, E> {
+            Ok(of(Kind::NullValue(0)))
+        }
+
+        fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Value, D::Error> {
+            deserializer.deserialize_any(self)
+        }
+
+        fn visit_bool<E>(self, v: bool) -> Result<Value, E> {
+            Ok(of(Kind::BoolValue(v)))
+        }
+
+        #[expect(clippy::cast_precision_loss)] // proto `Value` numbers are doubles
+        fn visit_i64<E>(self, v: i64) -> Result<Value, E> {
+            Ok(of(Kind::NumberValue(v as f64)))
+        }
+
+        #[expect(clippy::cast_precision_loss)] // proto `Value` numbers are doubles
+        fn visit_u64<E>(self, v: u64) -> Result<Value, E> {
+            Ok(of(Kind::NumberValue(v as f64)))
+        }
+
+        fn visit_f64<E>(self, v: f64) -> Result<Value, E> {
+            Ok(of(Kind::NumberValue(v)))
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Value, E> {
+            Ok(of(Kind::StringValue(v.to_string())))
+        }
+
+        fn visit_string<E>(self, v: String) -> Result<Value, E> {
+            Ok(of(Kind::StringValue(v)))
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+            let mut values = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(Owned(v)) = seq.next_element()? {
+                values.push(v);
+            }
+            Ok(of(Kind::ListValue(ListValue { values })))
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+            let mut fields = BTreeMap::new();
+            while let Some((k, Owned(v))) = map.next_entry::<String, Owned>()? {
+                fields.insert(k, v);
+            }
+            Ok(of(Kind::StructValue(Struct { fields })))
+        }
+    }
+
+    /// Serialize a `Value` as the JSON value it represents.
+    ///
+    /// # Errors
+    ///
+    /// Returns `S::Error` if a number is `NaN` or infinite.
+    pub fn serialize<S>(value: &Value, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Json(value).serialize(serializer)
+    }
+
+    /// Deserialize a `Value` from any JSON value (`null` becomes `NullValue`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `D::Error` if the input is not valid JSON data.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Owned::deserialize(deserializer).map(|Owned(v)| v)
+    }
+}
+
+/// Serde adapter for `Option<prost_types::Value>` ↔ arbitrary JSON.
+///
+/// `None` serializes as `null`. A JSON `null` deserializes to
+/// `Some(NullValue)` — per the proto3 JSON mapping, `null` is a real value for
+/// `google.protobuf.Value` — while an absent field (with `default`) stays `None`.
+///
+/// # Errors
+///
+/// See [`value`].
+pub mod opt_value {
+    use prost_types::Value;
+    use serde::{Deserializer, Serializer};
+
+    /// Serialize an optional `Value` as JSON (`None` → `null`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `S::Error` if a number is `NaN` or infinite.
+    pub fn serialize<S>(value: &Option<Value>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match value {
+            Some(v) => super::value::serialize(v, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize a present `Value` from any JSON value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `D::Error` if the input is not valid JSON data.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Value>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::value::deserialize(deserializer).map(Some)
+    }
+}
+
+/// Serde adapter for `prost_types::Struct` ↔ JSON object.
+///
+/// Named with a trailing underscore because `struct` is a keyword.
+///
+/// # Errors
+///
+/// Serialization fails for `NaN` or infinite numbers.
+/// Deserialization fails if the input is not a JSON object.
+pub mod struct_ {
+    use prost_types::Struct;
+    use prost_types::value::Kind;
+    use serde::{self, Deserialize, Deserializer, Serializer};
+
+    /// Serialize a `Struct` as a JSON object.
+    ///
+    /// # Errors
+    ///
+    /// Returns `S::Error` if a number is `NaN` or infinite.
+    pub fn serialize<S>(value: &Struct, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::value::serialize_struct(value, serializer)
+    }
+
+    /// Deserialize a `Struct` from a JSON object.
+    ///
+    /// # Errors
+    ///
+    /// Returns `D::Error` if the input is not a JSON object.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Struct, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        from_value(super::value::Owned::deserialize(deserializer)?.0)
+            .map_err(serde::de::Error::custom)
+    }
+
+    /// Unwrap a deserialized `Value` that must be a JSON object.
+    pub(crate) fn from_value(value: prost_types::Value) -> Result<Struct, &'static str> {
+        match value.kind {
+            Some(Kind::StructValue(s)) => Ok(s),
+            _ => Err("expected a JSON object for google.protobuf.Struct"),
+        }
+    }
+}
+
+/// Serde adapter for `Option<prost_types::Struct>` ↔ JSON object or `null`.
+///
+/// # Errors
+///
+/// See [`struct_`].
+pub mod opt_struct {
+    use prost_types::Struct;
+    use serde::{self, Deserialize, Deserializer, Serializer};
+
+    /// Serialize an optional `Struct` as a JSON object (`None` → `null`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `S::Error` if a number is `NaN` or infinite.
+    pub fn serialize<S>(value: &Option<Struct>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match value {
+            Some(s) => super::struct_::serialize(s, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize an optional `Struct` from a JSON object or `null`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `D::Error` if the input is neither a JSON object nor `null`.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Struct>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<super::value::Owned>::deserialize(deserializer)?
+            .map(|owned| super::struct_::from_value(owned.0))
+            .transpose()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// Serde adapter for `Option<i64>` fields from `google.protobuf.Int64Value`.
+///
+/// prost maps wrapper types to `Option<primitive>`, which serde already
+/// handles for 32-bit, bool, float and string wrappers. 64-bit wrappers are
+/// encoded as decimal strings in proto3 JSON (`"42"`), which this adapter
+/// writes; numbers and strings are both accepted on input. `None` ↔ `null`;
+/// add `skip_serializing_if = "Option::is_none"` to omit it instead.
+///
+/// # Errors
+///
+/// Deserialization fails if the input is not an integer or integer string in range.
+pub mod opt_int64_value {
+    use serde::{Deserializer, Serializer};
+
+    /// Serialize an optional `i64` as a decimal string (`None` → `null`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `S::Error` if serialization fails.
+    pub fn serialize<S>(value: &Option<i64>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match value {
+            Some(v) => serializer.collect_str(v),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize an optional `i64` from a number, decimal string or `null`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `D::Error` if the input is not an `i64` in number or string form.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<i64>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::int64_wrapper::deserialize(deserializer)
+    }
+}
+
+/// Serde adapter for `Option<u64>` fields from `google.protobuf.UInt64Value`.
+///
+/// Same encoding as [`opt_int64_value`]: a decimal string on the wire,
+/// numbers accepted on input, `None` ↔ `null`.
+///
+/// # Errors
+///
+/// Deserialization fails if the input is not an unsigned integer or integer string in range.
+pub mod opt_uint64_value {
+    use serde::{Deserializer, Serializer};
+
+    /// Serialize an optional `u64` as a decimal string (`None` → `null`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `S::Error` if serialization fails.
+    pub fn serialize<S>(value: &Option<u64>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match value {
+            Some(v) => serializer.collect_str(v),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize an optional `u64` from a number, decimal string or `null`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `D::Error` if the input is not a `u64` in number or string form.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::int64_wrapper::deserialize(deserializer)
+    }
+}
+
+/// Shared number-or-string deserializer for the 64-bit wrapper adapters.
+mod int64_wrapper {
+    use std::fmt::{self, Display};
+    use std::marker::PhantomData;
+    use std::str::FromStr;
+
+    use serde::Deserializer;
+    use serde::de::{Error, Visitor};
+
+    struct WrapperVisitor<T>(PhantomData<T>);
+
+    impl<'de, T> Visitor<'de> for WrapperVisitor<T>
+    where
+        T: FromStr + TryFrom<i64> + TryFrom<u64>,
+        <T as FromStr>::Err: Display,
+        <T as TryFrom<i64>>::Error: Display,
+        <T as TryFrom<u64>>::Error: Display,
+    {
+        type Value = Option<T>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a 64-bit integer, integer string, or null")
+        }
+
+        fn visit_none<E: Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<Self::Value, D::Error> {
+            deserializer.deserialize_any(self)
+        }
+
+        fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+            T::try_from(v).map(Some).map_err(E::custom)
+        }
+
+        fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+            T::try_from(v).map(Some).map_err(E::custom)
+        }
+
+        fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+            v.parse().map(Some).map_err(E::custom)
+        }
+    }
+
+    pub(super) fn deserialize<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: FromStr + TryFrom<i64> + TryFrom<u64>,
+        <T as FromStr>::Err: Display,
+        <T as TryFrom<i64>>::Error: Display,
+        <T as TryFrom<u64>>::Error: Display,
+    {
+        deserializer.deserialize_option(WrapperVisitor(PhantomData))
+    }
+}
+
+/// Deserializer for repeated scalar query parameters (`?tags=a,b,c`).
+///
+/// Axum's `Query` extractor cannot collect repeated keys into a `Vec`, so
+/// generated REST handlers accept repeated fields as a single comma-separated
+/// value instead, the same encoding used for `FieldMask`. An empty value yields
+/// an empty list.
+///
+/// ```ignore
+/// #[derive(serde::Deserialize)]
+/// struct ListQuery {
+///     #[serde(default, deserialize_with = "tonic_rest::serde::comma_separated::deserialize")]
+///     ids: Vec<u64>,
+/// }
+/// ```
+pub mod comma_separated {
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    use serde::{Deserialize, Deserializer, de::Error as _};
+
+    /// Deserialize a comma-separated string into a list of `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `D::Error` if the input is not a string or any element fails to parse.
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: FromStr,
+        T::Err: Display,
+    {
+        let s = String::deserialize(deserializer)?;
+        if s.is_empty() {
+            return Ok(Vec::new());
+        }
+        s.split(',')
+            .map(|item| item.parse().map_err(D::Error::custom))
+            .collect()
+    }
+}
+
+/// Enum value recasing for [`define_enum_serde`]; not public API.
+#[doc(hidden)]
+pub mod enum_case {
+    /// `partially_healthy` → `partiallyHealthy`.
+    #[must_use]
+    pub fn snake_to_camel(s: &str) -> String {
+        let mut out = String::with_capacity(s.len());
+        let mut upper_next = false;
+        for c in s.chars() {
+            if c == '_' {
+                upper_next = true;
+            } else if upper_next {
+                out.push(c.to_ascii_uppercase());
+                upper_next = false;
+            } else {
+                out.push(c);
+            }
+        }
+        out
+    }
+
+    /// `partiallyHealthy` → `PARTIALLY_HEALTHY`.
+    #[must_use]
+    pub fn camel_to_screaming(s: &str) -> String {
+        let mut out = String::with_capacity(s.len() + 4);
+        for (i, c) in s.chars().enumerate() {
+            if c.is_ascii_uppercase() && i > 0 {
+                out.push('_');
+            }
+            out.push(c.to_ascii_uppercase());
+        }
+        out
+    }
+}
+
 /// Generate `#[serde(with)]` modules for proto3 enum fields (`i32` in prost).
 ///
 /// Serializes as the proto enum name string (e.g., `"USER_ROLE_ADMIN"`) following
@@ -481,6 +1141,9 @@ pub mod field_mask {
 /// With an optional prefix, strips the prefix and lowercases for REST-friendly output:
 /// `define_enum_serde!(health_status, HealthStatus, "HEALTH_STATUS_")` →
 /// `"healthy"` / `"unhealthy"` instead of `"HEALTH_STATUS_HEALTHY"`.
+/// A trailing `upper` or `camel` keeps the prefix stripping but picks another
+/// casing: `"PARTIALLY_HEALTHY"` or `"partiallyHealthy"`. These match the
+/// `upper` / `camel` `enum_case` of `tonic-rest-openapi`.
 ///
 /// For each invocation, three sub-modules are created inside `{name}`:
 /// - `{name}`            — for `i32` fields (`#[serde(with = "serde_wkt::user_role")]`)
@@ -527,8 +1190,20 @@ pub mod field_mask {
 /// }
 /// ```
 ///
-/// Deserialization accepts both the wire format and the original proto name,
-/// as well as raw integers for forward compatibility.
+/// Other casings of the stripped name:
+///
+/// ```ignore
+/// mod serde_wkt {
+///     // "PARTIALLY_HEALTHY"
+///     tonic_rest::define_enum_serde!(health_status, crate::HealthStatus, "HEALTH_STATUS_", upper);
+///     // "partiallyHealthy"
+///     tonic_rest::define_enum_serde!(health_state, crate::HealthStatus, "HEALTH_STATUS_", camel);
+/// }
+/// ```
+///
+/// Deserialization accepts the wire format, the original proto name (in any
+/// case, e.g. `"ACTIVE"` or `"active"`), and raw integers — which the proto3
+/// JSON mapping permits on input and which keep unknown values forward compatible.
 #[macro_export]
 macro_rules! define_enum_serde {
     ($name:ident, $enum_type:ty) => {
@@ -542,6 +1217,18 @@ macro_rules! define_enum_serde {
                 format!("{}{}", $prefix, upper)
             });
     };
+    ($name:ident, $enum_type:ty, $prefix:literal, upper) => {
+        $crate::define_enum_serde!(@impl $name, $enum_type,
+            |s: &str| s.strip_prefix($prefix).unwrap_or(s).to_string(),
+            |s: &str| format!("{}{}", $prefix, s.to_uppercase()));
+    };
+    ($name:ident, $enum_type:ty, $prefix:literal, camel) => {
+        $crate::define_enum_serde!(@impl $name, $enum_type,
+            |s: &str| $crate::serde::enum_case::snake_to_camel(
+                &s.strip_prefix($prefix).unwrap_or(s).to_lowercase(),
+            ),
+            |s: &str| format!("{}{}", $prefix, $crate::serde::enum_case::camel_to_screaming(s)));
+    };
     (@impl $name:ident, $enum_type:ty, $to_wire:expr, $from_wire:expr) => {
         #[allow(clippy::missing_errors_doc)]
         pub mod $name {
@@ -580,6 +1267,8 @@ macro_rules! define_enum_serde {
                         let from_wire: fn(&str) -> String = $from_wire;
                         let canonical = from_wire(v);
                         <$enum_type>::from_str_name(&canonical)
+                            // Lowercase spelling of an unprefixed name (`"active"`)
+                            .or_else(|| <$enum_type>::from_str_name(&canonical.to_uppercase()))
                             .map(|e| e as i32)
                             .ok_or_else(|| {
                                 E::custom(
@@ -728,6 +1417,66 @@ macro_rules! define_enum_serde {
     };
 }
 
+/// Generate a `#[serde(with)]` module for a message-style wrapper type.
+///
+/// prost maps `google.protobuf.*Value` wrappers to `Option<primitive>` by
+/// default, but types generated with `compile_well_known_types()` — or
+/// project-specific wrappers such as `message UserId { string value = 1; }` —
+/// are structs with a single `value` field. Per the proto3 JSON mapping a
+/// wrapper is written as its bare scalar, so `Some(Int32Value { value: 42 })`
+/// serializes as `42` and `None` as `null`.
+///
+/// The generated `{name}` module handles `Option<Wrapper>` fields. Pair it
+/// with `skip_serializing_if = "Option::is_none"` to omit absent wrappers,
+/// which keeps "unset" distinguishable from a zero value in update requests.
+///
+/// # Examples
+///
+/// ```ignore
+/// // Given a wrapper message:
+/// // pub struct Int32Value { pub value: i32 }
+///
+/// mod serde_wkt {
+///     tonic_rest::define_wrapper_serde!(int32_value, crate::Int32Value, i32);
+/// }
+///
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct UpdateLimits {
+///     #[serde(with = "serde_wkt::int32_value", default, skip_serializing_if = "Option::is_none")]
+///     max_sessions: Option<Int32Value>,   // serializes as 42, or is omitted
+/// }
+/// ```
+#[macro_export]
+macro_rules! define_wrapper_serde {
+    ($name:ident, $wrapper:ty, $inner:ty) => {
+        #[allow(clippy::missing_errors_doc, clippy::ref_option)]
+        pub mod $name {
+            use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+            type Wrapper = $wrapper;
+
+            /// Serialize `Option<Wrapper>` → bare scalar or `null`.
+            pub fn serialize<S>(value: &Option<Wrapper>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                match value {
+                    Some(w) => w.value.serialize(serializer),
+                    None => serializer.serialize_none(),
+                }
+            }
+
+            /// Deserialize bare scalar or `null` → `Option<Wrapper>`.
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Wrapper>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                Ok(Option::<$inner>::deserialize(deserializer)?.map(|value| Wrapper { value }))
+            }
+        }
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use prost_types::{Duration, FieldMask, Timestamp};
@@ -823,6 +1572,58 @@ mod tests {
         "HEALTH_STATUS_"
     );
 
+    // --- Casing variants ---
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    #[repr(i32)]
+    enum DeliveryState {
+        Unspecified = 0,
+        PartiallyDelivered = 1,
+    }
+
+    impl DeliveryState {
+        #[expect(clippy::trivially_copy_pass_by_ref)]
+        fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Unspecified => "DELIVERY_STATE_UNSPECIFIED",
+                Self::PartiallyDelivered => "DELIVERY_STATE_PARTIALLY_DELIVERED",
+            }
+        }
+
+        fn from_str_name(s: &str) -> Option<Self> {
+            match s {
+                "DELIVERY_STATE_UNSPECIFIED" => Some(Self::Unspecified),
+                "DELIVERY_STATE_PARTIALLY_DELIVERED" => Some(Self::PartiallyDelivered),
+                _ => None,
+            }
+        }
+    }
+
+    impl TryFrom<i32> for DeliveryState {
+        type Error = &'static str;
+        fn try_from(value: i32) -> Result<Self, Self::Error> {
+            match value {
+                0 => Ok(Self::Unspecified),
+                1 => Ok(Self::PartiallyDelivered),
+                _ => Err("unknown"),
+            }
+        }
+    }
+
+    define_enum_serde!(
+        delivery_upper,
+        crate::serde::tests::DeliveryState,
+        "DELIVERY_STATE_",
+        upper
+    );
+
+    define_enum_serde!(
+        delivery_camel,
+        crate::serde::tests::DeliveryState,
+        "DELIVERY_STATE_",
+        camel
+    );
+
     #[derive(Serialize, Deserialize, Debug)]
     struct TsRequired {
         #[serde(with = "super::timestamp")]
@@ -843,6 +1644,202 @@ mod tests {
         assert_eq!(back.ts.seconds, 1_736_934_600);
     }
 
+    #[derive(Serialize, Deserialize, Debug, Default)]
+    struct TsList {
+        #[serde(with = "super::vec_timestamp", default)]
+        event_times: Vec<Timestamp>,
+    }
+
+    #[test]
+    fn vec_timestamp_serializes_as_rfc3339_array() {
+        let w = TsList {
+            event_times: vec![
+                Timestamp {
+                    seconds: 1_736_934_600,
+                    nanos: 0,
+                },
+                Timestamp {
+                    seconds: 1_736_938_200,
+                    nanos: 500_000_000,
+                },
+            ],
+        };
+        let json = serde_json::to_value(&w).unwrap();
+        assert_eq!(
+            json["event_times"],
+            serde_json::json!(["2025-01-15T09:50:00+00:00", "2025-01-15T10:50:00.500+00:00"])
+        );
+        let back: TsList = serde_json::from_value(json).unwrap();
+        assert_eq!(back.event_times, w.event_times);
+
+        let empty: TsList = serde_json::from_str("{}").unwrap();
+        assert!(empty.event_times.is_empty());
+        assert!(serde_json::from_str::<TsList>(r#"{"event_times":["nope"]}"#).is_err());
+    }
+
+    #[derive(Serialize, Deserialize, Debug)]
+    struct DurFmList {
+        #[serde(with = "super::vec_duration")]
+        durations: Vec<Duration>,
+        #[serde(with = "super::vec_field_mask")]
+        masks: Vec<FieldMask>,
+    }
+
+    #[test]
+    fn vec_duration_and_field_mask_round_trip() {
+        let w = DurFmList {
+            durations: vec![Duration {
+                seconds: 300,
+                nanos: 0,
+            }],
+            masks: vec![FieldMask {
+                paths: vec!["display_name".into(), "email".into()],
+            }],
+        };
+        let json = serde_json::to_value(&w).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"durations": ["300s"], "masks": ["displayName,email"]})
+        );
+        let back: DurFmList = serde_json::from_value(json).unwrap();
+        assert_eq!(back.durations, w.durations);
+        assert_eq!(back.masks, w.masks);
+    }
+
+    #[derive(Serialize, Deserialize, Debug)]
+    struct Dynamic {
+        #[serde(with = "super::opt_value", default)]
+        value: Option<prost_types::Value>,
+        #[serde(with = "super::opt_struct", default)]
+        attrs: Option<prost_types::Struct>,
+    }
+
+    fn dynamic_round_trip(json: serde_json::Value) -> serde_json::Value {
+        let parsed: Dynamic = serde_json::from_value(json).unwrap();
+        serde_json::to_value(&parsed).unwrap()
+    }
+
+    #[test]
+    fn value_null_round_trips_as_null_value() {
+        let parsed: Dynamic = serde_json::from_value(serde_json::json!({"value": null})).unwrap();
+        assert_eq!(
+            parsed.value.as_ref().unwrap().kind,
+            Some(prost_types::value::Kind::NullValue(0))
+        );
+        assert_eq!(
+            serde_json::to_value(&parsed).unwrap(),
+            serde_json::json!({"value": null, "attrs": null})
+        );
+
+        let absent: Dynamic = serde_json::from_str("{}").unwrap();
+        assert!(absent.value.is_none());
+    }
+
+    #[test]
+    fn value_numbers_round_trip() {
+        let json =
+            serde_json::json!({"value": [1, -2, 1.5, 9_007_199_254_740_992_u64], "attrs": null});
+        assert_eq!(dynamic_round_trip(json.clone()), json);
+
+        let nan = prost_types::Value {
+            kind: Some(prost_types::value::Kind::NumberValue(f64::NAN)),
+        };
+        assert!(serde_json::to_value(super::value::Json(&nan)).is_err());
+    }
+
+    #[test]
+    fn struct_nested_object_and_array_round_trip() {
+        let json = serde_json::json!({
+            "value": {"name": "Ada", "tags": ["a", true, null], "meta": {"depth": 2}},
+            "attrs": {"enabled": false, "limits": {"max": 10}, "ids": [1, 2]}
+        });
+        assert_eq!(dynamic_round_trip(json.clone()), json);
+
+        let parsed: Dynamic = serde_json::from_value(json).unwrap();
+        let attrs = parsed.attrs.unwrap();
+        assert_eq!(attrs.fields.len(), 3);
+        assert!(matches!(
+            attrs.fields["limits"].kind,
+            Some(prost_types::value::Kind::StructValue(_))
+        ));
+    }
+
+    #[test]
+    fn struct_rejects_non_object() {
+        let err = serde_json::from_value::<Dynamic>(serde_json::json!({"attrs": [1]}));
+        assert!(err.is_err());
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    pub(super) struct Int32Value {
+        value: i32,
+    }
+
+    define_wrapper_serde!(int32_value, crate::serde::tests::Int32Value, i32);
+
+    #[derive(Serialize, Deserialize, Debug)]
+    struct Wrappers {
+        #[serde(with = "int32_value", default)]
+        limit: Option<Int32Value>,
+        #[serde(with = "int32_value", default, skip_serializing_if = "Option::is_none")]
+        quota: Option<Int32Value>,
+        #[serde(with = "super::opt_int64_value", default)]
+        big: Option<i64>,
+        #[serde(with = "super::opt_uint64_value", default)]
+        ubig: Option<u64>,
+    }
+
+    #[test]
+    fn wrapper_some_serializes_as_bare_scalar() {
+        let w = Wrappers {
+            limit: Some(Int32Value { value: 42 }),
+            quota: Some(Int32Value { value: 0 }),
+            big: Some(i64::MIN),
+            ubig: Some(u64::MAX),
+        };
+        let json = serde_json::to_value(&w).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "limit": 42,
+                "quota": 0,
+                "big": "-9223372036854775808",
+                "ubig": "18446744073709551615"
+            })
+        );
+        let back: Wrappers = serde_json::from_value(json).unwrap();
+        assert_eq!(back.limit, Some(Int32Value { value: 42 }));
+        assert_eq!(back.quota, Some(Int32Value { value: 0 }));
+        assert_eq!(back.big, Some(i64::MIN));
+        assert_eq!(back.ubig, Some(u64::MAX));
+    }
+
+    #[test]
+    fn wrapper_none_is_null_or_omitted() {
+        let w = Wrappers {
+            limit: None,
+            quota: None,
+            big: None,
+            ubig: None,
+        };
+        let json = serde_json::to_value(&w).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"limit": null, "big": null, "ubig": null})
+        );
+
+        let back: Wrappers = serde_json::from_str("{}").unwrap();
+        assert!(back.limit.is_none() && back.quota.is_none() && back.big.is_none());
+    }
+
+    #[test]
+    fn int64_wrapper_accepts_numbers_and_rejects_out_of_range() {
+        let w: Wrappers = serde_json::from_str(r#"{"big": 7, "ubig": "8"}"#).unwrap();
+        assert_eq!((w.big, w.ubig), (Some(7), Some(8)));
+        assert!(serde_json::from_str::<Wrappers>(r#"{"ubig": -1}"#).is_err());
+        assert!(serde_json::from_str::<Wrappers>(r#"{"big": "x"}"#).is_err());
+    }
+
     #[derive(Serialize, Deserialize, Debug)]
     struct TsWrapper {
         #[serde(with = "super::opt_timestamp", default)]
@@ -1056,6 +2053,28 @@ mod tests {
         assert_eq!(w.status, 2);
     }
 
+    #[test]
+    fn enum_deserialize_number_name_and_lowercase_agree() {
+        for input in [
+            r#"{"status":1}"#,
+            r#"{"status":"ACTIVE"}"#,
+            r#"{"status":"active"}"#,
+        ] {
+            let w: EnumWrapper = serde_json::from_str(input).unwrap();
+            assert_eq!(w.status, TestStatus::Active as i32, "{input}");
+        }
+        for input in [
+            r#"{"status":1}"#,
+            r#"{"status":"HEALTH_STATUS_HEALTHY"}"#,
+            r#"{"status":"HEALTHY"}"#,
+            r#"{"status":"healthy"}"#,
+        ] {
+            let w: HealthWrapper = serde_json::from_str(input).unwrap();
+            assert_eq!(w.status, HealthStatus::Healthy as i32, "{input}");
+        }
+        assert!(serde_json::from_str::<EnumWrapper>(r#"{"status":"activ"}"#).is_err());
+    }
+
     #[test]
     fn optional_enum_none() {
         let w: OptEnumWrapper = serde_json::from_str(r#"{"status":null}"#).unwrap();
@@ -1187,4 +2206,97 @@ mod tests {
         let back: RepHealthWrapper = serde_json::from_str(&json).unwrap();
         assert_eq!(back.statuses, vec![1, 2]);
     }
+
+    // --- Casing variant tests ---
+
+    #[derive(Serialize, Deserialize, Debug)]
+    struct UpperDeliveryWrapper {
+        #[serde(with = "delivery_upper")]
+        state: i32,
+        #[serde(with = "delivery_upper::optional", default)]
+        previous: Option<i32>,
+        #[serde(with = "delivery_upper::repeated")]
+        history: Vec<i32>,
+    }
+
+    #[derive(Serialize, Deserialize, Debug)]
+    struct CamelDeliveryWrapper {
+        #[serde(with = "delivery_camel")]
+        state: i32,
+        #[serde(with = "delivery_camel::optional", default)]
+        previous: Option<i32>,
+        #[serde(with = "delivery_camel::repeated")]
+        history: Vec<i32>,
+    }
+
+    #[test]
+    fn upper_case_enum_round_trip() {
+        let w = UpperDeliveryWrapper {
+            state: 1,
+            previous: Some(0),
+            history: vec![0, 1],
+        };
+        let json = serde_json::to_string(&w).unwrap();
+        assert_eq!(
+            json,
+            r#"{"state":"PARTIALLY_DELIVERED","previous":"UNSPECIFIED","history":["UNSPECIFIED","PARTIALLY_DELIVERED"]}"#
+        );
+        let back: UpperDeliveryWrapper = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.state, 1);
+        assert_eq!(back.previous, Some(0));
+        assert_eq!(back.history, vec![0, 1]);
+    }
+
+    #[test]
+    fn camel_case_enum_round_trip() {
+        let w = CamelDeliveryWrapper {
+            state: 1,
+            previous: None,
+            history: vec![1],
+        };
+        let json = serde_json::to_string(&w).unwrap();
+        assert_eq!(
+            json,
+            r#"{"state":"partiallyDelivered","previous":null,"history":["partiallyDelivered"]}"#
+        );
+        let back: CamelDeliveryWrapper = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.state, 1);
+        assert_eq!(back.previous, None);
+        assert_eq!(back.history, vec![1]);
+    }
+
+    #[test]
+    fn camel_case_enum_accepts_proto_name() {
+        let w: CamelDeliveryWrapper =
+            serde_json::from_str(r#"{"state":"DELIVERY_STATE_PARTIALLY_DELIVERED","history":[]}"#)
+                .unwrap();
+        assert_eq!(w.state, 1);
+    }
+
+    // --- Comma-separated query tests ---
+
+    #[derive(Deserialize)]
+    struct CommaQuery {
+        #[serde(default, deserialize_with = "super::comma_separated::deserialize")]
+        ids: Vec<u64>,
+    }
+
+    #[test]
+    fn comma_separated_parses_list() {
+        let q: CommaQuery = serde_json::from_str(r#"{"ids":"1,2,3"}"#).unwrap();
+        assert_eq!(q.ids, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn comma_separated_missing_and_empty() {
+        let q: CommaQuery = serde_json::from_str("{}").unwrap();
+        assert!(q.ids.is_empty());
+        let q: CommaQuery = serde_json::from_str(r#"{"ids":""}"#).unwrap();
+        assert!(q.ids.is_empty());
+    }
+
+    #[test]
+    fn comma_separated_rejects_bad_element() {
+        assert!(serde_json::from_str::<CommaQuery>(r#"{"ids":"1,x"}"#).is_err());
+    }
 }
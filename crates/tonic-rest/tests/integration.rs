@@ -16,13 +16,13 @@ use axum::body::Body;
 use axum::extract::{Extension, Json, Query, State};
 use axum::http::{HeaderMap, Request, StatusCode};
 use axum::response::sse::{Event, Sse};
-use axum::routing::{get, post};
+use axum::routing::{get, head, post};
 use futures::stream::{self, Stream, StreamExt};
 use http_body_util::BodyExt;
 use serde::{Deserialize, Serialize};
 use tower::ServiceExt;
 
-use tonic_rest::{RestError, build_tonic_request, sse_error_event};
+use tonic_rest::{RestError, build_tonic_request, json_not_found_fallback, sse_error_event};
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 struct TestRequest {
@@ -356,3 +356,157 @@ async fn json_endpoint_without_auth_extension() {
     // Auth should be empty string (our handler defaults to "").
     assert_eq!(json["auth"], "");
 }
+
+#[tokio::test]
+async fn unmatched_route_returns_json_not_found() {
+    let response = app()
+        .fallback(json_not_found_fallback())
+        .oneshot(
+            Request::builder()
+                .uri("/no/such/route")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let ct = response
+        .headers()
+        .get("content-type")
+        .unwrap()
+        .to_str()
+        .unwrap();
+    assert!(ct.contains("application/json"), "got content-type: {ct}");
+
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["error"]["code"], 404);
+    assert_eq!(json["error"]["status"], "NOT_FOUND");
+    assert_eq!(json["error"]["message"], "no route for GET /no/such/route");
+}
+
+/// Shape of the generated `operation_id_for` lookup.
+fn operation_id_for(method: &axum::http::Method, matched_path: &str) -> Option<&'static str> {
+    match (method.as_str(), matched_path) {
+        ("POST", "/items") => Some("ItemService_CreateItem"),
+        ("GET", "/items/{item_id}") => Some("ItemService_GetItem"),
+        _ => None,
+    }
+}
+
+/// Middleware labelling responses with the operation ID of the matched route.
+async fn label_operation(
+    request: Request<Body>,
+    next: axum::middleware::Next,
+) -> axum::response::Response {
+    let operation_id = request
+        .extensions()
+        .get::<axum::extract::MatchedPath>()
+        .and_then(|path| operation_id_for(request.method(), path.as_str()));
+    let mut response = next.run(request).await;
+    if let Some(operation_id) = operation_id {
+        response
+            .headers_mut()
+            .insert("x-operation-id", operation_id.parse().unwrap());
+    }
+    response
+}
+
+#[tokio::test]
+async fn matched_path_resolves_operation_id() {
+    let app = Router::new()
+        .route("/items/{item_id}", get(|| async { "item" }))
+        .route_layer(axum::middleware::from_fn(label_operation));
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/items/abc-123")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["x-operation-id"], "ItemService_GetItem");
+}
+
+/// `auto_head` registers `head(handler)` next to `get(handler)` on the same
+/// path; Axum accepts the pair and strips the body from HEAD responses.
+#[tokio::test]
+async fn auto_head_route_shares_get_handler_without_body() {
+    async fn get_item() -> Json<TestResponse> {
+        Json(TestResponse {
+            id: "item-1".to_string(),
+            name: "widget".to_string(),
+        })
+    }
+
+    let app = Router::new()
+        .route("/items/{item_id}", get(get_item))
+        .route("/items/{item_id}", head(get_item));
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("HEAD")
+                .uri("/items/item-1")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "application/json");
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    assert!(body.is_empty());
+}
+
+/// `cors_preflight` adds an `OPTIONS` closure alongside the method routes of
+/// a path; the preflight answers with the allowed methods and no body.
+#[tokio::test]
+async fn cors_preflight_route_lists_allowed_methods() {
+    async fn list_items() -> &'static str {
+        "[]"
+    }
+
+    let app = Router::new()
+        .route("/items", get(list_items))
+        .route("/items", post(list_items))
+        .route(
+            "/items",
+            axum::routing::options(|| async {
+                (
+                    StatusCode::NO_CONTENT,
+                    [
+                        (axum::http::header::ALLOW, "GET, POST, OPTIONS"),
+                        (
+                            axum::http::header::ACCESS_CONTROL_ALLOW_METHODS,
+                            "GET, POST, OPTIONS",
+                        ),
+                    ],
+                )
+            }),
+        );
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("OPTIONS")
+                .uri("/items")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert_eq!(response.headers()["allow"], "GET, POST, OPTIONS");
+    assert_eq!(
+        response.headers()["access-control-allow-methods"],
+        "GET, POST, OPTIONS"
+    );
+}